@@ -58,6 +58,8 @@ impl Sandbox for BadgeExample {
                                         .color(style::colors::SNOW)
                                         .size(BADGE_TEXT_SIZE),
                                 )
+                                .padding([4, 8])
+                                .min_width(32)
                                 .style(predefined_style(i)),
                             ),
                     )
@@ -2,7 +2,7 @@ use iced::{
     button, Align, Button, Color, Container, Element, Length, Row, Sandbox, Settings, Text,
 };
 
-use iced_aw::color_picker::{self, ColorPicker};
+use iced_aw::color_picker::{self, ColorPicker, ComponentMode};
 
 fn main() -> iced::Result {
     ColorPickerExample::run(Settings::default())
@@ -14,10 +14,12 @@ enum Message {
     ChooseColor,
     SubmitColor(Color),
     CancelColor,
+    PreviewColor(Color),
 }
 
 struct ColorPickerExample {
     color: Color,
+    preview: Option<Color>,
     state: color_picker::State,
     button_state: button::State,
 }
@@ -28,6 +30,7 @@ impl Sandbox for ColorPickerExample {
     fn new() -> Self {
         ColorPickerExample {
             color: Color::default(),
+            preview: None,
             state: color_picker::State::new(),
             button_state: button::State::new(),
         }
@@ -45,11 +48,16 @@ impl Sandbox for ColorPickerExample {
             }
             Message::SubmitColor(color) => {
                 self.color = color;
+                self.preview = None;
                 self.state.show(false);
             }
             Message::CancelColor => {
+                self.preview = None;
                 self.state.show(false);
             }
+            Message::PreviewColor(color) => {
+                self.preview = Some(color);
+            }
         }
     }
 
@@ -62,13 +70,18 @@ impl Sandbox for ColorPickerExample {
             but,
             Message::CancelColor,
             Message::SubmitColor,
-        );
+        )
+        .component_inputs(ComponentMode::Hsv)
+        .on_change(Message::PreviewColor);
+
+        // The text is recolored live while the color is picked.
+        let shown = self.preview.unwrap_or(self.color);
 
         let row = Row::new()
             .align_items(Align::Center)
             .spacing(10)
             .push(datepicker)
-            .push(Text::new(format!("Color: {:?}", self.color)));
+            .push(Text::new(format!("Color: {:?}", shown)).color(shown));
 
         Container::new(row)
             .center_x()
@@ -1,3 +1,4 @@
+use chrono::{Datelike, Local, NaiveDate, Weekday};
 use iced::{button, Align, Button, Container, Element, Length, Row, Sandbox, Settings, Text};
 
 use iced_aw::date_picker::{self, Date, DatePicker};
@@ -18,6 +19,7 @@ struct DatePickerExample {
     date: Date,
     state: date_picker::State,
     button_state: button::State,
+    open_button_state: button::State,
 }
 
 impl Sandbox for DatePickerExample {
@@ -25,9 +27,10 @@ impl Sandbox for DatePickerExample {
 
     fn new() -> Self {
         DatePickerExample {
-            date: Date::default(),
+            date: Local::today().naive_local().into(),
             state: date_picker::State::now(),
             button_state: button::State::new(),
+            open_button_state: button::State::new(),
         }
     }
 
@@ -60,13 +63,25 @@ impl Sandbox for DatePickerExample {
             but,
             Message::CancelDate,
             Message::SubmitDate,
-        );
+        )
+        .date(self.date)
+        // Sundays can't be selected
+        .day_is_enabled(Box::new(|date| {
+            NaiveDate::from(date).weekday() != Weekday::Sun
+        }))
+        .today(Local::today().naive_local().into())
+        .show_week_numbers(true);
+
+        // The picker is opened by its state, so any other button can open it too.
+        let open = Button::new(&mut self.open_button_state, Text::new("Pick a date"))
+            .on_press(Message::ChooseDate);
 
         let row = Row::new()
             .align_items(Align::Center)
             .spacing(10)
             .push(datepicker)
-            .push(Text::new(format!("Date: {}", self.date,)));
+            .push(Text::new(format!("Date: {}", self.date,)))
+            .push(open);
 
         Container::new(row)
             .center_x()
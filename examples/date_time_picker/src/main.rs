@@ -0,0 +1,84 @@
+use chrono::{Duration, Local};
+use iced::{button, Align, Button, Container, Element, Length, Row, Sandbox, Settings, Text};
+
+use iced_aw::date_time_picker::{self, DateTime, DateTimePicker};
+
+fn main() -> iced::Result {
+    DateTimePickerExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+#[allow(clippy::enum_variant_names)]
+enum Message {
+    ChooseDateTime,
+    SubmitDateTime(DateTime),
+    CancelDateTime,
+}
+
+struct DateTimePickerExample {
+    date_time: DateTime,
+    state: date_time_picker::State,
+    button_state: button::State,
+}
+
+impl Sandbox for DateTimePickerExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        DateTimePickerExample {
+            date_time: DateTime::from_naive(Local::now().naive_local(), false, true),
+            state: date_time_picker::State::now(),
+            button_state: button::State::new(),
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("DateTimePicker example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::ChooseDateTime => {
+                self.state.set_date_time(self.date_time);
+                self.state.show(true);
+            }
+            Message::SubmitDateTime(date_time) => {
+                self.date_time = date_time;
+                self.state.show(false);
+            }
+            Message::CancelDateTime => {
+                self.state.show(false);
+            }
+        }
+    }
+
+    fn view(&mut self) -> Element<'_, Self::Message> {
+        let but = Button::new(&mut self.button_state, Text::new("Schedule event"))
+            .on_press(Message::ChooseDateTime);
+
+        // Events can only be scheduled within the next 90 days.
+        let now = Local::now().naive_local();
+        let datetimepicker = DateTimePicker::new(
+            &mut self.state,
+            but,
+            Message::CancelDateTime,
+            Message::SubmitDateTime,
+        )
+        .use_24h()
+        .min(DateTime::from_naive(now, false, true))
+        .max(DateTime::from_naive(now + Duration::days(90), false, true));
+
+        let row = Row::new()
+            .align_items(Align::Center)
+            .spacing(10)
+            .push(datetimepicker)
+            .push(Text::new(format!("Event: {}", self.date_time)));
+
+        Container::new(row)
+            .center_x()
+            .center_y()
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .into()
+    }
+}
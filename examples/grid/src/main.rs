@@ -8,6 +8,14 @@ use iced_aw::Grid;
 // Number of columns for the grid
 const COLUMNS: usize = 2;
 
+// Number of columns for the emoji palette
+const PALETTE_COLUMNS: usize = 4;
+
+// The emojis of the palette
+const EMOJIS: [&str; 12] = [
+    "😀", "😂", "😍", "😎", "🤔", "😴", "🥳", "😇", "🙃", "😭", "😡", "🤯",
+];
+
 fn main() -> iced::Result {
     GridExample::run(Settings::default())
 }
@@ -47,8 +55,15 @@ impl Sandbox for GridExample {
     }
 
     fn view(&mut self) -> Element<'_, self::Message> {
-        // Creates a grid with two columns
+        // Creates a grid with two columns, striped rows and a header row
+        // staying at the top while scrolling
         let mut grid = Grid::with_columns(COLUMNS)
+            .row_style(|row| match row {
+                0 => Some(Color::WHITE),
+                row if row % 2 == 1 => Some(Color::from_rgb8(230, 230, 240)),
+                _ => None,
+            })
+            .sticky_header(true)
             .push(Text::new("Column 1").color(Color::from_rgb8(255, 0, 0)))
             .push(Text::new("Column 2").color(Color::from_rgb8(255, 0, 0)));
 
@@ -61,6 +76,15 @@ impl Sandbox for GridExample {
             )));
         }
 
+        // Pours the emojis into a palette with four columns
+        let palette = Grid::with_children(
+            EMOJIS
+                .iter()
+                .map(|emoji| Text::new(*emoji).size(32).width(Length::Units(48))),
+        )
+        .columns(PALETTE_COLUMNS)
+        .justify_items(Align::Center);
+
         let add_button: Element<'_, Message> =
             Button::new(&mut self.button_state, Text::new("Add element"))
                 .on_press(Message::AddElement)
@@ -71,6 +95,7 @@ impl Sandbox for GridExample {
             .align_items(Align::Center)
             .push(grid)
             .push(add_button)
+            .push(palette)
             .into();
 
         let content = Scrollable::new(&mut self.scrollable_state)
@@ -1,9 +1,12 @@
 use iced::{
-    button, Align, Button, Container, Element, HorizontalAlignment, Length, Row, Sandbox, Settings,
-    Text,
+    button, Align, Button, Column, Container, Element, HorizontalAlignment, Length, Row, Sandbox,
+    Settings, Text, VerticalAlignment,
 };
 
-use iced_aw::{modal, Card, Modal};
+use iced_aw::{
+    date_picker::{self, Date, DatePicker},
+    modal, Card, Modal,
+};
 
 fn main() -> iced::Result {
     ModalExample::run(Settings::default())
@@ -13,21 +16,50 @@ fn main() -> iced::Result {
 enum Message {
     OpenModal,
     CloseModal,
+    OpenSheet,
+    CloseSheet,
     CancelButtonPressed,
     OkButtonPressed,
+    CloseConfirm,
+    DiscardButtonPressed,
+    ChooseDate,
+    CancelDate,
+    SubmitDate(Date),
 }
 
 #[derive(Default)]
 struct ModalExample {
     open_state: button::State,
     modal_state: modal::State<ModalState>,
+    open_sheet_state: button::State,
+    sheet_state: modal::State<()>,
+    confirm_state: modal::State<ConfirmState>,
     last_message: Option<Message>,
+    date: Option<Date>,
 }
 
-#[derive(Default)]
 struct ModalState {
     cancel_state: button::State,
     ok_state: button::State,
+    date_button_state: button::State,
+    date_picker_state: date_picker::State,
+}
+
+impl Default for ModalState {
+    fn default() -> Self {
+        Self {
+            cancel_state: button::State::default(),
+            ok_state: button::State::default(),
+            date_button_state: button::State::default(),
+            date_picker_state: date_picker::State::now(),
+        }
+    }
+}
+
+#[derive(Default)]
+struct ConfirmState {
+    keep_state: button::State,
+    discard_state: button::State,
 }
 
 impl Sandbox for ModalExample {
@@ -45,8 +77,26 @@ impl Sandbox for ModalExample {
         match message {
             Message::OpenModal => self.modal_state.show(true),
             Message::CloseModal => self.modal_state.show(false),
-            Message::CancelButtonPressed => self.modal_state.show(false),
+            // Asks for confirmation in a second modal on top of the first one
+            Message::CancelButtonPressed => self.confirm_state.show(true),
+            Message::CloseConfirm => self.confirm_state.show(false),
+            Message::DiscardButtonPressed => {
+                self.confirm_state.show(false);
+                self.modal_state.show(false);
+            }
             Message::OkButtonPressed => self.modal_state.show(false),
+            Message::OpenSheet => self.sheet_state.show(true),
+            Message::CloseSheet => self.sheet_state.show(false),
+            Message::ChooseDate => {
+                let picker = &mut self.modal_state.inner_mut().date_picker_state;
+                picker.reset();
+                picker.show(true);
+            }
+            Message::CancelDate => self.modal_state.inner_mut().date_picker_state.show(false),
+            Message::SubmitDate(date) => {
+                self.date = Some(date);
+                self.modal_state.inner_mut().date_picker_state.show(false);
+            }
         }
         self.last_message = Some(message)
     }
@@ -60,6 +110,10 @@ impl Sandbox for ModalExample {
                     Button::new(&mut self.open_state, Text::new("Open modal!"))
                         .on_press(Message::OpenModal),
                 )
+                .push(
+                    Button::new(&mut self.open_sheet_state, Text::new("Open bottom sheet!"))
+                        .on_press(Message::OpenSheet),
+                )
                 .push(Text::new(format!(
                     "Last message: {}",
                     match self.last_message.as_ref() {
@@ -68,16 +122,45 @@ impl Sandbox for ModalExample {
                             Message::CloseModal => "Modal closed",
                             Message::CancelButtonPressed => "Modal canceled",
                             Message::OkButtonPressed => "Modal accepted",
+                            Message::CloseConfirm => "Confirmation closed",
+                            Message::DiscardButtonPressed => "Modal discarded",
+                            Message::OpenSheet => "Bottom sheet opened",
+                            Message::CloseSheet => "Bottom sheet closed",
+                            Message::ChooseDate => "Date picker opened",
+                            Message::CancelDate => "Date picker canceled",
+                            Message::SubmitDate(_) => "Date picked",
                         },
                         None => "None",
                     }
                 ))),
         );
 
-        Modal::new(&mut self.modal_state, content, |state| {
+        let date = self.date;
+        let dialog = Modal::new(&mut self.modal_state, content, move |state| {
+            // The date picker opens its overlay on top of the modal
+            let date_picker = DatePicker::new(
+                &mut state.date_picker_state,
+                Button::new(&mut state.date_button_state, Text::new("Pick a date"))
+                    .on_press(Message::ChooseDate),
+                Message::CancelDate,
+                Message::SubmitDate,
+            );
+
             Card::new(
                 Text::new("My modal"),
-                Text::new("This is a modal!"), //Text::new("Zombie ipsum reversus ab viral inferno, nam rick grimes malum cerebro. De carne lumbering animata corpora quaeritis. Summus brains sit​​, morbo vel maleficia? De apocalypsi gorger omero undead survivor dictum mauris. Hi mindless mortuis soulless creaturas, imo evil stalking monstra adventus resi dentevil vultus comedat cerebella viventium. Qui animated corpse, cricket bat max brucks terribilem incessu zomby. The voodoo sacerdos flesh eater, suscitat mortuos comedere carnem virus. Zonbi tattered for solum oculi eorum defunctis go lum cerebro. Nescio brains an Undead zombies. Sicut malus putrid voodoo horror. Nigh tofth eliv ingdead.")
+                Column::new()
+                    .spacing(10)
+                    .push(Text::new("This is a modal!"))
+                    .push(
+                        Row::new()
+                            .spacing(10)
+                            .align_items(Align::Center)
+                            .push(date_picker)
+                            .push(Text::new(date.map_or_else(
+                                || String::from("No date"),
+                                |date| format!("Date: {}", date),
+                            ))),
+                    ), //Text::new("Zombie ipsum reversus ab viral inferno, nam rick grimes malum cerebro. De carne lumbering animata corpora quaeritis. Summus brains sit​​, morbo vel maleficia? De apocalypsi gorger omero undead survivor dictum mauris. Hi mindless mortuis soulless creaturas, imo evil stalking monstra adventus resi dentevil vultus comedat cerebella viventium. Qui animated corpse, cricket bat max brucks terribilem incessu zomby. The voodoo sacerdos flesh eater, suscitat mortuos comedere carnem virus. Zonbi tattered for solum oculi eorum defunctis go lum cerebro. Nescio brains an Undead zombies. Sicut malus putrid voodoo horror. Nigh tofth eliv ingdead.")
             )
             .foot(
                 Row::new()
@@ -107,7 +190,47 @@ impl Sandbox for ModalExample {
             .into()
         })
         .backdrop(Message::CloseModal)
-        .on_esc(Message::CloseModal)
+        .backdrop_dismiss(true)
+        .on_esc(Message::CloseModal);
+
+        // The confirmation is stacked on top of the dialog. Escape and the
+        // backdrop only close the topmost modal.
+        let confirm = Modal::new(&mut self.confirm_state, dialog, |state| {
+            Card::new(
+                Text::new("Discard changes?"),
+                Text::new("Your changes will be lost."),
+            )
+            .foot_buttons(vec![
+                Button::new(&mut state.keep_state, Text::new("Keep editing"))
+                    .on_press(Message::CloseConfirm)
+                    .into(),
+                Button::new(&mut state.discard_state, Text::new("Discard"))
+                    .on_press(Message::DiscardButtonPressed)
+                    .into(),
+            ])
+            .max_width(250)
+            .into()
+        })
+        .backdrop(Message::CloseConfirm)
+        .on_esc(Message::CloseConfirm);
+
+        // A bottom sheet spanning the whole width of the window
+        Modal::new(&mut self.sheet_state, confirm, |_state| {
+            Card::new(
+                Text::new("Bottom sheet"),
+                Column::new()
+                    .spacing(10)
+                    .push(Text::new("Share"))
+                    .push(Text::new("Copy link"))
+                    .push(Text::new("Delete")),
+            )
+            .on_close(Message::CloseSheet)
+            .into()
+        })
+        .alignment(HorizontalAlignment::Center, VerticalAlignment::Bottom)
+        .max_height(300)
+        .backdrop(Message::CloseSheet)
+        .on_esc(Message::CloseSheet)
         .into()
     }
 }
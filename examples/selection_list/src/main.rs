@@ -28,6 +28,7 @@ struct Example {
 #[derive(Debug, Clone)]
 enum Message {
     LanguageSelected(String),
+    LanguageMoved(usize, usize),
 }
 
 impl Sandbox for Example {
@@ -59,6 +60,10 @@ impl Sandbox for Example {
                     self.vec.push("Rusty".into());
                 }
             }
+            Message::LanguageMoved(from, to) => {
+                let language = self.vec.remove(from);
+                self.vec.insert(to, language);
+            }
         }
     }
 
@@ -69,7 +74,10 @@ impl Sandbox for Example {
             &Some(self.selected_language.clone()),
             Message::LanguageSelected,
             CustomStyle::style(),
-        );
+        )
+        .filterable(true)
+        .type_to_search(true)
+        .on_reorder(Message::LanguageMoved);
 
         let mut content = Column::new()
             .width(Length::Fill)
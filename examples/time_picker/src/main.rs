@@ -12,12 +12,17 @@ enum Message {
     ChooseTime,
     SubmitTime(Time),
     CancelTime,
+    ChooseDialTime,
+    SubmitDialTime(Time),
+    CancelDialTime,
 }
 
 struct TimePickerExample {
     time: Time,
     state: time_picker::State,
     button_state: button::State,
+    dial_state: time_picker::State,
+    dial_button_state: button::State,
 }
 
 impl Sandbox for TimePickerExample {
@@ -28,6 +33,8 @@ impl Sandbox for TimePickerExample {
             time: Time::default_hm(Period::H24),
             state: time_picker::State::now(),
             button_state: button::State::new(),
+            dial_state: time_picker::State::now(),
+            dial_button_state: button::State::new(),
         }
     }
 
@@ -48,6 +55,17 @@ impl Sandbox for TimePickerExample {
             Message::CancelTime => {
                 self.state.show(false);
             }
+            Message::ChooseDialTime => {
+                self.dial_state.reset();
+                self.dial_state.show(true);
+            }
+            Message::SubmitDialTime(time) => {
+                self.time = time;
+                self.dial_state.show(false);
+            }
+            Message::CancelDialTime => {
+                self.dial_state.show(false);
+            }
         }
     }
 
@@ -64,10 +82,24 @@ impl Sandbox for TimePickerExample {
         //.show_seconds()
         .use_24h();
 
+        let but = Button::new(&mut self.dial_button_state, Text::new("Set Time on dial"))
+            .on_press(Message::ChooseDialTime);
+
+        // Picks the hours and then the minutes on a dial, like mobile pickers.
+        let dial_timepicker = TimePicker::new(
+            &mut self.dial_state,
+            but,
+            Message::CancelDialTime,
+            Message::SubmitDialTime,
+        )
+        .minute_step(5)
+        .dial();
+
         let row = Row::new()
             .align_items(Align::Center)
             .spacing(10)
             .push(timepicker)
+            .push(dial_timepicker)
             .push(Text::new(format!("Time: {}", self.time)));
 
         Container::new(row)
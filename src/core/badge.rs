@@ -0,0 +1,201 @@
+//! Helper functions for displaying counts on a badge and for its padding.
+//!
+//! *This API requires the following crate features to be activated: `badge`*
+
+/// A numeric value displayed on a badge.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Count {
+    /// The value of the [`Count`](Count).
+    pub value: u64,
+    /// The highest value displayed as is. Larger values are displayed as
+    /// the maximum followed by a `+`.
+    pub max: Option<u64>,
+    /// Whether nothing is displayed if the value is zero.
+    pub hide_zero: bool,
+}
+
+impl Count {
+    /// Creates a new [`Count`](Count) of the given value without a maximum.
+    #[must_use]
+    pub const fn new(value: u64) -> Self {
+        Self {
+            value,
+            max: None,
+            hide_zero: false,
+        }
+    }
+
+    /// Returns true if nothing should be displayed for this [`Count`](Count).
+    #[must_use]
+    pub const fn is_hidden(&self) -> bool {
+        self.hide_zero && self.value == 0
+    }
+
+    /// Gets the text displayed for this [`Count`](Count).
+    ///
+    /// The text is empty if the [`Count`](Count) is hidden.
+    #[must_use]
+    pub fn label(&self) -> String {
+        if self.is_hidden() {
+            return String::new();
+        }
+
+        match self.max {
+            Some(max) if self.value > max => format!("{}+", max),
+            _ => self.value.to_string(),
+        }
+    }
+}
+
+/// The padding around the content of a badge.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Padding {
+    /// The padding above the content.
+    pub top: u16,
+    /// The padding right of the content.
+    pub right: u16,
+    /// The padding below the content.
+    pub bottom: u16,
+    /// The padding left of the content.
+    pub left: u16,
+}
+
+impl Padding {
+    /// Creates a new [`Padding`](Padding) of the same amount on all sides.
+    #[must_use]
+    pub const fn new(padding: u16) -> Self {
+        Self {
+            top: padding,
+            right: padding,
+            bottom: padding,
+            left: padding,
+        }
+    }
+
+    /// Gets the sum of the left and right padding.
+    #[must_use]
+    pub fn horizontal(&self) -> f32 {
+        f32::from(self.left) + f32::from(self.right)
+    }
+
+    /// Gets the sum of the top and bottom padding.
+    #[must_use]
+    pub fn vertical(&self) -> f32 {
+        f32::from(self.top) + f32::from(self.bottom)
+    }
+}
+
+impl From<u16> for Padding {
+    fn from(padding: u16) -> Self {
+        Self::new(padding)
+    }
+}
+
+impl From<[u16; 2]> for Padding {
+    /// Creates a [`Padding`](Padding) from the vertical and the horizontal
+    /// padding.
+    fn from([vertical, horizontal]: [u16; 2]) -> Self {
+        Self {
+            top: vertical,
+            right: horizontal,
+            bottom: vertical,
+            left: horizontal,
+        }
+    }
+}
+
+impl From<[u16; 4]> for Padding {
+    /// Creates a [`Padding`](Padding) from the top, right, bottom and left
+    /// padding.
+    fn from([top, right, bottom, left]: [u16; 4]) -> Self {
+        Self {
+            top,
+            right,
+            bottom,
+            left,
+        }
+    }
+}
+
+/// Gets the width of a badge of the given height around content of the given
+/// width.
+///
+/// The badge is at least as wide as it is high, so short content is drawn as
+/// a circle instead of a narrow pill, and at least as wide as the minimum
+/// width.
+#[must_use]
+pub fn pill_width(content_width: f32, padding: &Padding, height: f32, min_width: u16) -> f32 {
+    (content_width + padding.horizontal())
+        .max(height)
+        .max(f32::from(min_width))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{pill_width, Count, Padding};
+
+    #[test]
+    fn padding_from_test() {
+        assert_eq!(Padding::from(3), Padding::new(3));
+        assert_eq!(
+            Padding::from([2, 5]),
+            Padding {
+                top: 2,
+                right: 5,
+                bottom: 2,
+                left: 5
+            }
+        );
+        assert!((Padding::from([1, 2, 3, 4]).horizontal() - 6.0).abs() < f32::EPSILON);
+        assert!((Padding::from([1, 2, 3, 4]).vertical() - 4.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn pill_width_test() {
+        let padding = Padding::new(4);
+
+        // A single digit is drawn as a circle.
+        assert!((pill_width(8.0, &padding, 24.0, 0) - 24.0).abs() < f32::EPSILON);
+        // Longer content is drawn as a pill.
+        assert!((pill_width(30.0, &padding, 24.0, 0) - 38.0).abs() < f32::EPSILON);
+        assert!((pill_width(8.0, &padding, 24.0, 32) - 32.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn label_without_max_test() {
+        assert_eq!(Count::new(0).label(), "0");
+        assert_eq!(Count::new(1234).label(), "1234");
+    }
+
+    #[test]
+    fn label_with_max_test() {
+        let count = |value| Count {
+            max: Some(99),
+            ..Count::new(value)
+        };
+
+        assert_eq!(count(98).label(), "98");
+        assert_eq!(count(99).label(), "99");
+        assert_eq!(count(100).label(), "99+");
+        assert_eq!(count(u64::MAX).label(), "99+");
+    }
+
+    #[test]
+    fn hide_zero_test() {
+        let hidden = Count {
+            hide_zero: true,
+            ..Count::new(0)
+        };
+        assert!(hidden.is_hidden());
+        assert_eq!(hidden.label(), "");
+
+        let shown = Count {
+            hide_zero: true,
+            ..Count::new(1)
+        };
+        assert!(!shown.is_hidden());
+        assert_eq!(shown.label(), "1");
+
+        assert!(!Count::new(0).is_hidden());
+    }
+}
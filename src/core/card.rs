@@ -0,0 +1,43 @@
+//! Helper functions for coordinating the collapsed state of several cards.
+//!
+//! *This API requires the following crate features to be activated: `card`*
+
+/// Applies the requested collapsed state of the card at the given index to
+/// the open cards of an accordion.
+///
+/// It expects the sorted indices of the open cards. Opening a card closes all
+/// other cards unless `multiple` is set. It returns the new sorted indices.
+#[must_use]
+pub fn toggle(open: &[usize], index: usize, collapsed: bool, multiple: bool) -> Vec<usize> {
+    if collapsed {
+        open.iter().copied().filter(|open| *open != index).collect()
+    } else if multiple {
+        let mut open = open.to_vec();
+        if let Err(position) = open.binary_search(&index) {
+            open.insert(position, index);
+        }
+        open
+    } else {
+        vec![index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::toggle;
+
+    #[test]
+    fn toggle_single_test() {
+        assert_eq!(toggle(&[], 2, false, false), vec![2]);
+        assert_eq!(toggle(&[2], 0, false, false), vec![0]);
+        assert_eq!(toggle(&[2], 2, true, false), Vec::<usize>::new());
+        assert_eq!(toggle(&[2], 0, true, false), vec![2]);
+    }
+
+    #[test]
+    fn toggle_multiple_test() {
+        assert_eq!(toggle(&[0, 3], 2, false, true), vec![0, 2, 3]);
+        assert_eq!(toggle(&[0, 2, 3], 2, false, true), vec![0, 2, 3]);
+        assert_eq!(toggle(&[0, 2, 3], 2, true, true), vec![0, 3]);
+    }
+}
@@ -24,6 +24,13 @@ pub const HOUR_RADIUS_PERCENTAGE_NO_SECONDS: f32 = 0.5;
 /// clock's size.
 pub const MINUTE_RADIUS_PERCENTAGE_NO_SECONDS: f32 = 0.9;
 
+/// The radius of the outer ring of the dial based on the clock's size.
+pub const DIAL_RADIUS_PERCENTAGE: f32 = 0.8;
+
+/// The radius of the inner ring of the 24 hour dial based on the clock's
+/// size.
+pub const DIAL_INNER_RADIUS_PERCENTAGE: f32 = 0.5;
+
 /// The current period of the clock.
 #[derive(Clone, Debug)]
 pub enum Period {
@@ -118,11 +125,25 @@ pub fn circle_points(distance_radius: f32, center: Point, amount: u16) -> Vec<Po
     points
 }
 
+/// Gets the hour at the given index of a ring of the 24 hour dial.
+///
+/// The outer ring holds the hours 12 and 1 to 11, the inner ring the hours 0
+/// and 13 to 23, both starting at the top.
+#[must_use]
+pub const fn dial_hour(index: u32, inner: bool) -> u32 {
+    match (index % 12, inner) {
+        (0, false) => 12,
+        (index, false) => index,
+        (0, true) => 0,
+        (index, true) => index + 12,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use iced_graphics::{Point, Vector};
 
-    use super::{circle_points, nearest_point, nearest_radius, NearestRadius};
+    use super::{circle_points, dial_hour, nearest_point, nearest_radius, NearestRadius};
 
     #[test]
     fn circle_points_test() {
@@ -212,4 +233,13 @@ mod tests {
         let result = nearest_point(&points, cursor_position);
         assert_eq!(index, result);
     }
+
+    #[test]
+    fn dial_hour_test() {
+        assert_eq!(dial_hour(0, false), 12);
+        assert_eq!(dial_hour(3, false), 3);
+        assert_eq!(dial_hour(0, true), 0);
+        assert_eq!(dial_hour(3, true), 15);
+        assert_eq!(dial_hour(11, true), 23);
+    }
 }
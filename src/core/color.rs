@@ -25,6 +25,104 @@ impl Hsv {
     }
 }
 
+/// The color components that can be entered as numbers in a color picker.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ComponentMode {
+    /// No components are entered as numbers.
+    #[default]
+    None,
+    /// Hue (0-360), saturation (0-100) and value (0-100).
+    Hsv,
+    /// Hue (0-360), saturation (0-100) and lightness (0-100).
+    Hsl,
+}
+
+impl ComponentMode {
+    /// Gets the labels of the three components, if any.
+    #[must_use]
+    pub const fn labels(self) -> Option<[&'static str; 3]> {
+        match self {
+            Self::None => None,
+            Self::Hsv => Some(["H:", "S:", "V:"]),
+            Self::Hsl => Some(["H:", "S:", "L:"]),
+        }
+    }
+}
+
+/// Gets the hue (0-360) and the other two components (0-100) of the color in
+/// the given [`ComponentMode`](ComponentMode), if any.
+#[must_use]
+pub fn components(color: Color, mode: ComponentMode) -> Option<[u16; 3]> {
+    let hsv: Hsv = color.into();
+    let (saturation, third) = match mode {
+        ComponentMode::None => return None,
+        ComponentMode::Hsv => (hsv.saturation, hsv.value),
+        ComponentMode::Hsl => hsv_to_hsl(hsv.saturation, hsv.value),
+    };
+    let percent = |value: f32| (value * 100.0).round() as u16;
+
+    Some([hsv.hue, percent(saturation), percent(third)])
+}
+
+/// Sets the component at the given index (0 = hue) of the color in the given
+/// [`ComponentMode`](ComponentMode), keeping its alpha.
+///
+/// The hue wraps around at 360, the other components are clamped to 0-100.
+#[must_use]
+pub fn with_component(color: Color, mode: ComponentMode, index: usize, value: i32) -> Color {
+    let Some([hue, saturation, third]) = components(color, mode) else {
+        return color;
+    };
+    let mut components = [i32::from(hue), i32::from(saturation), i32::from(third)];
+    let Some(component) = components.get_mut(index) else {
+        return color;
+    };
+    *component = value;
+
+    let hue = components[0].rem_euclid(360) as u16;
+    let [saturation, third] = [components[1], components[2]].map(|value| {
+        #[allow(clippy::cast_precision_loss)]
+        let value = value.clamp(0, 100) as f32 / 100.0;
+        value
+    });
+    let (saturation, value) = match mode {
+        ComponentMode::Hsl => hsl_to_hsv(saturation, third),
+        _ => (saturation, third),
+    };
+
+    Color {
+        a: color.a,
+        ..Hsv::from_hsv(hue, saturation, value).into()
+    }
+}
+
+/// Converts the HSV saturation and value into the HSL saturation and
+/// lightness.
+fn hsv_to_hsl(saturation: f32, value: f32) -> (f32, f32) {
+    let lightness = value * (1.0 - saturation / 2.0);
+    let divisor = lightness.min(1.0 - lightness);
+    let saturation = if divisor <= 0.0 {
+        0.0
+    } else {
+        (value - lightness) / divisor
+    };
+
+    (saturation, lightness)
+}
+
+/// Converts the HSL saturation and lightness into the HSV saturation and
+/// value.
+fn hsl_to_hsv(saturation: f32, lightness: f32) -> (f32, f32) {
+    let value = saturation.mul_add(lightness.min(1.0 - lightness), lightness);
+    let saturation = if value <= 0.0 {
+        0.0
+    } else {
+        2.0 * (1.0 - lightness / value)
+    };
+
+    (saturation, value)
+}
+
 /// Creates a string of hexadecimal characters.
 pub trait HexString {
     /// Turns self into a string of hexadecimal characters.
@@ -35,14 +133,104 @@ impl HexString for Color {
     fn as_hex_string(&self) -> String {
         format!(
             "#{:02X?}{:02X?}{:02X?}{:02X?}",
-            (self.r * 255.0) as u8,
-            (self.g * 255.0) as u8,
-            (self.b * 255.0) as u8,
-            (self.a * 255.0) as u8,
+            (self.r * 255.0).round() as u8,
+            (self.g * 255.0).round() as u8,
+            (self.b * 255.0).round() as u8,
+            (self.a * 255.0).round() as u8,
         )
     }
 }
 
+/// (De)serializes a [`Color`](iced_graphics::Color) as a `#RRGGBBAA` string.
+///
+/// Use it on a field holding a color with
+/// `#[serde(with = "iced_aw::core::color::serde_hex")]`.
+///
+/// *This API requires the following crate features to be activated: `serde`*
+#[cfg(feature = "serde")]
+pub mod serde_hex {
+    use iced_graphics::Color;
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    use super::{hex_to_color, HexString};
+
+    /// Serializes the color as a `#RRGGBBAA` string.
+    ///
+    /// # Errors
+    /// Returns the error of the serializer.
+    pub fn serialize<S>(color: &Color, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&color.as_hex_string())
+    }
+
+    /// Deserializes the color from a `#RRGGBB` or `#RRGGBBAA` string.
+    ///
+    /// # Errors
+    /// Returns an error if the string is not a hexadecimal color.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Color, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let hex = String::deserialize(deserializer)?;
+
+        hex_to_color(&hex).ok_or_else(|| {
+            de::Error::invalid_value(de::Unexpected::Str(&hex), &"a color like #RRGGBBAA")
+        })
+    }
+}
+
+/// Parses a string of hexadecimal characters in the form of `#RRGGBB` or
+/// `#RRGGBBAA` into a [`Color`](iced_graphics::Color).
+///
+/// The leading `#` is optional. Returns `None` if the string is not a valid
+/// hexadecimal color.
+#[must_use]
+pub fn hex_to_color(hex: &str) -> Option<Color> {
+    let hex = hex.trim();
+    let hex = hex.strip_prefix('#').unwrap_or(hex);
+
+    if !(hex.len() == 6 || hex.len() == 8) || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let channel = |index: usize| {
+        u8::from_str_radix(&hex[index..index + 2], 16)
+            .ok()
+            .map(|value| f32::from(value) / 255.0)
+    };
+
+    Some(Color {
+        r: channel(0)?,
+        g: channel(2)?,
+        b: channel(4)?,
+        a: if hex.len() == 8 { channel(6)? } else { 1.0 },
+    })
+}
+
+/// Normalizes a [`Color`](iced_graphics::Color) from an outside source.
+///
+/// Every channel is clamped to 0-1 and channels that are not a number are
+/// treated as 0.
+#[must_use]
+pub fn normalize(color: Color) -> Color {
+    let channel = |value: f32| {
+        if value.is_nan() {
+            0.0
+        } else {
+            value.clamp(0.0, 1.0)
+        }
+    };
+
+    Color {
+        r: channel(color.r),
+        g: channel(color.g),
+        b: channel(color.b),
+        a: channel(color.a),
+    }
+}
+
 impl From<Color> for Hsv {
     // https://de.wikipedia.org/wiki/HSV-Farbraum#Umrechnung_RGB_in_HSV/HSL
     fn from(color: Color) -> Self {
@@ -101,7 +289,9 @@ impl From<Hsv> for Color {
 mod tests {
     use iced_graphics::Color;
 
-    use super::Hsv;
+    use super::{
+        components, hex_to_color, normalize, with_component, ComponentMode, HexString, Hsv,
+    };
 
     #[test]
     fn rgb_to_hsv() {
@@ -334,4 +524,127 @@ mod tests {
         let light_blue_red_rgb = Color::from_rgb(1.0, 0.0, 0.25);
         assert_eq!(light_blue_red_rgb, light_blue_red_hsv.into());
     }
+
+    #[test]
+    fn hex_to_color_test() {
+        assert_eq!(
+            hex_to_color("#FF0000"),
+            Some(Color::from_rgb(1.0, 0.0, 0.0))
+        );
+        assert_eq!(
+            hex_to_color("00ff0080"),
+            Some(Color::from_rgba(0.0, 1.0, 0.0, 128.0 / 255.0))
+        );
+
+        let color = Color::from_rgba(51.0 / 255.0, 102.0 / 255.0, 153.0 / 255.0, 204.0 / 255.0);
+        assert_eq!(hex_to_color(&color.as_hex_string()), Some(color));
+
+        assert_eq!(hex_to_color("#FF00"), None);
+        assert_eq!(hex_to_color("#GG0000"), None);
+        assert_eq!(hex_to_color(""), None);
+    }
+
+    #[test]
+    fn as_hex_string_rounds_test() {
+        let color = hex_to_color("#7F3366CC").expect("The color should be valid");
+        assert_eq!(color.as_hex_string(), "#7F3366CC");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip_test() {
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Config {
+            #[serde(with = "super::serde_hex")]
+            color: Color,
+        }
+
+        let config = Config {
+            color: Color::from_rgba(1.0, 0.5, 0.0, 0.8),
+        };
+        let json = serde_json::to_string(&config).expect("The color should serialize");
+        assert_eq!(json, r##"{"color":"#FF8000CC"}"##);
+
+        let config: Config = serde_json::from_str(&json).expect("The color should deserialize");
+        assert_eq!(config.color.as_hex_string(), "#FF8000CC");
+        assert_eq!(
+            serde_json::from_str::<Config>(&json).expect("The color should deserialize"),
+            config
+        );
+
+        assert!(serde_json::from_str::<Config>(r#"{"color":"red"}"#).is_err());
+    }
+
+    #[test]
+    fn components_test() {
+        let orange = Color::from_rgb(1.0, 0.5, 0.0);
+
+        assert_eq!(components(orange, ComponentMode::None), None);
+        assert_eq!(components(orange, ComponentMode::Hsv), Some([30, 100, 100]));
+        assert_eq!(components(orange, ComponentMode::Hsl), Some([30, 100, 50]));
+        assert_eq!(
+            components(Color::from_rgb(0.5, 0.5, 0.5), ComponentMode::Hsl),
+            Some([0, 0, 50])
+        );
+    }
+
+    #[test]
+    fn with_component_test() {
+        let red = Color::from_rgba(1.0, 0.0, 0.0, 0.5);
+
+        // Hue
+        assert_eq!(
+            with_component(red, ComponentMode::Hsv, 0, 30),
+            Color::from_rgba(1.0, 0.5, 0.0, 0.5)
+        );
+        // Lightness
+        assert_eq!(
+            with_component(red, ComponentMode::Hsl, 2, 100),
+            Color::from_rgba(1.0, 1.0, 1.0, 0.5)
+        );
+        // Value
+        assert_eq!(
+            with_component(red, ComponentMode::Hsv, 2, 0),
+            Color::from_rgba(0.0, 0.0, 0.0, 0.5)
+        );
+        assert_eq!(with_component(red, ComponentMode::None, 0, 30), red);
+    }
+
+    #[test]
+    fn with_component_wraps_and_clamps_test() {
+        let red = Color::from_rgb(1.0, 0.0, 0.0);
+
+        assert_eq!(
+            with_component(red, ComponentMode::Hsv, 0, 390),
+            with_component(red, ComponentMode::Hsv, 0, 30)
+        );
+        assert_eq!(
+            with_component(red, ComponentMode::Hsv, 0, -330),
+            with_component(red, ComponentMode::Hsv, 0, 30)
+        );
+        assert_eq!(
+            with_component(red, ComponentMode::Hsl, 1, 250),
+            with_component(red, ComponentMode::Hsl, 1, 100)
+        );
+        assert_eq!(
+            with_component(red, ComponentMode::Hsv, 2, -5),
+            Color::from_rgb(0.0, 0.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn normalize_test() {
+        let color = Color::from_rgba(0.2, 0.4, 0.6, 0.8);
+        assert_eq!(normalize(color), color);
+
+        assert_eq!(
+            normalize(Color {
+                r: 1.5,
+                g: -0.5,
+                b: f32::NAN,
+                a: f32::INFINITY,
+            }),
+            Color::from_rgba(1.0, 0.0, 0.0, 1.0)
+        );
+    }
 }
@@ -3,12 +3,21 @@
 use std::fmt::Display;
 
 #[cfg(not(target_arch = "wasm32"))]
-use chrono::{Datelike, Duration, NaiveDate};
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
 #[cfg(not(target_arch = "wasm32"))]
 use lazy_static::lazy_static;
 
+/// The names of the months from January to December.
+pub type MonthNames = [String; 12];
+
+/// The names of the weekdays from Monday to Sunday.
+pub type WeekdayNames = [String; 7];
+
 /// The date value
-#[derive(Clone, Copy, Debug, Default)]
+///
+/// With the `serde` feature it is (de)serialized as an ISO-8601 date string
+/// like `2021-03-14`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
 pub struct Date {
     /// The year value of the date.
     pub year: i32,
@@ -24,6 +33,23 @@ impl Date {
     pub const fn from_ymd(year: i32, month: u32, day: u32) -> Self {
         Self { year, month, day }
     }
+
+    /// Parses an ISO-8601 date string like `2021-03-14`.
+    #[cfg(feature = "serde")]
+    fn parse(date: &str) -> Option<Self> {
+        let (year, rest) = date.split_at(date.get(1..)?.find('-')? + 1);
+        let mut parts = rest.strip_prefix('-')?.split('-');
+        let month = parts.next()?;
+        let day = parts.next()?;
+
+        if parts.next().is_some() || month.len() != 2 || day.len() != 2 {
+            return None;
+        }
+
+        let date = Self::from_ymd(year.parse().ok()?, month.parse().ok()?, day.parse().ok()?);
+
+        ((1..=12).contains(&date.month) && (1..=31).contains(&date.day)).then_some(date)
+    }
 }
 
 impl Display for Date {
@@ -32,6 +58,33 @@ impl Display for Date {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for Date {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Date {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let date = String::deserialize(deserializer)?;
+
+        Self::parse(&date).ok_or_else(|| {
+            serde::de::Error::invalid_value(
+                serde::de::Unexpected::Str(&date),
+                &"an ISO-8601 date like 2021-03-14",
+            )
+        })
+    }
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 impl From<Date> for NaiveDate {
     fn from(date: Date) -> Self {
@@ -139,17 +192,42 @@ pub enum IsInMonth {
     Next,
 }
 
+/// Calculates the number of days of the previous month shown in front of the
+/// first day of the given month in the calendar table starting with the given
+/// weekday.
+///
+/// If the month starts on the first weekday, a whole week of the previous month
+/// is shown.
+#[cfg(not(target_arch = "wasm32"))]
+#[must_use]
+pub fn leading_days(year: i32, month: u32, first_weekday: Weekday) -> usize {
+    let first_day = NaiveDate::from_ymd(year, month, 1);
+    let offset =
+        (7 + first_day.weekday().num_days_from_monday() - first_weekday.num_days_from_monday()) % 7;
+
+    if offset == 0 {
+        7
+    } else {
+        offset as usize
+    }
+}
+
 /// Calculates the day number at the given position in the calendar table based
-/// on the given year and month.
+/// on the given year and month and the first weekday of the table.
 #[cfg(not(target_arch = "wasm32"))]
 #[must_use]
-pub fn position_to_day(x: usize, y: usize, year: i32, month: u32) -> (usize, IsInMonth) {
+pub fn position_to_day(
+    x: usize,
+    y: usize,
+    year: i32,
+    month: u32,
+    first_weekday: Weekday,
+) -> (usize, IsInMonth) {
     let (x, y) = (x as isize, y as isize);
     let first_day = NaiveDate::from_ymd(year, month, 1);
-    let day_of_week = first_day.weekday().num_days_from_monday() as isize;
-    let day_of_week = if day_of_week == 0 { 7 } else { day_of_week };
+    let leading_days = leading_days(year, month, first_weekday) as isize;
 
-    let day = (x + 7 * y) + 1 - day_of_week;
+    let day = (x + 7 * y) + 1 - leading_days;
 
     if day < 1 {
         let last_month = first_day.pred();
@@ -167,6 +245,102 @@ pub fn position_to_day(x: usize, y: usize, year: i32, month: u32) -> (usize, IsI
     }
 }
 
+/// Calculates the date at the given position in the calendar table based on
+/// the month of the given date.
+///
+/// # Panics
+/// Panics if the calculated day is not valid for its month, which should never
+/// happen.
+#[cfg(not(target_arch = "wasm32"))]
+#[must_use]
+pub fn position_to_date(x: usize, y: usize, date: NaiveDate, first_weekday: Weekday) -> NaiveDate {
+    let (day, is_in_month) = position_to_day(x, y, date.year(), date.month(), first_weekday);
+
+    let month = match is_in_month {
+        IsInMonth::Previous => pred_month(date),
+        IsInMonth::Same => date,
+        IsInMonth::Next => succ_month(date),
+    };
+
+    month
+        .with_day(day as u32)
+        .expect("Month with calculated day should be valid")
+}
+
+/// Calculates the ISO-8601 week number of the given row in the calendar table
+/// based on the month of the given date.
+///
+/// ISO weeks start on Monday and the first week of a year contains its first
+/// Thursday. If the table does not start on Monday, a row spans two ISO weeks
+/// and the week of its Monday is used, as it holds most days of the row.
+#[cfg(not(target_arch = "wasm32"))]
+#[must_use]
+pub fn week_number(y: usize, date: NaiveDate, first_weekday: Weekday) -> u32 {
+    let first_day_of_row = position_to_date(0, y, date, first_weekday);
+    let days_to_monday = (7 - first_day_of_row.weekday().num_days_from_monday()) % 7;
+
+    (first_day_of_row + Duration::days(i64::from(days_to_monday)))
+        .iso_week()
+        .week()
+}
+
+/// Checks if the given date lays inside of the optional minimum and maximum
+/// dates (inclusive).
+#[cfg(not(target_arch = "wasm32"))]
+#[must_use]
+pub fn is_in_range(date: NaiveDate, min: Option<NaiveDate>, max: Option<NaiveDate>) -> bool {
+    !matches!(min, Some(min) if date < min) && !matches!(max, Some(max) if date > max)
+}
+
+/// Checks if at least one day of the month of the given date lays inside of
+/// the optional minimum and maximum dates (inclusive).
+#[cfg(not(target_arch = "wasm32"))]
+#[must_use]
+pub fn is_month_in_range(date: NaiveDate, min: Option<NaiveDate>, max: Option<NaiveDate>) -> bool {
+    let first = NaiveDate::from_ymd(date.year(), date.month(), 1);
+    let last = NaiveDate::from_ymd(
+        date.year(),
+        date.month(),
+        num_days_of_month(date.year(), date.month()),
+    );
+
+    !matches!(min, Some(min) if last < min) && !matches!(max, Some(max) if first > max)
+}
+
+/// Checks if at least one day of the given year lays inside of the optional
+/// minimum and maximum dates (inclusive).
+#[cfg(not(target_arch = "wasm32"))]
+#[must_use]
+pub fn is_year_in_range(year: i32, min: Option<NaiveDate>, max: Option<NaiveDate>) -> bool {
+    !matches!(min, Some(min) if year < min.year()) && !matches!(max, Some(max) if year > max.year())
+}
+
+/// Creates a date in the given year and month with the day of the given date,
+/// clamped to the last day of the month.
+#[cfg(not(target_arch = "wasm32"))]
+#[must_use]
+pub fn with_year_month(date: NaiveDate, year: i32, month: u32) -> NaiveDate {
+    let day = date.day().min(num_days_of_month(year, month));
+
+    NaiveDate::from_ymd(year, month, day)
+}
+
+/// Gets the first year of the page of the given number of years containing
+/// the given year.
+#[cfg(not(target_arch = "wasm32"))]
+#[must_use]
+pub const fn year_page(year: i32, years_per_page: i32) -> i32 {
+    year - year.rem_euclid(years_per_page)
+}
+
+/// Clamps the given date into the optional minimum and maximum dates.
+#[cfg(not(target_arch = "wasm32"))]
+#[must_use]
+pub fn clamp_date(date: NaiveDate, min: Option<NaiveDate>, max: Option<NaiveDate>) -> NaiveDate {
+    let date = max.map_or(date, |max| date.min(max));
+    min.map_or(date, |min| date.max(min))
+}
+
 /// Checks if the given year is a leap year.
 #[cfg(not(target_arch = "wasm32"))]
 const fn is_leap_year(year: i32) -> bool {
@@ -200,6 +374,16 @@ pub fn year_as_string(date: NaiveDate) -> String {
     date.format("%Y").to_string()
 }
 
+/// Gets the labels of the weekdays in the order of the calendar table starting
+/// with the given weekday.
+#[cfg(not(target_arch = "wasm32"))]
+#[must_use]
+pub fn ordered_weekday_labels(labels: &[String], first_weekday: Weekday) -> Vec<String> {
+    let first = first_weekday.num_days_from_monday() as usize;
+
+    (0..7).map(|i| labels[(first + i) % 7].clone()).collect()
+}
+
 /// Gets the string representation of the month of the given date.
 #[cfg(not(target_arch = "wasm32"))]
 #[must_use]
@@ -265,11 +449,12 @@ lazy_static! {
 #[cfg(test)]
 #[cfg(not(target_arch = "wasm32"))]
 mod tests {
-    use chrono::NaiveDate;
+    use chrono::{NaiveDate, Weekday};
 
     use super::{
-        is_leap_year, num_days_of_month, position_to_day, pred_month, pred_year, succ_month,
-        succ_year, IsInMonth,
+        clamp_date, is_in_range, is_leap_year, is_month_in_range, is_year_in_range, leading_days,
+        num_days_of_month, ordered_weekday_labels, position_to_date, position_to_day, pred_month,
+        pred_year, succ_month, succ_year, week_number, with_year_month, year_page, IsInMonth,
     };
 
     #[test]
@@ -326,6 +511,38 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn with_year_month_test() {
+        let date = NaiveDate::from_ymd(2020, 1, 31);
+        assert_eq!(
+            with_year_month(date, 2021, 2),
+            NaiveDate::from_ymd(2021, 2, 28)
+        );
+        assert_eq!(
+            with_year_month(date, 1990, 7),
+            NaiveDate::from_ymd(1990, 7, 31)
+        );
+    }
+
+    #[test]
+    fn year_page_test() {
+        assert_eq!(year_page(2021, 16), 2016);
+        assert_eq!(year_page(2016, 16), 2016);
+        assert_eq!(year_page(-1, 16), -16);
+    }
+
+    #[test]
+    fn is_year_in_range_test() {
+        let min = Some(NaiveDate::from_ymd(2000, 6, 1));
+        let max = Some(NaiveDate::from_ymd(2010, 6, 1));
+
+        assert!(is_year_in_range(2000, min, max));
+        assert!(is_year_in_range(2010, min, max));
+        assert!(!is_year_in_range(1999, min, max));
+        assert!(!is_year_in_range(2011, min, max));
+        assert!(is_year_in_range(1900, None, None));
+    }
+
     #[test]
     fn succ_year_test() {
         let date = NaiveDate::from_ymd(2020, 5, 6);
@@ -344,57 +561,169 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn week_number_test() {
+        let date = NaiveDate::from_ymd(2024, 1, 1);
+
+        // The first row shows the last week of 2023.
+        assert_eq!(week_number(0, date, Weekday::Mon), 52);
+        assert_eq!(week_number(1, date, Weekday::Mon), 1);
+        assert_eq!(week_number(5, date, Weekday::Mon), 5);
+
+        // The row from Sunday 2023-12-31 to Saturday 2024-01-06 is week 1.
+        assert_eq!(week_number(0, date, Weekday::Sun), 1);
+
+        // 2021-01-01 is a Friday, so it belongs to week 53 of 2020.
+        let date = NaiveDate::from_ymd(2021, 1, 1);
+        assert_eq!(week_number(0, date, Weekday::Mon), 53);
+        assert_eq!(week_number(1, date, Weekday::Mon), 1);
+    }
+
     #[test]
     fn position_to_day_test() {
-        let (day, is_in_month) = position_to_day(0, 0, 2020, 12);
+        let (day, is_in_month) = position_to_day(0, 0, 2020, 12, Weekday::Mon);
         assert_eq!(day, 30);
         assert_eq!(is_in_month, IsInMonth::Previous);
 
-        let (day, is_in_month) = position_to_day(1, 0, 2020, 12);
+        let (day, is_in_month) = position_to_day(1, 0, 2020, 12, Weekday::Mon);
         assert_eq!(day, 1);
         assert_eq!(is_in_month, IsInMonth::Same);
 
-        let (day, is_in_month) = position_to_day(3, 4, 2020, 12);
+        let (day, is_in_month) = position_to_day(3, 4, 2020, 12, Weekday::Mon);
         assert_eq!(day, 31);
         assert_eq!(is_in_month, IsInMonth::Same);
 
-        let (day, is_in_month) = position_to_day(6, 5, 2020, 12);
+        let (day, is_in_month) = position_to_day(6, 5, 2020, 12, Weekday::Mon);
         assert_eq!(day, 10);
         assert_eq!(is_in_month, IsInMonth::Next);
 
-        let (day, is_in_month) = position_to_day(0, 0, 2020, 11);
+        let (day, is_in_month) = position_to_day(0, 0, 2020, 11, Weekday::Mon);
         assert_eq!(day, 26);
         assert_eq!(is_in_month, IsInMonth::Previous);
 
-        let (day, is_in_month) = position_to_day(6, 0, 2020, 11);
+        let (day, is_in_month) = position_to_day(6, 0, 2020, 11, Weekday::Mon);
         assert_eq!(day, 1);
         assert_eq!(is_in_month, IsInMonth::Same);
 
-        let (day, is_in_month) = position_to_day(0, 5, 2020, 11);
+        let (day, is_in_month) = position_to_day(0, 5, 2020, 11, Weekday::Mon);
         assert_eq!(day, 30);
         assert_eq!(is_in_month, IsInMonth::Same);
 
-        let (day, is_in_month) = position_to_day(6, 5, 2020, 11);
+        let (day, is_in_month) = position_to_day(6, 5, 2020, 11, Weekday::Mon);
         assert_eq!(day, 6);
         assert_eq!(is_in_month, IsInMonth::Next);
 
-        let (day, is_in_month) = position_to_day(0, 0, 2021, 2);
+        let (day, is_in_month) = position_to_day(0, 0, 2021, 2, Weekday::Mon);
         assert_eq!(day, 25);
         assert_eq!(is_in_month, IsInMonth::Previous);
 
-        let (day, is_in_month) = position_to_day(0, 1, 2021, 2);
+        let (day, is_in_month) = position_to_day(0, 1, 2021, 2, Weekday::Mon);
         assert_eq!(day, 1);
         assert_eq!(is_in_month, IsInMonth::Same);
 
-        let (day, is_in_month) = position_to_day(6, 4, 2021, 2);
+        let (day, is_in_month) = position_to_day(6, 4, 2021, 2, Weekday::Mon);
         assert_eq!(day, 28);
         assert_eq!(is_in_month, IsInMonth::Same);
 
-        let (day, is_in_month) = position_to_day(0, 5, 2021, 2);
+        let (day, is_in_month) = position_to_day(0, 5, 2021, 2, Weekday::Mon);
         assert_eq!(day, 1);
         assert_eq!(is_in_month, IsInMonth::Next);
     }
 
+    #[test]
+    fn position_to_date_test() {
+        let date = NaiveDate::from_ymd(2020, 12, 15);
+
+        let result = position_to_date(0, 0, date, Weekday::Mon);
+        let expected = NaiveDate::from_ymd(2020, 11, 30);
+        assert_eq!(result, expected);
+
+        let result = position_to_date(3, 4, date, Weekday::Mon);
+        let expected = NaiveDate::from_ymd(2020, 12, 31);
+        assert_eq!(result, expected);
+
+        let result = position_to_date(6, 5, date, Weekday::Mon);
+        let expected = NaiveDate::from_ymd(2021, 1, 10);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn position_to_day_sunday_test() {
+        // December 2020 starts on a Tuesday
+        let (day, is_in_month) = position_to_day(0, 0, 2020, 12, Weekday::Sun);
+        assert_eq!(day, 29);
+        assert_eq!(is_in_month, IsInMonth::Previous);
+
+        let (day, is_in_month) = position_to_day(2, 0, 2020, 12, Weekday::Sun);
+        assert_eq!(day, 1);
+        assert_eq!(is_in_month, IsInMonth::Same);
+
+        let (day, is_in_month) = position_to_day(4, 4, 2020, 12, Weekday::Sun);
+        assert_eq!(day, 31);
+        assert_eq!(is_in_month, IsInMonth::Same);
+    }
+
+    #[test]
+    fn leading_days_test() {
+        // July 2020 starts on a Wednesday
+        assert_eq!(leading_days(2020, 7, Weekday::Sun), 3);
+        assert_eq!(leading_days(2020, 7, Weekday::Mon), 2);
+        assert_eq!(leading_days(2020, 7, Weekday::Wed), 7);
+        assert_eq!(leading_days(2020, 7, Weekday::Thu), 6);
+    }
+
+    #[test]
+    fn ordered_weekday_labels_test() {
+        let labels: Vec<String> = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"]
+            .iter()
+            .map(|label| (*label).to_owned())
+            .collect();
+
+        let result = ordered_weekday_labels(&labels, Weekday::Sun);
+        assert_eq!(result[0], "Su");
+        assert_eq!(result[1], "Mo");
+        assert_eq!(result[6], "Sa");
+
+        let result = ordered_weekday_labels(&labels, Weekday::Mon);
+        assert_eq!(result, labels);
+    }
+
+    #[test]
+    fn range_test() {
+        let min = Some(NaiveDate::from_ymd(2020, 5, 10));
+        let max = Some(NaiveDate::from_ymd(2020, 7, 20));
+
+        assert!(is_in_range(NaiveDate::from_ymd(2020, 5, 10), min, max));
+        assert!(is_in_range(NaiveDate::from_ymd(2020, 7, 20), min, max));
+        assert!(!is_in_range(NaiveDate::from_ymd(2020, 5, 9), min, max));
+        assert!(!is_in_range(NaiveDate::from_ymd(2020, 7, 21), min, max));
+        assert!(is_in_range(NaiveDate::from_ymd(1900, 1, 1), None, max));
+
+        assert!(is_month_in_range(NaiveDate::from_ymd(2020, 5, 1), min, max));
+        assert!(is_month_in_range(
+            NaiveDate::from_ymd(2020, 7, 31),
+            min,
+            max
+        ));
+        assert!(!is_month_in_range(
+            NaiveDate::from_ymd(2020, 4, 30),
+            min,
+            max
+        ));
+        assert!(!is_month_in_range(
+            NaiveDate::from_ymd(2020, 8, 1),
+            min,
+            max
+        ));
+
+        let result = clamp_date(NaiveDate::from_ymd(2020, 1, 1), min, max);
+        assert_eq!(result, NaiveDate::from_ymd(2020, 5, 10));
+        let result = clamp_date(NaiveDate::from_ymd(2021, 1, 1), min, max);
+        assert_eq!(result, NaiveDate::from_ymd(2020, 7, 20));
+        let result = clamp_date(NaiveDate::from_ymd(2020, 6, 1), min, max);
+        assert_eq!(result, NaiveDate::from_ymd(2020, 6, 1));
+    }
+
     #[test]
     fn is_leap_year_test() {
         assert_eq!(is_leap_year(2020), true);
@@ -420,4 +749,20 @@ mod tests {
         assert_eq!(num_days_of_month(2020, 11), 30);
         assert_eq!(num_days_of_month(2020, 12), 31);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip_test() {
+        let date = super::Date::from_ymd(2021, 3, 4);
+        let json = serde_json::to_string(&date).expect("The date should serialize");
+        assert_eq!(json, "\"2021-03-04\"");
+        assert_eq!(
+            serde_json::from_str::<super::Date>(&json).expect("The date should deserialize"),
+            date
+        );
+
+        for invalid in ["\"2021-13-04\"", "\"2021-3-4\"", "\"2021-03\"", "\"date\""] {
+            assert!(serde_json::from_str::<super::Date>(invalid).is_err());
+        }
+    }
 }
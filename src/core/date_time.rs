@@ -0,0 +1,129 @@
+//! Helper functions for combining dates and times.
+//!
+//! *This API requires the following crate features to be activated: `date_time_picker`*
+use std::fmt::Display;
+
+#[cfg(not(target_arch = "wasm32"))]
+use chrono::NaiveDateTime;
+
+use super::{date::Date, time::Time};
+
+/// The date time value containing both parts picked by a date time picker.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DateTime {
+    /// The date part of the date time.
+    pub date: Date,
+    /// The time part of the date time.
+    pub time: Time,
+}
+
+impl DateTime {
+    /// Creates a new date time of the given date and time.
+    #[must_use]
+    pub const fn new(date: Date, time: Time) -> Self {
+        Self { date, time }
+    }
+
+    /// Creates a [`DateTime`](DateTime) from the given
+    /// [`NaiveDateTime`](chrono::NaiveDateTime) with its time presented either
+    /// in the 24 hour or in the 12 hour format.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[must_use]
+    pub fn from_naive(date_time: NaiveDateTime, show_seconds: bool, use_24h: bool) -> Self {
+        Self {
+            date: date_time.date().into(),
+            time: Time::from_naive(date_time.time(), show_seconds, use_24h),
+        }
+    }
+}
+
+impl Display for DateTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.date, self.time)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl From<DateTime> for NaiveDateTime {
+    fn from(date_time: DateTime) -> Self {
+        chrono::NaiveDate::from(date_time.date).and_time(date_time.time.into())
+    }
+}
+
+/// Clamps the given date time into the optional minimum and maximum date times
+/// (inclusive).
+#[cfg(not(target_arch = "wasm32"))]
+#[must_use]
+pub fn clamp_date_time(
+    date_time: NaiveDateTime,
+    min: Option<NaiveDateTime>,
+    max: Option<NaiveDateTime>,
+) -> NaiveDateTime {
+    let date_time = max.map_or(date_time, |max| date_time.min(max));
+    min.map_or(date_time, |min| date_time.max(min))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, NaiveDateTime};
+
+    use super::{clamp_date_time, DateTime};
+    use crate::core::{
+        date::Date,
+        time::{Period, Time},
+    };
+
+    fn date_time(hour: u32, minute: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd(2021, 3, 14).and_hms(hour, minute, 0)
+    }
+
+    #[test]
+    fn date_time_to_naive() {
+        let date_time = DateTime::new(
+            Date::from_ymd(2021, 3, 14),
+            Time::Hm {
+                hour: 1,
+                minute: 5,
+                period: Period::Pm,
+            },
+        );
+
+        assert_eq!(NaiveDateTime::from(date_time), self::date_time(13, 5));
+        assert_eq!(date_time.to_string(), "2021-03-14 01:05 PM");
+    }
+
+    #[test]
+    fn naive_to_date_time() {
+        assert_eq!(
+            DateTime::from_naive(date_time(13, 5), false, true),
+            DateTime::new(
+                Date::from_ymd(2021, 3, 14),
+                Time::Hm {
+                    hour: 13,
+                    minute: 5,
+                    period: Period::H24,
+                },
+            )
+        );
+    }
+
+    #[test]
+    fn clamp_date_time_test() {
+        let min = Some(date_time(9, 0));
+        let max = Some(date_time(17, 30));
+
+        assert_eq!(
+            clamp_date_time(date_time(12, 0), min, max),
+            date_time(12, 0)
+        );
+        assert_eq!(clamp_date_time(date_time(8, 59), min, max), date_time(9, 0));
+        assert_eq!(
+            clamp_date_time(date_time(18, 0), min, max),
+            date_time(17, 30)
+        );
+        assert_eq!(
+            clamp_date_time(date_time(18, 0), None, None),
+            date_time(18, 0)
+        );
+    }
+}
@@ -0,0 +1,395 @@
+//! Helper functions for placing the cells of a grid and sizing its tracks.
+//!
+//! *This API requires the following crate features to be activated: `grid`*
+
+/// The position of a cell in a grid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Placement {
+    /// The first row of the cell.
+    pub row: usize,
+    /// The first column of the cell.
+    pub column: usize,
+    /// The number of rows spanned by the cell.
+    pub row_span: usize,
+    /// The number of columns spanned by the cell.
+    pub column_span: usize,
+}
+
+/// Places cells with the given `(row_span, column_span)`s in a grid with the
+/// given number of columns.
+///
+/// The cells are placed row by row into the first free slot after the
+/// previous cell. A span exceeding the remaining columns is clamped to the
+/// edge of the grid. A span overlapping an already placed cell is clamped to
+/// end before it, so the first placed cell wins. Spans of zero count as one.
+#[must_use]
+pub fn place(columns: usize, spans: impl IntoIterator<Item = (usize, usize)>) -> Vec<Placement> {
+    if columns == 0 {
+        return Vec::new();
+    }
+
+    let mut occupied: Vec<Vec<bool>> = Vec::new();
+    let is_free = |occupied: &Vec<Vec<bool>>, row: usize, column: usize| !matches!(occupied.get(row), Some(cells) if cells[column]);
+
+    let mut row = 0;
+    let mut column = 0;
+
+    spans
+        .into_iter()
+        .map(|(row_span, column_span)| {
+            while !is_free(&occupied, row, column) {
+                column += 1;
+                if column == columns {
+                    column = 0;
+                    row += 1;
+                }
+            }
+
+            let column_span = (1..column_span.clamp(1, columns - column))
+                .take_while(|offset| is_free(&occupied, row, column + offset))
+                .count()
+                + 1;
+            let row_span = (1..row_span.max(1))
+                .take_while(|offset| {
+                    (column..column + column_span)
+                        .all(|column| is_free(&occupied, row + offset, column))
+                })
+                .count()
+                + 1;
+
+            if occupied.len() < row + row_span {
+                occupied.resize(row + row_span, vec![false; columns]);
+            }
+            for cells in &mut occupied[row..row + row_span] {
+                for cell in &mut cells[column..column + column_span] {
+                    *cell = true;
+                }
+            }
+
+            let placement = Placement {
+                row,
+                column,
+                row_span,
+                column_span,
+            };
+
+            column += column_span;
+            if column == columns {
+                column = 0;
+                row += 1;
+            }
+
+            placement
+        })
+        .collect()
+}
+
+/// Calculates the sizes of `count` tracks (columns or rows) to fit the
+/// cells given by their first track, their number of spanned tracks and
+/// their size.
+///
+/// Each track is as large as its largest single track cell. If a spanning
+/// cell is larger than its spanned tracks, the missing size is split evenly
+/// between them.
+#[must_use]
+pub fn track_sizes(count: usize, cells: &[(usize, usize, f32)]) -> Vec<f32> {
+    let mut sizes = vec![0.0_f32; count];
+
+    for (start, _, size) in cells.iter().filter(|(_, span, _)| *span <= 1) {
+        if let Some(track) = sizes.get_mut(*start) {
+            *track = track.max(*size);
+        }
+    }
+
+    for (start, span, size) in cells.iter().filter(|(_, span, _)| *span > 1) {
+        let end = (start + span).min(count);
+        if *start >= end {
+            continue;
+        }
+
+        let spanned: f32 = sizes[*start..end].iter().sum();
+        if *size > spanned {
+            #[allow(clippy::cast_precision_loss)]
+            let extra = (size - spanned) / (end - start) as f32;
+            for track in &mut sizes[*start..end] {
+                *track += extra;
+            }
+        }
+    }
+
+    sizes
+}
+
+/// The size of a track (column or row) of a grid.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Track {
+    /// A track of the given size.
+    Size(f32),
+    /// A track filling a portion of the space left by the other tracks.
+    ///
+    /// The measured size is used if the available space is unbounded.
+    Fill {
+        /// The portion of the left space.
+        portion: u16,
+        /// The size needed by the cells of the track.
+        measured: f32,
+    },
+}
+
+/// Calculates the sizes of the given [`Track`](Track)s in the available
+/// space.
+///
+/// The space left by the sized tracks is split between the filling tracks
+/// by their portions.
+#[must_use]
+pub fn resolve_tracks(tracks: &[Track], available: f32) -> Vec<f32> {
+    let (sized, portions) = tracks
+        .iter()
+        .fold((0.0, 0), |(sized, portions), track| match track {
+            Track::Size(size) => (sized + size, portions),
+            Track::Fill { portion, .. } => (sized, portions + u32::from(*portion)),
+        });
+    let left = (available - sized).max(0.0);
+
+    tracks
+        .iter()
+        .map(|track| match track {
+            Track::Size(size) => *size,
+            Track::Fill { measured, .. } if !left.is_finite() || portions == 0 => *measured,
+            #[allow(clippy::cast_precision_loss)]
+            Track::Fill { portion, .. } => left * f32::from(*portion) / portions as f32,
+        })
+        .collect()
+}
+
+/// Calculates the offset of each track from the sizes of the tracks.
+#[must_use]
+pub fn track_offsets(sizes: &[f32]) -> Vec<f32> {
+    sizes
+        .iter()
+        .scan(0.0, |offset, size| {
+            let start = *offset;
+            *offset += size;
+            Some(start)
+        })
+        .collect()
+}
+
+/// Splits the background of each of the given number of rows of a grid into
+/// areas of adjacent cells.
+///
+/// A row is a single area, except for the columns covered by cells spanning
+/// several rows. Such a cell is an area of its own over all its rows, which
+/// belongs to its first row. The areas are returned grouped by the row they
+/// belong to.
+#[must_use]
+pub fn row_areas(columns: usize, rows: usize, placements: &[Placement]) -> Vec<Vec<Placement>> {
+    let spanning: Vec<&Placement> = placements
+        .iter()
+        .filter(|placement| placement.row_span > 1)
+        .collect();
+
+    (0..rows)
+        .map(|row| {
+            let covered = |column: usize| {
+                spanning.iter().any(|placement| {
+                    (placement.row..placement.row + placement.row_span).contains(&row)
+                        && (placement.column..placement.column + placement.column_span)
+                            .contains(&column)
+                })
+            };
+
+            let mut areas: Vec<Placement> = Vec::new();
+            for column in (0..columns).filter(|column| !covered(*column)) {
+                match areas.last_mut() {
+                    Some(area) if area.column + area.column_span == column => {
+                        area.column_span += 1;
+                    }
+                    _ => areas.push(Placement {
+                        row,
+                        column,
+                        row_span: 1,
+                        column_span: 1,
+                    }),
+                }
+            }
+
+            areas.extend(
+                spanning
+                    .iter()
+                    .filter(|placement| placement.row == row)
+                    .copied(),
+            );
+
+            areas
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{place, resolve_tracks, row_areas, track_offsets, track_sizes, Placement, Track};
+
+    fn placement(row: usize, column: usize, row_span: usize, column_span: usize) -> Placement {
+        Placement {
+            row,
+            column,
+            row_span,
+            column_span,
+        }
+    }
+
+    #[test]
+    fn place_without_spans_test() {
+        assert_eq!(
+            place(2, vec![(1, 1); 3]),
+            vec![
+                placement(0, 0, 1, 1),
+                placement(0, 1, 1, 1),
+                placement(1, 0, 1, 1)
+            ]
+        );
+        assert!(place(0, vec![(1, 1)]).is_empty());
+    }
+
+    #[test]
+    fn place_header_test() {
+        // A full width header over a two column body.
+        assert_eq!(
+            place(2, vec![(1, 2), (1, 1), (1, 1)]),
+            vec![
+                placement(0, 0, 1, 2),
+                placement(1, 0, 1, 1),
+                placement(1, 1, 1, 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn place_clamps_to_edge_test() {
+        assert_eq!(
+            place(3, vec![(1, 1), (1, 5)]),
+            vec![placement(0, 0, 1, 1), placement(0, 1, 1, 2)]
+        );
+    }
+
+    #[test]
+    fn place_skips_row_spans_test() {
+        assert_eq!(
+            place(2, vec![(2, 1), (1, 1), (1, 1), (1, 1)]),
+            vec![
+                placement(0, 0, 2, 1),
+                placement(0, 1, 1, 1),
+                placement(1, 1, 1, 1),
+                placement(2, 0, 1, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn place_first_placed_wins_test() {
+        // The second cell spans down into the row of the third cell, so the
+        // column span of the third cell is clamped before it.
+        assert_eq!(
+            place(3, vec![(1, 1), (2, 1), (1, 1), (1, 3)]),
+            vec![
+                placement(0, 0, 1, 1),
+                placement(0, 1, 2, 1),
+                placement(0, 2, 1, 1),
+                placement(1, 0, 1, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn track_sizes_test() {
+        assert_eq!(
+            track_sizes(2, &[(0, 1, 10.0), (1, 1, 20.0), (0, 1, 15.0)]),
+            vec![15.0, 20.0]
+        );
+        // The spanning cell is 10 wider than both tracks.
+        assert_eq!(
+            track_sizes(2, &[(0, 1, 10.0), (1, 1, 20.0), (0, 2, 40.0)]),
+            vec![15.0, 25.0]
+        );
+        // A smaller spanning cell does not change the tracks.
+        assert_eq!(
+            track_sizes(2, &[(0, 1, 10.0), (1, 1, 20.0), (0, 2, 20.0)]),
+            vec![10.0, 20.0]
+        );
+    }
+
+    #[test]
+    fn track_offsets_test() {
+        assert_eq!(track_offsets(&[10.0, 20.0, 5.0]), vec![0.0, 10.0, 30.0]);
+    }
+
+    #[test]
+    fn resolve_tracks_test() {
+        let fill = Track::Fill {
+            portion: 1,
+            measured: 20.0,
+        };
+
+        assert_eq!(
+            resolve_tracks(&[Track::Size(100.0), fill, fill], 400.0),
+            vec![100.0, 150.0, 150.0]
+        );
+        assert_eq!(
+            resolve_tracks(
+                &[
+                    Track::Size(100.0),
+                    Track::Fill {
+                        portion: 3,
+                        measured: 0.0
+                    },
+                    fill
+                ],
+                500.0
+            ),
+            vec![100.0, 300.0, 100.0]
+        );
+        // No space left
+        assert_eq!(
+            resolve_tracks(&[Track::Size(100.0), fill], 50.0),
+            vec![100.0, 0.0]
+        );
+        // Unbounded space
+        assert_eq!(
+            resolve_tracks(&[Track::Size(100.0), fill], f32::INFINITY),
+            vec![100.0, 20.0]
+        );
+    }
+
+    #[test]
+    fn row_areas_test() {
+        assert_eq!(
+            row_areas(2, 2, &place(2, vec![(1, 1); 4])),
+            vec![vec![placement(0, 0, 1, 2)], vec![placement(1, 0, 1, 2)]]
+        );
+
+        // The first cell spans the first column of both rows.
+        let placements = place(3, vec![(2, 1), (1, 1), (1, 1), (1, 1), (1, 1)]);
+        assert_eq!(
+            row_areas(3, 2, &placements),
+            vec![
+                vec![placement(0, 1, 1, 2), placement(0, 0, 2, 1)],
+                vec![placement(1, 1, 1, 2)]
+            ]
+        );
+
+        // A cell spanning the middle column splits the second row.
+        let placements = place(3, vec![(1, 1), (2, 1), (1, 1), (1, 1), (1, 1)]);
+        assert_eq!(
+            row_areas(3, 2, &placements),
+            vec![
+                vec![
+                    placement(0, 0, 1, 1),
+                    placement(0, 2, 1, 1),
+                    placement(0, 1, 2, 1)
+                ],
+                vec![placement(1, 0, 1, 1), placement(1, 2, 1, 1)]
+            ]
+        );
+    }
+}
@@ -1,21 +1,45 @@
 //! A module fitting `iced_core`.
 
+#[cfg(feature = "badge")]
+pub mod badge;
+
+#[cfg(feature = "card")]
+pub mod card;
+
 #[cfg(feature = "date_picker")]
 //#[cfg(all(feature = "date_picker", not(target_arch = "wasm32")))]
 pub mod date;
 
+#[cfg(feature = "date_time_picker")]
+pub mod date_time;
+
 #[cfg(all(feature = "time_picker", not(target_arch = "wasm32")))]
 pub mod clock;
 
 #[cfg(all(feature = "color_picker", not(target_arch = "wasm32")))]
 pub mod color;
 
+#[cfg(feature = "grid")]
+pub mod grid;
+
+#[cfg(feature = "number_input")]
+pub mod number;
+
 #[cfg(not(target_arch = "wasm32"))]
 pub mod overlay;
 
 #[cfg(not(target_arch = "wasm32"))]
 pub mod renderer;
 
+#[cfg(feature = "selection_list")]
+pub mod selection_list;
+
+#[cfg(feature = "split")]
+pub mod split;
+
+#[cfg(feature = "tab_bar")]
+pub mod tab_bar;
+
 #[cfg(feature = "time_picker")]
 //#[cfg(all(feature = "time_picker", not(target_arch = "wasm32")))]
 pub mod time;
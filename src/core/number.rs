@@ -0,0 +1,547 @@
+//! Helper functions for formatting and parsing the values of a number input.
+//!
+//! *This API requires the following crate features to be activated: `number_input`*
+use num_traits::Num;
+use std::fmt::Display;
+
+/// The radix an integer value of a number input is displayed and edited in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Radix {
+    /// Decimal digits (`0-9`).
+    #[default]
+    Decimal,
+    /// Hexadecimal digits (`0-9a-fA-F`).
+    Hexadecimal,
+    /// Binary digits (`0-1`).
+    Binary,
+}
+
+impl Radix {
+    /// Returns the base of the [`Radix`](Radix).
+    #[must_use]
+    pub const fn base(self) -> u32 {
+        match self {
+            Self::Decimal => 10,
+            Self::Hexadecimal => 16,
+            Self::Binary => 2,
+        }
+    }
+
+    /// Returns the prefix that may be put in front of a value in the
+    /// [`Radix`](Radix), e.g. `0x` for hexadecimal values.
+    #[must_use]
+    pub const fn prefix(self) -> &'static str {
+        match self {
+            Self::Decimal => "",
+            Self::Hexadecimal => "0x",
+            Self::Binary => "0b",
+        }
+    }
+
+    /// Returns true if the given character is a digit of the [`Radix`](Radix).
+    #[must_use]
+    pub const fn is_digit(self, c: char) -> bool {
+        c.is_digit(self.base())
+    }
+
+    /// Returns the digits of the [`Radix`](Radix) in the given numeric type
+    /// followed by its base.
+    fn digits<T: Num + Copy>(self) -> Vec<T> {
+        std::iter::successors(Some(T::zero()), |digit| Some(*digit + T::one()))
+            .take(self.base() as usize + 1)
+            .collect()
+    }
+}
+
+/// Formats the given integer value in the given [`Radix`](Radix).
+///
+/// Hexadecimal digits are lower case and no prefix is added. Returns `None`
+/// if the value is not an integer.
+#[must_use]
+pub fn format_radix<T: Num + PartialOrd + Copy>(value: T, radix: Radix) -> Option<String> {
+    let mut digits = radix.digits::<T>();
+    let base = digits.pop()?;
+
+    let mut text = Vec::new();
+    let mut rest = value;
+    loop {
+        // The remainder is negative for negative values, so the magnitude of
+        // the value is never taken and the minimum of a type can't overflow.
+        let remainder = rest % base;
+        let digit = if remainder < T::zero() {
+            T::zero() - remainder
+        } else {
+            remainder
+        };
+        let digit = digits.iter().position(|d| *d == digit)?;
+        #[allow(clippy::cast_possible_truncation)]
+        text.push(std::char::from_digit(digit as u32, radix.base())?);
+
+        rest = (rest - remainder) / base;
+        if rest == T::zero() {
+            break;
+        }
+    }
+
+    if value < T::zero() {
+        text.push('-');
+    }
+
+    Some(text.into_iter().rev().collect())
+}
+
+/// Parses the given text as an integer value in the given [`Radix`](Radix)
+/// and clamps it into the given bounds.
+///
+/// The text may start with a sign followed by the prefix of the radix.
+/// Values beyond the bounds are clamped while they are parsed, so they can't
+/// overflow the numeric type. Returns `None` if the text contains no digits
+/// or any character that isn't a digit of the radix.
+#[must_use]
+pub fn parse_radix<T: Num + PartialOrd + Copy>(
+    text: &str,
+    radix: Radix,
+    bounds: (T, T),
+) -> Option<T> {
+    let (min, max) = bounds;
+    let (is_negative, unsigned) = text
+        .strip_prefix('-')
+        .map_or((false, text), |unsigned| (true, unsigned));
+    let prefix = radix.prefix();
+    let unsigned = unsigned
+        .strip_prefix(prefix)
+        .or_else(|| unsigned.strip_prefix(prefix.to_uppercase().as_str()))
+        .unwrap_or(unsigned);
+
+    if unsigned.is_empty() {
+        return None;
+    }
+
+    let mut digits = radix.digits::<T>();
+    let base = digits.pop()?;
+
+    let mut value = T::zero();
+    let mut is_clamped = false;
+    for c in unsigned.chars() {
+        let digit = digits[c.to_digit(radix.base())? as usize];
+
+        if is_clamped {
+            continue;
+        }
+
+        if is_negative {
+            if min >= T::zero() || value < (min + digit) / base {
+                value = min;
+                is_clamped = true;
+            } else {
+                value = value * base - digit;
+            }
+        } else if digit > max || value > (max - digit) / base {
+            value = max;
+            is_clamped = true;
+        } else {
+            value = value * base + digit;
+        }
+    }
+
+    Some(if value < min {
+        min
+    } else if value > max {
+        max
+    } else {
+        value
+    })
+}
+
+/// Increases the given value by `step` times `factor`, saturating at `max`.
+///
+/// The value is left unchanged if it isn't below `max` or the step or factor
+/// aren't positive. Neither the product nor the sum can overflow the numeric
+/// type, even if the distance between the value and `max` doesn't fit into it.
+#[must_use]
+pub fn increase<T: Num + PartialOrd + Copy>(value: T, step: T, factor: T, max: T) -> T {
+    let zero = T::zero();
+    if value >= max || step <= zero || factor <= zero {
+        return value;
+    }
+
+    if value < zero && max >= zero {
+        if step <= max / factor {
+            return value + step * factor;
+        }
+
+        // Split the value into a multiple of the factor and a non positive
+        // remainder, so the step can be added before multiplying.
+        let rem = value % factor;
+        let steps = (value - rem) / factor + step;
+        let limit = max / factor;
+        if steps <= limit {
+            steps * factor + rem
+        } else if steps - T::one() > limit {
+            max
+        } else {
+            let base = (steps - T::one()) * factor;
+            let amount = factor + rem;
+            if amount < max - base {
+                base + amount
+            } else {
+                max
+            }
+        }
+    } else if step <= (max - value) / factor {
+        value + step * factor
+    } else {
+        max
+    }
+}
+
+/// Decreases the given value by `step` times `factor`, saturating at `min`.
+///
+/// The value is left unchanged if it isn't above `min` or the step or factor
+/// aren't positive. Neither the product nor the difference can overflow the
+/// numeric type, even if the distance between the value and `min` doesn't fit
+/// into it.
+#[must_use]
+pub fn decrease<T: Num + PartialOrd + Copy>(value: T, step: T, factor: T, min: T) -> T {
+    let zero = T::zero();
+    if value <= min || step <= zero || factor <= zero {
+        return value;
+    }
+
+    if value >= zero && min < zero {
+        if step <= value / factor {
+            return value - step * factor;
+        }
+
+        // Split the value into a multiple of the factor and a non negative
+        // remainder, so the step can be subtracted before multiplying.
+        let rem = value % factor;
+        let steps = (value - rem) / factor - step;
+        let limit = min / factor;
+        if steps >= limit {
+            steps * factor + rem
+        } else if steps + T::one() < limit {
+            min
+        } else {
+            let base = (steps + T::one()) * factor;
+            let amount = factor - rem;
+            if base >= zero {
+                let new_val = base - amount;
+                if new_val > min {
+                    new_val
+                } else {
+                    min
+                }
+            } else if amount < base - min {
+                base - amount
+            } else {
+                min
+            }
+        }
+    } else if step <= (value - min) / factor {
+        value - step * factor
+    } else {
+        min
+    }
+}
+
+/// Formats the given value with the given amount of decimal places.
+///
+/// The amount of decimal places is ignored for integer values. If no amount is
+/// given, the value is formatted as it is.
+#[must_use]
+pub fn format_value<T: Display>(value: T, decimals: Option<usize>) -> String {
+    decimals.map_or_else(
+        || value.to_string(),
+        |decimals| format!("{:.*}", decimals, value),
+    )
+}
+
+/// Inserts the group separator between every three digits of the integer part
+/// of the given formatted value and replaces its decimal point by the given
+/// decimal separator.
+#[must_use]
+pub fn localize(text: &str, group_separator: Option<char>, decimal_separator: char) -> String {
+    let (sign, unsigned) = text
+        .strip_prefix('-')
+        .map_or(("", text), |unsigned| ("-", unsigned));
+    let (integer, fraction) = unsigned
+        .split_once('.')
+        .map_or((unsigned, None), |(integer, fraction)| {
+            (integer, Some(fraction))
+        });
+
+    let mut localized = String::from(sign);
+    let len = integer.chars().count();
+    for (i, c) in integer.chars().enumerate() {
+        if let Some(separator) = group_separator.filter(|_| i > 0 && (len - i) % 3 == 0) {
+            localized.push(separator);
+        }
+        localized.push(c);
+    }
+
+    if let Some(fraction) = fraction {
+        localized.push(decimal_separator);
+        localized.push_str(fraction);
+    }
+
+    localized
+}
+
+/// Extracts a value from the given pasted text, so that it can be parsed.
+///
+/// All characters that aren't digits of the [`Radix`](Radix) are dropped,
+/// e.g. `"$1,234.56"` becomes `"1234.56"`. A minus sign in front of the first
+/// digit and the prefix of the radix are kept. The first decimal separator
+/// behind a digit is replaced by a decimal point if `decimals` is set,
+/// otherwise the fraction is dropped. A group separator is never taken for the
+/// decimal separator. Returns `None` if the text contains no digits.
+#[must_use]
+pub fn sanitize(
+    text: &str,
+    radix: Radix,
+    group_separator: Option<char>,
+    decimal_separator: char,
+    decimals: bool,
+) -> Option<String> {
+    let prefix = radix.prefix();
+    let mut sanitized = String::new();
+    let mut has_digits = false;
+    let mut has_decimal_point = false;
+
+    let mut rest = text;
+    while let Some(c) = rest.chars().next() {
+        if !has_digits
+            && !prefix.is_empty()
+            && rest.len() > prefix.len()
+            && rest.is_char_boundary(prefix.len())
+            && rest[..prefix.len()].eq_ignore_ascii_case(prefix)
+            && rest[prefix.len()..].starts_with(|c| radix.is_digit(c))
+        {
+            sanitized.push_str(&rest[..prefix.len()]);
+            rest = &rest[prefix.len()..];
+            continue;
+        }
+
+        if radix.is_digit(c) {
+            sanitized.push(c);
+            has_digits = true;
+        } else if c == '-' && sanitized.is_empty() {
+            sanitized.push(c);
+        } else if radix == Radix::Decimal
+            && c == decimal_separator
+            && Some(c) != group_separator
+            && has_digits
+        {
+            if !decimals {
+                break;
+            }
+            if !has_decimal_point {
+                sanitized.push('.');
+                has_decimal_point = true;
+            }
+        }
+
+        rest = &rest[c.len_utf8()..];
+    }
+
+    has_digits.then(|| sanitized.trim_end_matches('.').to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        decrease, format_radix, format_value, increase, localize, parse_radix, sanitize, Radix,
+    };
+
+    #[test]
+    fn step_test() {
+        assert_eq!(increase(5_u32, 2, 1, 100), 7);
+        assert_eq!(increase(5_u32, 2, 10, 100), 25);
+        assert_eq!(increase(95_u32, 2, 10, 100), 100);
+        assert_eq!(decrease(25_u32, 2, 10, 0), 5);
+        assert_eq!(decrease(5_u32, 2, 10, 0), 0);
+        assert_eq!(increase(-5_i32, 2, 10, 100), 15);
+        assert_eq!(decrease(5_i32, 2, 10, -100), -15);
+
+        // Values at or beyond the bound and non positive steps are kept.
+        assert_eq!(increase(100_u32, 2, 1, 100), 100);
+        assert_eq!(decrease(0_u32, 2, 1, 0), 0);
+        assert_eq!(increase(5_i32, -2, 1, 100), 5);
+        assert_eq!(decrease(5_i32, 2, 0, -100), 5);
+
+        assert!((increase(0.5_f64, 0.25, 10.0, 10.0) - 3.0).abs() < f64::EPSILON);
+        assert!((decrease(0.5_f64, 0.25, 10.0, -1.0) + 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn step_limits_test() {
+        macro_rules! assert_limits {
+            ($($t:ty),*) => {$(
+                let (min, max) = (<$t>::MIN, <$t>::MAX);
+                assert_eq!(increase(max - 1, max, max, max), max);
+                assert_eq!(increase(min, max, max, max), max);
+                assert_eq!(increase(min, 1, 1, max), min + 1);
+                assert_eq!(increase(min, max / 2 + 1, 4, max), max);
+                assert_eq!(decrease(min + 1, max, max, min), min);
+                assert_eq!(decrease(max, max, max, min), min);
+                assert_eq!(decrease(max, 1, 1, min), max - 1);
+                assert_eq!(decrease(max, max / 2 + 1, 4, min), min);
+            )*};
+        }
+
+        assert_limits!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+        assert_eq!(increase(-128_i8, 100, 2, 127), 72);
+        assert_eq!(decrease(127_i8, 100, 2, -128), -73);
+    }
+
+    #[test]
+    fn step_exhaustive_test() {
+        for value in i8::MIN..=i8::MAX {
+            for step in 1..=i8::MAX {
+                for &factor in &[1, 2, 3, 10, 127] {
+                    for &(min, max) in &[(i8::MIN, i8::MAX), (-100, 100), (-10, -1), (1, 120)] {
+                        if !(min..=max).contains(&value) {
+                            continue;
+                        }
+                        let wide = |v: i32| v.max(i32::from(min)).min(i32::from(max));
+                        let amount = i32::from(step) * i32::from(factor);
+
+                        assert_eq!(
+                            i32::from(increase(value, step, factor, max)),
+                            wide(i32::from(value) + amount),
+                        );
+                        assert_eq!(
+                            i32::from(decrease(value, step, factor, min)),
+                            wide(i32::from(value) - amount),
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[allow(clippy::approx_constant)]
+    fn format_value_test() {
+        let value = 1.23456_f64;
+        assert_eq!(format_value(value, Some(2)), "1.23");
+        assert_eq!(format_value(value, None), "1.23456");
+        assert!((value - 1.23456).abs() < f64::EPSILON);
+
+        let value = 3.14159_f64;
+        assert_eq!(format_value(value, Some(2)), "3.14");
+        assert_eq!(format_value(value, Some(4)), "3.1416");
+        assert!((value - 3.14159).abs() < f64::EPSILON);
+
+        assert_eq!(format_value(2.7_f32, Some(0)), "3");
+        assert_eq!(format_value(1.0_f32, Some(3)), "1.000");
+
+        assert_eq!(format_value(42_u32, Some(2)), "42");
+        assert_eq!(format_value(-7_i64, None), "-7");
+    }
+
+    #[test]
+    fn localize_test() {
+        assert_eq!(localize("1234567", Some(' '), '.'), "1 234 567");
+        assert_eq!(localize("1000000", Some(','), '.'), "1,000,000");
+        assert_eq!(localize("-1234567.891", Some('.'), ','), "-1.234.567,891");
+        assert_eq!(localize("123", Some(','), '.'), "123");
+        assert_eq!(localize("-123456", Some(','), '.'), "-123,456");
+        assert_eq!(localize("3.", None, ','), "3,");
+    }
+
+    #[test]
+    fn format_radix_test() {
+        assert_eq!(format_radix(255_u8, Radix::Hexadecimal), Some("ff".into()));
+        assert_eq!(format_radix(0_u8, Radix::Hexadecimal), Some("0".into()));
+        assert_eq!(format_radix(5_u32, Radix::Binary), Some("101".into()));
+        assert_eq!(
+            format_radix(-128_i8, Radix::Hexadecimal),
+            Some("-80".into())
+        );
+        assert_eq!(format_radix(-42_i32, Radix::Decimal), Some("-42".into()));
+        assert_eq!(format_radix(1.5_f32, Radix::Hexadecimal), None);
+    }
+
+    #[test]
+    fn parse_radix_test() {
+        let bounds = (u8::MIN, u8::MAX);
+        assert_eq!(parse_radix("ff", Radix::Hexadecimal, bounds), Some(255));
+        assert_eq!(parse_radix("0xFF", Radix::Hexadecimal, bounds), Some(255));
+        assert_eq!(parse_radix("0b101", Radix::Binary, bounds), Some(5));
+        assert_eq!(parse_radix("", Radix::Hexadecimal, bounds), None);
+        assert_eq!(parse_radix("0x", Radix::Hexadecimal, bounds), None);
+        assert_eq!(parse_radix("fg", Radix::Hexadecimal, bounds), None);
+        assert_eq!(parse_radix("2", Radix::Binary, bounds), None);
+
+        // The value is round tripped.
+        let text = format_radix(255_u8, Radix::Hexadecimal).unwrap_or_default();
+        assert_eq!(parse_radix(&text, Radix::Hexadecimal, bounds), Some(255));
+    }
+
+    #[test]
+    fn parse_radix_clamps_test() {
+        let bounds = (u8::MIN, u8::MAX);
+        assert_eq!(parse_radix("fff", Radix::Hexadecimal, bounds), Some(255));
+        assert_eq!(parse_radix("-1", Radix::Hexadecimal, bounds), Some(0));
+        assert_eq!(parse_radix("ffff_ff", Radix::Hexadecimal, bounds), None);
+
+        let bounds = (i8::MIN, i8::MAX);
+        assert_eq!(parse_radix("-80", Radix::Hexadecimal, bounds), Some(-128));
+        assert_eq!(parse_radix("-81", Radix::Hexadecimal, bounds), Some(-128));
+        assert_eq!(parse_radix("7f", Radix::Hexadecimal, bounds), Some(127));
+        assert_eq!(parse_radix("80", Radix::Hexadecimal, bounds), Some(127));
+
+        assert_eq!(parse_radix("ff", Radix::Hexadecimal, (16, 100)), Some(100));
+        assert_eq!(parse_radix("1", Radix::Hexadecimal, (16, 100)), Some(16));
+    }
+
+    #[test]
+    fn sanitize_test() {
+        let decimal = |text| sanitize(text, Radix::Decimal, Some(','), '.', true);
+        assert_eq!(decimal("$1,234.56"), Some("1234.56".into()));
+        assert_eq!(decimal("  -42 EUR "), Some("-42".into()));
+        assert_eq!(decimal("Total: 1,000.5.0"), Some("1000.50".into()));
+        assert_eq!(decimal("approx. 12"), Some("12".into()));
+        assert_eq!(decimal("12."), Some("12".into()));
+        assert_eq!(decimal("n/a"), None);
+        assert_eq!(decimal(""), None);
+        assert_eq!(decimal("-.,$"), None);
+
+        // Localized values are round tripped.
+        let localized = localize(&format_value(1_234_567_u32, None), Some(' '), '.');
+        assert_eq!(
+            sanitize(&localized, Radix::Decimal, Some(' '), '.', false),
+            Some("1234567".into())
+        );
+
+        // The fraction is dropped without decimal places.
+        assert_eq!(
+            sanitize("$1,234.56", Radix::Decimal, Some(','), '.', false),
+            Some("1234".into())
+        );
+        assert_eq!(
+            sanitize("1.234,5 €", Radix::Decimal, Some('.'), ',', true),
+            Some("1234.5".into())
+        );
+    }
+
+    #[test]
+    fn sanitize_radix_test() {
+        assert_eq!(
+            sanitize("0x1FF", Radix::Hexadecimal, None, '.', false),
+            Some("0x1FF".into())
+        );
+        assert_eq!(
+            sanitize("#FF_00", Radix::Hexadecimal, None, '.', false),
+            Some("FF00".into())
+        );
+        assert_eq!(
+            sanitize("-0b1010 (ten)", Radix::Binary, None, '.', false),
+            Some("-0b1010".into())
+        );
+        assert_eq!(sanitize("xyz", Radix::Binary, None, '.', false), None);
+    }
+}
@@ -1,5 +1,11 @@
 //! Helper functions for overlays
-use iced_native::{Point, Size};
+use std::time::{Duration, Instant};
+
+use iced_native::{mouse, touch, Event, Point, Size};
+
+/// The minimum time between two reported changes caused by moving the
+/// cursor, which is about one frame at 60 Hz.
+pub const CHANGE_INTERVAL: Duration = Duration::from_millis(16);
 
 /// Trait containing functions for positioning of nodes.
 pub trait Position {
@@ -31,3 +37,77 @@ impl Position for iced_native::layout::Node {
         ));
     }
 }
+
+/// Returns true if the event is a movement of the cursor or of a finger.
+#[must_use]
+pub const fn is_movement(event: &Event) -> bool {
+    matches!(
+        event,
+        Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. })
+    )
+}
+
+/// Throttles the change messages of the overlay of a picker.
+///
+/// Changes caused by moving the cursor, like dragging, are reported at most
+/// once per [`CHANGE_INTERVAL`](CHANGE_INTERVAL). All other changes, like
+/// clicks and key presses, are reported immediately. A held back change is
+/// reported with the next event after the interval.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ChangeThrottle {
+    /// Whether a change is held back.
+    pending: bool,
+    /// The time of the last reported change.
+    reported_at: Option<Instant>,
+}
+
+impl ChangeThrottle {
+    /// Records whether an event changed the picked value and returns true if
+    /// the change should be reported now.
+    ///
+    /// It expects whether the event was a movement of the cursor and the
+    /// current time.
+    pub fn report(&mut self, changed: bool, moved: bool, now: Instant) -> bool {
+        self.pending |= changed;
+
+        let due = !moved
+            || !matches!(self.reported_at, Some(at) if now.duration_since(at) < CHANGE_INTERVAL);
+
+        if self.pending && due {
+            self.pending = false;
+            self.reported_at = Some(now);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::{ChangeThrottle, CHANGE_INTERVAL};
+
+    #[test]
+    fn change_throttle_test() {
+        let mut throttle = ChangeThrottle::default();
+        let start = Instant::now();
+
+        assert!(!throttle.report(false, false, start));
+        assert!(throttle.report(true, true, start));
+
+        // Dragging within the interval is held back...
+        let soon = start + Duration::from_millis(1);
+        assert!(!throttle.report(true, true, soon));
+        // ... until the interval has passed, even without a new change.
+        assert!(throttle.report(false, true, start + CHANGE_INTERVAL));
+        assert!(!throttle.report(false, true, start + 2 * CHANGE_INTERVAL));
+
+        // Other changes are reported immediately.
+        let later = start + 2 * CHANGE_INTERVAL;
+        assert!(throttle.report(true, true, later));
+        assert!(throttle.report(true, false, later));
+    }
+}
@@ -0,0 +1,341 @@
+//! Helper functions for selecting several options of a selection list.
+//!
+//! *This API requires the following crate features to be activated: `selection_list`*
+use std::ops::Range;
+
+/// The way a click changes the selected options of a selection list.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Click {
+    /// A plain click selecting only the clicked option.
+    Replace,
+    /// A click toggling the clicked option (Ctrl+click).
+    Toggle,
+    /// A click selecting all options between the anchor and the clicked
+    /// option (Shift+click).
+    Range,
+}
+
+/// A keyboard navigation moving the selection of a selection list.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Navigation {
+    /// Moves to the previous option (Up).
+    Previous,
+    /// Moves to the next option (Down).
+    Next,
+    /// Moves a page of options up (Page Up).
+    PageUp,
+    /// Moves a page of options down (Page Down).
+    PageDown,
+    /// Moves to the first option (Home).
+    First,
+    /// Moves to the last option (End).
+    Last,
+}
+
+/// Applies a click on the option at the given index to the selected options.
+///
+/// It expects the sorted indices of the selected options and the anchor of
+/// range selections. It returns the new sorted indices and the new anchor.
+#[must_use]
+pub fn select(
+    selected: &[usize],
+    anchor: Option<usize>,
+    index: usize,
+    click: Click,
+) -> (Vec<usize>, Option<usize>) {
+    match click {
+        Click::Replace => (vec![index], Some(index)),
+        Click::Toggle => {
+            let mut selected = selected.to_vec();
+            match selected.binary_search(&index) {
+                Ok(position) => {
+                    let _ = selected.remove(position);
+                }
+                Err(position) => selected.insert(position, index),
+            }
+            (selected, Some(index))
+        }
+        Click::Range => {
+            let anchor = anchor.unwrap_or(index);
+            let range = anchor.min(index)..=anchor.max(index);
+            (range.collect(), Some(anchor))
+        }
+    }
+}
+
+/// Applies a keyboard navigation to the row of the current option in a list
+/// of `len` options showing `page` options at once.
+///
+/// Without a current option, moving down starts at the first option and
+/// moving up at the last. It returns the row of the new option or `None` if
+/// the list is empty.
+#[must_use]
+pub fn navigate(
+    current: Option<usize>,
+    len: usize,
+    page: usize,
+    navigation: Navigation,
+) -> Option<usize> {
+    let last = len.checked_sub(1)?;
+    let page = page.max(1);
+
+    let row = match (current, navigation) {
+        (_, Navigation::First) | (None, Navigation::Next | Navigation::PageDown) => 0,
+        (_, Navigation::Last) | (None, Navigation::Previous | Navigation::PageUp) => last,
+        (Some(row), Navigation::Previous) => row.saturating_sub(1),
+        (Some(row), Navigation::Next) => row + 1,
+        (Some(row), Navigation::PageUp) => row.saturating_sub(page),
+        (Some(row), Navigation::PageDown) => row + page,
+    };
+
+    Some(row.min(last))
+}
+
+/// Returns true if the label contains the filter, ignoring the case.
+///
+/// An empty filter matches every label.
+#[must_use]
+pub fn matches_filter(label: &str, filter: &str) -> bool {
+    label.to_lowercase().contains(&filter.to_lowercase())
+}
+
+/// Returns true if the label starts with the prefix, ignoring the case.
+#[must_use]
+pub fn matches_prefix(label: &str, prefix: &str) -> bool {
+    label.to_lowercase().starts_with(&prefix.to_lowercase())
+}
+
+/// Calculates the scroll offset showing the whole item at the given
+/// position in a viewport of the given height.
+///
+/// The offset is kept if the item is already fully visible.
+#[must_use]
+pub fn scroll_into_view(offset: f32, viewport_height: f32, item_y: f32, item_height: f32) -> f32 {
+    if item_y < offset {
+        item_y
+    } else if item_y + item_height > offset + viewport_height {
+        item_y + item_height - viewport_height
+    } else {
+        offset
+    }
+}
+
+/// Gets the row a dragged option would be dropped at.
+///
+/// It expects the vertical centers of all rows at their original positions,
+/// the row of the dragged option and the current vertical center of the
+/// dragged option.
+#[must_use]
+pub fn drop_row(centers: &[f32], from: usize, center: f32) -> usize {
+    centers
+        .iter()
+        .enumerate()
+        .filter(|(row, c)| *row != from && **c < center)
+        .count()
+}
+
+/// Maps the index of an option in a grouped selection list to the index of
+/// its group and its index inside the group.
+///
+/// It expects the number of options of each group in order. It returns `None`
+/// if the index is behind the last option.
+#[must_use]
+pub fn group_position(
+    group_sizes: impl IntoIterator<Item = usize>,
+    index: usize,
+) -> Option<(usize, usize)> {
+    let mut start = 0;
+
+    for (group, size) in group_sizes.into_iter().enumerate() {
+        if index < start + size {
+            return Some((group, index - start));
+        }
+        start += size;
+    }
+
+    None
+}
+
+/// Gets the slots of a list of equally high slots that are visible in a
+/// viewport scrolled by the given offset, extended by the overscan on both
+/// sides.
+///
+/// All slots are visible in a viewport of infinite height.
+#[must_use]
+pub fn visible_slots(
+    offset: f32,
+    viewport_height: f32,
+    slot_height: f32,
+    slots: usize,
+    overscan: usize,
+) -> Range<usize> {
+    if !viewport_height.is_finite() || slot_height <= 0.0 {
+        return 0..slots;
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    let max_offset = (slots as f32 * slot_height - viewport_height).max(0.0);
+    let offset = offset.clamp(0.0, max_offset);
+
+    let first = (offset / slot_height).floor() as usize;
+    let last = ((offset + viewport_height) / slot_height).ceil() as usize;
+
+    first.saturating_sub(overscan).min(slots)..(last + overscan).min(slots)
+}
+
+/// Gets the first row of a list placed at or behind the given slot.
+///
+/// Each section header takes a slot above its row. It expects the sorted rows
+/// the headers are placed above.
+#[must_use]
+pub fn slot_to_row(slot: usize, header_rows: &[usize]) -> usize {
+    let mut row = slot.saturating_sub(header_rows.len());
+    while row + header_rows.partition_point(|header| *header <= row) < slot {
+        row += 1;
+    }
+    row
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        drop_row, group_position, matches_filter, matches_prefix, navigate, scroll_into_view,
+        select, slot_to_row, visible_slots, Click, Navigation,
+    };
+
+    #[test]
+    fn replace_test() {
+        assert_eq!(
+            select(&[1, 2, 3], Some(1), 5, Click::Replace),
+            (vec![5], Some(5))
+        );
+    }
+
+    #[test]
+    fn toggle_test() {
+        assert_eq!(
+            select(&[1, 3], Some(1), 2, Click::Toggle),
+            (vec![1, 2, 3], Some(2))
+        );
+        assert_eq!(
+            select(&[1, 2, 3], Some(1), 2, Click::Toggle),
+            (vec![1, 3], Some(2))
+        );
+        assert_eq!(select(&[], None, 0, Click::Toggle), (vec![0], Some(0)));
+    }
+
+    #[test]
+    fn range_test() {
+        assert_eq!(
+            select(&[2], Some(2), 5, Click::Range),
+            (vec![2, 3, 4, 5], Some(2))
+        );
+        assert_eq!(
+            select(&[2], Some(2), 0, Click::Range),
+            (vec![0, 1, 2], Some(2))
+        );
+        assert_eq!(select(&[], None, 4, Click::Range), (vec![4], Some(4)));
+
+        // A second range click keeps the anchor of the first one.
+        let (selected, anchor) = select(&[2], Some(2), 5, Click::Range);
+        assert_eq!(
+            select(&selected, anchor, 3, Click::Range),
+            (vec![2, 3], Some(2))
+        );
+    }
+
+    #[test]
+    fn matches_filter_test() {
+        assert!(matches_filter("Haskell", ""));
+        assert!(matches_filter("Haskell", "ask"));
+        assert!(matches_filter("Haskell", "HASK"));
+        assert!(!matches_filter("Haskell", "rust"));
+    }
+
+    #[test]
+    fn matches_prefix_test() {
+        assert!(matches_prefix("Rust", "ru"));
+        assert!(matches_prefix("Rust", "RUST"));
+        assert!(!matches_prefix("Rust", "ust"));
+        assert!(!matches_prefix("Rust", "Rusty"));
+    }
+
+    #[test]
+    fn scroll_into_view_test() {
+        // Fully visible
+        assert!((scroll_into_view(20.0, 100.0, 40.0, 20.0) - 20.0).abs() < f32::EPSILON);
+        // Above the viewport
+        assert!((scroll_into_view(50.0, 100.0, 40.0, 20.0) - 40.0).abs() < f32::EPSILON);
+        // Below the viewport
+        assert!((scroll_into_view(0.0, 100.0, 100.0, 20.0) - 20.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn navigate_test() {
+        assert_eq!(navigate(Some(3), 10, 4, Navigation::Previous), Some(2));
+        assert_eq!(navigate(Some(0), 10, 4, Navigation::Previous), Some(0));
+        assert_eq!(navigate(Some(3), 10, 4, Navigation::Next), Some(4));
+        assert_eq!(navigate(Some(9), 10, 4, Navigation::Next), Some(9));
+        assert_eq!(navigate(Some(3), 10, 4, Navigation::PageUp), Some(0));
+        assert_eq!(navigate(Some(3), 10, 4, Navigation::PageDown), Some(7));
+        assert_eq!(navigate(Some(7), 10, 4, Navigation::PageDown), Some(9));
+        assert_eq!(navigate(Some(3), 10, 4, Navigation::First), Some(0));
+        assert_eq!(navigate(Some(3), 10, 4, Navigation::Last), Some(9));
+    }
+
+    #[test]
+    fn navigate_without_current_test() {
+        assert_eq!(navigate(None, 10, 4, Navigation::Next), Some(0));
+        assert_eq!(navigate(None, 10, 4, Navigation::Previous), Some(9));
+        assert_eq!(navigate(None, 0, 4, Navigation::Next), None);
+        assert_eq!(navigate(Some(2), 0, 4, Navigation::Last), None);
+    }
+
+    #[test]
+    fn drop_row_test() {
+        let centers = [10.0, 30.0, 50.0, 70.0];
+
+        assert_eq!(drop_row(&centers, 0, 10.0), 0);
+        assert_eq!(drop_row(&centers, 0, 55.0), 2);
+        assert_eq!(drop_row(&centers, 0, 100.0), 3);
+        assert_eq!(drop_row(&centers, 3, 25.0), 1);
+        assert_eq!(drop_row(&centers, 3, -5.0), 0);
+        assert_eq!(drop_row(&centers, 2, 45.0), 2);
+    }
+
+    #[test]
+    fn group_position_test() {
+        let sizes = [2, 0, 3];
+
+        assert_eq!(group_position(sizes, 0), Some((0, 0)));
+        assert_eq!(group_position(sizes, 1), Some((0, 1)));
+        // The empty group is skipped.
+        assert_eq!(group_position(sizes, 2), Some((2, 0)));
+        assert_eq!(group_position(sizes, 4), Some((2, 2)));
+        assert_eq!(group_position(sizes, 5), None);
+    }
+
+    #[test]
+    fn visible_slots_test() {
+        assert_eq!(visible_slots(0.0, 100.0, 20.0, 100_000, 0), 0..5);
+        assert_eq!(visible_slots(50.0, 100.0, 20.0, 100_000, 2), 0..10);
+        assert_eq!(visible_slots(1000.0, 100.0, 20.0, 100_000, 2), 48..57);
+        // The offset is clamped to the end of the list.
+        assert_eq!(visible_slots(1000.0, 100.0, 20.0, 10, 2), 3..10);
+        assert_eq!(visible_slots(0.0, f32::INFINITY, 20.0, 10, 2), 0..10);
+    }
+
+    #[test]
+    fn slot_to_row_test() {
+        assert_eq!(slot_to_row(3, &[]), 3);
+
+        // Slots: header, 0, 1, header, 2
+        let headers = [0, 2];
+        assert_eq!(slot_to_row(0, &headers), 0);
+        assert_eq!(slot_to_row(1, &headers), 0);
+        assert_eq!(slot_to_row(2, &headers), 1);
+        assert_eq!(slot_to_row(3, &headers), 2);
+        assert_eq!(slot_to_row(4, &headers), 2);
+        assert_eq!(slot_to_row(5, &headers), 3);
+    }
+}
@@ -0,0 +1,64 @@
+//! Helper functions for positioning the divider of a split.
+//!
+//! *This API requires the following crate features to be activated: `split`*
+
+/// Clamps the size of the first element of a split.
+///
+/// It expects the wanted size of the first element, the space available for
+/// both elements (without the divider) and the minimum and maximum sizes of
+/// the first and the second element.
+///
+/// If the available space is smaller than the sum of both minimum sizes, the
+/// space is shared in proportion to the minimum sizes.
+#[must_use]
+pub fn clamp_first_size(
+    size: f32,
+    available: f32,
+    (min_first, max_first): (f32, f32),
+    (min_second, max_second): (f32, f32),
+) -> f32 {
+    let available = available.max(0.0);
+    let min_sum = min_first + min_second;
+
+    if available < min_sum {
+        return available * min_first / min_sum;
+    }
+
+    let lower = min_first.max(available - max_second);
+    let upper = max_first.min(available - min_second);
+
+    size.max(lower).min(upper)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::clamp_first_size;
+
+    const UNBOUNDED: (f32, f32) = (0.0, f32::INFINITY);
+
+    #[test]
+    fn clamp_min_test() {
+        let limits = (50.0, f32::INFINITY);
+
+        assert!((clamp_first_size(10.0, 200.0, limits, UNBOUNDED) - 50.0).abs() < f32::EPSILON);
+        assert!((clamp_first_size(190.0, 200.0, UNBOUNDED, limits) - 150.0).abs() < f32::EPSILON);
+        assert!((clamp_first_size(100.0, 200.0, limits, limits) - 100.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn clamp_max_test() {
+        let limits = (0.0, 80.0);
+
+        assert!((clamp_first_size(150.0, 200.0, limits, UNBOUNDED) - 80.0).abs() < f32::EPSILON);
+        assert!((clamp_first_size(10.0, 200.0, UNBOUNDED, limits) - 120.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn proportional_fallback_test() {
+        let size = clamp_first_size(10.0, 60.0, (100.0, f32::INFINITY), (50.0, f32::INFINITY));
+        assert!((size - 40.0).abs() < f32::EPSILON);
+
+        let size = clamp_first_size(10.0, -5.0, (100.0, f32::INFINITY), (50.0, f32::INFINITY));
+        assert!(size.abs() < f32::EPSILON);
+    }
+}
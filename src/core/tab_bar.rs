@@ -0,0 +1,100 @@
+//! Helper functions for navigating, reordering and fitting the tabs of a tab
+//! bar.
+//!
+//! *This API requires the following crate features to be activated: `tab_bar`*
+
+/// Gets the index a dragged tab would be dropped at.
+///
+/// It expects the horizontal centers of all tabs at their original positions,
+/// the index of the dragged tab and the current horizontal center of the
+/// dragged tab.
+#[must_use]
+pub fn drop_index(centers: &[f32], from: usize, center: f32) -> usize {
+    centers
+        .iter()
+        .enumerate()
+        .filter(|(i, c)| *i != from && **c < center)
+        .count()
+}
+
+/// Gets the index of the tab next to the given one, wrapping around at both
+/// ends of the tab bar.
+#[must_use]
+pub fn cycle(index: usize, count: usize, forward: bool) -> usize {
+    if count == 0 {
+        0
+    } else if forward {
+        (index + 1) % count
+    } else {
+        (index + count - 1) % count
+    }
+}
+
+/// Gets the indices of the tabs that fit into the available width.
+///
+/// It expects the widths of all tabs and the spacing between them. The
+/// leading tabs are kept as long as they fit next to the active tab, which
+/// is always kept even if it does not fit by itself.
+#[must_use]
+pub fn visible_tabs(widths: &[f32], spacing: f32, available: f32, active: usize) -> Vec<usize> {
+    let fits = |tabs: &[usize]| {
+        let width: f32 = tabs.iter().map(|tab| widths[*tab]).sum();
+        #[allow(clippy::cast_precision_loss)]
+        let spacing = spacing * tabs.len().saturating_sub(1) as f32;
+        width + spacing <= available
+    };
+
+    (0..=widths.len())
+        .rev()
+        .map(|count| {
+            let mut tabs: Vec<usize> = (0..count).collect();
+            if count <= active && active < widths.len() {
+                tabs.push(active);
+            }
+            tabs
+        })
+        .find(|tabs| tabs.len() <= 1 || fits(tabs))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{cycle, drop_index, visible_tabs};
+
+    #[test]
+    fn cycle_test() {
+        assert_eq!(cycle(0, 3, true), 1);
+        assert_eq!(cycle(2, 3, true), 0);
+        assert_eq!(cycle(0, 3, false), 2);
+        assert_eq!(cycle(1, 3, false), 0);
+        assert_eq!(cycle(0, 1, true), 0);
+        assert_eq!(cycle(0, 0, true), 0);
+    }
+
+    #[test]
+    fn drop_index_test() {
+        let centers = [10.0, 30.0, 50.0, 70.0];
+
+        assert_eq!(drop_index(&centers, 0, 10.0), 0);
+        assert_eq!(drop_index(&centers, 0, 35.0), 1);
+        assert_eq!(drop_index(&centers, 0, 100.0), 3);
+        assert_eq!(drop_index(&centers, 3, -5.0), 0);
+        assert_eq!(drop_index(&centers, 3, 45.0), 2);
+        assert_eq!(drop_index(&centers, 1, 30.0), 1);
+        assert_eq!(drop_index(&centers, 2, 25.0), 1);
+    }
+
+    #[test]
+    fn visible_tabs_test() {
+        let widths = [30.0, 30.0, 30.0, 30.0];
+
+        assert_eq!(visible_tabs(&widths, 10.0, 200.0, 0), vec![0, 1, 2, 3]);
+        assert_eq!(visible_tabs(&widths, 10.0, 110.0, 1), vec![0, 1, 2]);
+        // The active tab replaces the last leading tab.
+        assert_eq!(visible_tabs(&widths, 10.0, 110.0, 3), vec![0, 1, 3]);
+        assert_eq!(visible_tabs(&widths, 10.0, 70.0, 3), vec![0, 3]);
+        // The active tab is kept even without any space.
+        assert_eq!(visible_tabs(&widths, 10.0, 10.0, 2), vec![2]);
+        assert!(visible_tabs(&[], 10.0, 10.0, 0).is_empty());
+    }
+}
@@ -4,7 +4,10 @@
 use std::fmt::Display;
 
 /// The time value
-#[derive(Clone, Copy, Debug)]
+///
+/// With the `serde` feature it is (de)serialized as a string like `13:05`,
+/// `13:05:30` or `01:05 PM`.
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Time {
     /// The time value containing hour, minute and period.
     Hm {
@@ -50,6 +53,74 @@ impl Time {
             period,
         }
     }
+
+    /// Creates a [`Time`](Time) from the given [`NaiveTime`](chrono::NaiveTime)
+    /// presented either in the 24 hour or in the 12 hour format.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[must_use]
+    pub fn from_naive(time: chrono::NaiveTime, show_seconds: bool, use_24h: bool) -> Self {
+        use chrono::Timelike;
+
+        let (hour, period) = if use_24h {
+            (time.hour(), Period::H24)
+        } else {
+            let (pm, hour) = time.hour12();
+            (hour, if pm { Period::Pm } else { Period::Am })
+        };
+
+        if show_seconds {
+            Self::Hms {
+                hour,
+                minute: time.minute(),
+                second: time.second(),
+                period,
+            }
+        } else {
+            Self::Hm {
+                hour,
+                minute: time.minute(),
+                period,
+            }
+        }
+    }
+
+    /// Parses a time string like `13:05`, `13:05:30` or `01:05 PM`.
+    #[cfg(feature = "serde")]
+    fn parse(time: &str) -> Option<Self> {
+        let (time, period) = [(" AM", Period::Am), (" PM", Period::Pm)]
+            .iter()
+            .find_map(|(suffix, period)| time.strip_suffix(suffix).map(|time| (time, *period)))
+            .unwrap_or((time, Period::H24));
+
+        let mut parts = time.split(':').map(|part| {
+            (part.len() == 2)
+                .then(|| part.parse::<u32>().ok())
+                .flatten()
+                .filter(|value| *value < 60)
+        });
+        let hour = parts.next()??;
+        let minute = parts.next()??;
+        let second = parts.next().map_or(Some(None), |second| second.map(Some))?;
+
+        let hours = if period == Period::H24 { 0..24 } else { 1..13 };
+        if parts.next().is_some() || !hours.contains(&hour) {
+            return None;
+        }
+
+        Some(second.map_or(
+            Self::Hm {
+                hour,
+                minute,
+                period,
+            },
+            |second| Self::Hms {
+                hour,
+                minute,
+                second,
+                period,
+            },
+        ))
+    }
 }
 
 impl Display for Time {
@@ -74,6 +145,33 @@ impl Display for Time {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for Time {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Time {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let time = String::deserialize(deserializer)?;
+
+        Self::parse(&time).ok_or_else(|| {
+            serde::de::Error::invalid_value(
+                serde::de::Unexpected::Str(&time),
+                &"a time like 13:05, 13:05:30 or 01:05 PM",
+            )
+        })
+    }
+}
+
 /// The current period of the clock
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Period {
@@ -127,12 +225,71 @@ impl From<Time> for chrono::NaiveTime {
     }
 }
 
+/// Switches the period of the given time from AM to PM and vice versa.
+#[cfg(not(target_arch = "wasm32"))]
+#[must_use]
+pub fn toggle_period(time: chrono::NaiveTime) -> chrono::NaiveTime {
+    time + chrono::Duration::hours(12)
+}
+
+/// Gets the multiple of the minute step nearest to the given minute.
+///
+/// The result is in the range `0..=60`, where `60` stands for the full next
+/// hour. A step of `0` is treated as `1` and steps above `60` as `60`.
+#[must_use]
+pub fn nearest_minute(minute: u32, step: u8) -> u32 {
+    let step = u32::from(step.clamp(1, 60));
+    let lower = minute / step * step;
+    let upper = (lower + step).min(60);
+
+    if minute - lower < upper - minute {
+        lower
+    } else {
+        upper
+    }
+}
+
+/// Snaps the minutes of the given time to the nearest multiple of the minute
+/// step, rolling over into the next hour if that is nearer.
+#[cfg(not(target_arch = "wasm32"))]
+#[must_use]
+pub fn snap_minute(time: chrono::NaiveTime, step: u8) -> chrono::NaiveTime {
+    use chrono::Timelike;
+
+    let minute = time.minute();
+    let nearest = nearest_minute(minute, step);
+
+    time + chrono::Duration::minutes(i64::from(nearest) - i64::from(minute))
+}
+
+/// Moves the given time by one minute step forwards or backwards, snapping it
+/// to the multiples of the step beforehand.
+#[cfg(not(target_arch = "wasm32"))]
+#[must_use]
+pub fn step_minute(time: chrono::NaiveTime, step: u8, forward: bool) -> chrono::NaiveTime {
+    use chrono::Timelike;
+
+    let time = snap_minute(time, step);
+    let minute = i64::from(time.minute());
+    let step = i64::from(step.clamp(1, 60));
+
+    let delta = if forward {
+        (minute + step).min(60) - minute
+    } else if minute == 0 {
+        -(60 - 59 / step * step)
+    } else {
+        -step
+    };
+
+    time + chrono::Duration::minutes(delta)
+}
+
 #[cfg(test)]
 #[cfg(not(target_arch = "wasm32"))]
 mod tests {
     use chrono::NaiveTime;
 
-    use super::{Period, Time};
+    use super::{nearest_minute, snap_minute, step_minute, toggle_period, Period, Time};
 
     #[test]
     fn time_to_naive() {
@@ -205,4 +362,143 @@ mod tests {
         let naive: NaiveTime = time.into();
         assert_eq!(naive, NaiveTime::from_hms(17, 52, 0));
     }
+
+    #[test]
+    fn naive_to_time_12h() {
+        let time = Time::from_naive(NaiveTime::from_hms(13, 30, 0), false, false);
+        assert_eq!(time.to_string(), "01:30 PM");
+
+        let naive: NaiveTime = time.into();
+        assert_eq!(naive, NaiveTime::from_hms(13, 30, 0));
+
+        let time = Time::from_naive(NaiveTime::from_hms(0, 15, 0), false, false);
+        assert_eq!(time.to_string(), "12:15 AM");
+        let naive: NaiveTime = time.into();
+        assert_eq!(naive, NaiveTime::from_hms(0, 15, 0));
+
+        let time = Time::from_naive(NaiveTime::from_hms(12, 15, 0), false, false);
+        assert_eq!(time.to_string(), "12:15 PM");
+        let naive: NaiveTime = time.into();
+        assert_eq!(naive, NaiveTime::from_hms(12, 15, 0));
+
+        let time = Time::from_naive(NaiveTime::from_hms(13, 30, 5), true, true);
+        assert_eq!(time.to_string(), "13:30:05");
+    }
+
+    #[test]
+    fn toggle_period_test() {
+        assert_eq!(
+            toggle_period(NaiveTime::from_hms(0, 0, 0)),
+            NaiveTime::from_hms(12, 0, 0)
+        );
+        assert_eq!(
+            toggle_period(NaiveTime::from_hms(12, 0, 0)),
+            NaiveTime::from_hms(0, 0, 0)
+        );
+        assert_eq!(
+            toggle_period(NaiveTime::from_hms(13, 30, 0)),
+            NaiveTime::from_hms(1, 30, 0)
+        );
+    }
+
+    #[test]
+    fn nearest_minute_test() {
+        assert_eq!(nearest_minute(0, 15), 0);
+        assert_eq!(nearest_minute(7, 15), 0);
+        assert_eq!(nearest_minute(8, 15), 15);
+        assert_eq!(nearest_minute(53, 15), 60);
+        assert_eq!(nearest_minute(12, 5), 10);
+        assert_eq!(nearest_minute(13, 5), 15);
+        assert_eq!(nearest_minute(58, 7), 60);
+        assert_eq!(nearest_minute(57, 7), 56);
+        assert_eq!(nearest_minute(42, 1), 42);
+        assert_eq!(nearest_minute(42, 0), 42);
+    }
+
+    #[test]
+    fn snap_minute_test() {
+        assert_eq!(
+            snap_minute(NaiveTime::from_hms(10, 7, 0), 15),
+            NaiveTime::from_hms(10, 0, 0)
+        );
+        assert_eq!(
+            snap_minute(NaiveTime::from_hms(10, 53, 0), 15),
+            NaiveTime::from_hms(11, 0, 0)
+        );
+        assert_eq!(
+            snap_minute(NaiveTime::from_hms(23, 59, 0), 5),
+            NaiveTime::from_hms(0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn step_minute_test() {
+        assert_eq!(
+            step_minute(NaiveTime::from_hms(10, 45, 0), 15, true),
+            NaiveTime::from_hms(11, 0, 0)
+        );
+        assert_eq!(
+            step_minute(NaiveTime::from_hms(10, 0, 0), 15, false),
+            NaiveTime::from_hms(9, 45, 0)
+        );
+        assert_eq!(
+            step_minute(NaiveTime::from_hms(10, 56, 0), 7, true),
+            NaiveTime::from_hms(11, 0, 0)
+        );
+        assert_eq!(
+            step_minute(NaiveTime::from_hms(10, 0, 0), 7, false),
+            NaiveTime::from_hms(9, 56, 0)
+        );
+        assert_eq!(
+            step_minute(NaiveTime::from_hms(10, 8, 0), 5, true),
+            NaiveTime::from_hms(10, 15, 0)
+        );
+        assert_eq!(
+            step_minute(NaiveTime::from_hms(10, 21, 0), 1, false),
+            NaiveTime::from_hms(10, 20, 0)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip_test() {
+        for (time, json) in [
+            (
+                Time::Hm {
+                    hour: 13,
+                    minute: 5,
+                    period: Period::H24,
+                },
+                "\"13:05\"",
+            ),
+            (
+                Time::Hms {
+                    hour: 1,
+                    minute: 5,
+                    second: 30,
+                    period: Period::Pm,
+                },
+                "\"01:05:30 PM\"",
+            ),
+        ] {
+            assert_eq!(
+                serde_json::to_string(&time).expect("The time should serialize"),
+                json
+            );
+            assert_eq!(
+                serde_json::from_str::<Time>(json).expect("The time should deserialize"),
+                time
+            );
+        }
+
+        for invalid in [
+            "\"24:00\"",
+            "\"13:05 PM\"",
+            "\"1:05\"",
+            "\"13:60\"",
+            "\"time\"",
+        ] {
+            assert!(serde_json::from_str::<Time>(invalid).is_err());
+        }
+    }
 }
@@ -1,19 +1,39 @@
 //! Use a badge for color highlighting important information.
 //!
 //! *This API requires the following crate features to be activated: badge*
-use iced_graphics::{defaults, Backend, Color, Defaults, Primitive, Renderer};
+use iced_graphics::{defaults, Backend, Color, Defaults, Primitive, Rectangle, Renderer};
 
 pub use crate::style::badge::{Style, StyleSheet};
 use crate::{core::renderer::DrawEnvironment, native::badge};
-
-/// The ratio of the border radius.
-const BORDER_RADIUS_RATIO: f32 = 34.0 / 15.0;
+pub use badge::{Anchor, Offset, Padding};
 
 /// A badge for color highlighting small information.
 ///
 /// This is an alias of an `iced_native` Badge with an `iced_wgpu::Renderer`.
 pub type Badge<'a, Message, Backend> = badge::Badge<'a, Message, Renderer<Backend>>;
 
+/// A [`Badge`] overlaid on a corner of some content.
+///
+/// This is an alias of an `iced_native` `BadgedContent` with an `iced_wgpu::Renderer`.
+pub type BadgedContent<'a, Message, Backend> = badge::BadgedContent<'a, Message, Renderer<Backend>>;
+
+/// Draws the background of a badge with the given bounds.
+///
+/// Without a border radius set by the style the corners are fully rounded.
+pub(crate) fn background(bounds: Rectangle, style: &Style) -> Primitive {
+    let border_radius = style
+        .border_radius
+        .unwrap_or_else(|| bounds.width.min(bounds.height) / 2.0);
+
+    Primitive::Quad {
+        bounds,
+        background: style.background,
+        border_radius,
+        border_width: style.border_width,
+        border_color: style.border_color.unwrap_or(Color::BLACK),
+    }
+}
+
 impl<B> badge::Renderer for Renderer<B>
 where
     B: Backend,
@@ -34,19 +54,7 @@ where
             env.style_sheet.active()
         };
 
-        //println!("height: {}", bounds.height);
-        // 34 15
-        //  x
-        let border_radius = style
-            .border_radius
-            .unwrap_or_else(|| (bounds.height as f32 / BORDER_RADIUS_RATIO));
-        let background = Primitive::Quad {
-            bounds,
-            background: style.background,
-            border_radius,
-            border_width: style.border_width,
-            border_color: style.border_color.unwrap_or(Color::BLACK),
-        };
+        let background = background(bounds, &style);
 
         let (content, mouse_interaction) = content.draw(
             self,
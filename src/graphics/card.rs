@@ -8,13 +8,18 @@ use iced_graphics::{
 use iced_native::{mouse, Element, Layout};
 
 pub use crate::style::card::{Style, StyleSheet};
-use crate::{core::renderer::DrawEnvironment, native::card};
+use crate::{core::renderer::DrawEnvironment, graphics::icons::Icon, native::card};
 
 /// A card consisting of a head, body and optional foot.
 ///
 /// This is an alias of an `iced_native` Card with an `iced_wgpu::Renderer`.
 pub type Card<'a, Message, Backend> = card::Card<'a, Message, Renderer<Backend>>;
 
+/// A group of collapsible cards of which only one or several are open.
+///
+/// This is an alias of an `iced_native` Accordion with an `iced_wgpu::Renderer`.
+pub type Accordion<'a, Message, Backend> = card::Accordion<'a, Message, Renderer<Backend>>;
+
 impl<B> card::Renderer for Renderer<B>
 where
     B: Backend + backend::Text,
@@ -33,10 +38,17 @@ where
         head: &iced_native::Element<'_, Message, Self>,
         body: &iced_native::Element<'_, Message, Self>,
         foot: &Option<iced_native::Element<'_, Message, Self>>,
+        collapsible: bool,
+        collapsed: bool,
     ) -> Self::Output {
         let bounds = env.layout.bounds();
         let mut children = env.layout.children();
-        let style = env.style_sheet.active();
+        let is_mouse_over = bounds.contains(env.cursor_position);
+        let style = if is_mouse_over {
+            env.style_sheet.hovered()
+        } else {
+            env.style_sheet.active()
+        };
 
         let mouse_interaction = mouse::Interaction::default();
 
@@ -67,8 +79,18 @@ where
             env.cursor_position,
             env.viewport.expect("A viewport should exist for Card"),
             &style,
+            collapsible.then_some(collapsed),
         );
 
+        if collapsed {
+            return (
+                Primitive::Group {
+                    primitives: vec![background, border, head],
+                },
+                mouse_interaction.max(head_mouse_interaction),
+            );
+        }
+
         // ----------- Body ----------------------
         let body_layout = children
             .next()
@@ -115,6 +137,7 @@ fn draw_head<Message, B>(
     cursor_position: Point,
     viewport: &Rectangle,
     style: &Style,
+    collapsed: Option<bool>,
 ) -> (Primitive, mouse::Interaction)
 where
     B: Backend + backend::Text,
@@ -142,6 +165,43 @@ where
         viewport,
     );
 
+    let (toggle, toggle_mouse_interaction) = collapsed.map_or_else(
+        || (Primitive::None, mouse::Interaction::default()),
+        |collapsed| {
+            let toggle_bounds = head_children
+                .next()
+                .expect("Graphics: Layout should have a toggle layout")
+                .bounds();
+            let is_mouse_over_toggle = toggle_bounds.contains(cursor_position);
+
+            (
+                Primitive::Text {
+                    content: if collapsed {
+                        Icon::CaretRightFill
+                    } else {
+                        Icon::CaretDownFill
+                    }
+                    .into(),
+                    font: super::icons::ICON_FONT,
+                    size: toggle_bounds.height + if is_mouse_over_toggle { 5.0 } else { 0.0 },
+                    bounds: Rectangle {
+                        x: toggle_bounds.center_x(),
+                        y: toggle_bounds.center_y(),
+                        ..toggle_bounds
+                    },
+                    color: style.close_color,
+                    horizontal_alignment: HorizontalAlignment::Center,
+                    vertical_alignment: VerticalAlignment::Center,
+                },
+                if is_mouse_over_toggle {
+                    mouse::Interaction::Pointer
+                } else {
+                    mouse::Interaction::default()
+                },
+            )
+        },
+    );
+
     let (close, close_mouse_interaction) = head_children.next().map_or(
         (Primitive::None, mouse::Interaction::default()),
         |close_layout| {
@@ -174,9 +234,11 @@ where
 
     (
         Primitive::Group {
-            primitives: vec![head_background, head, close],
+            primitives: vec![head_background, head, toggle, close],
         },
-        head_mouse_interaction.max(close_mouse_interaction),
+        head_mouse_interaction
+            .max(toggle_mouse_interaction)
+            .max(close_mouse_interaction),
     )
 }
 
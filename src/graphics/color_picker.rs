@@ -16,15 +16,17 @@ use crate::{
         color::{HexString, Hsv},
         renderer::DrawEnvironment,
     },
+    graphics::focus_ring::focus_ring,
     native::overlay::color_picker::Focus,
     style::{
         color_picker::{Style, StyleSheet},
+        focus_ring::FocusRing,
         style_state::StyleState,
     },
 };
 
 use crate::native::color_picker;
-pub use crate::native::color_picker::State;
+pub use crate::native::color_picker::{ComponentMode, State};
 
 /// An input element for picking colors.
 ///
@@ -32,6 +34,12 @@ pub use crate::native::color_picker::State;
 pub type ColorPicker<'a, Message, Backend> =
     color_picker::ColorPicker<'a, Message, Renderer<Backend>>;
 
+/// The size of a single square of the checkerboard behind the color preview.
+const CHECKERBOARD_SQUARE_SIZE: f32 = 8.0;
+
+/// The border color of the hex input if it contains an invalid color.
+const INVALID_HEX_BORDER_COLOR: Color = Color::from_rgb(0.8, 0.0, 0.0);
+
 impl<B> color_picker::Renderer for Renderer<B>
 where
     B: Backend + backend::Text,
@@ -42,9 +50,14 @@ where
         &mut self,
         env: DrawEnvironment<'_, Self::Defaults, Self::Style, Focus>,
         color: &iced_graphics::Color,
+        show_alpha: bool,
+        swatches: &[Color],
         sat_value_canvas_cache: &canvas::Cache,
         hue_canvas_cache: &canvas::Cache,
-        //text_input: &iced_native::Element<'_, Message, Self>,
+        hex_input: Option<&iced_native::Element<'_, String, Self>>,
+        hex_valid: bool,
+        component_mode: ComponentMode,
+        component_inputs: &[iced_native::Element<'_, String, Self>],
         cancel_button: &iced_native::Element<'_, Message, Self>,
         submit_button: &iced_native::Element<'_, Message, Self>,
     ) -> Self::Output {
@@ -81,6 +94,7 @@ where
             .expect("Graphics: Layout should have a 1. block layout");
         let (block1, block1_mouse_interaction) = block1(
             color,
+            swatches,
             sat_value_canvas_cache,
             hue_canvas_cache,
             block1_layout,
@@ -96,6 +110,11 @@ where
         let (block2, block2_mouse_interaction) = block2(
             self,
             color,
+            show_alpha,
+            hex_input,
+            hex_valid,
+            component_mode,
+            component_inputs,
             cancel_button,
             submit_button,
             &DrawEnvironment {
@@ -107,6 +126,7 @@ where
                 focus: env.focus,
             },
             &style,
+            env.style_sheet.focus_ring(),
         );
 
         (
@@ -121,8 +141,10 @@ where
 }
 
 /// Draws the 1. block of the color picker containing the HSV part.
+#[allow(clippy::too_many_arguments)]
 fn block1(
     color: &Color,
+    swatches: &[Color],
     sat_value_canvas_cache: &canvas::Cache,
     hue_canvas_cache: &canvas::Cache,
     layout: Layout<'_>,
@@ -145,19 +167,39 @@ fn block1(
         focus,
     );
 
+    // ----------- Swatches ----------------------
+    let swatches_layout = layout
+        .children()
+        .nth(2)
+        .expect("Graphics: Layout should have a swatches layout");
+    let (swatches, swatches_mouse_interaction) =
+        self::swatches(swatches_layout, swatches, cursor_position, style);
+
     // ----------- Block 1 end ------------------
 
-    (hsv_color, hsv_color_mouse_interaction)
+    (
+        Primitive::Group {
+            primitives: vec![hsv_color, swatches],
+        },
+        hsv_color_mouse_interaction.max(swatches_mouse_interaction),
+    )
 }
 
 /// Draws the 2. block of the color picker containing the RGBA part, Hex and buttons.
+#[allow(clippy::too_many_arguments, clippy::too_many_lines)]
 fn block2<Message, B>(
     renderer: &mut Renderer<B>,
     color: &Color,
+    show_alpha: bool,
+    hex_input: Option<&iced_native::Element<'_, String, Renderer<B>>>,
+    hex_valid: bool,
+    component_mode: ComponentMode,
+    component_inputs: &[iced_native::Element<'_, String, Renderer<B>>],
     cancel_button: &iced_native::Element<'_, Message, Renderer<B>>,
     submit_button: &iced_native::Element<'_, Message, Renderer<B>>,
     env: &DrawEnvironment<'_, Defaults, (), Focus>,
     style: &HashMap<StyleState, Style>,
+    focus_ring_style: Option<FocusRing>,
 ) -> (Primitive, mouse::Interaction)
 where
     B: Backend + backend::Text,
@@ -172,6 +214,7 @@ where
     let (rgba_color, rgba_color_mouse_interaction) = rgba_color(
         rgba_color_layout,
         color,
+        show_alpha,
         env.cursor_position,
         env.defaults,
         style,
@@ -182,13 +225,31 @@ where
     let hex_text_layout = block2_children
         .next()
         .expect("Graphics: Layout should have a hex text layout");
-    let hex_text = hex_text(
-        hex_text_layout,
-        color,
-        env.cursor_position,
-        env.defaults,
-        style,
-        env.focus,
+    let (hex_text, hex_text_mouse_interaction) = hex_input.map_or_else(
+        || {
+            (
+                hex_text(
+                    hex_text_layout,
+                    color,
+                    env.cursor_position,
+                    env.defaults,
+                    style,
+                    env.focus,
+                ),
+                mouse::Interaction::default(),
+            )
+        },
+        |hex_input| {
+            hex_input_text(
+                renderer,
+                hex_text_layout,
+                color,
+                hex_input,
+                hex_valid,
+                env,
+                style,
+            )
+        },
     );
 
     // ----------- Buttons -------------------------
@@ -218,30 +279,33 @@ where
             .expect("Should have a viewport for ColorPicker"),
     );
 
-    // Buttons are not focusable right now...
+    // The buttons are only focused by keyboard.
     let cancel_button_focus = if env.focus == Focus::Cancel {
-        Primitive::Quad {
-            bounds: cancel_button_layout.bounds(),
-            background: Color::TRANSPARENT.into(),
-            border_radius: style[&StyleState::Focused].border_radius,
-            border_width: style[&StyleState::Focused].border_width,
-            border_color: style[&StyleState::Focused].border_color,
-        }
+        focus_ring(cancel_button_layout.bounds(), focus_ring_style)
     } else {
         Primitive::None
     };
 
     let submit_button_focus = if env.focus == Focus::Submit {
-        Primitive::Quad {
-            bounds: submit_button_layout.bounds(),
-            background: Color::TRANSPARENT.into(),
-            border_radius: style[&StyleState::Focused].border_radius,
-            border_width: style[&StyleState::Focused].border_width,
-            border_color: style[&StyleState::Focused].border_color,
-        }
+        focus_ring(submit_button_layout.bounds(), focus_ring_style)
     } else {
         Primitive::None
     };
+
+    // ----------- Component inputs ----------------
+    let (component_inputs, component_inputs_mouse_interaction) =
+        block2_children.next().map_or_else(
+            || (Primitive::None, mouse::Interaction::default()),
+            |component_inputs_layout| {
+                self::component_inputs(
+                    renderer,
+                    component_inputs_layout,
+                    component_mode,
+                    component_inputs,
+                    env,
+                )
+            },
+        );
     // ----------- Block 2 end ------------------
 
     (
@@ -249,6 +313,7 @@ where
             primitives: vec![
                 rgba_color,
                 hex_text,
+                component_inputs,
                 cancel_button,
                 submit_button,
                 cancel_button_focus,
@@ -256,6 +321,8 @@ where
             ],
         },
         rgba_color_mouse_interaction
+            .max(hex_text_mouse_interaction)
+            .max(component_inputs_mouse_interaction)
             .max(cancel_mouse_interaction)
             .max(submit_mouse_interaction),
     )
@@ -439,11 +506,52 @@ fn hsv_color(
     )
 }
 
+/// Draws the preset and recent color swatches.
+fn swatches(
+    layout: Layout<'_>,
+    swatches: &[Color],
+    cursor_position: Point,
+    style: &HashMap<StyleState, Style>,
+) -> (Primitive, mouse::Interaction) {
+    let mut mouse_interaction = mouse::Interaction::default();
+
+    let primitives = layout
+        .children()
+        .zip(swatches)
+        .map(|(swatch_layout, swatch)| {
+            let bounds = swatch_layout.bounds();
+
+            let swatch_style_state = if bounds.contains(cursor_position) {
+                mouse_interaction = mouse_interaction.max(mouse::Interaction::Pointer);
+                StyleState::Hovered
+            } else {
+                StyleState::Active
+            };
+
+            Primitive::Group {
+                primitives: vec![
+                    checkerboard(bounds),
+                    Primitive::Quad {
+                        bounds,
+                        background: (*swatch).into(),
+                        border_radius: style[&swatch_style_state].bar_border_radius,
+                        border_width: style[&swatch_style_state].bar_border_width,
+                        border_color: style[&swatch_style_state].bar_border_color,
+                    },
+                ],
+            }
+        })
+        .collect();
+
+    (Primitive::Group { primitives }, mouse_interaction)
+}
+
 /// Draws the RGBA color area.
 #[allow(clippy::too_many_lines)]
 fn rgba_color(
     layout: Layout<'_>,
     color: &Color,
+    show_alpha: bool,
     cursor_position: Point,
     defaults: &Defaults,
     style: &HashMap<StyleState, Style>,
@@ -598,18 +706,22 @@ fn rgba_color(
     );
 
     // Alpha
-    let alpha_row_layout = rgba_color_children
-        .next()
-        .expect("Graphics: Layout should have an alpha row layout");
-
-    let (alpha, alpha_mouse_interaction) = f(
-        alpha_row_layout,
-        "A:",
-        Color::from_rgba(0.0, 0.0, 0.0, color.a),
-        color.a,
-        cursor_position,
-        Focus::Alpha,
-    );
+    let (alpha, alpha_mouse_interaction) = if show_alpha {
+        let alpha_row_layout = rgba_color_children
+            .next()
+            .expect("Graphics: Layout should have an alpha row layout");
+
+        f(
+            alpha_row_layout,
+            "A:",
+            Color::from_rgba(0.0, 0.0, 0.0, color.a),
+            color.a,
+            cursor_position,
+            Focus::Alpha,
+        )
+    } else {
+        (Primitive::None, mouse::Interaction::default())
+    };
 
     (
         Primitive::Group {
@@ -642,6 +754,7 @@ fn hex_text(
 
     Primitive::Group {
         primitives: vec![
+            checkerboard(layout.bounds()),
             Primitive::Quad {
                 bounds: layout.bounds(),
                 background: color.clone().into(),
@@ -673,3 +786,184 @@ fn hex_text(
         ],
     }
 }
+
+/// Draws the color preview next to the editable hex input.
+fn hex_input_text<B>(
+    renderer: &mut Renderer<B>,
+    layout: Layout<'_>,
+    color: &Color,
+    hex_input: &iced_native::Element<'_, String, Renderer<B>>,
+    hex_valid: bool,
+    env: &DrawEnvironment<'_, Defaults, (), Focus>,
+    style: &HashMap<StyleState, Style>,
+) -> (Primitive, mouse::Interaction)
+where
+    B: Backend + backend::Text,
+{
+    let mut children = layout.children();
+
+    let preview_layout = children
+        .next()
+        .expect("Graphics: Layout should have a color preview layout");
+    let hex_input_layout = children
+        .next()
+        .expect("Graphics: Layout should have a hex input layout");
+
+    let preview_style_state = if preview_layout.bounds().contains(env.cursor_position) {
+        StyleState::Hovered
+    } else {
+        StyleState::Active
+    };
+
+    let preview = Primitive::Group {
+        primitives: vec![
+            checkerboard(preview_layout.bounds()),
+            Primitive::Quad {
+                bounds: preview_layout.bounds(),
+                background: (*color).into(),
+                border_radius: style[&preview_style_state].bar_border_radius,
+                border_width: style[&preview_style_state].bar_border_width,
+                border_color: style[&preview_style_state].bar_border_color,
+            },
+        ],
+    };
+
+    let (hex_input, hex_input_mouse_interaction) = hex_input.draw(
+        renderer,
+        env.defaults,
+        hex_input_layout,
+        env.cursor_position,
+        env.viewport
+            .expect("Should have a viewport for ColorPicker"),
+    );
+
+    let invalid = if hex_valid {
+        Primitive::None
+    } else {
+        Primitive::Quad {
+            bounds: hex_input_layout.bounds(),
+            background: Color::TRANSPARENT.into(),
+            border_radius: style[&StyleState::Active].bar_border_radius,
+            border_width: style[&StyleState::Active].bar_border_width.max(1.0),
+            border_color: INVALID_HEX_BORDER_COLOR,
+        }
+    };
+
+    (
+        Primitive::Group {
+            primitives: vec![preview, hex_input, invalid],
+        },
+        hex_input_mouse_interaction,
+    )
+}
+
+/// Draws the labeled numeric inputs of the color components.
+fn component_inputs<B>(
+    renderer: &mut Renderer<B>,
+    layout: Layout<'_>,
+    component_mode: ComponentMode,
+    component_inputs: &[iced_native::Element<'_, String, Renderer<B>>],
+    env: &DrawEnvironment<'_, Defaults, (), Focus>,
+) -> (Primitive, mouse::Interaction)
+where
+    B: Backend + backend::Text,
+{
+    let labels = component_mode.labels().unwrap_or_default();
+
+    let (primitives, mouse_interaction) = layout
+        .children()
+        .zip(component_inputs)
+        .zip(labels)
+        .map(|((cell_layout, component_input), label)| {
+            let mut children = cell_layout.children();
+
+            let label_layout = children
+                .next()
+                .expect("Graphics: Layout should have a component label layout");
+            let component_input_layout = children
+                .next()
+                .expect("Graphics: Layout should have a component input layout");
+
+            let label = Primitive::Text {
+                content: label.to_owned(),
+                bounds: Rectangle {
+                    x: label_layout.bounds().center_x(),
+                    y: label_layout.bounds().center_y(),
+                    ..label_layout.bounds()
+                },
+                color: env.defaults.text.color,
+                size: label_layout.bounds().width,
+                font: iced_graphics::Font::default(),
+                horizontal_alignment: iced_graphics::HorizontalAlignment::Center,
+                vertical_alignment: iced_graphics::VerticalAlignment::Center,
+            };
+
+            let (component_input, mouse_interaction) = component_input.draw(
+                renderer,
+                env.defaults,
+                component_input_layout,
+                env.cursor_position,
+                env.viewport
+                    .expect("Should have a viewport for ColorPicker"),
+            );
+
+            (
+                Primitive::Group {
+                    primitives: vec![label, component_input],
+                },
+                mouse_interaction,
+            )
+        })
+        .fold(
+            (Vec::new(), mouse::Interaction::default()),
+            |(mut primitives, interaction), (primitive, mouse_interaction)| {
+                primitives.push(primitive);
+                (primitives, interaction.max(mouse_interaction))
+            },
+        );
+
+    (Primitive::Group { primitives }, mouse_interaction)
+}
+
+/// Draws a checkerboard pattern inside the given bounds to make transparent
+/// colors visible.
+fn checkerboard(bounds: Rectangle) -> Primitive {
+    let columns = (bounds.width / CHECKERBOARD_SQUARE_SIZE).ceil() as u16;
+    let rows = (bounds.height / CHECKERBOARD_SQUARE_SIZE).ceil() as u16;
+
+    let squares = (0..rows)
+        .flat_map(|row| (0..columns).map(move |column| (row, column)))
+        .filter(|(row, column)| (row + column) % 2 == 0)
+        .map(|(row, column)| Primitive::Quad {
+            bounds: Rectangle {
+                x: f32::from(column).mul_add(CHECKERBOARD_SQUARE_SIZE, bounds.x),
+                y: f32::from(row).mul_add(CHECKERBOARD_SQUARE_SIZE, bounds.y),
+                width: CHECKERBOARD_SQUARE_SIZE,
+                height: CHECKERBOARD_SQUARE_SIZE,
+            },
+            background: Color::from_rgb(0.8, 0.8, 0.8).into(),
+            border_radius: 0.0,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        })
+        .collect();
+
+    Primitive::Clip {
+        bounds,
+        offset: Vector::new(0, 0),
+        content: Box::new(Primitive::Group {
+            primitives: vec![
+                Primitive::Quad {
+                    bounds,
+                    background: Color::WHITE.into(),
+                    border_radius: 0.0,
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                Primitive::Group {
+                    primitives: squares,
+                },
+            ],
+        }),
+    }
+}
@@ -7,7 +7,10 @@ use crate::{
     core::{date::IsInMonth, renderer::DrawEnvironment},
     style::{date_picker::Style, style_state::StyleState},
 };
-use crate::{native::overlay::date_picker::Focus, style::date_picker::StyleSheet};
+use crate::{
+    native::overlay::date_picker::{Focus, PickerCell, Range},
+    style::date_picker::StyleSheet,
+};
 
 use chrono::{self, Datelike};
 use iced_graphics::{
@@ -16,9 +19,12 @@ use iced_graphics::{
 use iced_native::{mouse, Element};
 
 use crate::native::date_picker;
-pub use crate::native::date_picker::{Date, State};
+pub use crate::native::date_picker::{Date, MonthNames, State, Weekday, WeekdayNames};
 
-use super::icons::{Icon, ICON_FONT};
+use super::{
+    focus_ring::focus_ring,
+    icons::{Icon, ICON_FONT},
+};
 
 /// An input element for picking dates.
 ///
@@ -38,8 +44,15 @@ where
         date: chrono::NaiveDate,
         year_str: &str,
         month_str: &str,
+        first_weekday: Weekday,
+        weekday_labels: &[String],
+        is_day_enabled: &dyn Fn(chrono::NaiveDate) -> bool,
+        range: Option<Range>,
+        today: Option<chrono::NaiveDate>,
+        picker: &[PickerCell],
         cancel_button: &Element<'_, Message, Self>,
         submit_button: &Element<'_, Message, Self>,
+        today_button: Option<&Element<'_, Message, Self>>,
     ) -> Self::Output {
         let bounds = env.layout.bounds();
         let mut children = env.layout.children();
@@ -87,15 +100,39 @@ where
         );
 
         // ----------- Days ---------------------------
-        let days_layout = date_children
+        // The optional week numbers are laid out in front of the days.
+        let mut table_children: Vec<iced_native::Layout<'_>> = date_children
             .next()
             .expect("Graphics: Layout should have a days layout parent")
             .children()
             .next()
+            .expect("Graphics: Layout should have a days table layout")
+            .children()
+            .collect();
+        let days_layout = table_children
+            .pop()
             .expect("Graphics: Layout should have a days layout");
-
-        let (days, days_mouse_interaction) =
-            days(days_layout, date, env.cursor_position, &style, env.focus);
+        let week_numbers = table_children.pop().map_or(Primitive::None, |layout| {
+            week_numbers(layout, date, first_weekday, &style)
+        });
+
+        // The month and year pickers replace the days.
+        let (days, days_mouse_interaction) = if picker.is_empty() {
+            days(
+                days_layout,
+                date,
+                first_weekday,
+                weekday_labels,
+                is_day_enabled,
+                range,
+                today,
+                env.cursor_position,
+                &style,
+                env.focus,
+            )
+        } else {
+            picker_table(days_layout, picker, env.cursor_position, &style)
+        };
 
         // ----------- Buttons ------------------------
         let cancel_button_layout = children
@@ -122,27 +159,23 @@ where
             &bounds,
         );
 
-        // Buttons are not focusable right now...
+        let (today_button, today_mouse_interaction) =
+            today_button.zip(children.next()).map_or_else(
+                || (Primitive::None, mouse::Interaction::default()),
+                |(today_button, layout)| {
+                    today_button.draw(self, env.defaults, layout, env.cursor_position, &bounds)
+                },
+            );
+
+        // The buttons are only focused by keyboard.
         let cancel_button_focus = if env.focus == Focus::Cancel {
-            Primitive::Quad {
-                bounds: cancel_button_layout.bounds(),
-                background: Color::TRANSPARENT.into(),
-                border_radius: style[&StyleState::Focused].border_radius,
-                border_width: style[&StyleState::Focused].border_width,
-                border_color: style[&StyleState::Focused].border_color,
-            }
+            focus_ring(cancel_button_layout.bounds(), env.style_sheet.focus_ring())
         } else {
             Primitive::None
         };
 
         let submit_button_focus = if env.focus == Focus::Submit {
-            Primitive::Quad {
-                bounds: submit_button_layout.bounds(),
-                background: Color::TRANSPARENT.into(),
-                border_radius: style[&StyleState::Focused].border_radius,
-                border_width: style[&StyleState::Focused].border_width,
-                border_color: style[&StyleState::Focused].border_color,
-            }
+            focus_ring(submit_button_layout.bounds(), env.style_sheet.focus_ring())
         } else {
             Primitive::None
         };
@@ -152,9 +185,11 @@ where
                 primitives: vec![
                     background,
                     month_year,
+                    week_numbers,
                     days,
                     cancel_button,
                     submit_button,
+                    today_button,
                     cancel_button_focus,
                     submit_button_focus,
                 ],
@@ -163,7 +198,8 @@ where
                 .max(month_year_mouse_interaction)
                 .max(days_mouse_interaction)
                 .max(cancel_mouse_interaction)
-                .max(submit_mouse_interaction),
+                .max(submit_mouse_interaction)
+                .max(today_mouse_interaction),
         )
     }
 }
@@ -214,7 +250,8 @@ fn month_year(
         let left_arrow_hovered = left_bounds.contains(cursor_position);
         let right_arrow_hovered = right_bounds.contains(cursor_position);
 
-        if left_arrow_hovered || right_arrow_hovered {
+        // The center opens the month or year picker.
+        if left_arrow_hovered || right_arrow_hovered || center_bounds.contains(cursor_position) {
             mouse_interaction = mouse_interaction.max(mouse::Interaction::Pointer);
         }
 
@@ -293,9 +330,15 @@ fn month_year(
 }
 
 /// Draws the days
+#[allow(clippy::too_many_arguments)]
 fn days(
     layout: iced_native::Layout<'_>,
     date: chrono::NaiveDate,
+    first_weekday: Weekday,
+    weekday_labels: &[String],
+    is_day_enabled: &dyn Fn(chrono::NaiveDate) -> bool,
+    range: Option<Range>,
+    today: Option<chrono::NaiveDate>,
     cursor_position: iced_graphics::Point,
     //style: &Style,
     style: &HashMap<StyleState, Style>,
@@ -306,10 +349,19 @@ fn days(
     let day_labels_layout = children
         .next()
         .expect("Graphics: Layout should have a day labels layout");
-    let labels = day_labels(day_labels_layout, style, focus);
-
-    let (table, table_mouse_interaction) =
-        day_table(&mut children, date, cursor_position, style, focus);
+    let labels = day_labels(day_labels_layout, weekday_labels, style, focus);
+
+    let (table, table_mouse_interaction) = day_table(
+        &mut children,
+        date,
+        first_weekday,
+        is_day_enabled,
+        range,
+        today,
+        cursor_position,
+        style,
+        focus,
+    );
 
     (
         Primitive::Group {
@@ -319,9 +371,51 @@ fn days(
     )
 }
 
+/// Draws the week numbers in front of the rows of the day table.
+fn week_numbers(
+    layout: iced_native::Layout<'_>,
+    date: chrono::NaiveDate,
+    first_weekday: Weekday,
+    style: &HashMap<StyleState, Style>,
+) -> Primitive {
+    // The first row is next to the day labels.
+    let primitives = layout
+        .children()
+        .skip(1)
+        .enumerate()
+        .map(|(y, row)| {
+            let bounds = row
+                .children()
+                .next()
+                .expect("Graphics: Layout should have a week number layout")
+                .bounds();
+
+            Primitive::Text {
+                content: format!(
+                    "{:02}",
+                    crate::core::date::week_number(y, date, first_weekday)
+                ),
+                bounds: Rectangle {
+                    x: bounds.center_x(),
+                    y: bounds.center_y(),
+                    ..bounds
+                },
+                color: style[&StyleState::Active].text_color,
+                size: bounds.width.min(bounds.height),
+                font: iced_graphics::Font::default(),
+                horizontal_alignment: HorizontalAlignment::Center,
+                vertical_alignment: VerticalAlignment::Center,
+            }
+        })
+        .collect();
+
+    Primitive::Group { primitives }
+}
+
 /// Draws the day labels
 fn day_labels(
     layout: iced_native::Layout<'_>,
+    weekday_labels: &[String],
     style: &HashMap<StyleState, Style>,
     _focus: Focus,
 ) -> Primitive {
@@ -331,7 +425,7 @@ fn day_labels(
         let bounds = label.bounds();
 
         labels.push(Primitive::Text {
-            content: crate::core::date::WEEKDAY_LABELS[i].clone(),
+            content: weekday_labels[i].clone(),
             bounds: Rectangle {
                 x: bounds.center_x(),
                 y: bounds.center_y(),
@@ -349,9 +443,14 @@ fn day_labels(
 }
 
 /// Draws the day table
+#[allow(clippy::too_many_arguments)]
 fn day_table(
     children: &mut dyn Iterator<Item = iced_native::Layout<'_>>,
     date: chrono::NaiveDate,
+    first_weekday: Weekday,
+    is_day_enabled: &dyn Fn(chrono::NaiveDate) -> bool,
+    range: Option<Range>,
+    today: Option<chrono::NaiveDate>,
     cursor_position: iced_graphics::Point,
     style: &HashMap<StyleState, Style>,
     focus: Focus,
@@ -360,18 +459,30 @@ fn day_table(
 
     let mut mouse_interaction = mouse::Interaction::default();
 
-    for (y, row) in children.enumerate() {
+    let rows: Vec<iced_native::Layout<'_>> = children.collect();
+
+    // The hovered day previews the end of a started range.
+    let hovered = rows.iter().enumerate().find_map(|(y, row)| {
+        row.children()
+            .position(|label| label.bounds().contains(cursor_position))
+            .map(|x| crate::core::date::position_to_date(x, y, date, first_weekday))
+    });
+
+    for (y, row) in rows.into_iter().enumerate() {
         for (x, label) in row.children().enumerate() {
             let bounds = label.bounds();
             let (number, is_in_month) =
-                crate::core::date::position_to_day(x, y, date.year(), date.month());
+                crate::core::date::position_to_day(x, y, date.year(), date.month(), first_weekday);
+            let day = crate::core::date::position_to_date(x, y, date, first_weekday);
+            let enabled = is_day_enabled(day);
 
-            let mouse_over = bounds.contains(cursor_position);
+            let mouse_over = enabled && bounds.contains(cursor_position);
             if mouse_over {
                 mouse_interaction = mouse_interaction.max(mouse::Interaction::Pointer);
             }
 
-            let selected = date.day() == number as u32 && is_in_month == IsInMonth::Same;
+            let is_date = date.day() == number as u32 && is_in_month == IsInMonth::Same;
+            let selected = range.map_or(is_date, |range| range.contains(day, hovered));
 
             let mut style_state = StyleState::Active;
             if selected {
@@ -389,7 +500,18 @@ fn day_table(
                 border_color: Color::TRANSPARENT,
             });
 
-            if focus == Focus::Day && selected {
+            // The current date is ringed in the text color.
+            if today == Some(day) {
+                primitives.push(Primitive::Quad {
+                    bounds,
+                    background: Color::TRANSPARENT.into(),
+                    border_radius: bounds.height / 2.0,
+                    border_width: 1.0,
+                    border_color: style[&StyleState::Active].text_color,
+                });
+            }
+
+            if focus == Focus::Day && is_date {
                 primitives.push(Primitive::Quad {
                     bounds,
                     background: Color::TRANSPARENT.into(),
@@ -406,7 +528,7 @@ fn day_table(
                     y: bounds.center_y(),
                     ..bounds
                 },
-                color: if is_in_month == IsInMonth::Same {
+                color: if enabled && is_in_month == IsInMonth::Same {
                     style.get(&style_state).unwrap().text_color
                 } else {
                     style.get(&style_state).unwrap().text_attenuated_color
@@ -425,3 +547,62 @@ fn day_table(
 
     (Primitive::Group { primitives }, mouse_interaction)
 }
+
+/// Draws the cells of the month or year picker.
+fn picker_table(
+    layout: iced_native::Layout<'_>,
+    picker: &[PickerCell],
+    cursor_position: iced_graphics::Point,
+    style: &HashMap<StyleState, Style>,
+) -> (Primitive, mouse::Interaction) {
+    let mut primitives: Vec<Primitive> = Vec::new();
+
+    let mut mouse_interaction = mouse::Interaction::default();
+
+    let cells = layout.children().flat_map(iced_native::Layout::children);
+
+    for (cell, layout) in picker.iter().zip(cells) {
+        let bounds = layout.bounds();
+
+        let mouse_over = cell.is_enabled && bounds.contains(cursor_position);
+        if mouse_over {
+            mouse_interaction = mouse_interaction.max(mouse::Interaction::Pointer);
+        }
+
+        let mut style_state = StyleState::Active;
+        if cell.is_selected {
+            style_state = style_state.max(StyleState::Selected);
+        }
+        if mouse_over {
+            style_state = style_state.max(StyleState::Hovered);
+        }
+
+        primitives.push(Primitive::Quad {
+            bounds,
+            background: style[&style_state].day_background,
+            border_radius: bounds.height / 2.0,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        });
+
+        primitives.push(Primitive::Text {
+            content: cell.label.clone(),
+            bounds: Rectangle {
+                x: bounds.center_x(),
+                y: bounds.center_y(),
+                ..bounds
+            },
+            color: if cell.is_enabled {
+                style[&style_state].text_color
+            } else {
+                style[&style_state].text_attenuated_color
+            },
+            size: bounds.height,
+            font: iced_graphics::Font::default(),
+            horizontal_alignment: HorizontalAlignment::Center,
+            vertical_alignment: VerticalAlignment::Center,
+        });
+    }
+
+    (Primitive::Group { primitives }, mouse_interaction)
+}
@@ -0,0 +1,30 @@
+//! Use a date time picker as an input element for picking a date and a time.
+//!
+//! *This API requires the following crate features to be activated: `date_time_picker`*
+use iced_graphics::{backend, Backend, Primitive, Renderer};
+
+use crate::native::date_time_picker;
+pub use crate::native::date_time_picker::{Date, DateTime, Period, State, Time, Weekday};
+
+/// An input element for picking a date and a time.
+///
+/// This is an alias of an `iced_native` `DateTimePicker` with an `iced_wgpu::Renderer`.
+pub type DateTimePicker<'a, Message, Backend> =
+    date_time_picker::DateTimePicker<'a, Message, Renderer<Backend>>;
+
+impl<B> date_time_picker::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    fn draw(&mut self, date: Self::Output, time: Self::Output) -> Self::Output {
+        let (date, date_mouse_interaction) = date;
+        let (time, time_mouse_interaction) = time;
+
+        (
+            Primitive::Group {
+                primitives: vec![date, time],
+            },
+            date_mouse_interaction.max(time_mouse_interaction),
+        )
+    }
+}
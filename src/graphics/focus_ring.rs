@@ -0,0 +1,17 @@
+//! Draws the focus ring of widgets with the keyboard focus.
+use iced_graphics::{Color, Primitive, Rectangle};
+
+use crate::style::focus_ring::FocusRing;
+
+/// Draws the given [`FocusRing`](FocusRing) around the focused bounds.
+///
+/// Nothing is drawn if the style of the widget has no ring.
+pub fn focus_ring(focused: Rectangle, ring: Option<FocusRing>) -> Primitive {
+    ring.map_or(Primitive::None, |ring| Primitive::Quad {
+        bounds: ring.bounds(focused),
+        background: Color::TRANSPARENT.into(),
+        border_radius: ring.border_radius,
+        border_width: ring.width,
+        border_color: ring.color,
+    })
+}
@@ -1,10 +1,10 @@
 //! Use a grid as an input element for creating grids.
 //!
 //! *This API requires the following crate features to be activated: `grid`*
-use iced_graphics::{Backend, Point, Primitive, Rectangle, Renderer};
+use iced_graphics::{Backend, Color, Point, Primitive, Rectangle, Renderer, Vector};
 use iced_native::{mouse, Element, Layout};
 
-use crate::native::grid;
+use crate::native::grid::{self, StickyHeader};
 
 /// A container that distributes its contents in a grid.
 ///
@@ -22,27 +22,74 @@ where
         cursor_position: Point,
         viewport: &Rectangle,
         elements: &[Element<'_, Message, Self>],
+        row_backgrounds: &[(Rectangle, Color)],
+        header: Option<StickyHeader>,
     ) -> Self::Output {
         let mut mouse_cursor = mouse::Interaction::default();
 
+        let (header_cells, header_backgrounds, offset) = header.map_or((0, 0, 0.0), |header| {
+            (header.cells, header.backgrounds, header.offset)
+        });
+
+        let mut draw_row = |renderer: &mut Self,
+                            backgrounds: &[(Rectangle, Color)],
+                            cells: &[Element<'_, Message, Self>],
+                            layouts: &mut dyn Iterator<Item = Layout<'_>>,
+                            cursor_position: Point| {
+            let backgrounds = backgrounds.iter().map(|(bounds, color)| Primitive::Quad {
+                bounds: *bounds,
+                background: (*color).into(),
+                border_radius: 0.0,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            });
+
+            let cells: Vec<Primitive> = cells
+                .iter()
+                .zip(layouts)
+                .map(|(element, layout)| {
+                    let (primitive, new_mouse_cursor) =
+                        element.draw(renderer, defaults, layout, cursor_position, viewport);
+
+                    if new_mouse_cursor > mouse_cursor {
+                        mouse_cursor = new_mouse_cursor;
+                    }
+
+                    primitive
+                })
+                .collect();
+
+            backgrounds.chain(cells).collect::<Vec<_>>()
+        };
+
+        let mut layouts = layout.children();
+        let header_layouts: Vec<Layout<'_>> = layouts.by_ref().take(header_cells).collect();
+
+        // The body is drawn first, so the sticky header covers its rows.
+        let body = draw_row(
+            self,
+            &row_backgrounds[header_backgrounds..],
+            &elements[header_cells..],
+            &mut layouts,
+            cursor_position,
+        );
+        let header = draw_row(
+            self,
+            &row_backgrounds[..header_backgrounds],
+            &elements[..header_cells],
+            &mut header_layouts.into_iter(),
+            cursor_position - Vector::new(0.0, offset),
+        );
+
         (
             Primitive::Group {
-                primitives: {
-                    elements
-                        .iter()
-                        .zip(layout.children())
-                        .map(|(element, layout)| {
-                            let (primitive, new_mouse_cursor) =
-                                element.draw(self, defaults, layout, cursor_position, viewport);
-
-                            if new_mouse_cursor > mouse_cursor {
-                                mouse_cursor = new_mouse_cursor;
-                            }
-
-                            primitive
-                        })
-                        .collect()
-                },
+                primitives: vec![
+                    Primitive::Group { primitives: body },
+                    Primitive::Translate {
+                        translation: Vector::new(0.0, offset),
+                        content: Box::new(Primitive::Group { primitives: header }),
+                    },
+                ],
             },
             mouse_cursor,
         )
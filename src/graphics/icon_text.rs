@@ -5,6 +5,8 @@
 //!
 //! //! *This API requires the following crate features to be activated: `icon_text`*
 use iced_graphics::{backend, Backend, Font, Primitive, Rectangle, Renderer};
+use std::hash::Hash;
+
 use iced_native::mouse;
 
 use crate::native::icon_text;
@@ -16,6 +18,12 @@ use super::icons::ICON_FONT;
 /// This is an alias of an `iced_native` `IconText` with an `iced_wgpu::Renderer`.
 pub type IconText<Backend> = crate::native::icon_text::IconText<Renderer<Backend>>;
 
+/// An icon that produces a message when it is pressed.
+///
+/// This is an alias of an `iced_native` `PressableIconText` with an `iced_wgpu::Renderer`.
+pub type PressableIconText<'a, Message, Backend> =
+    crate::native::icon_text::PressableIconText<'a, Message, Renderer<Backend>>;
+
 impl<B> icon_text::Renderer for Renderer<B>
 where
     B: Backend + backend::Text,
@@ -30,6 +38,14 @@ where
         ICON_FONT
     }
 
+    fn hash_font(font: &Self::Font, state: &mut iced_native::Hasher) {
+        match font {
+            Font::Default => None,
+            Font::External { name, .. } => Some(name),
+        }
+        .hash(state);
+    }
+
     fn measure(
         &self,
         content: &str,
@@ -47,10 +63,11 @@ where
         bounds: iced_graphics::Rectangle,
         content: &str,
         size: u16,
-        font: Option<Self::Font>,
+        font: Self::Font,
         color: Option<iced_graphics::Color>,
         horizontal_alignment: iced_graphics::HorizontalAlignment,
         vertical_alignment: iced_graphics::VerticalAlignment,
+        is_mouse_over: bool,
     ) -> Self::Output {
         let x = match horizontal_alignment {
             iced_native::HorizontalAlignment::Left => bounds.x,
@@ -70,11 +87,109 @@ where
                 size: f32::from(size),
                 bounds: Rectangle { x, y, ..bounds },
                 color: color.unwrap_or(defaults.text.color),
-                font: font.unwrap_or_else(|| self.default_font()),
+                font,
                 horizontal_alignment,
                 vertical_alignment,
             },
-            mouse::Interaction::default(),
+            if is_mouse_over {
+                mouse::Interaction::Pointer
+            } else {
+                mouse::Interaction::default()
+            },
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::IconText;
+    use iced_graphics::{backend, Backend, Defaults, Font, Primitive, Renderer, Size};
+    use iced_native::{layout, Layout, Point, Widget};
+
+    /// A backend without a window that measures every content as a square.
+    struct Headless;
+
+    impl Backend for Headless {}
+
+    impl backend::Text for Headless {
+        const ICON_FONT: Font = Font::Default;
+        const CHECKMARK_ICON: char = '\u{2714}';
+        const ARROW_DOWN_ICON: char = '\u{25bc}';
+
+        fn default_size(&self) -> u16 {
+            20
+        }
+
+        fn measure(&self, contents: &str, size: f32, font: Font, _bounds: Size) -> (f32, f32) {
+            // The icon font is missing every glyph outside of the private use area.
+            let is_missing = matches!(font, Font::External { .. })
+                && contents
+                    .chars()
+                    .any(|c| !('\u{e000}'..='\u{f8ff}').contains(&c));
+
+            (if is_missing { 0.0 } else { size }, size)
+        }
+    }
+
+    #[test]
+    fn draw_default_size_and_font_test() {
+        let mut renderer = Renderer::new(Headless);
+        let icon_text: IconText<Headless> = IconText::new("\u{f25c}");
+
+        let node = <IconText<Headless> as Widget<(), _>>::layout(
+            &icon_text,
+            &renderer,
+            &layout::Limits::NONE,
+        );
+        assert_eq!(node.bounds().size(), Size::new(20.0, 20.0));
+
+        let (primitive, _) = <IconText<Headless> as Widget<(), _>>::draw(
+            &icon_text,
+            &mut renderer,
+            &Defaults::default(),
+            Layout::new(&node),
+            Point::ORIGIN,
+            &node.bounds(),
+        );
+
+        assert!(matches!(
+            primitive,
+            Primitive::Text {
+                size,
+                font: Font::External { name: "Icons", .. },
+                ..
+            } if (size - 20.0).abs() < f32::EPSILON
+        ));
+    }
+
+    #[test]
+    fn draw_font_fallback_test() {
+        let mut renderer = Renderer::new(Headless);
+        let icon_text: IconText<Headless> =
+            IconText::new("\u{1f600}").font_fallback(vec![Font::Default]);
+
+        let node = <IconText<Headless> as Widget<(), _>>::layout(
+            &icon_text,
+            &renderer,
+            &layout::Limits::NONE,
+        );
+        assert_eq!(node.bounds().size(), Size::new(20.0, 20.0));
+
+        let (primitive, _) = <IconText<Headless> as Widget<(), _>>::draw(
+            &icon_text,
+            &mut renderer,
+            &Defaults::default(),
+            Layout::new(&node),
+            Point::ORIGIN,
+            &node.bounds(),
+        );
+
+        assert!(matches!(
+            primitive,
+            Primitive::Text {
+                font: Font::Default,
+                ..
+            }
+        ));
+    }
+}
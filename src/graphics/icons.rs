@@ -42,3 +42,85 @@ impl std::fmt::Display for Icon {
         write!(f, "{}", icon_to_char(*self))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Icon;
+
+    /// The generated sources of the icon sets behind and without the `icons`
+    /// feature.
+    const SOURCES: [&str; 2] = [
+        include_str!("icons/bootstrap.rs"),
+        include_str!("icons/required.rs"),
+    ];
+
+    /// Returns the names of the variants of the `Icon` enum in the given source.
+    fn variants(source: &str) -> Vec<&str> {
+        let start = source.find("pub enum Icon {").unwrap_or(0);
+        let end = source[start..]
+            .find("\n}")
+            .map_or(source.len(), |end| start + end);
+
+        source[start..end]
+            .lines()
+            .skip(1)
+            .map(str::trim)
+            .filter(|line| !line.starts_with("///"))
+            .filter_map(|line| line.strip_suffix(','))
+            .collect()
+    }
+
+    /// Returns the names of the variants mapped by `icon_to_char` in the given
+    /// source together with the text of their character literal.
+    fn mapped(source: &str) -> Vec<(&str, String)> {
+        source
+            .lines()
+            .filter_map(|line| line.trim().strip_prefix("Icon::"))
+            .filter_map(|line| line.split_once(" => "))
+            .map(|(name, literal)| {
+                let literal = literal.trim_end_matches(',').trim_matches('\'');
+                let text = literal
+                    .strip_prefix("\\u{")
+                    .and_then(|hex| hex.strip_suffix('}'))
+                    .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                    .and_then(std::char::from_u32)
+                    .map_or_else(|| literal.to_owned(), String::from);
+                (name, text)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn icon_to_string_test() {
+        let icons = [
+            Icon::CaretDownFill,
+            Icon::CaretLeftFill,
+            Icon::CaretRightFill,
+            Icon::CaretUpFill,
+            Icon::Check,
+            Icon::X,
+        ];
+
+        for icon in &icons {
+            let result: String = (*icon).into();
+            assert_eq!(result.chars().count(), 1);
+            assert_eq!(result.chars().next(), Some(char::from(*icon)));
+        }
+    }
+
+    #[test]
+    fn every_icon_maps_to_a_char_test() {
+        for source in &SOURCES {
+            let variants = variants(source);
+            let mapped = mapped(source);
+            assert!(!variants.is_empty());
+
+            let names: Vec<&str> = mapped.iter().map(|(name, _)| *name).collect();
+            assert_eq!(names, variants);
+
+            for (name, text) in &mapped {
+                assert_eq!(text.chars().count(), 1, "{} maps to {:?}", name, text);
+            }
+        }
+    }
+}
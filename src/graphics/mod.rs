@@ -2,10 +2,20 @@
 
 pub mod icons;
 
+#[cfg(any(
+    feature = "color_picker",
+    feature = "date_picker",
+    feature = "number_input",
+    feature = "selection_list",
+    feature = "tab_bar",
+    feature = "time_picker"
+))]
+mod focus_ring;
+
 #[cfg(feature = "badge")]
 pub mod badge;
 #[cfg(feature = "badge")]
-pub use badge::Badge;
+pub use badge::{Badge, BadgedContent};
 
 #[cfg(feature = "card")]
 pub mod card;
@@ -22,6 +32,11 @@ pub mod date_picker;
 #[cfg(feature = "date_picker")]
 pub use date_picker::DatePicker;
 
+#[cfg(feature = "date_time_picker")]
+pub mod date_time_picker;
+#[cfg(feature = "date_time_picker")]
+pub use date_time_picker::DateTimePicker;
+
 #[cfg(feature = "floating_button")]
 pub mod floating_button;
 #[cfg(feature = "floating_button")]
@@ -35,7 +50,7 @@ pub use grid::Grid;
 #[cfg(feature = "icon_text")]
 pub mod icon_text;
 #[cfg(feature = "icon_text")]
-pub use icon_text::IconText;
+pub use icon_text::{IconText, PressableIconText};
 
 #[cfg(feature = "modal")]
 pub mod modal;
@@ -24,25 +24,39 @@ where
         &mut self,
         env: DrawEnvironment<Self::Defaults, Self::Style, ()>,
         modal: &iced_native::Element<'_, Message, Self>,
+        below: Option<Self::Output>,
+        above: Option<Self::Output>,
     ) -> Self::Output {
         let bounds = env.layout.bounds();
 
         let style = env.style_sheet.active();
 
-        let background = Primitive::Quad {
-            bounds,
-            background: style.background,
-            border_radius: 0.0,
-            border_width: 0.0,
-            border_color: Color::TRANSPARENT,
-        };
+        // The overlay below already dimmed the background
+        let background = below.map_or_else(
+            || Primitive::Quad {
+                bounds,
+                background: style.background,
+                border_radius: 0.0,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            |(below, _)| below,
+        );
 
         let (modal, mouse_interaction) =
             modal.draw(self, env.defaults, env.layout, env.cursor_position, &bounds);
 
+        // The overlay of the content is drawn on top of it
+        let (above, mouse_interaction) = above.map_or(
+            (Primitive::None, mouse_interaction),
+            |(above, above_mouse_interaction)| {
+                (above, above_mouse_interaction.max(mouse_interaction))
+            },
+        );
+
         (
             Primitive::Group {
-                primitives: vec![background, modal],
+                primitives: vec![background, modal, above],
             },
             mouse_interaction,
         )
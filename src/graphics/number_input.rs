@@ -1,13 +1,17 @@
 //! Display fields that can only be filled with numeric type.
 //!
 //! A [`NumberInput`] has some local [`State`].
+use crate::graphics::{
+    focus_ring::focus_ring,
+    icons::{Icon, ICON_FONT},
+};
 use crate::native::number_input::{self, ModifierState};
 use iced_graphics::backend::{self, Backend};
 use iced_graphics::{Primitive, Renderer};
 use iced_native::mouse;
 use iced_native::{Background, Color, HorizontalAlignment, Point, Rectangle, VerticalAlignment};
 
-pub use crate::native::number_input::State;
+pub use crate::native::number_input::{ButtonLayout, Radix, State};
 pub use crate::style::number_input::{Style, StyleSheet};
 
 /// A field that can only be filled with numeric type.
@@ -24,6 +28,7 @@ where
 
     const DEFAULT_PADDING: u16 = 5;
 
+    #[allow(clippy::too_many_lines)]
     fn draw(
         &mut self,
         cursor_position: Point,
@@ -33,10 +38,23 @@ where
         is_mouse_over: bool,
         is_decrease_disabled: bool,
         is_increase_disabled: bool,
+        content_bounds: Rectangle,
+        focused_bounds: Option<Rectangle>,
+        is_invalid: bool,
+        is_disabled: bool,
+        show_buttons: bool,
+        button_icons: Option<(Icon, Icon)>,
         (content, _): Self::Output,
+        (prefix, _): Self::Output,
+        (suffix, _): Self::Output,
         style: &<Self as number_input::Renderer>::Style,
         font: Self::Font,
     ) -> Self::Output {
+        let (increase_icon, decrease_icon, icon_font) = button_icons.map_or_else(
+            || (String::from("\u{25b2}"), String::from("\u{25bc}"), font),
+            |(increase, decrease)| (increase.into(), decrease.into(), ICON_FONT),
+        );
+
         let mouse_over_decrease = dec_bounds.contains(cursor_position);
         let mouse_over_increase = inc_bounds.contains(cursor_position);
 
@@ -67,13 +85,13 @@ where
             border_color: Color::TRANSPARENT,
         };
         let decrease_text = Primitive::Text {
-            content: String::from("\u{25bc}"),
+            content: decrease_icon,
             bounds: Rectangle {
                 x: dec_bounds.center_x(),
                 y: dec_bounds.center_y(),
                 ..dec_bounds
             },
-            font,
+            font: icon_font,
             size: dec_bounds.height * 0.9,
             color: decrease_btn_style.icon_color,
             horizontal_alignment: HorizontalAlignment::Center,
@@ -94,13 +112,13 @@ where
             border_color: Color::TRANSPARENT,
         };
         let increase_text = Primitive::Text {
-            content: String::from("\u{25b2}"),
+            content: increase_icon,
             bounds: Rectangle {
                 x: inc_bounds.center_x(),
                 y: inc_bounds.center_y(),
                 ..inc_bounds
             },
-            font,
+            font: icon_font,
             size: inc_bounds.height * 0.9,
             color: increase_btn_style.icon_color,
             horizontal_alignment: HorizontalAlignment::Center,
@@ -110,12 +128,40 @@ where
             primitives: vec![increase_button_rect, increase_text],
         };
 
+        let mut primitives = vec![content, prefix, suffix];
+        if show_buttons {
+            primitives.push(decrease_btn);
+            primitives.push(increase_btn);
+        }
+        if let Some(overlay) = style.disabled_overlay().filter(|_| is_disabled) {
+            primitives.push(Primitive::Quad {
+                bounds: content_bounds,
+                background: overlay,
+                border_radius: 0.0,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            });
+        }
+        if is_invalid {
+            primitives.push(Primitive::Quad {
+                bounds: content_bounds,
+                background: Background::Color(Color::TRANSPARENT),
+                border_radius: 0.0,
+                border_width: 1.0,
+                border_color: style.invalid_border_color(),
+            });
+        }
+        if let Some(focused_bounds) = focused_bounds {
+            primitives.push(focus_ring(focused_bounds, style.focus_ring()));
+        }
+
         (
-            Primitive::Group {
-                primitives: vec![content, decrease_btn, increase_btn],
-            },
-            if (mouse_over_decrease && !is_decrease_disabled)
-                || (mouse_over_increase && !is_increase_disabled)
+            Primitive::Group { primitives },
+            if is_disabled {
+                mouse::Interaction::default()
+            } else if show_buttons
+                && ((mouse_over_decrease && !is_decrease_disabled)
+                    || (mouse_over_increase && !is_increase_disabled))
             {
                 mouse::Interaction::Pointer
             } else if is_mouse_over {
@@ -1,10 +1,15 @@
 //! Display a list of selectable values.
-use iced_graphics::{backend, Backend, Color, Point, Primitive, Renderer};
-use iced_native::{mouse, HorizontalAlignment, Rectangle, VerticalAlignment};
+use iced_graphics::{backend, Backend, Color, Point, Primitive, Renderer, Vector};
+use iced_native::{mouse, Rectangle};
 
-pub use crate::native::selection_list::{self, list, State};
+use crate::graphics::focus_ring::focus_ring;
+pub use crate::native::selection_list::{self, list, DraggedRow, State};
+use crate::style::focus_ring::FocusRing;
 pub use crate::style::selection_list::{Style, StyleSheet};
 
+/// The width of the line showing where a dragged option would be dropped.
+const DROP_LINE_WIDTH: f32 = 2.0;
+
 /// A widget allowing the selection of a single value from a list of options.
 pub type SelectionList<'a, T, Message, Backend> =
     selection_list::SelectionList<'a, T, Message, Renderer<Backend>>;
@@ -17,24 +22,39 @@ where
         &mut self,
         bounds: Rectangle,
         _cursor_position: Point,
+        ring: Option<FocusRing>,
         style: &Style,
         (primitives, mouse_cursor): Self::Output,
+        filter: Option<Self::Output>,
     ) -> Self::Output {
-        (
-            Primitive::Group {
-                primitives: vec![
-                    Primitive::Quad {
-                        bounds,
-                        background: style.background,
-                        border_color: style.border_color,
-                        border_width: style.border_width,
-                        border_radius: 0.0,
-                    },
-                    primitives,
-                ],
+        let mut group = vec![
+            Primitive::Quad {
+                bounds,
+                background: style.background,
+                border_color: style.border_color,
+                border_width: style.border_width,
+                border_radius: 0.0,
             },
-            mouse_cursor,
-        )
+            primitives,
+        ];
+
+        if ring.is_some() {
+            group.push(focus_ring(bounds, ring));
+        }
+
+        let mouse_cursor = match filter {
+            Some((filter_primitives, filter_mouse_cursor)) => {
+                group.push(filter_primitives);
+                if filter_mouse_cursor == mouse::Interaction::default() {
+                    mouse_cursor
+                } else {
+                    filter_mouse_cursor
+                }
+            }
+            None => mouse_cursor,
+        };
+
+        (Primitive::Group { primitives: group }, mouse_cursor)
     }
 
     fn draw(&mut self) -> Self::Output {
@@ -46,76 +66,74 @@ impl<B> list::Renderer for Renderer<B>
 where
     B: Backend + backend::Text,
 {
-    fn draw<T: ToString>(
+    fn draw(
         &mut self,
         bounds: Rectangle,
         cursor_position: Point,
-        viewport: &Rectangle,
-        options: &[T],
-        hovered_option: Option<usize>,
-        font: Self::Font,
+        rows: Vec<(Rectangle, bool, Self::Output)>,
+        headers: Vec<Self::Output>,
+        dragged: Option<DraggedRow<Self::Output>>,
         style: &Style,
     ) -> Self::Output {
-        use std::f32;
         let is_mouse_over = bounds.contains(cursor_position);
-        let option_height = style.text_size + (style.padding * 2);
 
-        let mut primitives = Vec::new();
+        let mut primitives: Vec<Primitive> = rows
+            .into_iter()
+            .map(|row| draw_row(row, style))
+            .chain(headers.into_iter().map(|(header, _)| header))
+            .collect();
 
-        let offset = viewport.y - bounds.y;
-        let start = (offset / f32::from(option_height)) as usize;
-        let end = ((offset + viewport.height) / f32::from(option_height)).ceil() as usize;
+        let mouse_interaction = if let Some(dragged) = dragged {
+            if let Some(drop_y) = dragged.drop_y {
+                primitives.push(Primitive::Quad {
+                    bounds: Rectangle {
+                        y: drop_y - DROP_LINE_WIDTH / 2.0,
+                        height: DROP_LINE_WIDTH,
+                        ..bounds
+                    },
+                    background: style.selected_background,
+                    border_color: Color::TRANSPARENT,
+                    border_width: 0.0,
+                    border_radius: 0.0,
+                });
+            }
 
-        let visible_options = &options[start..end.min(options.len())];
+            // The dragged row is drawn on top of the others.
+            primitives.push(Primitive::Translate {
+                translation: Vector::new(0.0, dragged.offset),
+                content: Box::new(draw_row(dragged.row, style)),
+            });
 
-        for (i, option) in visible_options.iter().enumerate() {
-            let i = start + i;
-            let is_selected = hovered_option == Some(i);
+            mouse::Interaction::Grabbing
+        } else if is_mouse_over {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        };
 
-            let bounds = Rectangle {
-                x: bounds.x,
-                y: bounds.y + f32::from(option_height * i as u16),
-                width: bounds.width,
-                height: f32::from(style.text_size + (style.padding * 2)),
-            };
+        (Primitive::Group { primitives }, mouse_interaction)
+    }
+}
 
-            if is_selected {
-                primitives.push(Primitive::Quad {
+/// Draws a row of the list with the background of selected options.
+fn draw_row(
+    (bounds, is_selected, (item, _)): (Rectangle, bool, (Primitive, mouse::Interaction)),
+    style: &Style,
+) -> Primitive {
+    if is_selected {
+        Primitive::Group {
+            primitives: vec![
+                Primitive::Quad {
                     bounds,
                     background: style.selected_background,
                     border_color: Color::TRANSPARENT,
                     border_width: 0.0,
                     border_radius: 0.0,
-                });
-            }
-
-            primitives.push(Primitive::Text {
-                content: option.to_string(),
-                bounds: Rectangle {
-                    x: bounds.x,
-                    y: bounds.center_y(),
-                    width: f32::INFINITY,
-                    ..bounds
-                },
-                size: f32::from(style.text_size),
-                font,
-                color: if is_selected {
-                    style.selected_text_color
-                } else {
-                    style.text_color
                 },
-                horizontal_alignment: HorizontalAlignment::Left,
-                vertical_alignment: VerticalAlignment::Center,
-            });
+                item,
+            ],
         }
-
-        (
-            Primitive::Group { primitives },
-            if is_mouse_over {
-                mouse::Interaction::Pointer
-            } else {
-                mouse::Interaction::default()
-            },
-        )
+    } else {
+        item
     }
 }
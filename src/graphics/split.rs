@@ -1,12 +1,17 @@
 //! Use a split to split the available space in two parts to display two different elements.
 //!
 //! *This API requires the following crate features to be activated: split*
-use iced_graphics::{Backend, Color, Primitive, Renderer, Vector};
-use iced_native::mouse;
+use iced_graphics::{
+    Backend, Color, HorizontalAlignment, Primitive, Rectangle, Renderer, Vector, VerticalAlignment,
+};
+use iced_native::{mouse, Layout, Point};
 
-use crate::native::split;
-pub use crate::native::split::{Axis, State};
+pub use crate::native::split::{Axis, Pane, State, TreeState};
 pub use crate::style::split::{Style, StyleSheet};
+use crate::{
+    graphics::icons::{Icon, ICON_FONT},
+    native::split,
+};
 
 /// A split can divide the available space by half to display two different elements.
 /// It can split horizontally or vertically.
@@ -14,6 +19,11 @@ pub use crate::style::split::{Style, StyleSheet};
 /// This is an alias of an `iced_native` Split with an `iced_wgpu::Renderer`.
 pub type Split<'a, Message, Backend> = split::Split<'a, Message, Renderer<Backend>>;
 
+/// A tree of nested splits with elements as leaves.
+///
+/// This is an alias of an `iced_native` `SplitTree` with an `iced_wgpu::Renderer`.
+pub type SplitTree<'a, Message, Backend> = split::SplitTree<'a, Message, Renderer<Backend>>;
+
 impl<B> split::Renderer for Renderer<B>
 where
     B: Backend,
@@ -28,6 +38,9 @@ where
         second: &iced_native::Element<'_, Message, Self>,
         dragging: bool,
         axis: Axis,
+        drag_margin: f32,
+        collapsible: bool,
+        collapsed: Option<Pane>,
     ) -> Self::Output {
         let mut children = env.layout.children();
 
@@ -71,18 +84,30 @@ where
         let divider_layout = children
             .next()
             .expect("Graphics: Layout should have a divider layout");
-        let divider_mouse_interaction = if divider_layout.bounds().contains(env.cursor_position) {
-            match axis {
-                Axis::Horizontal => mouse::Interaction::ResizingVertically,
-                Axis::Vertical => mouse::Interaction::ResizingHorizontally,
-            }
+        let (collapse_buttons, collapse_mouse_interaction) = if collapsible {
+            draw_collapse_buttons(
+                divider_layout,
+                env.cursor_position,
+                env.style_sheet.active(),
+                axis,
+                collapsed,
+            )
         } else {
-            mouse::Interaction::default()
+            (Primitive::None, None)
+        };
+
+        let resizing = match axis {
+            Axis::Horizontal => mouse::Interaction::ResizingVertically,
+            Axis::Vertical => mouse::Interaction::ResizingHorizontally,
         };
+        let is_mouse_over_divider = divider_layout.bounds().contains(env.cursor_position);
+        let is_mouse_over_margin =
+            split::divider_hit_bounds(divider_layout.bounds(), axis, drag_margin)
+                .contains(env.cursor_position);
 
         let divider_style = if dragging {
             env.style_sheet.dragged()
-        } else if divider_layout.bounds().contains(env.cursor_position) {
+        } else if is_mouse_over_divider {
             env.style_sheet.hovered()
         } else {
             env.style_sheet.active()
@@ -120,6 +145,21 @@ where
             border_color: Color::TRANSPARENT,
         };
 
+        // The margin around the divider only resizes if the elements do not
+        // show an interaction of their own.
+        let content_mouse_interaction = first_mouse_interaction.max(second_mouse_interaction);
+        let mouse_interaction = if dragging {
+            resizing
+        } else if let Some(collapse_mouse_interaction) = collapse_mouse_interaction {
+            collapse_mouse_interaction
+        } else if is_mouse_over_divider
+            || (is_mouse_over_margin && content_mouse_interaction == mouse::Interaction::default())
+        {
+            resizing
+        } else {
+            content_mouse_interaction
+        };
+
         (
             Primitive::Group {
                 primitives: vec![
@@ -137,11 +177,68 @@ where
                         content: Box::new(second),
                     },
                     divider_background,
+                    collapse_buttons,
                 ],
             },
-            first_mouse_interaction
-                .max(second_mouse_interaction)
-                .max(divider_mouse_interaction),
+            mouse_interaction,
         )
     }
 }
+
+/// Draws the buttons on the divider of the [`Split`](split::Split) to collapse
+/// and restore its elements.
+///
+/// The mouse interaction is only returned if the cursor is over a button.
+fn draw_collapse_buttons(
+    divider_layout: Layout<'_>,
+    cursor_position: Point,
+    style: Style,
+    axis: Axis,
+    collapsed: Option<Pane>,
+) -> (Primitive, Option<mouse::Interaction>) {
+    let icons = match axis {
+        Axis::Horizontal => [Icon::CaretUpFill, Icon::CaretDownFill],
+        Axis::Vertical => [Icon::CaretLeftFill, Icon::CaretRightFill],
+    };
+    let mut mouse_interaction = None;
+
+    let primitives = divider_layout
+        .children()
+        .zip([Pane::First, Pane::Second])
+        .zip(icons)
+        .filter(|((_, pane), _)| collapsed != Some(*pane))
+        .flat_map(|((button_layout, _), icon)| {
+            let bounds = button_layout.bounds();
+            let is_mouse_over = bounds.contains(cursor_position);
+
+            if is_mouse_over {
+                mouse_interaction = Some(mouse::Interaction::Pointer);
+            }
+
+            [
+                Primitive::Quad {
+                    bounds,
+                    background: style.divider_background,
+                    border_radius: 2.0,
+                    border_width: style.divider_border_width,
+                    border_color: style.divider_border_color,
+                },
+                Primitive::Text {
+                    content: icon.into(),
+                    font: ICON_FONT,
+                    size: bounds.height * if is_mouse_over { 0.9 } else { 0.7 },
+                    bounds: Rectangle {
+                        x: bounds.center_x(),
+                        y: bounds.center_y(),
+                        ..bounds
+                    },
+                    color: style.border_color,
+                    horizontal_alignment: HorizontalAlignment::Center,
+                    vertical_alignment: VerticalAlignment::Center,
+                },
+            ]
+        })
+        .collect();
+
+    (Primitive::Group { primitives }, mouse_interaction)
+}
@@ -4,17 +4,28 @@
 //! to use the [`Tabs`](super::tabs) widget instead.
 //!
 //! *This API requires the following crate features to be activated: `tab_bar`*
-use iced_graphics::{backend, Backend, Color, Primitive, Rectangle, Renderer};
+use iced_graphics::{backend, Backend, Color, Primitive, Rectangle, Renderer, Vector};
 use iced_native::{mouse, Font, HorizontalAlignment, Layout, VerticalAlignment};
-pub use tab_bar::tab_label::TabLabel;
+pub use tab_bar::{tab_label::TabLabel, DraggedTab, Orientation, Overflow, State};
 
-pub use crate::style::tab_bar::{Style, StyleSheet};
-use crate::{core::renderer::DrawEnvironment, native::tab_bar};
+pub use crate::style::tab_bar::{Indicator, Style, StyleSheet};
+use crate::{
+    core::renderer::DrawEnvironment,
+    graphics::{
+        focus_ring::focus_ring,
+        icons::{Icon, ICON_FONT},
+    },
+    native::tab_bar,
+    style::badge,
+};
+
+/// The opacity of the scroll arrows if there is no tab to scroll to.
+const DISABLED_ARROW_ALPHA: f32 = 0.3;
 
 /// A tab bar to show tabs.
 ///
 /// This is an alias of an `iced_native` `TabBar` with an `iced_wgpu::Renderer`.
-pub type TabBar<Message, Backend> = tab_bar::TabBar<Message, Renderer<Backend>>;
+pub type TabBar<'a, Message, Backend> = tab_bar::TabBar<'a, Message, Renderer<Backend>>;
 
 impl<B> tab_bar::Renderer for Renderer<B>
 where
@@ -22,6 +33,8 @@ where
 {
     type Style = Box<dyn StyleSheet>;
 
+    type BadgeStyle = Box<dyn badge::StyleSheet>;
+
     const DEFAULT_ICON_SIZE: u16 = 32;
 
     const DEFAULT_TEXT_SIZE: u16 = 16;
@@ -32,17 +45,22 @@ where
 
     const DEFAULT_SPACING: u16 = 0;
 
+    #[allow(clippy::too_many_lines)]
     fn draw(
         &mut self,
-        env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, bool>,
         active_tab: usize,
+        hovered_tab: Option<usize>,
         tab_labels: &[TabLabel],
+        dragged: Option<DraggedTab>,
+        indicator: Option<Rectangle>,
         icon_font: Option<Font>,
         text_font: Option<Font>,
+        badge_style: &Self::BadgeStyle,
     ) -> Self::Output {
         // TODO tab bar background
         let bounds = env.layout.bounds();
-        let children = env.layout.children();
+        let mut children = env.layout.children();
         let is_mouse_over = bounds.contains(env.cursor_position);
         let style = if is_mouse_over {
             env.style_sheet.hovered(false)
@@ -62,49 +80,297 @@ where
             border_color: style.border_color.unwrap_or(Color::TRANSPARENT),
         }];
 
-        primitives = tab_labels.iter().enumerate().zip(children).fold(
-            primitives,
-            |mut primitives, ((i, tab), layout)| {
+        let mut ghost = Primitive::None;
+
+        let mut tabs = tab_labels
+            .iter()
+            .enumerate()
+            .zip(children.by_ref().take(tab_labels.len()))
+            .fold(Vec::new(), |mut primitives, ((i, tab), layout)| {
+                // Tabs collapsed into the menu have an empty layout.
+                if layout.children().next().is_none() {
+                    return primitives;
+                }
+
                 let (primitive, new_mouse_interaction) = draw_tab(
                     tab,
                     layout,
                     env.style_sheet,
                     i == active_tab,
+                    hovered_tab == Some(i),
                     env.cursor_position,
                     icon_font.unwrap_or(B::ICON_FONT),
                     text_font.unwrap_or_default(),
+                    badge_style,
                 );
 
                 if new_mouse_interaction > mouse_interaction {
                     mouse_interaction = new_mouse_interaction;
                 }
 
-                primitives.push(primitive);
+                match dragged {
+                    Some(dragged) if dragged.from == i => {
+                        ghost = Primitive::Translate {
+                            translation: dragged.offset,
+                            content: Box::new(primitive),
+                        };
+                    }
+                    Some(dragged) => {
+                        let shift = if dragged.from < i && i <= dragged.to {
+                            dragged.shift * -1.0
+                        } else if dragged.to <= i && i < dragged.from {
+                            dragged.shift
+                        } else {
+                            Vector::new(0.0, 0.0)
+                        };
+
+                        primitives.push(Primitive::Translate {
+                            translation: shift,
+                            content: Box::new(primitive),
+                        });
+                    }
+                    None => primitives.push(primitive),
+                }
                 primitives
-            },
-        );
+            });
+
+        let indicator_style = env.style_sheet.indicator();
+        if let Some(indicator) = indicator.filter(|_| indicator_style.height > 0.0) {
+            // The indicator follows the active tab while dragging.
+            let translation = match dragged {
+                Some(dragged) if dragged.from == active_tab => dragged.offset,
+                Some(dragged) if dragged.from < active_tab && active_tab <= dragged.to => {
+                    dragged.shift * -1.0
+                }
+                Some(dragged) if dragged.to <= active_tab && active_tab < dragged.from => {
+                    dragged.shift
+                }
+                _ => Vector::new(0.0, 0.0),
+            };
+
+            tabs.push(Primitive::Translate {
+                translation,
+                content: Box::new(Primitive::Quad {
+                    bounds: Rectangle {
+                        y: indicator.y + indicator.height - indicator_style.height,
+                        height: indicator_style.height,
+                        ..indicator
+                    },
+                    background: indicator_style.color.into(),
+                    border_radius: 0.0,
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                }),
+            });
+        }
+
+        if env.focus && dragged.is_none() {
+            // Tabs collapsed into the menu have an empty layout.
+            if let Some(active) = env
+                .layout
+                .children()
+                .nth(active_tab)
+                .filter(|layout| layout.children().next().is_some())
+            {
+                tabs.push(focus_ring(active.bounds(), env.style_sheet.focus_ring()));
+            }
+        }
+
+        // The dragged tab is drawn on top of the others.
+        tabs.push(ghost);
+
+        if dragged.is_some() {
+            mouse_interaction = mouse::Interaction::Grabbing;
+        }
+
+        let (first_control, second_control) = (children.next(), children.next());
+
+        if let (Some(left_arrow), Some(right_arrow)) = (first_control, second_control) {
+            let left_bounds = left_arrow.bounds();
+            let right_bounds = right_arrow.bounds();
+
+            primitives.push(Primitive::Clip {
+                bounds: Rectangle {
+                    x: left_bounds.x + left_bounds.width,
+                    width: right_bounds.x - left_bounds.x - left_bounds.width,
+                    ..bounds
+                },
+                offset: Vector::new(0, 0),
+                content: Box::new(Primitive::Group { primitives: tabs }),
+            });
+
+            for (arrow_bounds, icon, is_enabled) in [
+                (left_bounds, Icon::CaretLeftFill, active_tab > 0),
+                (
+                    right_bounds,
+                    Icon::CaretRightFill,
+                    active_tab + 1 < tab_labels.len(),
+                ),
+            ] {
+                let is_mouse_over = is_enabled && arrow_bounds.contains(env.cursor_position);
+
+                if is_mouse_over {
+                    mouse_interaction = mouse_interaction.max(mouse::Interaction::Pointer);
+                }
+
+                primitives.push(Primitive::Text {
+                    content: icon.into(),
+                    font: ICON_FONT,
+                    size: arrow_bounds.width + if is_mouse_over { 5.0 } else { 0.0 },
+                    bounds: Rectangle {
+                        x: arrow_bounds.center_x(),
+                        y: arrow_bounds.center_y(),
+                        ..arrow_bounds
+                    },
+                    color: if is_enabled {
+                        style.icon_color
+                    } else {
+                        Color {
+                            a: style.icon_color.a * DISABLED_ARROW_ALPHA,
+                            ..style.icon_color
+                        }
+                    },
+                    horizontal_alignment: HorizontalAlignment::Center,
+                    vertical_alignment: VerticalAlignment::Center,
+                });
+            }
+        } else if let Some(menu_button) = first_control {
+            primitives.extend(tabs);
+
+            let button_bounds = menu_button.bounds();
+            let is_mouse_over = button_bounds.contains(env.cursor_position);
+
+            if is_mouse_over {
+                mouse_interaction = mouse_interaction.max(mouse::Interaction::Pointer);
+            }
+
+            // The button shows three dots (⋯) growing while hovered.
+            let dot_size = button_bounds.width / if is_mouse_over { 6.0 } else { 8.0 };
+            for offset in [-2.0, 0.0, 2.0] {
+                primitives.push(Primitive::Quad {
+                    bounds: Rectangle {
+                        x: button_bounds.center_x() + (offset - 0.5) * dot_size,
+                        y: button_bounds.center_y() - dot_size / 2.0,
+                        width: dot_size,
+                        height: dot_size,
+                    },
+                    background: style.icon_color.into(),
+                    border_radius: dot_size / 2.0,
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                });
+            }
+        } else {
+            primitives.extend(tabs);
+        }
+
+        (Primitive::Group { primitives }, mouse_interaction)
+    }
+
+    fn draw_menu(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        tab_labels: &[TabLabel],
+        icon_font: Option<Font>,
+        text_font: Option<Font>,
+    ) -> Self::Output {
+        let bounds = env.layout.bounds();
+        let style = env.style_sheet.active(false);
+
+        let mut mouse_interaction = mouse::Interaction::default();
+
+        let mut primitives = vec![Primitive::Quad {
+            bounds,
+            background: style.tab_label_background,
+            border_radius: 0.0,
+            border_width: style.tab_label_border_width,
+            border_color: style.tab_label_border_color,
+        }];
+
+        for (tab, entry) in tab_labels.iter().zip(env.layout.children()) {
+            let entry_bounds = entry.bounds();
+            let content_bounds = entry
+                .children()
+                .next()
+                .expect("Graphics: Layout should have a content layout for an entry")
+                .bounds();
+
+            if entry_bounds.contains(env.cursor_position) {
+                mouse_interaction = mouse::Interaction::Pointer;
+
+                primitives.push(Primitive::Quad {
+                    bounds: entry_bounds,
+                    background: env.style_sheet.hovered(false).tab_label_background,
+                    border_radius: 0.0,
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                });
+            }
+
+            let (content, font, size, color) = match tab.badge().0 {
+                TabLabel::Icon(icon) => (
+                    icon.to_string(),
+                    icon_font.unwrap_or(B::ICON_FONT),
+                    content_bounds.height,
+                    style.icon_color,
+                ),
+                TabLabel::Text(text) | TabLabel::IconText(_, text) => (
+                    text.clone(),
+                    text_font.unwrap_or_default(),
+                    content_bounds.height,
+                    style.text_color,
+                ),
+                // Nested badges are already unwrapped by `TabLabel::badge`.
+                TabLabel::Badged(_, _) => continue,
+            };
+
+            primitives.push(Primitive::Text {
+                content,
+                font,
+                size,
+                bounds: Rectangle {
+                    y: content_bounds.center_y(),
+                    ..content_bounds
+                },
+                color,
+                horizontal_alignment: HorizontalAlignment::Left,
+                vertical_alignment: VerticalAlignment::Center,
+            });
+        }
 
         (Primitive::Group { primitives }, mouse_interaction)
     }
 }
 
 /// Draws a tab.
-#[allow(clippy::borrowed_box, clippy::too_many_lines)]
+#[allow(
+    clippy::borrowed_box,
+    clippy::too_many_arguments,
+    clippy::too_many_lines
+)]
 fn draw_tab(
     tab: &TabLabel,
     layout: Layout<'_>,
     style_sheet: &Box<dyn StyleSheet>,
     is_selected: bool,
+    is_hovered: bool,
     cursor_position: iced_native::Point,
     icon_font: Font,
     text_font: Font,
+    badge_style_sheet: &Box<dyn badge::StyleSheet>,
 ) -> (Primitive, mouse::Interaction) {
-    let is_mouse_over = layout.bounds().contains(cursor_position);
-    let style = if is_mouse_over {
+    let style = if is_hovered {
         style_sheet.hovered(is_selected)
     } else {
         style_sheet.active(is_selected)
     };
+    let badge_style = if is_hovered {
+        badge_style_sheet.hovered()
+    } else {
+        badge_style_sheet.active()
+    };
+    let (tab, count) = tab.badge();
+    let badged = |layout| draw_badge(layout, count, &badge_style, text_font);
 
     let bounds = layout.bounds();
     let mut children = layout.children();
@@ -142,10 +408,12 @@ fn draw_tab(
 
     let primitive = match tab {
         TabLabel::Icon(icon) => {
-            let icon_bounds = label_layout_children
-                .next()
-                .expect("Graphics: Layout should have an icon layout for an Icon")
-                .bounds();
+            let (icon_layout, badge) = badged(
+                label_layout_children
+                    .next()
+                    .expect("Graphics: Layout should have an icon layout for an Icon"),
+            );
+            let icon_bounds = icon_layout.bounds();
 
             Primitive::Group {
                 primitives: vec![
@@ -163,15 +431,18 @@ fn draw_tab(
                         horizontal_alignment: HorizontalAlignment::Center,
                         vertical_alignment: VerticalAlignment::Center,
                     },
+                    badge,
                     cross,
                 ],
             }
         }
         TabLabel::Text(text) => {
-            let text_bounds = label_layout_children
-                .next()
-                .expect("Graphics: Layout should have a text layout for a Text")
-                .bounds();
+            let (text_layout, badge) = badged(
+                label_layout_children
+                    .next()
+                    .expect("Graphics: Layout should have a text layout for a Text"),
+            );
+            let text_bounds = text_layout.bounds();
 
             Primitive::Group {
                 primitives: vec![
@@ -189,6 +460,7 @@ fn draw_tab(
                         horizontal_alignment: HorizontalAlignment::Center,
                         vertical_alignment: VerticalAlignment::Center,
                     },
+                    badge,
                     cross,
                 ],
             }
@@ -198,10 +470,12 @@ fn draw_tab(
                 .next()
                 .expect("Graphics: Layout should have an icons layout for an IconText")
                 .bounds();
-            let text_bounds = label_layout_children
-                .next()
-                .expect("Graphics: Layout should have a text layout for an IconText")
-                .bounds();
+            let (text_layout, badge) = badged(
+                label_layout_children
+                    .next()
+                    .expect("Graphics: Layout should have a text layout for an IconText"),
+            );
+            let text_bounds = text_layout.bounds();
 
             Primitive::Group {
                 primitives: vec![
@@ -232,18 +506,70 @@ fn draw_tab(
                         horizontal_alignment: HorizontalAlignment::Center,
                         vertical_alignment: VerticalAlignment::Center,
                     },
+                    badge,
                     cross,
                 ],
             }
         }
+        // Nested badges are already unwrapped by `TabLabel::badge`.
+        TabLabel::Badged(_, _) => Primitive::Group {
+            primitives: vec![background, cross],
+        },
     };
 
     (
         primitive,
-        if is_mouse_over {
+        if is_hovered {
             mouse::Interaction::Pointer
         } else {
             mouse::Interaction::default()
         },
     )
 }
+
+/// Splits the layout of a label with a badge into the layout of the label and
+/// the drawn badge. Without a badge, the layout is returned as it is.
+fn draw_badge<'a>(
+    layout: Layout<'a>,
+    count: usize,
+    style: &badge::Style,
+    font: Font,
+) -> (Layout<'a>, Primitive) {
+    if count == 0 {
+        return (layout, Primitive::None);
+    }
+
+    let mut children = layout.children();
+    let label_layout = children
+        .next()
+        .expect("Graphics: Layout should have a label layout for a badge");
+    let badge_layout = children
+        .next()
+        .expect("Graphics: Layout should have a badge layout");
+    let count_bounds = badge_layout
+        .children()
+        .next()
+        .expect("Graphics: Layout should have a count layout for a badge")
+        .bounds();
+
+    let badge = Primitive::Group {
+        primitives: vec![
+            crate::graphics::badge::background(badge_layout.bounds(), style),
+            Primitive::Text {
+                content: count.to_string(),
+                font,
+                size: count_bounds.height,
+                bounds: Rectangle {
+                    x: count_bounds.center_x(),
+                    y: count_bounds.center_y(),
+                    ..count_bounds
+                },
+                color: style.text_color,
+                horizontal_alignment: HorizontalAlignment::Center,
+                vertical_alignment: VerticalAlignment::Center,
+            },
+        ],
+    };
+
+    (label_layout, badge)
+}
@@ -7,7 +7,7 @@
 //! *This API requires the following crate features to be activated: tabs*
 use iced_graphics::{backend, Backend, Primitive, Renderer};
 
-pub use crate::style::tab_bar::{Style, StyleSheet};
+pub use crate::style::tab_bar::{Indicator, Style, StyleSheet};
 use crate::{core::renderer::DrawEnvironment, native::tabs};
 use iced_native::mouse;
 pub use tabs::tab_bar_position::TabBarPosition;
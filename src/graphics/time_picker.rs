@@ -2,31 +2,35 @@
 //!
 //! *This API requires the following crate features to be activated: `time_picker`*
 use crate::{
-    core::renderer::DrawEnvironment, native::overlay::time_picker::Focus,
+    core::renderer::DrawEnvironment,
+    native::overlay::time_picker::{Dial, Focus},
     style::style_state::StyleState,
 };
 use std::collections::HashMap;
 
 use crate::{
     core::clock::{
-        self, NearestRadius, HOUR_RADIUS_PERCENTAGE, HOUR_RADIUS_PERCENTAGE_NO_SECONDS,
-        MINUTE_RADIUS_PERCENTAGE, MINUTE_RADIUS_PERCENTAGE_NO_SECONDS, PERIOD_PERCENTAGE,
-        SECOND_RADIUS_PERCENTAGE,
+        self, NearestRadius, DIAL_INNER_RADIUS_PERCENTAGE, DIAL_RADIUS_PERCENTAGE,
+        HOUR_RADIUS_PERCENTAGE, HOUR_RADIUS_PERCENTAGE_NO_SECONDS, MINUTE_RADIUS_PERCENTAGE,
+        MINUTE_RADIUS_PERCENTAGE_NO_SECONDS, PERIOD_PERCENTAGE, SECOND_RADIUS_PERCENTAGE,
     },
     style::time_picker::{Style, StyleSheet},
 };
-use canvas::{Cache, LineCap, Path, Stroke, Text};
+use canvas::{Cache, Frame, LineCap, Path, Stroke, Text};
 use chrono::{NaiveTime, Timelike};
 use iced_graphics::{
-    backend, canvas, Backend, Color, HorizontalAlignment, Point, Primitive, Rectangle, Renderer,
-    Vector, VerticalAlignment,
+    backend, canvas, Backend, HorizontalAlignment, Point, Primitive, Rectangle, Renderer, Vector,
+    VerticalAlignment,
 };
 use iced_native::mouse;
 
 use crate::native::time_picker;
 pub use crate::native::time_picker::{Period, State, Time};
 
-use super::icons::{Icon, ICON_FONT};
+use super::{
+    focus_ring::focus_ring,
+    icons::{Icon, ICON_FONT},
+};
 
 /// The percentage size of the numbers.
 const NUMBER_SIZE_PERCENTAGE: f32 = 0.15;
@@ -90,6 +94,7 @@ where
             env.cursor_position,
             state.use_24h,
             state.show_seconds,
+            state.dial,
             &style,
         );
 
@@ -108,78 +113,51 @@ where
         );
 
         // ----------- Buttons ------------------------
-        let cancel_button_layout = children
-            .next()
-            .expect("Graphics: Layout should have a cancel button layout for a TimePicker");
-
-        let (cancel_button, cancel_mouse_interaction) = cancel_button.draw(
-            self,
-            env.defaults,
-            cancel_button_layout,
-            env.cursor_position,
-            &bounds,
-        );
-
-        let submit_button_layout = children
-            .next()
-            .expect("Graphics: Layout should have a submit button layout for a TimePicker");
-
-        let (submit_button, submit_mouse_interaction) = submit_button.draw(
-            self,
-            env.defaults,
-            submit_button_layout,
-            env.cursor_position,
-            &bounds,
-        );
+        // The buttons are hidden if the layout doesn't contain them.
+        let mut buttons = Vec::new();
+        let mut buttons_mouse_interaction = mouse::Interaction::default();
+        for (button, focus) in [
+            (cancel_button, Focus::Cancel),
+            (submit_button, Focus::Submit),
+        ] {
+            let Some(button_layout) = children.next() else {
+                break;
+            };
 
-        // Buttons are not focusable right now...
-        let cancel_button_focus = if env.focus == Focus::Cancel {
-            Primitive::Quad {
-                bounds: cancel_button_layout.bounds(),
-                background: Color::TRANSPARENT.into(),
-                border_radius: style[&StyleState::Focused].border_radius,
-                border_width: style[&StyleState::Focused].border_width,
-                border_color: style[&StyleState::Focused].border_color,
+            let (primitive, mouse_interaction) = button.draw(
+                self,
+                env.defaults,
+                button_layout,
+                env.cursor_position,
+                &bounds,
+            );
+            buttons.push(primitive);
+            buttons_mouse_interaction = buttons_mouse_interaction.max(mouse_interaction);
+
+            // The buttons are only focused by keyboard.
+            if env.focus == focus {
+                buttons.push(focus_ring(
+                    button_layout.bounds(),
+                    env.style_sheet.focus_ring(),
+                ));
             }
-        } else {
-            Primitive::None
-        };
+        }
 
-        let submit_button_focus = if env.focus == Focus::Submit {
-            Primitive::Quad {
-                bounds: submit_button_layout.bounds(),
-                background: Color::TRANSPARENT.into(),
-                border_radius: style[&StyleState::Focused].border_radius,
-                border_width: style[&StyleState::Focused].border_width,
-                border_color: style[&StyleState::Focused].border_color,
-            }
-        } else {
-            Primitive::None
-        };
+        let mut primitives = vec![background, clock, digital_clock];
+        primitives.extend(buttons);
 
         (
-            Primitive::Group {
-                primitives: vec![
-                    background,
-                    clock,
-                    digital_clock,
-                    cancel_button,
-                    submit_button,
-                    cancel_button_focus,
-                    submit_button_focus,
-                ],
-            },
+            Primitive::Group { primitives },
             mouse_interaction
                 .max(clock_mouse_interaction)
                 .max(digital_clock_mouse_interaction)
-                .max(cancel_mouse_interaction)
-                .max(submit_mouse_interaction),
+                .max(buttons_mouse_interaction),
         )
     }
 }
 
 /// Draws the analog clock.
-#[allow(clippy::too_many_lines)]
+#[allow(clippy::too_many_arguments, clippy::too_many_lines)]
 fn clock(
     layout: iced_native::Layout<'_>,
     time: NaiveTime,
@@ -187,6 +165,7 @@ fn clock(
     cursor_position: Point,
     use_24h: bool,
     show_seconds: bool,
+    dial: Option<Dial>,
     style: &HashMap<StyleState, Style>,
 ) -> (Primitive, mouse::Interaction) {
     let mut clock_style_state = StyleState::Active;
@@ -198,6 +177,23 @@ fn clock(
 
     let clock = clock_cache
         .draw(layout.bounds().size(), |frame| {
+            if let Some(dial) = dial {
+                let hovered = layout.bounds().contains(cursor_position);
+                let internal_cursor_position =
+                    cursor_position - Vector::new(layout.bounds().x, layout.bounds().y);
+
+                dial_face(
+                    frame,
+                    dial,
+                    time,
+                    hovered.then_some(internal_cursor_position),
+                    use_24h,
+                    style,
+                    &clock_style_state,
+                );
+                return;
+            }
+
             let center = frame.center();
             let radius = frame.width().min(frame.height()) * 0.5;
             let period = if time.hour12().0 {
@@ -453,6 +449,150 @@ fn clock(
     (clock, clock_mouse_interaction)
 }
 
+/// A ring of the dial with the labels of its points, the selected point and
+/// the size of the labels.
+type Ring = (Vec<Point>, Vec<Option<String>>, Option<usize>, f32);
+
+/// Draws the dial showing a single hand of the analog clock.
+#[allow(clippy::too_many_lines)]
+fn dial_face(
+    frame: &mut Frame,
+    dial: Dial,
+    time: NaiveTime,
+    cursor_position: Option<Point>,
+    use_24h: bool,
+    style: &HashMap<StyleState, Style>,
+    clock_style_state: &StyleState,
+) {
+    let center = frame.center();
+    let radius = frame.width().min(frame.height()) * 0.5;
+
+    let number_size = radius * NUMBER_SIZE_PERCENTAGE;
+    let dial_radius = radius * DIAL_RADIUS_PERCENTAGE;
+
+    let hand_stroke = Stroke {
+        width: style[clock_style_state].clock_hand_width,
+        color: style[clock_style_state].clock_hand_color,
+        line_cap: LineCap::Round,
+        ..Stroke::default()
+    };
+
+    let rings: Vec<Ring> = match dial {
+        Dial::Hour => {
+            let outer = clock::circle_points(dial_radius, center, 12);
+            let selected_outer =
+                (!use_24h || (1..=12).contains(&time.hour())).then(|| (time.hour() % 12) as usize);
+
+            let labels = (0..12)
+                .map(|i| Some(clock::dial_hour(i, false).to_string()))
+                .collect();
+
+            let mut rings = vec![(outer, labels, selected_outer, number_size)];
+
+            if use_24h {
+                let inner = clock::circle_points(radius * DIAL_INNER_RADIUS_PERCENTAGE, center, 12);
+                let selected_inner =
+                    (time.hour() == 0 || time.hour() > 12).then(|| (time.hour() % 12) as usize);
+                let labels = (0..12)
+                    .map(|i| Some(format!("{:02}", clock::dial_hour(i, true))))
+                    .collect();
+
+                rings.push((inner, labels, selected_inner, number_size * 0.8));
+            }
+
+            rings
+        }
+        Dial::Minute | Dial::Second => {
+            let value = if dial == Dial::Minute {
+                time.minute()
+            } else {
+                time.second()
+            };
+            let labels = (0..60)
+                .map(|i| (i % 5 == 0).then(|| format!("{i:02}")))
+                .collect();
+
+            vec![(
+                clock::circle_points(dial_radius, center, 60),
+                labels,
+                Some(value as usize),
+                number_size,
+            )]
+        }
+    };
+
+    if let Some(cursor_position) = cursor_position {
+        let nearest = rings
+            .iter()
+            .flat_map(|(points, _, _, _)| points.iter())
+            .min_by(|a, b| {
+                a.distance(cursor_position)
+                    .partial_cmp(&b.distance(cursor_position))
+                    .expect("Should be comparable")
+            });
+
+        if let Some(nearest) = nearest {
+            frame.fill(
+                &Path::circle(*nearest, number_size * 0.8),
+                style[&StyleState::Hovered].clock_number_background,
+            );
+        }
+    }
+
+    for (points, labels, selected, size) in &rings {
+        if let Some(point) = selected.and_then(|selected| points.get(selected)) {
+            frame.stroke(&Path::line(center, *point), hand_stroke);
+            frame.fill(
+                &Path::circle(*point, number_size * 0.8),
+                style[&StyleState::Selected].clock_number_background,
+            );
+        }
+
+        for (i, (point, label)) in points.iter().zip(labels).enumerate() {
+            let style_state = if *selected == Some(i) {
+                StyleState::Selected
+            } else {
+                StyleState::Active
+            };
+
+            if let Some(label) = label {
+                frame.fill_text(Text {
+                    content: label.clone(),
+                    position: *point,
+                    color: style[&style_state].clock_number_color,
+                    size: *size,
+                    font: iced_graphics::Font::default(),
+                    horizontal_alignment: HorizontalAlignment::Center,
+                    vertical_alignment: VerticalAlignment::Center,
+                });
+            } else {
+                frame.fill(
+                    &Path::circle(*point, number_size * 0.1),
+                    style[&StyleState::Active].clock_dots_color,
+                );
+            }
+        }
+    }
+
+    if !use_24h {
+        let period = if time.hour12().0 {
+            clock::Period::PM
+        } else {
+            clock::Period::AM
+        };
+
+        frame.fill_text(Text {
+            content: period.to_string(),
+            position: center,
+            color: style[clock_style_state].clock_number_color,
+            size: radius * PERIOD_SIZE_PERCENTAGE,
+            font: iced_graphics::Font::default(),
+            horizontal_alignment: HorizontalAlignment::Center,
+            vertical_alignment: VerticalAlignment::Center,
+        });
+    }
+}
+
 /// Draws the digital clock.
 #[allow(clippy::too_many_lines)]
 fn digital_clock(
@@ -645,13 +785,18 @@ fn digital_clock(
         )
     };
 
-    let period = if use_24h {
-        Primitive::None
+    let (period, period_mouse_interaction) = if use_24h {
+        (Primitive::None, mouse::Interaction::default())
     } else {
         let period = children
             .next()
             .expect("Graphics: Layout should have a period layout");
-        Primitive::Text {
+        let period_mouse_interaction = if period.bounds().contains(cursor_position) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        };
+        let period = Primitive::Text {
             content: if time.hour12().0 { "PM" } else { "AM" }.to_owned(),
             bounds: Rectangle {
                 x: period.bounds().center_x(),
@@ -663,7 +808,9 @@ fn digital_clock(
             font: iced_graphics::Font::default(),
             horizontal_alignment: HorizontalAlignment::Center,
             vertical_alignment: VerticalAlignment::Center,
-        }
+        };
+
+        (period, period_mouse_interaction)
     };
 
     (
@@ -680,6 +827,7 @@ fn digital_clock(
         mouse_interaction
             .max(hour_mouse_interaction)
             .max(minute_mouse_interaction)
-            .max(second_mouse_interaction),
+            .max(second_mouse_interaction)
+            .max(period_mouse_interaction),
     )
 }
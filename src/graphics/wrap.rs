@@ -3,6 +3,8 @@
 //! *This API requires the following crate features to be activated: `wrap`*
 use crate::native::wrap;
 
+pub use wrap::{direction, Justify};
+
 /// A widget that displays its children in multiple horizontal or vertical runs.
 ///
 /// This is an alias of an `iced_native` `Wrap` with an `iced_wgpu::Renderer`.
@@ -59,7 +59,7 @@ mod platform {
 
     #[doc(no_inline)]
     #[cfg(feature = "badge")]
-    pub use {crate::graphics::badge, badge::Badge};
+    pub use {crate::graphics::badge, badge::Badge, badge::BadgedContent};
 
     #[doc(no_inline)]
     #[cfg(feature = "card")]
@@ -73,6 +73,10 @@ mod platform {
     #[cfg(feature = "date_picker")]
     pub use {crate::graphics::date_picker, date_picker::DatePicker};
 
+    #[doc(no_inline)]
+    #[cfg(feature = "date_time_picker")]
+    pub use {crate::graphics::date_time_picker, date_time_picker::DateTimePicker};
+
     #[doc(no_inline)]
     #[cfg(feature = "floating_button")]
     pub use {crate::graphics::floating_button, floating_button::FloatingButton};
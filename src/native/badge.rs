@@ -4,10 +4,23 @@
 use std::hash::Hash;
 
 use iced_native::{
-    event, layout, Align, Clipboard, Element, Event, Layout, Length, Point, Rectangle, Widget,
+    event, layout, overlay, Align, Clipboard, Element, Event, Layout, Length, Point, Rectangle,
+    Size, Text, Widget,
 };
 
-use crate::core::renderer::DrawEnvironment;
+pub mod anchor;
+pub use anchor::Anchor;
+
+pub mod offset;
+pub use offset::Offset;
+
+pub use crate::core::badge::Padding;
+
+use super::overlay::badge::BadgeOverlay;
+use crate::core::{
+    badge::{pill_width, Count},
+    renderer::DrawEnvironment,
+};
 
 /// A badge for color highlighting small information.
 ///
@@ -22,11 +35,14 @@ use crate::core::renderer::DrawEnvironment;
 /// }
 ///
 /// let badge = Badge::<Message>::new(Text::new("Text"));
+/// let unread = Badge::<Message>::count(120).max(99).hide_zero(true);
 /// ```
 #[allow(missing_debug_implementations)]
 pub struct Badge<'a, Message, Renderer: self::Renderer> {
     /// The padding of the [`Badge`].
-    padding: u16,
+    padding: Padding,
+    /// The minimum width of the [`Badge`].
+    min_width: u16,
     /// The width of the [`Badge`].
     width: Length,
     /// The height of the [`Badge`].
@@ -39,6 +55,11 @@ pub struct Badge<'a, Message, Renderer: self::Renderer> {
     style: Renderer::Style,
     /// The content [`Element`](iced_native::Element) of the [`Badge`](Badge).
     content: Element<'a, Message, Renderer>,
+    /// The [`Count`](Count) displayed by the [`Badge`](Badge) if it was
+    /// created from a number.
+    count: Option<Count>,
+    /// Mirrors the horizontal alignment for a right-to-left layout.
+    rtl: bool,
 }
 
 impl<'a, Message, Renderer> Badge<'a, Message, Renderer>
@@ -54,19 +75,90 @@ where
         T: Into<Element<'a, Message, Renderer>>,
     {
         Badge {
-            padding: 7,
+            padding: Padding::new(7),
+            min_width: 0,
             width: Length::Shrink,
             height: Length::Shrink,
             horizontal_alignment: Align::Center,
             vertical_alignment: Align::Center,
             style: Renderer::Style::default(),
             content: content.into(),
+            count: None,
+            rtl: false,
+        }
+    }
+
+    /// Creates a new [`Badge`](Badge) displaying the given number.
+    pub fn count(value: u64) -> Self
+    where
+        Renderer: 'a + iced_native::text::Renderer,
+    {
+        let count = Count::new(value);
+        let mut badge = Self::new(Text::new(count.label()));
+        badge.count = Some(count);
+        badge
+    }
+
+    /// Sets the highest number displayed as is by a [`Badge`](Badge) created
+    /// with [`count`](Badge::count). Larger numbers are displayed as the
+    /// maximum followed by a `+`, e.g. `99+`.
+    pub fn max(self, max: u64) -> Self
+    where
+        Renderer: 'a + iced_native::text::Renderer,
+    {
+        self.update_count(|count| count.max = Some(max))
+    }
+
+    /// Hides a [`Badge`](Badge) created with [`count`](Badge::count) if its
+    /// number is zero.
+    pub fn hide_zero(self, hide_zero: bool) -> Self
+    where
+        Renderer: 'a + iced_native::text::Renderer,
+    {
+        self.update_count(|count| count.hide_zero = hide_zero)
+    }
+
+    /// Updates the [`Count`](Count) of the [`Badge`](Badge) and its displayed
+    /// text. Does nothing if the [`Badge`](Badge) has no [`Count`](Count).
+    fn update_count<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(&mut Count),
+        Renderer: 'a + iced_native::text::Renderer,
+    {
+        if let Some(count) = &mut self.count {
+            f(count);
+            self.content = Text::new(count.label()).into();
         }
+        self
+    }
+
+    /// Returns true if the [`Badge`](Badge) displays a zero
+    /// [`Count`](Count) that should be hidden.
+    #[must_use]
+    fn is_hidden(&self) -> bool {
+        self.count.as_ref().is_some_and(Count::is_hidden)
     }
 
     /// Sets the padding of the [`Badge`](Badge).
-    pub fn padding(mut self, units: u16) -> Self {
-        self.padding = units;
+    ///
+    /// The padding can be given for all sides at once, as `[vertical,
+    /// horizontal]` or as `[top, right, bottom, left]`.
+    pub fn padding<P>(mut self, padding: P) -> Self
+    where
+        P: Into<Padding>,
+    {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the minimum width of the [`Badge`](Badge).
+    ///
+    /// A shrinking [`Badge`](Badge) is never narrower than it is high, so
+    /// short content like a single digit is drawn as a circle. The minimum
+    /// width widens it further, e.g. to give all counts of a list the same
+    /// width.
+    pub fn min_width(mut self, min_width: u16) -> Self {
+        self.min_width = min_width;
         self
     }
 
@@ -99,6 +191,18 @@ where
         self.style = style.into();
         self
     }
+
+    /// Lays out the [`Badge`](Badge) from right to left.
+    ///
+    /// The horizontal alignment of the content is mirrored, so
+    /// [`Align::Start`](Align::Start) places it at the right edge. The content
+    /// itself is laid out as it is. Right-to-left layouts are also supported
+    /// by [`BadgedContent::rtl`](BadgedContent::rtl) and
+    /// [`IconText::rtl`](crate::native::IconText::rtl).
+    pub fn rtl(mut self, rtl: bool) -> Self {
+        self.rtl = rtl;
+        self
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer> for Badge<'a, Message, Renderer>
@@ -118,21 +222,49 @@ where
         renderer: &Renderer,
         limits: &iced_native::layout::Limits,
     ) -> iced_native::layout::Node {
-        let padding = f32::from(self.padding);
+        if self.is_hidden() {
+            return layout::Node::with_children(Size::ZERO, vec![layout::Node::new(Size::ZERO)]);
+        }
+
+        let padding = self.padding;
+        let padding_size = Size::new(padding.horizontal(), padding.vertical());
 
         let limits = limits
             .loose()
             .width(self.width)
             .height(self.height)
-            .pad(padding);
+            .min_width(u32::from(self.min_width))
+            .shrink(padding_size);
 
         let mut content = self.content.layout(renderer, &limits.loose());
-        let size = limits.resolve(content.size());
+        let mut size = limits.resolve(content.size());
+
+        if self.width == Length::Shrink {
+            let width = pill_width(
+                size.width,
+                &padding,
+                size.height + padding_size.height,
+                self.min_width,
+            );
+            size.width = (width - padding_size.width).min(limits.max().width);
+        }
+
+        let horizontal_alignment = match self.horizontal_alignment {
+            Align::Start if self.rtl => Align::End,
+            Align::End if self.rtl => Align::Start,
+            alignment => alignment,
+        };
 
-        content.move_to(Point::new(padding, padding));
-        content.align(self.horizontal_alignment, self.vertical_alignment, size);
+        content.move_to(Point::new(f32::from(padding.left), f32::from(padding.top)));
+        content.align(horizontal_alignment, self.vertical_alignment, size);
 
-        layout::Node::with_children(size.pad(padding), vec![content])
+        layout::Node::with_children(
+            Size::new(
+                size.width + padding_size.width,
+                size.height + padding_size.height,
+            ),
+            vec![content],
+        )
     }
 
     fn on_event(
@@ -184,13 +316,209 @@ where
         std::any::TypeId::of::<Marker>().hash(state);
 
         self.padding.hash(state);
+        self.min_width.hash(state);
         self.width.hash(state);
         self.height.hash(state);
+        self.rtl.hash(state);
 
         self.content.hash_layout(state);
     }
 }
 
+/// A [`Badge`](Badge) overlaid on a corner of some content.
+///
+/// The badge is drawn in an overlay, so it can extend beyond the bounds of
+/// the content without affecting its layout.
+///
+/// # Example
+/// ```
+/// # use iced_aw::native::badge::Anchor;
+/// # use iced_native::{Text, renderer::Null};
+/// #
+/// # pub type Badge<'a, Message> = iced_aw::native::Badge<'a, Message, Null>;
+/// # pub type BadgedContent<'a, Message> = iced_aw::native::BadgedContent<'a, Message, Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+/// }
+///
+/// let content = BadgedContent::<Message>::new(
+///     Text::new("Inbox"),
+///     Badge::new(Text::new("3")),
+/// )
+/// .anchor(Anchor::TopRight);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct BadgedContent<'a, Message, Renderer: self::Renderer> {
+    /// The anchor of the [`Badge`](Badge).
+    anchor: Anchor,
+    /// The offset of the [`Badge`](Badge) from its anchor.
+    offset: Offset,
+    /// Whether the [`Badge`](Badge) is hidden.
+    hidden: bool,
+    /// Mirrors the anchor for a right-to-left layout.
+    rtl: bool,
+    /// The underlying [`Element`](iced_native::Element) carrying the [`Badge`](Badge).
+    underlay: Element<'a, Message, Renderer>,
+    /// The [`Badge`](Badge) drawn over the underlay.
+    badge: Badge<'a, Message, Renderer>,
+}
+
+impl<'a, Message, Renderer> BadgedContent<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`BadgedContent`](BadgedContent) showing the given
+    /// [`Badge`](Badge) over the underlay.
+    ///
+    /// It expects:
+    ///     * the underlay [`Element`](iced_native::Element) carrying the [`Badge`](Badge).
+    ///     * the [`Badge`](Badge) to display on a corner of the underlay.
+    pub fn new<U>(underlay: U, badge: Badge<'a, Message, Renderer>) -> Self
+    where
+        U: Into<Element<'a, Message, Renderer>>,
+    {
+        BadgedContent {
+            anchor: Anchor::TopRight,
+            offset: Offset::default(),
+            hidden: false,
+            rtl: false,
+            underlay: underlay.into(),
+            badge,
+        }
+    }
+
+    /// Sets the [`Anchor`](Anchor) of the [`Badge`](Badge).
+    pub fn anchor(mut self, anchor: Anchor) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    /// Sets the [`Offset`](Offset) of the [`Badge`](Badge) from its [`Anchor`](Anchor).
+    pub fn offset<O>(mut self, offset: O) -> Self
+    where
+        O: Into<Offset>,
+    {
+        self.offset = offset.into();
+        self
+    }
+
+    /// Hide or unhide the [`Badge`](Badge) of the [`BadgedContent`](BadgedContent).
+    pub fn hide(mut self, hide: bool) -> Self {
+        self.hidden = hide;
+        self
+    }
+
+    /// Lays out the [`BadgedContent`](BadgedContent) from right to left.
+    ///
+    /// The [`Anchor`](Anchor) is mirrored, so the default
+    /// [`Anchor::TopRight`](Anchor::TopRight) places the [`Badge`](Badge) on
+    /// the top left corner. The [`Badge`](Badge) has its own
+    /// [`rtl`](Badge::rtl) flag for the alignment of its content.
+    pub fn rtl(mut self, rtl: bool) -> Self {
+        self.rtl = rtl;
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for BadgedContent<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.underlay.width()
+    }
+
+    fn height(&self) -> Length {
+        self.underlay.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &iced_native::layout::Limits,
+    ) -> iced_native::layout::Node {
+        self.underlay.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        self.underlay.on_event(
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            messages,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        self.underlay
+            .draw(renderer, defaults, layout, cursor_position, viewport)
+    }
+
+    fn hash_layout(&self, state: &mut iced_native::Hasher) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.anchor.hash(state);
+        (self.offset.x as u32).hash(state);
+        (self.offset.y as u32).hash(state);
+        self.hidden.hash(state);
+        self.rtl.hash(state);
+        self.underlay.hash_layout(state);
+    }
+
+    fn overlay(&mut self, layout: Layout<'_>) -> Option<overlay::Element<'_, Message, Renderer>> {
+        if self.hidden || self.badge.is_hidden() {
+            return None;
+        }
+
+        let anchor = if self.rtl {
+            self.anchor.mirrored()
+        } else {
+            self.anchor
+        };
+
+        let bounds = layout.bounds();
+        let position = match anchor {
+            Anchor::TopRight => Point::new(bounds.x + bounds.width, bounds.y),
+            Anchor::TopLeft => Point::new(bounds.x, bounds.y),
+            Anchor::BottomRight => Point::new(bounds.x + bounds.width, bounds.y + bounds.height),
+            Anchor::BottomLeft => Point::new(bounds.x, bounds.y + bounds.height),
+        };
+
+        Some(BadgeOverlay::new(&mut self.badge, anchor, self.offset).overlay(position))
+    }
+}
+
+impl<'a, Message, Renderer> From<BadgedContent<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(badged_content: BadgedContent<'a, Message, Renderer>) -> Self {
+        Element::new(badged_content)
+    }
+}
+
 /// The renderer of a [`Badge`](Badge).
 ///
 /// Your renderer will need to implement this trait before being
@@ -228,3 +556,78 @@ where
         Element::new(badge)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use iced_native::{
+        layout::Limits, renderer::Null, Align, Layout, Length, Row, Size, Text, Widget,
+    };
+
+    use super::{Anchor, Badge};
+
+    /// Gets the horizontal position of the content of a wide badge.
+    fn content_x(badge: Badge<'_, (), Null>) -> f32 {
+        let node = Widget::layout(
+            &badge.width(Length::Units(100)).padding(0),
+            &Null::new(),
+            &Limits::new(Size::ZERO, Size::new(200.0, 100.0)),
+        );
+
+        let x = Layout::new(&node)
+            .children()
+            .next()
+            .map_or(0.0, |content| content.bounds().x);
+        x
+    }
+
+    fn badge() -> Badge<'static, (), Null> {
+        Badge::new(
+            Row::new()
+                .width(Length::Units(20))
+                .height(Length::Units(20)),
+        )
+    }
+
+    #[test]
+    fn rtl_mirrors_alignment() {
+        assert!(content_x(badge().align_x(Align::Start)) < f32::EPSILON);
+        assert!((content_x(badge().align_x(Align::Start).rtl(true)) - 80.0).abs() < f32::EPSILON);
+        assert!(content_x(badge().align_x(Align::End).rtl(true)) < f32::EPSILON);
+    }
+
+    /// Gets the size of a shrinking badge.
+    fn size(badge: &Badge<'_, (), Null>) -> Size {
+        Widget::layout(
+            badge,
+            &Null::new(),
+            &Limits::new(Size::ZERO, Size::new(200.0, 100.0)),
+        )
+        .size()
+    }
+
+    #[test]
+    fn single_character_is_a_circle() {
+        let digit = size(&Badge::new(Text::new("1")));
+        assert!(digit.width >= digit.height);
+
+        let count = size(&Badge::count(7).padding([2, 4]));
+        assert!(count.width >= count.height);
+    }
+
+    #[test]
+    fn min_width_widens_the_pill() {
+        let wide = size(&badge().padding(0).min_width(50));
+        assert!((wide.width - 50.0).abs() < f32::EPSILON);
+        assert!((wide.height - 20.0).abs() < f32::EPSILON);
+
+        let padded = size(&badge().padding([2, 10]).min_width(30));
+        assert!((padded.width - 40.0).abs() < f32::EPSILON);
+        assert!((padded.height - 24.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn mirrored_anchor() {
+        assert_eq!(Anchor::TopRight.mirrored(), Anchor::TopLeft);
+        assert_eq!(Anchor::BottomLeft.mirrored(), Anchor::BottomRight);
+    }
+}
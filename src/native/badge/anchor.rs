@@ -0,0 +1,42 @@
+//! Use a badge for color highlighting important information.
+//!
+//! *This API requires the following crate features to be activated: `badge`*
+
+/// Positional [`Anchor`](Anchor) of the [`Badge`](super::Badge) of a
+/// [`BadgedContent`](super::BadgedContent).
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum Anchor {
+    /// Top right [`Anchor`](Anchor) for positioning the
+    /// [`Badge`](super::Badge) on the top right corner of the underlying
+    /// element.
+    TopRight,
+
+    /// Top left [`Anchor`](Anchor) for positioning the
+    /// [`Badge`](super::Badge) on the top left corner of the underlying
+    /// element.
+    TopLeft,
+
+    /// Bottom right [`Anchor`](Anchor) for positioning the
+    /// [`Badge`](super::Badge) on the bottom right corner of the underlying
+    /// element.
+    BottomRight,
+
+    /// Bottom left [`Anchor`](Anchor) for positioning the
+    /// [`Badge`](super::Badge) on the bottom left corner of the underlying
+    /// element.
+    BottomLeft,
+}
+
+impl Anchor {
+    /// Gets the [`Anchor`](Anchor) on the horizontally opposite corner, as
+    /// used by a right-to-left layout.
+    #[must_use]
+    pub const fn mirrored(self) -> Self {
+        match self {
+            Self::TopRight => Self::TopLeft,
+            Self::TopLeft => Self::TopRight,
+            Self::BottomRight => Self::BottomLeft,
+            Self::BottomLeft => Self::BottomRight,
+        }
+    }
+}
@@ -0,0 +1,45 @@
+//! Use a badge for color highlighting important information.
+//!
+//! *This API requires the following crate features to be activated: `badge`*
+
+use iced_native::Point;
+
+/// The [`Offset`](Offset) of the [`Badge`](super::Badge) of a
+/// [`BadgedContent`](super::BadgedContent).
+///
+/// Positive values move the badge from its [`Anchor`](super::Anchor) towards
+/// the center of the underlying element.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Offset {
+    /// Offset on the x-axis from the [`Anchor`](super::Anchor)
+    pub x: f32,
+    /// Offset on the y-axis from the [`Anchor`](super::Anchor)
+    pub y: f32,
+}
+
+impl From<f32> for Offset {
+    fn from(float: f32) -> Self {
+        Self { x: float, y: float }
+    }
+}
+
+impl From<[f32; 2]> for Offset {
+    fn from(array: [f32; 2]) -> Self {
+        Self {
+            x: array[0],
+            y: array[1],
+        }
+    }
+}
+
+impl From<Offset> for Point {
+    fn from(offset: Offset) -> Self {
+        Self::new(offset.x, offset.y)
+    }
+}
+
+impl From<&Offset> for Point {
+    fn from(offset: &Offset) -> Self {
+        Self::new(offset.x, offset.y)
+    }
+}
@@ -3,11 +3,19 @@
 //! *This API requires the following crate features to be activated: card*
 use std::hash::Hash;
 
-use iced_native::{event, touch, Clipboard, Element, Event, Layout, Length, Point, Size, Widget};
+use iced_native::{
+    event, row, touch, Clipboard, Element, Event, Layout, Length, Point, Row, Size, Widget,
+};
 use iced_native::{mouse, Align};
 
 use crate::core::renderer::DrawEnvironment;
 
+pub mod accordion;
+pub use accordion::Accordion;
+
+/// The horizontal spacing between the buttons of the foot of a [`Card`](Card).
+const FOOT_BUTTON_SPACING: u16 = 10;
+
 /// A card consisting of a head, body and optional foot.
 ///
 /// # Example
@@ -44,10 +52,19 @@ pub struct Card<'a, Message, Renderer: self::Renderer> {
     padding_body: f32,
     /// The padding of the foot of the [`Card`](Card).
     padding_foot: f32,
+    /// The optional horizontal alignment of the foot of the [`Card`](Card).
+    foot_alignment: Option<Align>,
     /// The optional size of the close icon of the [`Card`](Card).
     close_size: Option<f32>,
     /// The optional message that is send if the close icon of the [`Card`](Card) is pressed.
     on_close: Option<Message>,
+    /// Toggles the drawing of the collapse icon of the [`Card`](Card).
+    collapsible: bool,
+    /// Toggles hiding the body and foot of the [`Card`](Card).
+    collapsed: bool,
+    /// The optional function that produces the message if the collapse icon of
+    /// the [`Card`](Card) is pressed.
+    on_toggle: Option<Box<dyn Fn(bool) -> Message>>,
     /// The head [`Element`](iced_native::Element) of the [`Card`](Card).
     head: Element<'a, Message, Renderer>,
     /// The body [`Element`](iced_native::Element) of the [`Card`](Card).
@@ -69,6 +86,11 @@ where
     ///         the [`Card`](Card).
     ///     * the body [`Element`](iced_native::Element) to display at the middle
     ///         of the [`Card`](Card).
+    ///
+    /// The head can be any [`Element`](iced_native::Element), like a plain
+    /// [`Text`](iced_native::Text) title or a [`Row`](iced_native::Row)
+    /// holding an icon, a title and actions. The close and collapse icons are
+    /// placed after it.
     pub fn new<H, B>(head: H, body: B) -> Self
     where
         H: Into<Element<'a, Message, Renderer>>,
@@ -82,8 +104,12 @@ where
             padding_head: <Renderer as self::Renderer>::DEFAULT_PADDING,
             padding_body: <Renderer as self::Renderer>::DEFAULT_PADDING,
             padding_foot: <Renderer as self::Renderer>::DEFAULT_PADDING,
+            foot_alignment: None,
             close_size: None,
             on_close: None,
+            collapsible: false,
+            collapsed: false,
+            on_toggle: None,
             head: head.into(),
             body: body.into(),
             foot: None,
@@ -101,6 +127,36 @@ where
         self
     }
 
+    /// Sets the row of action buttons, like "Cancel" and "OK", as the foot of
+    /// the [`Card`](Card).
+    ///
+    /// The buttons are placed next to each other with a consistent spacing.
+    /// Unless another [`foot_alignment`](Card::foot_alignment) is set, they
+    /// are aligned to the right.
+    pub fn foot_buttons<I>(mut self, buttons: I) -> Self
+    where
+        Message: 'a,
+        Renderer: 'a + row::Renderer,
+        I: IntoIterator<Item = Element<'a, Message, Renderer>>,
+    {
+        let row = buttons
+            .into_iter()
+            .fold(Row::new().spacing(FOOT_BUTTON_SPACING), Row::push);
+
+        self.foot = Some(row.into());
+        let _ = self.foot_alignment.get_or_insert(Align::End);
+        self
+    }
+
+    /// Sets the horizontal alignment of the foot of the [`Card`](Card).
+    ///
+    /// The foot is aligned to the left by default, or to the right if it was
+    /// set by [`foot_buttons`](Card::foot_buttons).
+    pub fn foot_alignment(mut self, alignment: Align) -> Self {
+        self.foot_alignment = Some(alignment);
+        self
+    }
+
     /// Sets the width of the [`Card`](Card).
     pub fn width(mut self, width: Length) -> Self {
         self.width = width;
@@ -114,6 +170,12 @@ where
     }
 
     /// Sets the maximum width of the [`Card`](Card).
+    ///
+    /// The [`Card`](Card) still shrinks to the available space if it is
+    /// narrower. A capped [`Card`](Card) keeps its position in the parent, so
+    /// center it with a parent aligning its content to the center, like a
+    /// [`Container`](iced_native::Container) with `center_x` or a
+    /// [`Column`](iced_native::Column) with `align_items(Align::Center)`.
     pub fn max_width(mut self, width: u32) -> Self {
         self.max_width = width;
         self
@@ -169,6 +231,32 @@ where
         self
     }
 
+    /// Enables the drawing of a collapse icon on the head of the
+    /// [`Card`](Card).
+    pub fn collapsible(mut self, collapsible: bool) -> Self {
+        self.collapsible = collapsible;
+        self
+    }
+
+    /// Sets whether the [`Card`](Card) is collapsed, showing only its head.
+    pub fn collapsed(mut self, collapsed: bool) -> Self {
+        self.collapsed = collapsed;
+        self
+    }
+
+    /// Sets the function that will be called when the collapse icon of the
+    /// [`Card`](Card) is pressed.
+    ///
+    /// It takes the requested collapsed state, which the [`Card`](Card)
+    /// expects to receive back through [`collapsed`](Card::collapsed).
+    pub fn on_toggle<F>(mut self, on_toggle: F) -> Self
+    where
+        F: 'static + Fn(bool) -> Message,
+    {
+        self.on_toggle = Some(Box::new(on_toggle));
+        self
+    }
+
     /// Sets the style of the [`Card`](Card).
     pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
         self.style = style.into();
@@ -206,9 +294,14 @@ where
             self.padding_head,
             self.width,
             self.on_close.is_some(),
+            self.collapsible,
             self.close_size,
         );
 
+        if self.collapsed {
+            return iced_native::layout::Node::with_children(head_node.size(), vec![head_node]);
+        }
+
         let mut body_node = body_node(renderer, &limits, &self.body, self.padding_body, self.width);
 
         body_node.move_to(Point::new(
@@ -216,12 +309,19 @@ where
             body_node.bounds().y + head_node.bounds().height,
         ));
 
-        let mut foot_node = self
-            .foot
-            .as_ref()
-            .map_or_else(iced_native::layout::Node::default, |foot| {
-                foot_node(renderer, &limits, foot, self.padding_foot, self.width)
-            });
+        let mut foot_node =
+            self.foot
+                .as_ref()
+                .map_or_else(iced_native::layout::Node::default, |foot| {
+                    foot_node(
+                        renderer,
+                        &limits,
+                        foot,
+                        self.padding_foot,
+                        self.width,
+                        self.foot_alignment.unwrap_or(Align::Start),
+                    )
+                });
 
         foot_node.move_to(Point::new(
             foot_node.bounds().x,
@@ -252,16 +352,32 @@ where
             .next()
             .expect("Native: Layout should have a head layout");
         let mut head_children = head_layout.children();
-        let head_status = self.head.on_event(
-            event.clone(),
-            head_children
+        let head_content_layout = head_children
+            .next()
+            .expect("Native: Layout should have a head content layout");
+
+        let toggle_status = if self.collapsible {
+            let toggle_layout = head_children
                 .next()
-                .expect("Native: Layout should have a head content layout"),
-            cursor_position,
-            renderer,
-            clipboard,
-            messages,
-        );
+                .expect("Native: Layout should have a toggle layout");
+
+            match event {
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerPressed { .. })
+                    if toggle_layout.bounds().contains(cursor_position) =>
+                {
+                    self.on_toggle
+                        .as_ref()
+                        .map_or(event::Status::Ignored, |on_toggle| {
+                            messages.push((on_toggle)(!self.collapsed));
+                            event::Status::Captured
+                        })
+                }
+                _ => event::Status::Ignored,
+            }
+        } else {
+            event::Status::Ignored
+        };
 
         let close_status = head_children
             .next()
@@ -282,6 +398,25 @@ where
                 }
             });
 
+        // A press on one of the icons must not reach the head content.
+        let icon_status = toggle_status.merge(close_status);
+        let head_status = if icon_status == event::Status::Captured {
+            event::Status::Ignored
+        } else {
+            self.head.on_event(
+                event.clone(),
+                head_content_layout,
+                cursor_position,
+                renderer,
+                clipboard,
+                messages,
+            )
+        };
+
+        if self.collapsed {
+            return head_status.merge(icon_status);
+        }
+
         let body_layout = children
             .next()
             .expect("Native: Layout should have a body layout");
@@ -315,7 +450,7 @@ where
         });
 
         head_status
-            .merge(close_status)
+            .merge(icon_status)
             .merge(body_status)
             .merge(foot_status)
     }
@@ -340,6 +475,8 @@ where
             &self.head,
             &self.body,
             &self.foot,
+            self.collapsible,
+            self.collapsed,
         )
     }
 
@@ -352,6 +489,9 @@ where
         self.height.hash(state);
         self.max_width.hash(state);
         self.max_height.hash(state);
+        self.collapsible.hash(state);
+        self.collapsed.hash(state);
+        self.foot_alignment.hash(state);
         self.head.hash_layout(state);
         self.body.hash_layout(state);
         if let Some(foot) = self.foot.as_ref() {
@@ -361,6 +501,7 @@ where
 }
 
 /// Calculates the layout of the head.
+#[allow(clippy::too_many_arguments)]
 fn head_node<'a, Message, Renderer>(
     renderer: &Renderer,
     limits: &iced_native::layout::Limits,
@@ -368,6 +509,7 @@ fn head_node<'a, Message, Renderer>(
     padding: f32,
     width: Length,
     on_close: bool,
+    collapsible: bool,
     close_size: Option<f32>,
 ) -> iced_native::layout::Node
 where
@@ -380,14 +522,9 @@ where
         .pad(padding);
 
     let close_size = close_size.unwrap_or_else(|| renderer.default_size());
-    let mut close = if on_close {
-        limits = limits.shrink(Size::new(close_size, 0.0));
-        Some(iced_native::layout::Node::new(Size::new(
-            close_size, close_size,
-        )))
-    } else {
-        None
-    };
+    let icons_width =
+        close_size * (f32::from(u8::from(on_close)) + f32::from(u8::from(collapsible)));
+    limits = limits.shrink(Size::new(icons_width, 0.0));
 
     let mut head = head.layout(renderer, &limits);
     let mut size = limits.resolve(head.size());
@@ -395,20 +532,29 @@ where
     head.move_to(Point::new(padding, padding));
     head.align(Align::Start, Align::Center, head.size());
 
-    if let Some(node) = close.as_mut() {
-        size = Size::new(size.width + close_size, size.height);
-
-        node.move_to(Point::new(size.width - padding, padding));
-        node.align(Align::End, Align::Center, node.size())
+    size = Size::new(size.width + icons_width, size.height);
+    let mut children = vec![head];
+    let icon_space = Size::new(close_size, size.height);
+    let mut x = padding + size.width - close_size;
+
+    let close = on_close.then(|| {
+        let mut node = iced_native::layout::Node::new(Size::new(close_size, close_size));
+        node.move_to(Point::new(x, padding));
+        node.align(Align::Start, Align::Center, icon_space);
+        x -= close_size;
+        node
+    });
+
+    if collapsible {
+        let mut node = iced_native::layout::Node::new(Size::new(close_size, close_size));
+        node.move_to(Point::new(x, padding));
+        node.align(Align::Start, Align::Center, icon_space);
+        children.push(node);
     }
 
-    iced_native::layout::Node::with_children(
-        size.pad(padding),
-        match close {
-            Some(node) => vec![head, node],
-            None => vec![head],
-        },
-    )
+    children.extend(close);
+
+    iced_native::layout::Node::with_children(size.pad(padding), children)
 }
 
 /// Calculates the layout of the body.
@@ -445,6 +591,7 @@ fn foot_node<'a, Message, Renderer>(
     foot: &Element<'a, Message, Renderer>,
     padding: f32,
     width: Length,
+    alignment: Align,
 ) -> iced_native::layout::Node
 where
     Renderer: self::Renderer,
@@ -460,7 +607,7 @@ where
     let size = limits.resolve(foot.size());
 
     foot.move_to(Point::new(padding, padding));
-    foot.align(Align::Start, Align::Center, size);
+    foot.align(alignment, Align::Center, size);
 
     iced_native::layout::Node::with_children(size.pad(padding), vec![foot])
 }
@@ -486,6 +633,8 @@ pub trait Renderer: iced_native::Renderer {
         head: &Element<'_, Message, Self>,
         body: &Element<'_, Message, Self>,
         foot: &Option<Element<'_, Message, Self>>,
+        collapsible: bool,
+        collapsed: bool,
     ) -> Self::Output;
 }
 
@@ -505,6 +654,8 @@ impl Renderer for iced_native::renderer::Null {
         _head: &Element<'_, Message, Self>,
         _body: &Element<'_, Message, Self>,
         _foot: &Option<Element<'_, Message, Self>>,
+        _collapsible: bool,
+        _collapsed: bool,
     ) -> Self::Output {
     }
 }
@@ -518,3 +669,121 @@ where
         Element::new(card)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use iced_native::{
+        layout::Limits, renderer::Null, Align, Column, Container, Element, Layout, Length, Row,
+        Size, Text,
+    };
+
+    use super::Card;
+
+    fn card<'a>() -> Card<'a, (), Null> {
+        Card::new(Text::new("Head"), Text::new("Body")).max_width(300)
+    }
+
+    /// Gets the position and width of the card inside the given element.
+    fn card_width(element: &Element<'_, (), Null>, available: f32) -> (f32, f32) {
+        let node = element.layout(
+            &Null::new(),
+            &Limits::new(Size::ZERO, Size::new(available, 500.0)),
+        );
+        let bounds = Layout::new(&node)
+            .children()
+            .next()
+            .expect("The card should be laid out")
+            .bounds();
+
+        (bounds.x, bounds.width)
+    }
+
+    #[test]
+    fn max_width_caps_card() {
+        let column: Element<'_, (), Null> = Column::new().push(card()).into();
+        assert_eq!(card_width(&column, 1000.0), (0.0, 300.0));
+        assert_eq!(card_width(&column, 200.0), (0.0, 200.0));
+    }
+
+    #[test]
+    fn capped_card_can_be_centered() {
+        let column: Element<'_, (), Null> = Column::new()
+            .width(Length::Fill)
+            .align_items(Align::Center)
+            .push(card())
+            .into();
+        assert_eq!(card_width(&column, 1000.0), (350.0, 300.0));
+
+        let container: Element<'_, (), Null> =
+            Container::new(card()).width(Length::Fill).center_x().into();
+        assert_eq!(card_width(&container, 1000.0), (350.0, 300.0));
+    }
+
+    #[test]
+    fn custom_head_keeps_close_icon() {
+        let head = Row::new()
+            .push(Text::new("Icon").width(Length::Units(20)))
+            .push(Text::new("Title").width(Length::Fill));
+        let card: Element<'_, (), Null> = Card::new(head, Text::new("Body"))
+            .close_size(20.0)
+            .on_close(())
+            .into();
+
+        let node = card.layout(
+            &Null::new(),
+            &Limits::new(Size::ZERO, Size::new(200.0, 500.0)),
+        );
+        let head_layout = Layout::new(&node)
+            .children()
+            .next()
+            .expect("The card should have a head");
+        let mut head_children = head_layout.children();
+        let head_content = head_children.next().expect("The head should have content");
+        let close = head_children
+            .next()
+            .expect("The head should have a close icon");
+
+        assert_eq!(head_content.children().count(), 2);
+        assert!((head_content.bounds().width - 180.0).abs() < f32::EPSILON);
+        assert!((close.bounds().x - 180.0).abs() < f32::EPSILON);
+    }
+
+    /// Gets the horizontal position and width of the content of the foot.
+    fn foot_content(card: Card<'_, (), Null>) -> (f32, f32) {
+        let button = || {
+            Row::new()
+                .width(Length::Units(40))
+                .height(Length::Units(20))
+        };
+        let card: Element<'_, (), Null> = card
+            .foot_buttons(vec![button().into(), button().into()])
+            .on_close(())
+            .into();
+
+        let node = card.layout(
+            &Null::new(),
+            &Limits::new(Size::ZERO, Size::new(200.0, 500.0)),
+        );
+        let bounds = Layout::new(&node)
+            .children()
+            .nth(2)
+            .and_then(|foot| foot.children().next())
+            .expect("The card should have a foot")
+            .bounds();
+
+        (bounds.x, bounds.width)
+    }
+
+    #[test]
+    fn foot_buttons_are_aligned() {
+        assert_eq!(foot_content(card()), (110.0, 90.0));
+        assert_eq!(
+            foot_content(card().foot_alignment(Align::Center)),
+            (55.0, 90.0)
+        );
+        assert_eq!(
+            foot_content(card().foot_alignment(Align::Start)),
+            (0.0, 90.0)
+        );
+    }
+}
@@ -0,0 +1,221 @@
+//! Use an accordion to coordinate the collapsed state of a group of cards.
+//!
+//! *This API requires the following crate features to be activated: `card`*
+use std::rc::Rc;
+
+use iced_native::{column, Column, Element, Length};
+
+use super::Card;
+use crate::core::card::toggle;
+
+/// A group of collapsible [`Card`](Card)s of which only one is open at a
+/// time, or several in [`multiple`](Accordion::multiple) mode.
+///
+/// The [`Accordion`](Accordion) only sets the collapsed state of its cards,
+/// the cards themselves are drawn as usual. The open cards are owned by the
+/// application, which receives the new open cards on every toggle.
+///
+/// # Example
+/// ```
+/// # use iced_native::{renderer::Null, Text};
+/// #
+/// # pub type Accordion<'a, Message> = iced_aw::native::card::Accordion<'a, Message, Null>;
+/// # pub type Card<'a, Message> = iced_aw::native::Card<'a, Message, Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Toggled(Vec<usize>),
+/// }
+///
+/// let open = vec![0];
+///
+/// let accordion = Accordion::new(&open, Message::Toggled)
+///     .push(Card::new(Text::new("General"), Text::new("...")))
+///     .push(Card::new(Text::new("Advanced"), Text::new("...")));
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Accordion<'a, Message, Renderer: super::Renderer> {
+    /// The cards of the [`Accordion`](Accordion).
+    cards: Vec<Card<'a, Message, Renderer>>,
+    /// The sorted indices of the open cards.
+    open: Vec<usize>,
+    /// Allows several cards to be open at the same time.
+    multiple: bool,
+    /// The function that produces a message with the new open cards.
+    on_change: Rc<dyn Fn(Vec<usize>) -> Message>,
+    /// The vertical spacing between the cards.
+    spacing: u16,
+    /// The width of the [`Accordion`](Accordion).
+    width: Length,
+}
+
+impl<'a, Message, Renderer> Accordion<'a, Message, Renderer>
+where
+    Renderer: super::Renderer,
+{
+    /// Creates a new empty [`Accordion`](Accordion).
+    ///
+    /// It expects:
+    ///     * the indices of the open cards.
+    ///     * a function that will be called when a card is opened or closed,
+    ///         which takes the new indices of the open cards in ascending
+    ///         order.
+    pub fn new<F>(open: &[usize], on_change: F) -> Self
+    where
+        F: 'static + Fn(Vec<usize>) -> Message,
+    {
+        let mut open = open.to_vec();
+        open.sort_unstable();
+        open.dedup();
+
+        Self {
+            cards: Vec::new(),
+            open,
+            multiple: false,
+            on_change: Rc::new(on_change),
+            spacing: 0,
+            width: Length::Fill,
+        }
+    }
+
+    /// Sets whether several cards of the [`Accordion`](Accordion) can be
+    /// open at the same time.
+    ///
+    /// By default opening a card closes all other cards.
+    #[must_use]
+    pub fn multiple(mut self, multiple: bool) -> Self {
+        self.multiple = multiple;
+        self
+    }
+
+    /// Sets the vertical spacing between the cards of the
+    /// [`Accordion`](Accordion).
+    #[must_use]
+    pub fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the width of the [`Accordion`](Accordion).
+    #[must_use]
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Adds a [`Card`](Card) to the [`Accordion`](Accordion).
+    ///
+    /// Its collapsed state and toggle function are replaced by the
+    /// [`Accordion`](Accordion).
+    #[must_use]
+    pub fn push(mut self, card: Card<'a, Message, Renderer>) -> Self {
+        self.cards.push(card);
+        self
+    }
+}
+
+impl<'a, Message, Renderer> From<Accordion<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'static + Clone,
+    Renderer: 'a + super::Renderer + column::Renderer,
+{
+    fn from(accordion: Accordion<'a, Message, Renderer>) -> Self {
+        let Accordion {
+            cards,
+            open,
+            multiple,
+            on_change,
+            spacing,
+            width,
+        } = accordion;
+
+        cards
+            .into_iter()
+            .enumerate()
+            .fold(
+                Column::new().spacing(spacing).width(width),
+                |column, (index, card)| {
+                    let collapsed = open.binary_search(&index).is_err();
+                    let open = open.clone();
+                    let on_change = Rc::clone(&on_change);
+
+                    column.push(card.collapsible(true).collapsed(collapsed).on_toggle(
+                        move |collapsed| on_change(toggle(&open, index, collapsed, multiple)),
+                    ))
+                },
+            )
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iced_native::{
+        clipboard, layout::Limits, mouse, renderer::Null, Element, Event, Layout, Size, Text,
+    };
+
+    use super::Accordion;
+    use crate::native::Card;
+
+    /// Presses the collapse icon of the card at the given index.
+    fn toggle(accordion: Accordion<'_, Vec<usize>, Null>, index: usize) -> Vec<Vec<usize>> {
+        let mut element: Element<'_, Vec<usize>, Null> = accordion.into();
+        let renderer = Null::new();
+        let node = element.layout(&renderer, &Limits::new(Size::ZERO, Size::new(200.0, 500.0)));
+
+        let position = Layout::new(&node)
+            .children()
+            .nth(index)
+            .and_then(|card| card.children().next())
+            .and_then(|head| head.children().nth(1))
+            .expect("The card should have a collapse icon")
+            .bounds()
+            .center();
+
+        let mut messages = Vec::new();
+        let _ = element.on_event(
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Layout::new(&node),
+            position,
+            &renderer,
+            &mut clipboard::Null,
+            &mut messages,
+        );
+
+        messages
+    }
+
+    fn accordion<'a>(open: &[usize]) -> Accordion<'a, Vec<usize>, Null> {
+        Accordion::new(open, |open| open)
+            .push(Card::new(Text::new("First"), Text::new("Body")))
+            .push(Card::new(Text::new("Second"), Text::new("Body")))
+    }
+
+    #[test]
+    fn single_mode_closes_others() {
+        assert_eq!(toggle(accordion(&[0]), 1), vec![vec![1]]);
+        assert_eq!(toggle(accordion(&[0]), 0), vec![Vec::<usize>::new()]);
+    }
+
+    #[test]
+    fn multiple_mode_keeps_others() {
+        assert_eq!(toggle(accordion(&[0]).multiple(true), 1), vec![vec![0, 1]]);
+        assert_eq!(toggle(accordion(&[0, 1]).multiple(true), 0), vec![vec![1]]);
+    }
+
+    #[test]
+    fn closed_cards_are_collapsed() {
+        let element: Element<'_, Vec<usize>, Null> = accordion(&[1]).into();
+        let node = element.layout(
+            &Null::new(),
+            &Limits::new(Size::ZERO, Size::new(200.0, 500.0)),
+        );
+
+        let parts: Vec<usize> = Layout::new(&node)
+            .children()
+            .map(|card| card.children().count())
+            .collect();
+
+        assert_eq!(parts, vec![1, 3]);
+    }
+}
@@ -14,6 +14,8 @@ use super::{
     icon_text,
     overlay::color_picker::{self, ColorPickerOverlay, Focus},
 };
+pub use crate::core::color::ComponentMode;
+use crate::core::{color::normalize, overlay::ChangeThrottle};
 
 /// An input element for picking colors.
 ///
@@ -49,6 +51,13 @@ where
     Renderer: color_picker::Renderer,
 {
     /// The state of the [`ColorPicker`](ColorPicker).
+    ///
+    /// The visibility of the [`ColorPickerOverlay`](ColorPickerOverlay) is fully controlled by
+    /// the application through [`show`](State::show). The picker never opens or
+    /// closes itself: pressing the underlay, cancel or submit only sends the
+    /// messages of the application, which decides whether to change the
+    /// visibility. This way the overlay can also be opened from anywhere else,
+    /// like a menu entry, or stay open until a save has finished.
     state: &'a mut State,
     /// The underlying element.
     underlay: Element<'a, Message, Renderer>,
@@ -56,6 +65,18 @@ where
     on_cancel: Message,
     /// The function thet produces a message when the submit button of the [`ColorPickerOverlay`](ColorPickerOverlay) is pressed.
     on_submit: Box<dyn Fn(Color) -> Message>,
+    /// The optional function that produces a message when the color of the [`ColorPickerOverlay`](ColorPickerOverlay) changes.
+    on_change: Option<Box<dyn Fn(Color) -> Message>>,
+    /// The visibility of the alpha bar of the [`ColorPickerOverlay`](ColorPickerOverlay).
+    show_alpha: bool,
+    /// The visibility of the hex input of the [`ColorPickerOverlay`](ColorPickerOverlay).
+    show_hex_input: bool,
+    /// The numeric component inputs of the [`ColorPickerOverlay`](ColorPickerOverlay).
+    component_mode: ComponentMode,
+    /// The preset colors of the [`ColorPickerOverlay`](ColorPickerOverlay).
+    presets: Vec<Color>,
+    /// The maximum number of recent colors of the [`ColorPickerOverlay`](ColorPickerOverlay).
+    recent: usize,
     /// The style of the [`ColorPickerOverlay`](ColorPickerOverlay).
     style: <Renderer as color_picker::Renderer>::Style,
 }
@@ -85,10 +106,74 @@ where
             underlay: underlay.into(),
             on_cancel,
             on_submit: Box::new(on_submit),
+            on_change: None,
+            show_alpha: true,
+            show_hex_input: true,
+            component_mode: ComponentMode::None,
+            presets: Vec::new(),
+            recent: 0,
             style: <Renderer as color_picker::Renderer>::Style::default(),
         }
     }
 
+    /// Sets the function that produces a message when the user changes the
+    /// color of the [`ColorPicker`](ColorPicker) before submitting it, for
+    /// example to recolor a preview while dragging.
+    ///
+    /// Changes while dragging are sent at most once per frame. The submit and
+    /// cancel messages are sent as before.
+    pub fn on_change<F>(mut self, on_change: F) -> Self
+    where
+        F: 'static + Fn(Color) -> Message,
+    {
+        self.on_change = Some(Box::new(on_change));
+        self
+    }
+
+    /// Sets the visibility of the alpha bar of the [`ColorPicker`](ColorPicker).
+    ///
+    /// If the alpha bar is hidden, the picked color will always be opaque.
+    pub fn show_alpha(mut self, show_alpha: bool) -> Self {
+        self.show_alpha = show_alpha;
+        self
+    }
+
+    /// Sets the visibility of the hex input of the [`ColorPicker`](ColorPicker).
+    ///
+    /// The hex input accepts colors in the form of `#RRGGBB` and `#RRGGBBAA`.
+    pub fn show_hex_input(mut self, show_hex_input: bool) -> Self {
+        self.show_hex_input = show_hex_input;
+        self
+    }
+
+    /// Sets the numeric component inputs of the [`ColorPicker`](ColorPicker).
+    ///
+    /// With [`ComponentMode::Hsv`](ComponentMode::Hsv) or
+    /// [`ComponentMode::Hsl`](ComponentMode::Hsl) three inputs for the hue
+    /// (0-360) and the other two components (0-100) are shown beneath the hex
+    /// input. They stay in sync with the color area and the RGBA bars.
+    pub fn component_inputs(mut self, mode: ComponentMode) -> Self {
+        self.component_mode = mode;
+        self
+    }
+
+    /// Sets the preset colors of the [`ColorPicker`](ColorPicker).
+    ///
+    /// The presets are shown as clickable swatches beneath the color area.
+    pub fn presets(mut self, presets: Vec<Color>) -> Self {
+        self.presets = presets;
+        self
+    }
+
+    /// Sets the maximum number of recently submitted colors the
+    /// [`ColorPicker`](ColorPicker) keeps as swatches after the presets.
+    ///
+    /// A value of `0` disables the recent colors.
+    pub fn recent(mut self, recent: usize) -> Self {
+        self.recent = recent;
+        self
+    }
+
     /// Sets the style of the [`ColorPicker`](ColorPicker).
     pub fn style<S>(mut self, style: S) -> Self
     where
@@ -110,6 +195,14 @@ pub struct State {
     pub(crate) cancel_button: button::State,
     /// The state of the submit button.
     pub(crate) submit_button: button::State,
+    /// The state of the hex input.
+    pub(crate) hex_input: text_input::State,
+    /// The text of the hex input.
+    pub(crate) hex_text: String,
+    /// The states of the component inputs.
+    pub(crate) component_inputs: [text_input::State; 3],
+    /// The texts of the component inputs.
+    pub(crate) component_texts: [String; 3],
 }
 
 impl State {
@@ -121,15 +214,51 @@ impl State {
             overlay_state: color_picker::State::default(),
             cancel_button: button::State::new(),
             submit_button: button::State::new(),
+            hex_input: text_input::State::new(),
+            hex_text: String::new(),
+            component_inputs: Default::default(),
+            component_texts: Default::default(),
         }
     }
 
     /// Sets the visibility of the [`ColorPickerOverlay`](ColorPickerOverlay).
+    ///
+    /// Showing the overlay also gives it the keyboard focus.
     pub fn show(&mut self, b: bool) {
         self.overlay_state.focus = if b { Focus::Overlay } else { Focus::None };
+        self.overlay_state.change_throttle = ChangeThrottle::default();
         self.show = b;
     }
 
+    /// Returns true if the [`ColorPickerOverlay`](ColorPickerOverlay) is shown.
+    #[must_use]
+    pub const fn is_shown(&self) -> bool {
+        self.show
+    }
+
+    /// Selects the given color as if it was picked in the
+    /// [`ColorPickerOverlay`](ColorPickerOverlay).
+    ///
+    /// This is the way to feed in a color sampled by the application, like
+    /// from a screen eyedropper. The picker itself does not sample the screen,
+    /// capturing the color is the responsibility of the application.
+    ///
+    /// The color is normalized by clamping every channel to 0-1. All areas,
+    /// bars and inputs of the overlay show the new color, even if an input
+    /// is currently being edited.
+    pub fn set_color(&mut self, color: Color) {
+        self.overlay_state.color = normalize(color);
+        self.overlay_state.color_bar_dragged = ColorBarDragged::None;
+        self.overlay_state.sat_value_canvas_cache.clear();
+        self.overlay_state.hue_canvas_cache.clear();
+
+        // Unfocused inputs take their text from the new color
+        self.hex_input.unfocus();
+        for component_input in &mut self.component_inputs {
+            component_input.unfocus();
+        }
+    }
+
     /// Resets the color of the state.
     pub fn reset(&mut self) {
         self.overlay_state.color = Color::from_rgb(0.5, 0.25, 0.25);
@@ -216,8 +345,14 @@ where
                 self.on_cancel.clone(),
                 &self.on_submit,
                 position,
+                self.show_alpha,
+                self.show_hex_input,
+                self.component_mode,
+                &self.presets,
+                self.recent,
                 &self.style,
             )
+            .on_change(self.on_change.as_deref())
             .overlay(),
         )
     }
@@ -12,10 +12,12 @@ use iced_native::{
 pub use super::overlay::date_picker::Renderer;
 use super::{
     icon_text,
-    overlay::date_picker::{self, DatePickerOverlay, Focus},
+    overlay::date_picker::{self, DatePickerOverlay, Focus, Range, View},
 };
 
-pub use crate::core::date::Date;
+pub use crate::core::date::{Date, MonthNames, WeekdayNames};
+use crate::core::overlay::ChangeThrottle;
+pub use chrono::Weekday;
 /// An input element for picking dates.
 ///
 /// # Example
@@ -49,10 +51,28 @@ pub struct DatePicker<'a, Message: Clone, Renderer: date_picker::Renderer + butt
     state: &'a mut State,
     /// The underlying element.
     underlay: Element<'a, Message, Renderer>,
+    /// The date bound to the [`DatePicker`](DatePicker) by the application.
+    date: Option<Date>,
     /// The message that is send if the cancel button of the [`DatePickerOverlay`](DatePickerOverlay) is pressed.
     on_cancel: Message,
     /// The function that produces a message when the submit button of the [`DatePickerOverlay`](DatePickerOverlay) is pressed.
-    on_submit: Box<dyn Fn(Date) -> Message>,
+    on_submit: OnSubmit<Message>,
+    /// The optional function that produces a message when the date of the [`DatePickerOverlay`](DatePickerOverlay) changes.
+    on_change: Option<Box<dyn Fn(Date) -> Message>>,
+    /// The minimum selectable date of the [`DatePickerOverlay`](DatePickerOverlay).
+    min: Option<Date>,
+    /// The maximum selectable date of the [`DatePickerOverlay`](DatePickerOverlay).
+    max: Option<Date>,
+    /// The function that decides if a day of the [`DatePickerOverlay`](DatePickerOverlay) can be selected.
+    day_is_enabled: Option<Box<dyn Fn(Date) -> bool>>,
+    /// The first day of the week of the [`DatePickerOverlay`](DatePickerOverlay).
+    first_weekday: Weekday,
+    /// The names of the months and weekdays of the [`DatePickerOverlay`](DatePickerOverlay).
+    labels: Option<(MonthNames, WeekdayNames)>,
+    /// The current date highlighted by the [`DatePickerOverlay`](DatePickerOverlay).
+    today: Option<Date>,
+    /// Toggles the column of week numbers of the [`DatePickerOverlay`](DatePickerOverlay).
+    show_week_numbers: bool,
     /// The style of the [`DatePickerOverlay`](DatePickerOverlay).
     style: <Renderer as date_picker::Renderer>::Style,
     //button_style: <Renderer as button::Renderer>::Style, // clone not satisfied
@@ -75,17 +95,157 @@ impl<'a, Message: Clone, Renderer: date_picker::Renderer + button::Renderer>
     where
         U: Into<Element<'a, Message, Renderer>>,
         F: 'static + Fn(Date) -> Message,
+    {
+        Self::with_on_submit(
+            state,
+            underlay,
+            on_cancel,
+            OnSubmit::Date(Box::new(on_submit)),
+        )
+    }
+
+    /// Creates a new [`DatePicker`](DatePicker) wrapping around the given underlay
+    /// for picking a range of dates.
+    ///
+    /// The first clicked day sets the start of the range, the second one sets
+    /// the end of the range.
+    ///
+    /// It expects:
+    ///     * a mutable reference to the [`DatePicker`](DatePicker)'s [`State`](State).
+    ///     * the underlay [`Element`](iced_native::Element) on which this [`DatePicker`](DatePicker)
+    ///         will be wrapped around.
+    ///     * a message that will be send when the cancel button of the [`DatePicker`](DatePicker)
+    ///         is pressed.
+    ///     * a function that will be called when the submit button of the [`DatePicker`](DatePicker)
+    ///         is pressed, which takes the picked start and end [`Date`](crate::date_picker::Date)
+    ///         values.
+    pub fn range<U, F>(state: &'a mut State, underlay: U, on_cancel: Message, on_submit: F) -> Self
+    where
+        U: Into<Element<'a, Message, Renderer>>,
+        F: 'static + Fn(Date, Date) -> Message,
+    {
+        Self::with_on_submit(
+            state,
+            underlay,
+            on_cancel,
+            OnSubmit::Range(Box::new(on_submit)),
+        )
+    }
+
+    /// Creates a new [`DatePicker`](DatePicker) with the given submit function.
+    fn with_on_submit<U>(
+        state: &'a mut State,
+        underlay: U,
+        on_cancel: Message,
+        on_submit: OnSubmit<Message>,
+    ) -> Self
+    where
+        U: Into<Element<'a, Message, Renderer>>,
     {
         Self {
             state,
             underlay: underlay.into(),
+            date: None,
             on_cancel,
-            on_submit: Box::new(on_submit),
+            on_submit,
+            on_change: None,
+            min: None,
+            max: None,
+            day_is_enabled: None,
+            first_weekday: Weekday::Mon,
+            labels: None,
+            today: None,
+            show_week_numbers: false,
             style: <Renderer as date_picker::Renderer>::Style::default(),
             //button_style: <Renderer as button::Renderer>::Style::default(),
         }
     }
 
+    /// Binds the date of the application to the [`DatePicker`](DatePicker).
+    ///
+    /// Opening the [`DatePickerOverlay`](DatePickerOverlay) selects the bound
+    /// date and shows its month. If the application changes the bound date,
+    /// for example by loading a record, the shown date follows it, even while
+    /// the overlay is open.
+    pub fn date(mut self, date: Date) -> Self {
+        self.date = Some(date);
+        self
+    }
+
+    /// Sets the function that produces a message when the user changes the
+    /// date of the [`DatePicker`](DatePicker) before submitting it, for
+    /// example for a live preview.
+    ///
+    /// Changes while dragging are sent at most once per frame. The submit and
+    /// cancel messages are sent as before.
+    pub fn on_change<F>(mut self, on_change: F) -> Self
+    where
+        F: 'static + Fn(Date) -> Message,
+    {
+        self.on_change = Some(Box::new(on_change));
+        self
+    }
+
+    /// Sets the minimum selectable date of the [`DatePicker`](DatePicker).
+    ///
+    /// Days before this date are grayed out and can't be selected.
+    pub fn min(mut self, min: Date) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the maximum selectable date of the [`DatePicker`](DatePicker).
+    ///
+    /// Days after this date are grayed out and can't be selected.
+    pub fn max(mut self, max: Date) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets the function that decides if a day of the [`DatePicker`](DatePicker)
+    /// can be selected.
+    ///
+    /// Disabled days are grayed out, can't be clicked and are skipped by the
+    /// keyboard navigation. The function is only called for the visible days.
+    pub fn day_is_enabled(mut self, day_is_enabled: Box<dyn Fn(Date) -> bool>) -> Self {
+        self.day_is_enabled = Some(day_is_enabled);
+        self
+    }
+
+    /// Sets the first day of the week of the [`DatePicker`](DatePicker).
+    ///
+    /// The default is Monday.
+    pub fn first_weekday(mut self, first_weekday: Weekday) -> Self {
+        self.first_weekday = first_weekday;
+        self
+    }
+
+    /// Sets the displayed names of the months (from January to December) and
+    /// the weekdays (from Monday to Sunday) of the [`DatePicker`](DatePicker).
+    pub fn labels(mut self, months: MonthNames, weekdays: WeekdayNames) -> Self {
+        self.labels = Some((months, weekdays));
+        self
+    }
+
+    /// Sets the current date of the [`DatePicker`](DatePicker).
+    ///
+    /// The day of the current date is highlighted and a button is shown
+    /// that jumps to its month and selects it.
+    pub fn today(mut self, today: Date) -> Self {
+        self.today = Some(today);
+        self
+    }
+
+    /// Shows the ISO-8601 week numbers in a column in front of the days of
+    /// the [`DatePicker`](DatePicker).
+    ///
+    /// If the weeks do not start on Monday, each row shows the week number of
+    /// its Monday.
+    pub fn show_week_numbers(mut self, show_week_numbers: bool) -> Self {
+        self.show_week_numbers = show_week_numbers;
+        self
+    }
+
     /// Sets the style of the [`DatePicker`](DatePicker).
     pub fn style<S>(mut self, style: S) -> Self
     where
@@ -97,7 +257,26 @@ impl<'a, Message: Clone, Renderer: date_picker::Renderer + button::Renderer>
     }
 }
 
+/// The function that produces a message when the submit button of the
+/// [`DatePickerOverlay`](DatePickerOverlay) is pressed.
+#[allow(missing_debug_implementations)]
+pub enum OnSubmit<Message> {
+    /// Produces a message containing a single picked date.
+    Date(Box<dyn Fn(Date) -> Message>),
+
+    /// Produces a message containing the start and the end of the picked
+    /// range of dates.
+    Range(Box<dyn Fn(Date, Date) -> Message>),
+}
+
 /// The state of the [`DatePicker`](DatePicker) / [`DatePickerOverlay`](DatePickerOverlay).
+///
+/// The visibility of the [`DatePickerOverlay`](DatePickerOverlay) is fully controlled by
+/// the application through [`show`](State::show). The picker never opens or
+/// closes itself: pressing the underlay, cancel or submit only sends the
+/// messages of the application, which decides whether to change the
+/// visibility. This way the overlay can also be opened from anywhere else,
+/// like a menu entry, or stay open until a save has finished.
 #[derive(Debug)]
 pub struct State {
     /// The visibility of the overlay.
@@ -108,6 +287,10 @@ pub struct State {
     pub(crate) cancel_button: button::State,
     /// The state of the submit button.
     pub(crate) submit_button: button::State,
+    /// The state of the button selecting the current date.
+    pub(crate) today_button: button::State,
+    /// The bound date last taken over by the overlay.
+    pub(crate) bound_date: Option<Date>,
 }
 
 impl State {
@@ -119,18 +302,34 @@ impl State {
             overlay_state: date_picker::State::default(),
             cancel_button: button::State::new(),
             submit_button: button::State::new(),
+            today_button: button::State::new(),
+            bound_date: None,
         }
     }
 
     /// Sets the visibility of the [`DatePickerOverlay`](DatePickerOverlay).
+    ///
+    /// Showing the overlay also gives it the keyboard focus and shows the
+    /// days again.
     pub fn show(&mut self, b: bool) {
         self.overlay_state.focus = if b { Focus::Overlay } else { Focus::None };
+        self.overlay_state.view = View::Days;
+        // The bound date is taken over again when the overlay is opened.
+        self.bound_date = None;
+        self.overlay_state.change_throttle = ChangeThrottle::default();
         self.show = b;
     }
 
+    /// Returns true if the [`DatePickerOverlay`](DatePickerOverlay) is shown.
+    #[must_use]
+    pub const fn is_shown(&self) -> bool {
+        self.show
+    }
+
     /// Resets the date of the state to the current date.
     pub fn reset(&mut self) {
         self.overlay_state.date = Local::today().naive_local();
+        self.overlay_state.range = Range::default();
     }
 
     /// Set the date of the state to the given value.
@@ -203,6 +402,7 @@ where
         std::any::TypeId::of::<Marker>().hash(state);
 
         self.state.show.hash(state);
+        self.date.hash(state);
         self.underlay.hash_layout(state);
     }
 
@@ -211,6 +411,19 @@ where
             return self.underlay.overlay(layout);
         }
 
+        if let Some(date) = self
+            .date
+            .filter(|date| Some(*date) != self.state.bound_date)
+        {
+            self.state.overlay_state.date = crate::core::date::clamp_date(
+                date.into(),
+                self.min.map(Into::into),
+                self.max.map(Into::into),
+            );
+            self.state.overlay_state.range = Range::default();
+            self.state.bound_date = Some(date);
+        }
+
         let bounds = layout.bounds();
         let position = Point::new(bounds.center_x(), bounds.center_y());
 
@@ -220,9 +433,17 @@ where
                 self.on_cancel.clone(),
                 &self.on_submit,
                 position,
+                self.min,
+                self.max,
+                self.day_is_enabled.as_deref(),
+                self.first_weekday,
+                self.labels.as_ref(),
+                self.today,
+                self.show_week_numbers,
                 &self.style,
                 //self.button_style, // Clone not satisfied
             )
+            .on_change(self.on_change.as_deref())
             .overlay(),
         )
     }
@@ -245,3 +466,83 @@ where
         Element::new(date_picker)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Datelike, NaiveDate, Weekday};
+    use iced_native::{
+        clipboard, layout::Limits, mouse, renderer::Null, Element, Event, Layout, Size, Text,
+    };
+
+    use super::{Date, DatePicker, State};
+
+    #[derive(Clone, Debug, PartialEq)]
+    enum Message {
+        Cancel,
+        Submit(Date),
+    }
+
+    /// Clicks the submit button of a shown picker set to the given date with
+    /// the given enabled days and returns the produced messages.
+    fn submit(date: Date, day_is_enabled: Box<dyn Fn(Date) -> bool>) -> Vec<Message> {
+        let mut state = State::now();
+        state.set_date(date.year, date.month, date.day);
+        state.show(true);
+
+        let mut element: Element<'_, Message, Null> = DatePicker::new(
+            &mut state,
+            Text::new("Pick"),
+            Message::Cancel,
+            Message::Submit,
+        )
+        .day_is_enabled(day_is_enabled)
+        .into();
+
+        let renderer = Null::new();
+        let size = Size::new(800.0, 600.0);
+        let node = element.layout(&renderer, &Limits::new(Size::ZERO, size));
+        let mut overlay = element
+            .overlay(Layout::new(&node))
+            .expect("The picker should be shown");
+        let overlay_node = overlay.layout(&renderer, size);
+        let position = Layout::new(&overlay_node)
+            .children()
+            .nth(2)
+            .expect("The picker should have a submit button")
+            .bounds()
+            .center();
+
+        let mut messages = Vec::new();
+        for event in [
+            mouse::Event::ButtonPressed(mouse::Button::Left),
+            mouse::Event::ButtonReleased(mouse::Button::Left),
+        ] {
+            let _ = overlay.on_event(
+                Event::Mouse(event),
+                Layout::new(&overlay_node),
+                position,
+                &renderer,
+                &mut clipboard::Null,
+                &mut messages,
+            );
+        }
+
+        messages
+    }
+
+    #[test]
+    fn disabled_day_snaps_to_nearest_enabled_day() {
+        let is_weekday =
+            |date: Date| !matches!(NaiveDate::from(date).weekday(), Weekday::Sat | Weekday::Sun);
+
+        // The 14th of March 2021 is a Sunday.
+        let messages = submit(Date::from_ymd(2021, 3, 14), Box::new(is_weekday));
+        assert_eq!(messages, vec![Message::Submit(Date::from_ymd(2021, 3, 15))]);
+    }
+
+    #[test]
+    fn disabled_day_is_not_submitted() {
+        let messages = submit(Date::from_ymd(2021, 3, 14), Box::new(|_| false));
+        assert!(messages.is_empty());
+    }
+}
@@ -0,0 +1,477 @@
+//! Use a date time picker as an input element for picking a date and a time.
+//!
+//! *This API requires the following crate features to be activated: `date_time_picker`*
+use std::hash::Hash;
+
+use chrono::NaiveDateTime;
+use iced_native::{
+    button, column, container, event, overlay, row, text, Clipboard, Element, Event, Layout, Point,
+    Widget,
+};
+
+pub use super::overlay::date_time_picker::Renderer;
+use super::{
+    date_picker::{self, OnSubmit},
+    icon_text,
+    overlay::{
+        date_picker::DatePickerOverlay,
+        date_time_picker::{self, DateTimePickerOverlay, Part},
+        time_picker::{self as time_picker_overlay, TimePickerOverlay},
+    },
+    time_picker,
+};
+
+pub use crate::core::{
+    date::Date,
+    date_time::DateTime,
+    time::{Period, Time},
+};
+pub use chrono::Weekday;
+
+/// An input element for picking a date and a time at once.
+///
+/// It shows a calendar next to a clock and submits both parts together.
+///
+/// # Example
+/// ```
+/// # use iced_aw::date_time_picker;
+/// # use iced_native::{Button, Text, button, renderer::Null};
+/// #
+/// # pub type DateTimePicker<'a, Message> = iced_aw::native::DateTimePicker<'a, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Open,
+///     Cancel,
+///     Submit(date_time_picker::DateTime),
+/// }
+///
+/// let mut button_state = button::State::new();
+/// let mut state = date_time_picker::State::now();
+/// state.show(true);
+///
+/// let date_time_picker = DateTimePicker::new(
+///     &mut state,
+///     Button::new(&mut button_state, Text::new("Pick date and time"))
+///         .on_press(Message::Open),
+///     Message::Cancel,
+///     Message::Submit,
+/// )
+/// .use_24h();
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct DateTimePicker<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: date_time_picker::Renderer + button::Renderer,
+{
+    /// The state of the [`DateTimePicker`](DateTimePicker).
+    state: &'a mut State,
+    /// The underlying element.
+    underlay: Element<'a, Message, Renderer>,
+    /// The message that is send if the cancel button of the [`DateTimePickerOverlay`](DateTimePickerOverlay) is pressed.
+    on_cancel: Message,
+    /// The function that produces a message when the submit button of the [`DateTimePickerOverlay`](DateTimePickerOverlay) is pressed.
+    on_submit: Box<dyn Fn(DateTime) -> Message>,
+    /// The submit function of the calendar of the [`DateTimePickerOverlay`](DateTimePickerOverlay).
+    on_submit_date: OnSubmit<Part>,
+    /// The minimum date time of the [`DateTimePickerOverlay`](DateTimePickerOverlay).
+    min: Option<DateTime>,
+    /// The maximum date time of the [`DateTimePickerOverlay`](DateTimePickerOverlay).
+    max: Option<DateTime>,
+    /// The first day of the week of the [`DateTimePickerOverlay`](DateTimePickerOverlay).
+    first_weekday: Weekday,
+    /// The style of the calendar of the [`DateTimePickerOverlay`](DateTimePickerOverlay).
+    date_style: <Renderer as date_picker::Renderer>::Style,
+    /// The style of the clock of the [`DateTimePickerOverlay`](DateTimePickerOverlay).
+    time_style: <Renderer as time_picker::Renderer>::Style,
+}
+
+impl<'a, Message, Renderer> DateTimePicker<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: date_time_picker::Renderer + button::Renderer,
+{
+    /// Creates a new [`DateTimePicker`](DateTimePicker) wrapping around the given underlay.
+    ///
+    /// It expects:
+    ///     * a mutable reference to the [`DateTimePicker`](DateTimePicker)'s [`State`](State).
+    ///     * the underlay [`Element`](iced_native::Element) on which this [`DateTimePicker`](DateTimePicker)
+    ///         will be wrapped around.
+    ///     * a message that will be send when the cancel button of the [`DateTimePicker`](DateTimePicker)
+    ///         is pressed.
+    ///     * a function that will be called when the submit button of the [`DateTimePicker`](DateTimePicker)
+    ///         is pressed, which takes the picked [`DateTime`](crate::date_time_picker::DateTime) value.
+    pub fn new<U, F>(state: &'a mut State, underlay: U, on_cancel: Message, on_submit: F) -> Self
+    where
+        U: Into<Element<'a, Message, Renderer>>,
+        F: 'static + Fn(DateTime) -> Message,
+    {
+        Self {
+            state,
+            underlay: underlay.into(),
+            on_cancel,
+            on_submit: Box::new(on_submit),
+            on_submit_date: OnSubmit::Date(Box::new(Part::Date)),
+            min: None,
+            max: None,
+            first_weekday: Weekday::Mon,
+            date_style: <Renderer as date_picker::Renderer>::Style::default(),
+            time_style: <Renderer as time_picker::Renderer>::Style::default(),
+        }
+    }
+
+    /// Use 24 hour format instead of AM/PM.
+    pub fn use_24h(self) -> Self {
+        self.state.time.overlay_state.use_24h = true;
+        self
+    }
+
+    /// Enables the picker to also pick seconds.
+    pub fn show_seconds(self) -> Self {
+        self.state.time.overlay_state.show_seconds = true;
+        self
+    }
+
+    /// Sets the minimum date time of the [`DateTimePicker`](DateTimePicker).
+    ///
+    /// Days before its date can't be selected and an earlier time on its date
+    /// is submitted as the minimum.
+    pub fn min(mut self, min: DateTime) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the maximum date time of the [`DateTimePicker`](DateTimePicker).
+    ///
+    /// Days after its date can't be selected and a later time on its date is
+    /// submitted as the maximum.
+    pub fn max(mut self, max: DateTime) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets the first day of the week of the [`DateTimePicker`](DateTimePicker).
+    ///
+    /// The default is Monday.
+    pub fn first_weekday(mut self, first_weekday: Weekday) -> Self {
+        self.first_weekday = first_weekday;
+        self
+    }
+
+    /// Sets the style of the calendar of the [`DateTimePicker`](DateTimePicker).
+    pub fn date_style<S>(mut self, style: S) -> Self
+    where
+        S: Into<<Renderer as date_picker::Renderer>::Style>,
+    {
+        self.date_style = style.into();
+        self
+    }
+
+    /// Sets the style of the clock of the [`DateTimePicker`](DateTimePicker).
+    pub fn time_style<S>(mut self, style: S) -> Self
+    where
+        S: Into<<Renderer as time_picker::Renderer>::Style>,
+    {
+        self.time_style = style.into();
+        self
+    }
+}
+
+/// The state of the [`DateTimePicker`](DateTimePicker) / [`DateTimePickerOverlay`](DateTimePickerOverlay).
+///
+/// Like the states of the [`DatePicker`](crate::native::DatePicker) and the
+/// [`TimePicker`](crate::native::TimePicker), the visibility of the
+/// [`DateTimePickerOverlay`](DateTimePickerOverlay) is fully controlled by the
+/// application through [`show`](State::show).
+#[derive(Debug)]
+pub struct State {
+    /// The visibility of the overlay.
+    pub(crate) show: bool,
+    /// The state of the calendar.
+    pub(crate) date: date_picker::State,
+    /// The state of the clock.
+    pub(crate) time: time_picker::State,
+}
+
+impl State {
+    /// Creates a new [`State`](State) with the current date and time.
+    #[must_use]
+    pub fn now() -> Self {
+        Self {
+            show: false,
+            date: date_picker::State::now(),
+            time: time_picker::State::now(),
+        }
+    }
+
+    /// Sets the visibility of the [`DateTimePickerOverlay`](DateTimePickerOverlay).
+    ///
+    /// Showing the overlay gives the keyboard focus to the calendar.
+    pub fn show(&mut self, b: bool) {
+        self.date.show(b);
+        self.time.show(b);
+        self.time.overlay_state.focus = time_picker_overlay::Focus::None;
+        self.show = b;
+    }
+
+    /// Returns true if the [`DateTimePickerOverlay`](DateTimePickerOverlay) is shown.
+    #[must_use]
+    pub const fn is_shown(&self) -> bool {
+        self.show
+    }
+
+    /// Resets the date and time of the state to the current date and time.
+    pub fn reset(&mut self) {
+        self.date.reset();
+        self.time.reset();
+    }
+
+    /// Sets the date and time of the state to the given value.
+    pub fn set_date_time(&mut self, date_time: DateTime) {
+        let date_time = NaiveDateTime::from(date_time);
+        self.date.overlay_state.date = date_time.date();
+        self.time.overlay_state.time = date_time.time();
+        self.time.overlay_state.clock_cache.clear();
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for DateTimePicker<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: date_time_picker::Renderer
+        + button::Renderer
+        + column::Renderer
+        + container::Renderer
+        + icon_text::Renderer
+        + row::Renderer
+        + text::Renderer,
+{
+    fn width(&self) -> iced_native::Length {
+        self.underlay.width()
+    }
+
+    fn height(&self) -> iced_native::Length {
+        self.underlay.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &iced_native::layout::Limits,
+    ) -> iced_native::layout::Node {
+        self.underlay.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        self.underlay.on_event(
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            messages,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: iced_native::Layout<'_>,
+        cursor_position: iced_graphics::Point,
+        viewport: &iced_graphics::Rectangle,
+    ) -> Renderer::Output {
+        self.underlay
+            .draw(renderer, defaults, layout, cursor_position, viewport)
+    }
+
+    fn hash_layout(&self, state: &mut iced_native::Hasher) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.state.show.hash(state);
+        self.underlay.hash_layout(state);
+    }
+
+    fn overlay(&mut self, layout: Layout<'_>) -> Option<overlay::Element<'_, Message, Renderer>> {
+        if !self.state.show {
+            return self.underlay.overlay(layout);
+        }
+
+        let bounds = layout.bounds();
+        let position = Point::new(bounds.center_x(), bounds.center_y());
+
+        let State { date, time, .. } = &mut *self.state;
+
+        let date = DatePickerOverlay::new(
+            date,
+            Part::Cancel,
+            &self.on_submit_date,
+            position,
+            self.min.map(|min| min.date),
+            self.max.map(|max| max.date),
+            None,
+            self.first_weekday,
+            None,
+            None,
+            false,
+            &self.date_style,
+        );
+        let time =
+            TimePickerOverlay::new(time, Part::Cancel, &Part::Time, position, &self.time_style);
+
+        Some(
+            DateTimePickerOverlay::new(
+                date,
+                time,
+                self.on_cancel.clone(),
+                &self.on_submit,
+                position,
+                self.min,
+                self.max,
+            )
+            .overlay(),
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<DateTimePicker<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a
+        + date_time_picker::Renderer
+        + button::Renderer
+        + column::Renderer
+        + container::Renderer
+        + icon_text::Renderer
+        + row::Renderer
+        + text::Renderer,
+{
+    fn from(date_time_picker: DateTimePicker<'a, Message, Renderer>) -> Self {
+        Element::new(date_time_picker)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iced_native::{
+        clipboard, layout::Limits, mouse, renderer::Null, Element, Event, Layout, Size, Text,
+    };
+
+    use super::{Date, DateTime, DateTimePicker, Period, State, Time};
+
+    #[derive(Clone, Debug, PartialEq)]
+    enum Message {
+        Cancel,
+        Submit(DateTime),
+    }
+
+    fn date_time(hour: u32, minute: u32) -> DateTime {
+        DateTime::new(
+            Date::from_ymd(2021, 3, 14),
+            Time::Hm {
+                hour,
+                minute,
+                period: Period::H24,
+            },
+        )
+    }
+
+    /// Clicks the button at the given index of the calendar of a shown
+    /// picker set to the given date time and returns the produced messages.
+    fn click(
+        date_time: DateTime,
+        button: usize,
+        min: Option<DateTime>,
+    ) -> (Vec<Message>, Vec<usize>) {
+        let mut state = State::now();
+        state.set_date_time(date_time);
+        state.show(true);
+
+        let mut picker = DateTimePicker::new(
+            &mut state,
+            Text::new("Pick"),
+            Message::Cancel,
+            Message::Submit,
+        )
+        .use_24h();
+        if let Some(min) = min {
+            picker = picker.min(min);
+        }
+        let mut element: Element<'_, Message, Null> = picker.into();
+
+        let renderer = Null::new();
+        let size = Size::new(800.0, 600.0);
+        let node = element.layout(&renderer, &Limits::new(Size::ZERO, size));
+        let mut overlay = element
+            .overlay(Layout::new(&node))
+            .expect("The picker should be shown");
+        let overlay_node = overlay.layout(&renderer, size);
+        let layout = Layout::new(&overlay_node);
+
+        let parts = layout
+            .children()
+            .map(|part| part.children().count())
+            .collect();
+        let position = layout
+            .children()
+            .next()
+            .and_then(|date| date.children().nth(button))
+            .expect("The calendar should have buttons")
+            .bounds()
+            .center();
+
+        let mut messages = Vec::new();
+        for event in [
+            mouse::Event::ButtonPressed(mouse::Button::Left),
+            mouse::Event::ButtonReleased(mouse::Button::Left),
+        ] {
+            let _ = overlay.on_event(
+                Event::Mouse(event),
+                Layout::new(&overlay_node),
+                position,
+                &renderer,
+                &mut clipboard::Null,
+                &mut messages,
+            );
+        }
+
+        (messages, parts)
+    }
+
+    #[test]
+    fn submit_carries_date_and_time() {
+        let (messages, _) = click(date_time(13, 5), 2, None);
+        assert_eq!(messages, vec![Message::Submit(date_time(13, 5))]);
+    }
+
+    #[test]
+    fn cancel_is_sent_once() {
+        let (messages, _) = click(date_time(13, 5), 1, None);
+        assert_eq!(messages, vec![Message::Cancel]);
+    }
+
+    #[test]
+    fn submitted_date_time_is_clamped() {
+        let (messages, _) = click(date_time(8, 30), 2, Some(date_time(9, 0)));
+        assert_eq!(messages, vec![Message::Submit(date_time(9, 0))]);
+    }
+
+    #[test]
+    fn clock_has_no_buttons() {
+        let (_, parts) = click(date_time(13, 5), 2, None);
+        // The calendar has its table and buttons, the clock only its analog
+        // and digital clock.
+        assert_eq!(parts, vec![3, 2]);
+    }
+}
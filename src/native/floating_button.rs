@@ -18,6 +18,10 @@ use super::overlay::floating_button::FloatingButtonOverlay;
 
 /// A floating button floating over some content.
 ///
+/// Only the bounds of the button capture mouse and touch events. Events in
+/// the rest of the content, e.g. a scrollable list below the button, reach
+/// the content as usual.
+///
 /// # Example
 /// ```
 /// # use iced_native::{button, Button, Column, renderer::Null, Text};
@@ -109,6 +113,16 @@ where
         self.hidden = hide;
         self
     }
+
+    /// Shows or hides the [`Button`](iced_native::button::Button) on the
+    /// [`FloatingButton`](FloatingButton).
+    ///
+    /// A hidden button is neither laid out nor drawn and does not capture
+    /// any events.
+    pub fn visible(mut self, visible: bool) -> Self {
+        self.hidden = !visible;
+        self
+    }
 }
 
 impl<'a, B, Message, Renderer> Widget<Message, Renderer>
@@ -188,6 +202,11 @@ where
             Anchor::NorthEast => Point::new(bounds.width, 0.0),
             Anchor::SouthWest => Point::new(0.0, bounds.height),
             Anchor::SouthEast => Point::new(bounds.width, bounds.height),
+            Anchor::North => Point::new(bounds.width / 2.0, 0.0),
+            Anchor::East => Point::new(bounds.width, bounds.height / 2.0),
+            Anchor::South => Point::new(bounds.width / 2.0, bounds.height),
+            Anchor::West => Point::new(0.0, bounds.height / 2.0),
+            Anchor::Center => Point::new(bounds.width / 2.0, bounds.height / 2.0),
         };
 
         let position = Point::new(bounds.x + position.x, bounds.y + position.y);
@@ -210,3 +229,114 @@ where
         Element::new(floating_button)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use iced_native::{
+        button, clipboard, event, layout::Limits, mouse, renderer::Null, Button, Cache, Column,
+        Element, Event, Layout, Length, Point, Size, Text, UserInterface, Widget,
+    };
+
+    use super::FloatingButton;
+
+    #[derive(Clone, Debug, PartialEq)]
+    enum Message {
+        Content,
+        Floating,
+    }
+
+    /// The size of the window and the content.
+    const SIZE: Size = Size::new(200.0, 200.0);
+
+    /// Builds a 40x40 floating button in the bottom right corner of a content
+    /// button filling the window.
+    fn floating_button<'a>(
+        content_state: &'a mut button::State,
+        floating_state: &'a mut button::State,
+    ) -> FloatingButton<'a, impl Fn(&mut button::State) -> Button<'_, Message, Null>, Message, Null>
+    {
+        let content = Button::new(content_state, Column::new())
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .on_press(Message::Content);
+
+        FloatingButton::new(floating_state, content, |state| {
+            Button::new(state, Text::new("+"))
+                .width(Length::Units(40))
+                .height(Length::Units(40))
+                .on_press(Message::Floating)
+        })
+    }
+
+    /// Presses the floating button's overlay at the given position.
+    fn press_overlay(position: Point) -> event::Status {
+        let mut content_state = button::State::new();
+        let mut floating_state = button::State::new();
+        let mut floating_button = floating_button(&mut content_state, &mut floating_state);
+
+        let renderer = Null::new();
+        let node = Widget::layout(&floating_button, &renderer, &Limits::new(Size::ZERO, SIZE));
+        let mut overlay = Widget::overlay(&mut floating_button, Layout::new(&node))
+            .expect("The floating button should have an overlay");
+        let node = overlay.layout(&renderer, SIZE);
+
+        let mut messages = Vec::new();
+        overlay.on_event(
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Layout::new(&node),
+            position,
+            &renderer,
+            &mut clipboard::Null,
+            &mut messages,
+        )
+    }
+
+    /// Clicks at the given position in a window showing the floating button.
+    fn click(position: Point) -> Vec<Message> {
+        let mut content_state = button::State::new();
+        let mut floating_state = button::State::new();
+        let mut messages = Vec::new();
+
+        // Each event is handled by a freshly built interface, like in an
+        // application rebuilding its view after every update.
+        for event in [mouse::Event::ButtonPressed, mouse::Event::ButtonReleased] {
+            let element: Element<'_, Message, Null> =
+                floating_button(&mut content_state, &mut floating_state).into();
+            let mut renderer = Null::new();
+            let mut interface = UserInterface::build(element, SIZE, Cache::new(), &mut renderer);
+
+            let _ = interface.update(
+                &[Event::Mouse(event(mouse::Button::Left))],
+                position,
+                &renderer,
+                &mut clipboard::Null,
+                &mut messages,
+            );
+        }
+
+        messages
+    }
+
+    #[test]
+    fn press_outside_button_is_not_captured() {
+        // The button is placed 5 units away from the bottom right corner.
+        assert_eq!(
+            press_overlay(Point::new(175.0, 175.0)),
+            event::Status::Captured
+        );
+        assert_eq!(
+            press_overlay(Point::new(20.0, 20.0)),
+            event::Status::Ignored
+        );
+        assert_eq!(
+            press_overlay(Point::new(150.0, 175.0)),
+            event::Status::Ignored
+        );
+    }
+
+    #[test]
+    fn click_outside_button_reaches_content() {
+        assert_eq!(click(Point::new(175.0, 175.0)), vec![Message::Floating]);
+        assert_eq!(click(Point::new(20.0, 20.0)), vec![Message::Content]);
+    }
+}
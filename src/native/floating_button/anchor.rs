@@ -24,4 +24,29 @@ pub enum Anchor {
     /// [`Button`](iced_native::button::Button) on the bottom right of the
     /// underlying element.
     SouthEast,
+
+    /// North [`Anchor`](Anchor) for positioning the
+    /// [`Button`](iced_native::button::Button) on the top center of the
+    /// underlying element.
+    North,
+
+    /// East [`Anchor`](Anchor) for positioning the
+    /// [`Button`](iced_native::button::Button) on the right center of the
+    /// underlying element.
+    East,
+
+    /// South [`Anchor`](Anchor) for positioning the
+    /// [`Button`](iced_native::button::Button) on the bottom center of the
+    /// underlying element.
+    South,
+
+    /// West [`Anchor`](Anchor) for positioning the
+    /// [`Button`](iced_native::button::Button) on the left center of the
+    /// underlying element.
+    West,
+
+    /// Center [`Anchor`](Anchor) for positioning the
+    /// [`Button`](iced_native::button::Button) on the center of the
+    /// underlying element.
+    Center,
 }
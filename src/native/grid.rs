@@ -3,10 +3,14 @@
 //! *This API requires the following crate features to be activated: `grid`*
 use std::hash::Hash;
 
+use crate::core::grid::{
+    place, resolve_tracks, row_areas, track_offsets, track_sizes, Placement, Track,
+};
 use iced_native::{
     event,
     layout::{Limits, Node},
-    Clipboard, Element, Event, Hasher, Layout, Length, Point, Size, Widget,
+    Align, Clipboard, Color, Element, Event, Hasher, Layout, Length, Point, Rectangle, Size,
+    Widget,
 };
 
 /// A container that distributes its contents in a grid.
@@ -28,12 +32,115 @@ use iced_native::{
 ///     .push(Text::new("Second row, second column"));
 ///
 /// ```
+///
+/// A [`Cell`](Cell) can span several columns and rows:
+///
+/// ```
+/// # use iced_native::{renderer::Null, Text};
+/// #
+/// # pub type Grid<'a, Message> = iced_aw::native::Grid<'a, Message, Null>;
+/// # pub type Cell<'a, Message> = iced_aw::native::grid::Cell<'a, Message, Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+/// }
+///
+/// let grid = Grid::<Message>::with_columns(2)
+///     .push_cell(Cell::new(Text::new("Header")).column_span(2))
+///     .push(Text::new("Label"))
+///     .push(Text::new("Value"));
+///
+/// ```
 #[allow(missing_debug_implementations)]
 pub struct Grid<'a, Message, Renderer: self::Renderer> {
     /// The distribution [`Strategy`](Strategy) of the [`Grid`](Grid).
     strategy: Strategy,
     /// The elements in the [`Grid`](Grid).
     elements: Vec<Element<'a, Message, Renderer>>,
+    /// The spans of the elements as `(row_span, column_span)`.
+    spans: Vec<(usize, usize)>,
+    /// The alignments of the elements overriding the alignments of the
+    /// [`Grid`](Grid) as `(horizontal, vertical)`.
+    alignments: Vec<(Option<Align>, Option<Align>)>,
+    /// The horizontal alignment of the cells.
+    justify_items: Align,
+    /// The vertical alignment of the cells.
+    align_items: Align,
+    /// The horizontal alignments of the cells of each column.
+    column_alignments: Vec<Align>,
+    /// The vertical alignments of the cells of each row.
+    row_alignments: Vec<Align>,
+    /// The widths of the columns.
+    column_widths: Vec<Length>,
+    /// The heights of the rows.
+    row_heights: Vec<Length>,
+    /// The function giving the background colors of the rows.
+    row_style: Option<Box<dyn Fn(usize) -> Option<Color> + 'a>>,
+    /// Pins the first row to the top of the viewport.
+    sticky_header: bool,
+}
+
+/// A cell of a [`Grid`](Grid) that can span several columns and rows.
+#[allow(missing_debug_implementations)]
+pub struct Cell<'a, Message, Renderer> {
+    /// The element of the [`Cell`](Cell).
+    element: Element<'a, Message, Renderer>,
+    /// The number of rows spanned by the [`Cell`](Cell).
+    row_span: usize,
+    /// The number of columns spanned by the [`Cell`](Cell).
+    column_span: usize,
+    /// The horizontal alignment of the [`Cell`](Cell).
+    justify: Option<Align>,
+    /// The vertical alignment of the [`Cell`](Cell).
+    align: Option<Align>,
+}
+
+impl<'a, Message, Renderer> Cell<'a, Message, Renderer> {
+    /// Creates a new [`Cell`](Cell) spanning a single column and row.
+    pub fn new<E>(element: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        Self {
+            element: element.into(),
+            row_span: 1,
+            column_span: 1,
+            justify: None,
+            align: None,
+        }
+    }
+
+    /// Sets the number of columns spanned by the [`Cell`](Cell).
+    ///
+    /// A span exceeding the remaining columns of the row is clamped to the
+    /// edge of the [`Grid`](Grid).
+    #[must_use]
+    pub fn column_span(mut self, columns: usize) -> Self {
+        self.column_span = columns;
+        self
+    }
+
+    /// Sets the number of rows spanned by the [`Cell`](Cell).
+    #[must_use]
+    pub fn row_span(mut self, rows: usize) -> Self {
+        self.row_span = rows;
+        self
+    }
+
+    /// Sets the horizontal alignment of the [`Cell`](Cell) in its columns,
+    /// overriding the alignment of the [`Grid`](Grid).
+    #[must_use]
+    pub fn justify(mut self, justify: Align) -> Self {
+        self.justify = Some(justify);
+        self
+    }
+
+    /// Sets the vertical alignment of the [`Cell`](Cell) in its rows,
+    /// overriding the alignment of the [`Grid`](Grid).
+    #[must_use]
+    pub fn align(mut self, align: Align) -> Self {
+        self.align = Some(align);
+        self
+    }
 }
 
 /// The [`Strategy`](Strategy) of how to distribute the columns of the [`Grid`](Grid).
@@ -61,6 +168,16 @@ where
         Self {
             strategy: Strategy::Columns(columns),
             elements: Vec::new(),
+            spans: Vec::new(),
+            column_widths: Vec::new(),
+            row_heights: Vec::new(),
+            alignments: Vec::new(),
+            justify_items: Align::Start,
+            align_items: Align::Start,
+            column_alignments: Vec::new(),
+            row_alignments: Vec::new(),
+            row_style: None,
+            sticky_header: false,
         }
     }
 
@@ -71,24 +188,253 @@ where
         Self {
             strategy: Strategy::ColumnWidth(column_width),
             elements: Vec::new(),
+            spans: Vec::new(),
+            column_widths: Vec::new(),
+            row_heights: Vec::new(),
+            alignments: Vec::new(),
+            justify_items: Align::Start,
+            align_items: Align::Start,
+            column_alignments: Vec::new(),
+            row_alignments: Vec::new(),
+            row_style: None,
+            sticky_header: false,
         }
     }
 
-    /// Adds an [`Element`](Element) to the [`Grid`](Grid).
-    pub fn push<E>(mut self, element: E) -> Self
+    /// Creates a new [`Grid`](Grid) with the given elements.
+    ///
+    /// The elements flow row by row into the columns set by
+    /// [`columns`](Grid::columns), so element `i` is placed in column
+    /// `i % columns` of row `i / columns`. Without a number of columns, the
+    /// elements are laid out in a single column.
+    pub fn with_children<I, E>(children: I) -> Self
     where
+        I: IntoIterator<Item = E>,
         E: Into<Element<'a, Message, Renderer>>,
     {
-        self.elements.push(element.into());
+        children.into_iter().fold(Self::with_columns(1), Self::push)
+    }
+
+    /// Sets the number of columns of the [`Grid`](Grid).
+    ///
+    /// This replaces a column width given by
+    /// [`with_column_width`](Grid::with_column_width).
+    #[must_use]
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.strategy = Strategy::Columns(columns);
+        self
+    }
+
+    /// Sets the widths of the columns of the [`Grid`](Grid).
+    ///
+    /// `Fill` and `FillPortion` columns share the space left by the other
+    /// columns. `Shrink` columns and the columns without a given width are
+    /// as wide as their widest cell, or as wide as the column width of a
+    /// [`Grid`](Grid) created by
+    /// [`with_column_width`](Grid::with_column_width).
+    #[must_use]
+    pub fn column_widths(mut self, widths: Vec<Length>) -> Self {
+        self.column_widths = widths;
+        self
+    }
+
+    /// Sets the heights of the rows of the [`Grid`](Grid).
+    ///
+    /// `Fill` and `FillPortion` rows share the space left by the other rows.
+    /// `Shrink` rows and the rows without a given height are as high as their
+    /// highest cell.
+    #[must_use]
+    pub fn row_heights(mut self, heights: Vec<Length>) -> Self {
+        self.row_heights = heights;
+        self
+    }
+
+    /// Sets the horizontal alignment of the cells of the [`Grid`](Grid) in
+    /// their columns.
+    ///
+    /// The default is [`Align::Start`](Align::Start).
+    #[must_use]
+    pub fn justify_items(mut self, justify: Align) -> Self {
+        self.justify_items = justify;
+        self
+    }
+
+    /// Sets the vertical alignment of the cells of the [`Grid`](Grid) in their
+    /// rows.
+    ///
+    /// The default is [`Align::Start`](Align::Start).
+    #[must_use]
+    pub fn align_items(mut self, align: Align) -> Self {
+        self.align_items = align;
+        self
+    }
+
+    /// Sets the horizontal alignments of the cells of each column, overriding
+    /// [`justify_items`](Grid::justify_items).
+    ///
+    /// Columns without a given alignment keep the alignment of the
+    /// [`Grid`](Grid). A spanning cell uses the alignment of its first column.
+    #[must_use]
+    pub fn column_alignments(mut self, alignments: Vec<Align>) -> Self {
+        self.column_alignments = alignments;
+        self
+    }
+
+    /// Sets the vertical alignments of the cells of each row, overriding
+    /// [`align_items`](Grid::align_items).
+    ///
+    /// Rows without a given alignment keep the alignment of the
+    /// [`Grid`](Grid). A spanning cell uses the alignment of its first row.
+    #[must_use]
+    pub fn row_alignments(mut self, alignments: Vec<Align>) -> Self {
+        self.row_alignments = alignments;
+        self
+    }
+
+    /// Sets the function giving the background color of each row of the
+    /// [`Grid`](Grid) by its index, or `None` for no background.
+    ///
+    /// The backgrounds are drawn behind the cells. A cell spanning several
+    /// rows is drawn on the background of its first row as a whole. By
+    /// default the rows have no background.
+    #[must_use]
+    pub fn row_style<F>(mut self, row_style: F) -> Self
+    where
+        F: 'a + Fn(usize) -> Option<Color>,
+    {
+        self.row_style = Some(Box::new(row_style));
         self
     }
 
+    /// Stripes the rows of the [`Grid`](Grid) by drawing every second row,
+    /// starting with the second one, on the given background color.
+    ///
+    /// This replaces the function given by [`row_style`](Grid::row_style).
+    #[must_use]
+    pub fn striped(self, color: Color) -> Self {
+        self.row_style(move |row| (row % 2 == 1).then_some(color))
+    }
+
+    /// Pins the first row of the [`Grid`](Grid) to the top of the viewport,
+    /// so the header of a table stays visible while the other rows scroll
+    /// underneath it inside a [`Scrollable`](iced_native::Scrollable).
+    ///
+    /// The header is drawn over the scrolled rows, so it should have a
+    /// background given by [`row_style`](Grid::row_style). It stays inside
+    /// the [`Grid`](Grid) and scrolls away with its last row. Only the drawing
+    /// is pinned: the `Scrollable` does not tell the [`Grid`](Grid) its offset
+    /// when handling events, so widgets in the header receive events at their
+    /// scrolled position. The header is best used for labels.
+    #[must_use]
+    pub fn sticky_header(mut self, sticky_header: bool) -> Self {
+        self.sticky_header = sticky_header;
+        self
+    }
+
+    /// Gets the pinned header row for the given viewport if the header is
+    /// sticky.
+    fn header(&self, layout: Layout<'_>, viewport: &Rectangle) -> Option<StickyHeader> {
+        if !self.sticky_header {
+            return None;
+        }
+
+        let mut trailing = layout.children().skip(self.elements.len());
+        let rows = self.row_style.as_ref().and_then(|_| trailing.next());
+        let header = trailing.next()?.bounds();
+        let bounds = layout.bounds();
+
+        let cells = layout
+            .children()
+            .take(self.elements.len())
+            .take_while(|cell| cell.bounds().y < header.y + header.height)
+            .count();
+        let backgrounds = match (
+            &self.row_style,
+            rows.and_then(|rows| rows.children().next()),
+        ) {
+            (Some(row_style), Some(areas)) if row_style(0).is_some() => areas.children().count(),
+            _ => 0,
+        };
+
+        Some(StickyHeader {
+            cells,
+            backgrounds,
+            offset: (viewport.y - header.y).clamp(0.0, (bounds.height - header.height).max(0.0)),
+        })
+    }
+
+    /// Gets the bounds and colors of the row backgrounds laid out behind the
+    /// cells.
+    fn row_backgrounds(&self, layout: Layout<'_>) -> Vec<(Rectangle, Color)> {
+        if let (Some(row_style), Some(rows)) =
+            (&self.row_style, layout.children().nth(self.elements.len()))
+        {
+            rows.children()
+                .enumerate()
+                .filter_map(|(row, areas)| row_style(row).map(|color| (areas, color)))
+                .flat_map(|(areas, color)| areas.children().map(move |area| (area.bounds(), color)))
+                .collect()
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Measures the sizes of the elements at the given placements. With a
+    /// column width every element is as wide as its columns.
+    fn measure(
+        &self,
+        renderer: &Renderer,
+        limits: &Limits,
+        placements: &[Placement],
+        column_width: Option<f32>,
+    ) -> Vec<Size> {
+        self.elements
+            .iter()
+            .zip(placements)
+            .map(|(element, placement)| {
+                #[allow(clippy::cast_precision_loss)]
+                let limits = column_width.map_or(*limits, |column_width| {
+                    let width = column_width * placement.column_span as f32;
+                    limits.width(Length::Units(width as u16))
+                });
+
+                element.layout(renderer, &limits).size()
+            })
+            .collect()
+    }
+
+    /// Adds an [`Element`](Element) to the [`Grid`](Grid).
+    pub fn push<E>(self, element: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.push_cell(Cell::new(element))
+    }
+
     /// Inserts an [`Element`](Element) into the [`Grid`](Grid).
     pub fn insert<E>(&mut self, element: E)
     where
         E: Into<Element<'a, Message, Renderer>>,
     {
-        self.elements.push(element.into());
+        self.insert_cell(Cell::new(element));
+    }
+
+    /// Adds a [`Cell`](Cell) to the [`Grid`](Grid).
+    ///
+    /// The cells are placed row by row into the first free slot after the
+    /// previous cell. If the span of a cell overlaps an already placed cell,
+    /// the span is clamped to end before it.
+    #[must_use]
+    pub fn push_cell(mut self, cell: Cell<'a, Message, Renderer>) -> Self {
+        self.insert_cell(cell);
+        self
+    }
+
+    /// Inserts a [`Cell`](Cell) into the [`Grid`](Grid).
+    pub fn insert_cell(&mut self, cell: Cell<'a, Message, Renderer>) {
+        self.elements.push(cell.element);
+        self.spans.push((cell.row_span, cell.column_span));
+        self.alignments.push((cell.justify, cell.align));
     }
 }
 
@@ -97,69 +443,151 @@ where
     Renderer: self::Renderer,
 {
     fn width(&self) -> Length {
-        Length::Shrink
+        if self
+            .column_widths
+            .iter()
+            .any(|width| width.fill_factor() > 0)
+        {
+            Length::Fill
+        } else {
+            Length::Shrink
+        }
     }
 
     fn height(&self) -> Length {
-        Length::Shrink
+        if self
+            .row_heights
+            .iter()
+            .any(|height| height.fill_factor() > 0)
+        {
+            Length::Fill
+        } else {
+            Length::Shrink
+        }
     }
 
+    #[allow(clippy::too_many_lines)]
     fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
         if self.elements.is_empty() {
             return Node::new(Size::ZERO);
         }
 
-        match self.strategy {
-            // find out how wide a column is by finding the widest cell in it
-            Strategy::Columns(columns) => {
-                if columns == 0 {
-                    return Node::new(Size::ZERO);
-                }
-
-                let mut layouts = Vec::with_capacity(self.elements.len());
-                let mut column_widths = Vec::<f32>::with_capacity(columns);
-
-                for (column, element) in (0..columns).cycle().zip(&self.elements) {
-                    let layout = element.layout(renderer, limits).size();
-                    layouts.push(layout);
-
-                    if let Some(column_width) = column_widths.get_mut(column) {
-                        *column_width = column_width.max(layout.width);
-                    } else {
-                        column_widths.insert(column, layout.width);
-                    }
-                }
-
-                let column_aligns =
-                    std::iter::once(&0.)
-                        .chain(column_widths.iter())
-                        .scan(0., |state, width| {
-                            *state += width;
-                            Some(*state)
-                        });
-                let grid_width = column_widths.iter().sum();
-
-                build_grid(columns, column_aligns, layouts.into_iter(), grid_width)
-            }
+        let (columns, column_width) = match self.strategy {
+            Strategy::Columns(columns) => (columns, None),
             // find number of columns by checking how many can fit
             Strategy::ColumnWidth(column_width) => {
-                let column_limits = limits.width(Length::Units(column_width));
-                let column_width: f32 = column_width.into();
-                let max_width = limits.max().width;
-                let columns = (max_width / column_width).floor() as usize;
-
-                let layouts = self
-                    .elements
+                let column_width = f32::from(column_width);
+                // An unbounded width or a zero column width would fit
+                // infinitely many columns, so there are never more columns
+                // than elements.
+                let columns = (limits.max().width / column_width).floor() as usize;
+                (columns.min(self.elements.len()).max(1), Some(column_width))
+            }
+        };
+
+        let placements = place(columns, self.spans.iter().copied());
+        let sizes = self.measure(renderer, limits, &placements, column_width);
+
+        // find out how wide a column is by finding the widest cell in it
+        let measured_columns = column_width.map_or_else(
+            || {
+                track_sizes(
+                    columns,
+                    &placements
+                        .iter()
+                        .zip(&sizes)
+                        .map(|(placement, size)| {
+                            (placement.column, placement.column_span, size.width)
+                        })
+                        .collect::<Vec<_>>(),
+                )
+            },
+            |column_width| vec![column_width; columns],
+        );
+        let column_widths = resolve_tracks(
+            &tracks(&self.column_widths, &measured_columns),
+            limits.max().width,
+        );
+
+        let rows = placements
+            .iter()
+            .map(|placement| placement.row + placement.row_span)
+            .max()
+            .unwrap_or(0);
+        let measured_rows = track_sizes(
+            rows,
+            &placements
+                .iter()
+                .zip(&sizes)
+                .map(|(placement, size)| (placement.row, placement.row_span, size.height))
+                .collect::<Vec<_>>(),
+        );
+        let row_heights = resolve_tracks(
+            &tracks(&self.row_heights, &measured_rows),
+            limits.max().height,
+        );
+
+        let column_offsets = track_offsets(&column_widths);
+        let row_offsets = track_offsets(&row_heights);
+
+        let mut nodes: Vec<Node> = self
+            .elements
+            .iter()
+            .zip(&placements)
+            .zip(&self.alignments)
+            .map(|((element, placement), (justify, align))| {
+                let width: f32 = column_widths
+                    [placement.column..placement.column + placement.column_span]
                     .iter()
-                    .map(|element| element.layout(renderer, &column_limits).size());
-                let column_aligns =
-                    std::iter::successors(Some(0.), |width| Some(width + column_width));
-                #[allow(clippy::cast_precision_loss)] // TODO: possible precision loss
-                let grid_width = (columns as f32) * column_width;
+                    .sum();
+                let height: f32 = row_heights[placement.row..placement.row + placement.row_span]
+                    .iter()
+                    .sum();
+                // with a column width every cell is as wide as its columns
+                let min_width = column_width.map_or(0.0, |_| width);
 
-                build_grid(columns, column_aligns, layouts, grid_width)
-            }
+                let mut node = element.layout(
+                    renderer,
+                    &Limits::new(Size::new(min_width, 0.0), Size::new(width, height)),
+                );
+                node.move_to(Point::new(
+                    column_offsets[placement.column],
+                    row_offsets[placement.row],
+                ));
+                node.align(
+                    justify
+                        .or_else(|| self.column_alignments.get(placement.column).copied())
+                        .unwrap_or(self.justify_items),
+                    align
+                        .or_else(|| self.row_alignments.get(placement.row).copied())
+                        .unwrap_or(self.align_items),
+                    Size::new(width, height),
+                );
+                node
+            })
+            .collect();
+
+        let size = Size::new(column_widths.iter().sum(), row_heights.iter().sum());
+
+        if self.row_style.is_some() {
+            // The row backgrounds follow the cells.
+            nodes.push(row_background_nodes(
+                row_areas(columns, rows, &placements),
+                (&column_widths, &column_offsets),
+                (&row_heights, &row_offsets),
+                size,
+            ));
         }
+
+        if self.sticky_header {
+            // The header row follows the row backgrounds.
+            nodes.push(Node::new(Size::new(
+                size.width,
+                row_heights.first().copied().unwrap_or(0.0),
+            )));
+        }
+
+        Node::with_children(size, nodes)
     }
 
     fn on_event(
@@ -200,7 +628,15 @@ where
         cursor_position: Point,
         viewport: &iced_graphics::Rectangle,
     ) -> Renderer::Output {
-        renderer.draw(defaults, layout, cursor_position, viewport, &self.elements)
+        renderer.draw(
+            defaults,
+            layout,
+            cursor_position,
+            viewport,
+            &self.elements,
+            &self.row_backgrounds(layout),
+            self.header(layout, viewport),
+        )
     }
 
     fn hash_layout(&self, state: &mut Hasher) {
@@ -211,35 +647,82 @@ where
         for element in &self.elements {
             element.hash_layout(state);
         }
+
+        self.spans.hash(state);
+        self.column_widths.hash(state);
+        self.row_heights.hash(state);
+        self.alignments.hash(state);
+        self.justify_items.hash(state);
+        self.align_items.hash(state);
+        self.column_alignments.hash(state);
+        self.row_alignments.hash(state);
+        self.row_style.is_some().hash(state);
+        self.sticky_header.hash(state);
     }
 }
 
-/// Builds the layout of the [`Grid`](grid).
-fn build_grid(
-    columns: usize,
-    column_aligns: impl Iterator<Item = f32> + Clone,
-    layouts: impl Iterator<Item = Size> + ExactSizeIterator,
-    grid_width: f32,
+/// Lays out the given areas of the row backgrounds of a [`Grid`](Grid)
+/// grouped by row, given the sizes and offsets of the columns and rows.
+fn row_background_nodes(
+    areas: Vec<Vec<Placement>>,
+    (column_widths, column_offsets): (&[f32], &[f32]),
+    (row_heights, row_offsets): (&[f32], &[f32]),
+    size: Size,
 ) -> Node {
-    let mut nodes = Vec::with_capacity(layouts.len());
-    let mut grid_height = 0.;
-    let mut row_height = 0.;
-
-    for ((column, column_align), size) in (0..columns).zip(column_aligns).cycle().zip(layouts) {
-        if column == 0 {
-            grid_height += row_height;
-            row_height = 0.;
-        }
-
-        let mut node = Node::new(size);
-        node.move_to(Point::new(column_align, grid_height));
-        nodes.push(node);
-        row_height = row_height.max(size.height);
-    }
+    let rows = areas
+        .into_iter()
+        .map(|areas| {
+            let areas = areas
+                .into_iter()
+                .map(|area| {
+                    let mut node = Node::new(Size::new(
+                        column_widths[area.column..area.column + area.column_span]
+                            .iter()
+                            .sum(),
+                        row_heights[area.row..area.row + area.row_span].iter().sum(),
+                    ));
+                    node.move_to(Point::new(
+                        column_offsets[area.column],
+                        row_offsets[area.row],
+                    ));
+                    node
+                })
+                .collect();
+
+            Node::with_children(size, areas)
+        })
+        .collect();
+
+    Node::with_children(size, rows)
+}
 
-    grid_height += row_height;
+/// Turns the given lengths of the tracks of a [`Grid`](Grid) into
+/// [`Track`](Track)s. Tracks without a length or with a `Shrink` length keep
+/// their measured size.
+fn tracks(lengths: &[Length], measured: &[f32]) -> Vec<Track> {
+    measured
+        .iter()
+        .enumerate()
+        .map(|(index, measured)| match lengths.get(index) {
+            Some(Length::Units(units)) => Track::Size(f32::from(*units)),
+            Some(length) if length.fill_factor() > 0 => Track::Fill {
+                portion: length.fill_factor(),
+                measured: *measured,
+            },
+            _ => Track::Size(*measured),
+        })
+        .collect()
+}
 
-    Node::with_children(Size::new(grid_width, grid_height), nodes)
+/// The first row of a [`Grid`](Grid) pinned to the top of the viewport.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StickyHeader {
+    /// The number of leading elements in the header row.
+    pub cells: usize,
+    /// The number of leading row backgrounds of the header row.
+    pub backgrounds: usize,
+    /// The vertical offset of the header row from its laid out position.
+    pub offset: f32,
 }
 
 /// The renderer of a [`Grid`](Grid).
@@ -251,6 +734,9 @@ pub trait Renderer: iced_native::Renderer {
     ///
     /// In addition to the default parameters, it expects:
     /// - the list of [`Element`](Element)s
+    /// - the bounds and colors of the row backgrounds to draw behind them
+    /// - the optional [`StickyHeader`](StickyHeader) to draw over the other rows
+    #[allow(clippy::too_many_arguments)]
     fn draw<Message>(
         &mut self,
         defaults: &Self::Defaults,
@@ -258,6 +744,8 @@ pub trait Renderer: iced_native::Renderer {
         cursor_position: Point,
         viewport: &iced_graphics::Rectangle,
         elements: &[Element<'_, Message, Self>],
+        row_backgrounds: &[(Rectangle, Color)],
+        header: Option<StickyHeader>,
     ) -> Self::Output;
 }
 
@@ -270,6 +758,8 @@ impl Renderer for iced_native::renderer::Null {
         _cursor_position: Point,
         _viewport: &iced_graphics::Rectangle,
         _elements: &[Element<'_, Message, Self>],
+        _row_backgrounds: &[(Rectangle, Color)],
+        _header: Option<StickyHeader>,
     ) {
     }
 }
@@ -283,3 +773,214 @@ where
         Element::new(grid)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use iced_native::{
+        layout::Limits, renderer::Null, Align, Color, Layout, Length, Point, Rectangle, Size, Text,
+        Widget,
+    };
+
+    use super::{Cell, Grid, StickyHeader};
+
+    fn column_offsets(grid: &Grid<'_, (), Null>, width: f32) -> Vec<(f32, f32)> {
+        let node = grid.layout(
+            &Null::new(),
+            &Limits::new(Size::ZERO, Size::new(width, 100.0)),
+        );
+
+        Layout::new(&node)
+            .children()
+            .map(|child| (child.bounds().x, child.bounds().width))
+            .collect()
+    }
+
+    #[test]
+    fn fixed_and_fill_columns() {
+        let grid = Grid::with_columns(3)
+            .column_widths(vec![Length::Units(100), Length::Fill, Length::Fill])
+            .push(Text::new("Fixed").width(Length::Fill))
+            .push(Text::new("First").width(Length::Fill))
+            .push(Text::new("Second").width(Length::Fill));
+
+        assert_eq!(
+            column_offsets(&grid, 400.0),
+            vec![(0.0, 100.0), (100.0, 150.0), (250.0, 150.0)]
+        );
+    }
+
+    #[test]
+    fn column_width_under_unbounded_limit() {
+        let grid = Grid::with_column_width(50)
+            .push(Text::new("First").width(Length::Fill))
+            .push(Text::new("Second").width(Length::Fill))
+            .push(Text::new("Third").width(Length::Fill));
+
+        assert_eq!(
+            column_offsets(&grid, f32::INFINITY),
+            vec![(0.0, 50.0), (50.0, 50.0), (100.0, 50.0)]
+        );
+        assert_eq!(
+            column_offsets(&grid, 120.0),
+            vec![(0.0, 50.0), (50.0, 50.0), (0.0, 50.0)]
+        );
+    }
+
+    #[test]
+    fn explicit_widths_with_zero_column_width() {
+        let grid = Grid::with_column_width(0)
+            .column_widths(vec![Length::Units(30), Length::Fill])
+            .push(Text::new("Label").width(Length::Fill))
+            .push(Text::new("Value").width(Length::Fill));
+
+        assert_eq!(
+            column_offsets(&grid, 100.0),
+            vec![(0.0, 30.0), (30.0, 70.0)]
+        );
+    }
+
+    #[test]
+    fn spanning_cell() {
+        let grid = Grid::with_columns(2)
+            .column_widths(vec![Length::Units(50), Length::Units(70)])
+            .push_cell(Cell::new(Text::new("Header").width(Length::Fill)).column_span(2))
+            .push(Text::new("Label").width(Length::Fill))
+            .push(Text::new("Value").width(Length::Fill));
+
+        assert_eq!(
+            column_offsets(&grid, 400.0),
+            vec![(0.0, 120.0), (0.0, 50.0), (50.0, 70.0)]
+        );
+    }
+
+    #[test]
+    fn aligned_cells() {
+        let grid = Grid::with_columns(2)
+            .column_widths(vec![Length::Units(100), Length::Units(100)])
+            .column_alignments(vec![Align::End])
+            .justify_items(Align::Center)
+            .push(Text::new("Number").width(Length::Units(40)))
+            .push(Text::new("Text").width(Length::Units(40)))
+            .push_cell(Cell::new(Text::new("Left").width(Length::Units(40))).justify(Align::Start));
+
+        assert_eq!(
+            column_offsets(&grid, 400.0),
+            vec![(60.0, 40.0), (130.0, 40.0), (0.0, 40.0)]
+        );
+    }
+
+    #[test]
+    fn children_flow_into_columns() {
+        let grid: Grid<'_, (), Null> = Grid::with_children((0..5).map(|_| {
+            Text::new("Item")
+                .width(Length::Units(10))
+                .height(Length::Units(20))
+        }))
+        .columns(2);
+
+        let node = grid.layout(
+            &Null::new(),
+            &Limits::new(Size::ZERO, Size::new(400.0, 400.0)),
+        );
+        let positions: Vec<(f32, f32)> = Layout::new(&node)
+            .children()
+            .map(|child| (child.bounds().x, child.bounds().y))
+            .collect();
+
+        assert_eq!(
+            positions,
+            vec![
+                (0.0, 0.0),
+                (10.0, 0.0),
+                (0.0, 20.0),
+                (10.0, 20.0),
+                (0.0, 40.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn striped_rows_with_spanning_cell() {
+        let cell = || {
+            Text::new("Cell")
+                .width(Length::Fill)
+                .height(Length::Units(20))
+        };
+        let grid: Grid<'_, (), Null> = Grid::with_columns(2)
+            .column_widths(vec![Length::Units(50), Length::Units(70)])
+            .striped(Color::BLACK)
+            .push(cell())
+            .push(cell())
+            .push_cell(Cell::new(cell()).row_span(2))
+            .push(cell())
+            .push(cell());
+
+        let node = grid.layout(
+            &Null::new(),
+            &Limits::new(Size::ZERO, Size::new(400.0, 400.0)),
+        );
+
+        // The spanning cell is drawn as a whole on the background of its
+        // first row, the second one.
+        assert_eq!(
+            grid.row_backgrounds(Layout::new(&node)),
+            vec![
+                (
+                    Rectangle::new(Point::new(50.0, 20.0), Size::new(70.0, 20.0)),
+                    Color::BLACK
+                ),
+                (
+                    Rectangle::new(Point::new(0.0, 20.0), Size::new(50.0, 40.0)),
+                    Color::BLACK
+                ),
+            ]
+        );
+
+        // The row backgrounds follow the cells.
+        assert_eq!(Layout::new(&node).children().count(), 6);
+    }
+
+    #[test]
+    fn sticky_header_follows_viewport() {
+        let cell = || {
+            Text::new("Cell")
+                .width(Length::Fill)
+                .height(Length::Units(20))
+        };
+        let grid: Grid<'_, (), Null> = Grid::with_children((0..10).map(|_| cell()))
+            .columns(2)
+            .row_style(|row| (row == 0).then_some(Color::WHITE))
+            .sticky_header(true);
+
+        let node = grid.layout(
+            &Null::new(),
+            &Limits::new(Size::ZERO, Size::new(400.0, 400.0)),
+        );
+        let header = |y: f32| {
+            grid.header(
+                Layout::new(&node),
+                &Rectangle::new(Point::new(0.0, y), Size::new(400.0, 50.0)),
+            )
+        };
+
+        let sticky_header = |offset: f32| {
+            Some(StickyHeader {
+                cells: 2,
+                backgrounds: 1,
+                offset,
+            })
+        };
+
+        assert_eq!(header(0.0), sticky_header(0.0));
+        assert_eq!(header(30.0), sticky_header(30.0));
+        // The header scrolls away with the last row.
+        assert_eq!(header(500.0), sticky_header(80.0));
+        assert_eq!(
+            grid.sticky_header(false).header(
+                Layout::new(&node),
+                &Rectangle::new(Point::ORIGIN, Size::new(400.0, 50.0))
+            ),
+            None
+        );
+    }
+}
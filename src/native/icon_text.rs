@@ -6,8 +6,10 @@
 //! //! *This API requires the following crate features to be activated: `icon_text`*
 use std::hash::Hash;
 
+use crate::graphics::icons::Icon;
 use iced_native::{
-    Color, Element, HorizontalAlignment, Length, Rectangle, Size, VerticalAlignment, Widget,
+    event, mouse, touch, Clipboard, Color, Element, Event, HorizontalAlignment, Layout, Length,
+    Point, Rectangle, Size, VerticalAlignment, Widget,
 };
 
 /// Text widget with icon font.
@@ -21,6 +23,9 @@ pub struct IconText<Renderer: self::Renderer> {
     color: Option<Color>,
     /// The optional font of the [`IconText`](IconText).
     font: Option<Renderer::Font>,
+    /// The fonts that are tried in order if the font of the
+    /// [`IconText`](IconText) can't display its content.
+    font_fallback: Vec<Renderer::Font>,
     /// The width of the [`IconText`](IconText).
     width: Length,
     /// The height of the [`IconText`](IconText).
@@ -29,6 +34,11 @@ pub struct IconText<Renderer: self::Renderer> {
     horizontal_alignment: HorizontalAlignment,
     /// The vertical alignment of the [`IconText`](IconText).
     vertical_alignment: VerticalAlignment,
+    /// Swaps the meaning of the left and right alignment for a right-to-left
+    /// layout.
+    rtl: bool,
+    /// The optional human-readable description of the icon.
+    accessibility_label: Option<String>,
 }
 
 impl<Renderer: self::Renderer> IconText<Renderer> {
@@ -42,13 +52,22 @@ impl<Renderer: self::Renderer> IconText<Renderer> {
             size: None,
             color: None,
             font: None,
+            font_fallback: Vec::new(),
             width: Length::Shrink,
             height: Length::Shrink,
             horizontal_alignment: HorizontalAlignment::Center,
             vertical_alignment: VerticalAlignment::Center,
+            rtl: false,
+            accessibility_label: None,
         }
     }
 
+    /// Creates a new [`IconText`](IconText) displaying the given
+    /// [`Icon`](crate::graphics::icons::Icon).
+    pub fn from_icon(icon: Icon) -> Self {
+        Self::new(icon)
+    }
+
     /// Sets the size of the [`IconText`](IconText).
     pub fn size(mut self, size: u16) -> Self {
         self.size = Some(size);
@@ -67,6 +86,13 @@ impl<Renderer: self::Renderer> IconText<Renderer> {
         self
     }
 
+    /// Sets the fonts that are tried in order if the font of the
+    /// [`IconText`](IconText) can't display its content.
+    pub fn font_fallback(mut self, fonts: Vec<Renderer::Font>) -> Self {
+        self.font_fallback = fonts;
+        self
+    }
+
     /// Sets the width of the [`IconText`](IconText) boundaries.
     pub fn width(mut self, width: Length) -> Self {
         self.width = width;
@@ -92,6 +118,103 @@ impl<Renderer: self::Renderer> IconText<Renderer> {
         self.vertical_alignment = alignment;
         self
     }
+
+    /// Lays out the [`IconText`](IconText) from right to left.
+    ///
+    /// The meaning of [`HorizontalAlignment::Left`](iced_native::HorizontalAlignment::Left)
+    /// and [`HorizontalAlignment::Right`](iced_native::HorizontalAlignment::Right)
+    /// is swapped, so left aligns the icon at the start of the line on the
+    /// right. Right-to-left layouts are also supported by
+    /// [`Badge::rtl`](crate::native::Badge::rtl) and
+    /// [`BadgedContent::rtl`](crate::native::BadgedContent::rtl).
+    pub fn rtl(mut self, rtl: bool) -> Self {
+        self.rtl = rtl;
+        self
+    }
+
+    /// Sets the human-readable description of the icon of the
+    /// [`IconText`](IconText), like "Delete" for a trash can.
+    ///
+    /// The codepoint of an icon is meaningless to assistive technologies and
+    /// tooltips, so icon-only buttons should describe their action. The label
+    /// does not affect the rendering; it is only stored to be surfaced through
+    /// [`accessible_name`](IconText::accessible_name).
+    pub fn accessibility_label(mut self, label: impl Into<String>) -> Self {
+        self.accessibility_label = Some(label.into());
+        self
+    }
+
+    /// Makes the [`IconText`](IconText) pressable, producing the given message
+    /// when it is pressed and released again within its bounds.
+    ///
+    /// This is a shorthand for [`PressableIconText::new`](PressableIconText::new).
+    pub fn on_press<Message>(
+        self,
+        state: &mut State,
+        message: Message,
+    ) -> PressableIconText<'_, Message, Renderer> {
+        PressableIconText::new(state, self, message)
+    }
+
+    /// Returns the human-readable description of the icon of the
+    /// [`IconText`](IconText), if one was set by
+    /// [`accessibility_label`](IconText::accessibility_label).
+    pub fn accessible_name(&self) -> Option<&str> {
+        self.accessibility_label.as_deref()
+    }
+
+    /// Returns the first font of the [`IconText`](IconText) that is able to
+    /// display its content.
+    ///
+    /// A font is considered to be missing the glyph if the measured width
+    /// of the content is zero. If no font covers the content, the primary
+    /// font will be used.
+    fn resolve_font(&self, renderer: &Renderer, size: u16) -> Renderer::Font {
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+
+        if self.font_fallback.is_empty() {
+            return font;
+        }
+
+        std::iter::once(font)
+            .chain(self.font_fallback.iter().copied())
+            .find(|font| {
+                // The text backends can't be asked whether a font covers a
+                // glyph, so an empty measurement is taken as it missing one.
+                let (width, _) = renderer.measure(&self.content, size, *font, Size::INFINITY);
+                width > 0.0
+            })
+            .unwrap_or(font)
+    }
+
+    /// Draws the [`IconText`](IconText) in the given bounds.
+    fn draw_icon(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        bounds: Rectangle,
+        is_mouse_over: bool,
+    ) -> Renderer::Output {
+        let size = self.size.unwrap_or_else(|| renderer.default_size());
+        let font = self.resolve_font(renderer, size);
+        let horizontal_alignment = match self.horizontal_alignment {
+            HorizontalAlignment::Left if self.rtl => HorizontalAlignment::Right,
+            HorizontalAlignment::Right if self.rtl => HorizontalAlignment::Left,
+            alignment => alignment,
+        };
+
+        renderer.draw(
+            defaults,
+            bounds,
+            &self.content,
+            size,
+            font,
+            self.color,
+            horizontal_alignment,
+            self.vertical_alignment,
+            is_mouse_over,
+        )
+    }
 }
 
 impl<Message, Renderer> Widget<Message, Renderer> for IconText<Renderer>
@@ -120,7 +243,7 @@ where
         let (width, height) = renderer.measure(
             &self.content,
             size,
-            self.font.unwrap_or_else(|| renderer.default_font()),
+            self.resolve_font(renderer, size),
             bounds,
         );
 
@@ -137,16 +260,7 @@ where
         _cursor_position: iced_graphics::Point,
         _viewport: &iced_graphics::Rectangle,
     ) -> Renderer::Output {
-        renderer.draw(
-            defaults,
-            layout.bounds(),
-            &self.content,
-            self.size.unwrap_or_else(|| renderer.default_size()),
-            self.font,
-            self.color,
-            self.horizontal_alignment,
-            self.vertical_alignment,
-        )
+        self.draw_icon(renderer, defaults, layout.bounds(), false)
     }
 
     fn hash_layout(&self, state: &mut iced_native::Hasher) {
@@ -156,8 +270,13 @@ where
 
         self.content.hash(state);
         self.size.hash(state);
+        self.font.is_some().hash(state);
+        for font in self.font.iter().chain(&self.font_fallback) {
+            Renderer::hash_font(font, state);
+        }
         self.width.hash(state);
         self.height.hash(state);
+        self.accessibility_label.hash(state);
     }
 }
 
@@ -175,11 +294,18 @@ pub trait Renderer: iced_native::Renderer {
     /// Returns the default font of [`IconText`](IconText).
     fn default_font(&self) -> Self::Font;
 
+    /// Feeds the given font into the layout hash of an
+    /// [`IconText`](IconText).
+    fn hash_font(font: &Self::Font, state: &mut iced_native::Hasher);
+
     /// Measures the [`IconText`](IconText) in the given bounds and returns the
     /// minimum boundaries that can fit the contents.
     fn measure(&self, content: &str, size: u16, font: Self::Font, bounds: Size) -> (f32, f32);
 
     /// Draws an [`IconText`](IconText).
+    ///
+    /// The mouse is only reported to be over a
+    /// [`PressableIconText`](PressableIconText).
     #[allow(clippy::too_many_arguments)]
     fn draw(
         &mut self,
@@ -187,13 +313,53 @@ pub trait Renderer: iced_native::Renderer {
         bounds: Rectangle,
         content: &str,
         size: u16,
-        font: Option<Self::Font>,
+        font: Self::Font,
         color: Option<Color>,
         horizontal_alignment: HorizontalAlignment,
         vertical_alignment: VerticalAlignment,
+        is_mouse_over: bool,
     ) -> Self::Output;
 }
 
+#[cfg(debug_assertions)]
+impl Renderer for iced_native::renderer::Null {
+    type Font = iced_native::Font;
+
+    fn default_size(&self) -> u16 {
+        iced_native::text::Renderer::default_size(self)
+    }
+
+    fn default_font(&self) -> Self::Font {
+        iced_native::Font::Default
+    }
+
+    fn hash_font(font: &Self::Font, state: &mut iced_native::Hasher) {
+        match font {
+            iced_native::Font::Default => None,
+            iced_native::Font::External { name, .. } => Some(name),
+        }
+        .hash(state);
+    }
+
+    fn measure(&self, content: &str, size: u16, font: Self::Font, bounds: Size) -> (f32, f32) {
+        iced_native::text::Renderer::measure(self, content, size, font, bounds)
+    }
+
+    fn draw(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _bounds: Rectangle,
+        _content: &str,
+        _size: u16,
+        _font: Self::Font,
+        _color: Option<Color>,
+        _horizontal_alignment: HorizontalAlignment,
+        _vertical_alignment: VerticalAlignment,
+        _is_mouse_over: bool,
+    ) -> Self::Output {
+    }
+}
+
 impl<'a, Message, Renderer> From<IconText<Renderer>> for Element<'a, Message, Renderer>
 where
     Renderer: self::Renderer + 'a,
@@ -210,10 +376,225 @@ impl<Renderer: self::Renderer> Clone for IconText<Renderer> {
             size: self.size,
             color: self.color,
             font: self.font,
+            font_fallback: self.font_fallback.clone(),
             width: self.width,
             height: self.height,
             horizontal_alignment: self.horizontal_alignment,
             vertical_alignment: self.vertical_alignment,
+            rtl: self.rtl,
+            accessibility_label: self.accessibility_label.clone(),
+        }
+    }
+}
+
+/// An [`IconText`](IconText) that produces a message when it is pressed, so
+/// that an icon can act as a button.
+///
+/// The message is only produced if the icon is pressed and released again
+/// within its bounds.
+#[allow(missing_debug_implementations)]
+pub struct PressableIconText<'a, Message, Renderer: self::Renderer> {
+    /// The state of the [`PressableIconText`](PressableIconText).
+    state: &'a mut State,
+    /// The pressable [`IconText`](IconText).
+    icon: IconText<Renderer>,
+    /// The message that is send if the [`PressableIconText`](PressableIconText) is pressed.
+    on_press: Message,
+}
+
+impl<'a, Message, Renderer: self::Renderer> PressableIconText<'a, Message, Renderer> {
+    /// Creates a new [`PressableIconText`](PressableIconText) of the given
+    /// [`IconText`](IconText).
+    ///
+    /// It expects:
+    ///     * the local [`State`](State) of the [`PressableIconText`](PressableIconText).
+    ///     * the [`IconText`](IconText) to be pressed.
+    ///     * the message that is send if the [`IconText`](IconText) is pressed
+    ///         and released again within its bounds.
+    pub fn new(state: &'a mut State, icon: IconText<Renderer>, on_press: Message) -> Self {
+        Self {
+            state,
+            icon,
+            on_press,
         }
     }
 }
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for PressableIconText<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.icon.width
+    }
+
+    fn height(&self) -> Length {
+        self.icon.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &iced_native::layout::Limits,
+    ) -> iced_native::layout::Node {
+        <IconText<Renderer> as Widget<Message, Renderer>>::layout(&self.icon, renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        let is_mouse_over = layout.bounds().contains(cursor_position);
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if is_mouse_over =>
+            {
+                self.state.is_pressed = true;
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+                if self.state.is_pressed =>
+            {
+                self.state.is_pressed = false;
+
+                if is_mouse_over {
+                    messages.push(self.on_press.clone());
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            Event::Touch(touch::Event::FingerLost { .. }) => {
+                self.state.is_pressed = false;
+                event::Status::Ignored
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: iced_native::Layout<'_>,
+        cursor_position: iced_graphics::Point,
+        _viewport: &iced_graphics::Rectangle,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+
+        self.icon
+            .draw_icon(renderer, defaults, bounds, bounds.contains(cursor_position))
+    }
+
+    fn hash_layout(&self, state: &mut iced_native::Hasher) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        <IconText<Renderer> as Widget<Message, Renderer>>::hash_layout(&self.icon, state);
+    }
+}
+
+impl<'a, Message, Renderer> From<PressableIconText<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: self::Renderer + 'a,
+{
+    fn from(icon: PressableIconText<'a, Message, Renderer>) -> Element<'a, Message, Renderer> {
+        Element::new(icon)
+    }
+}
+
+/// The state of a [`PressableIconText`](PressableIconText).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct State {
+    /// If the [`PressableIconText`](PressableIconText) is currently pressed.
+    is_pressed: bool,
+}
+
+impl State {
+    /// Creates a new [`State`](State) for a [`PressableIconText`](PressableIconText).
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iced_native::{
+        clipboard, layout::Limits, mouse, renderer::Null, Event, Layout, Point, Size, Widget,
+    };
+
+    use std::hash::Hasher;
+
+    use super::{IconText, PressableIconText, State};
+
+    fn send(icon: &mut PressableIconText<'_, u8, Null>, events: &[(Event, Point)]) -> Vec<u8> {
+        let renderer = Null::new();
+        let node = icon.layout(&renderer, &Limits::new(Size::ZERO, Size::new(20.0, 20.0)));
+        let mut messages = Vec::new();
+        for (event, position) in events {
+            let _ = icon.on_event(
+                event.clone(),
+                Layout::new(&node),
+                *position,
+                &renderer,
+                &mut clipboard::Null,
+                &mut messages,
+            );
+        }
+        messages
+    }
+
+    #[test]
+    fn press_needs_press_and_release_inside() {
+        let press = Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left));
+        let release = Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left));
+        let inside = Point::new(5.0, 5.0);
+        let outside = Point::new(50.0, 50.0);
+
+        let mut state = State::new();
+        let icon = IconText::new("\u{f25c}").width(iced_native::Length::Fill);
+        let mut pressable = icon.on_press(&mut state, 1);
+
+        assert_eq!(
+            send(
+                &mut pressable,
+                &[(press.clone(), inside), (release.clone(), inside)]
+            ),
+            vec![1]
+        );
+        assert!(send(
+            &mut pressable,
+            &[(press.clone(), inside), (release.clone(), outside)]
+        )
+        .is_empty());
+        assert!(send(&mut pressable, &[(press, outside), (release, inside)]).is_empty());
+    }
+
+    #[test]
+    fn layout_hash_covers_fallback_fonts() {
+        fn hash(icon: &IconText<Null>) -> u64 {
+            let mut hasher = iced_native::Hasher::default();
+            <IconText<Null> as Widget<(), Null>>::hash_layout(icon, &mut hasher);
+            hasher.finish()
+        }
+
+        let font = |name| iced_native::Font::External { name, bytes: &[] };
+        let icon = |fonts| IconText::new("\u{f25c}").font_fallback(fonts);
+
+        assert_eq!(hash(&icon(vec![font("A")])), hash(&icon(vec![font("A")])));
+        assert_ne!(hash(&icon(vec![font("A")])), hash(&icon(vec![font("B")])));
+    }
+}
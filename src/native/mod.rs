@@ -5,7 +5,7 @@ pub mod overlay;
 #[cfg(feature = "badge")]
 pub mod badge;
 #[cfg(feature = "badge")]
-pub use badge::Badge;
+pub use badge::{Badge, BadgedContent};
 
 #[cfg(feature = "card")]
 pub mod card;
@@ -22,6 +22,11 @@ pub mod date_picker;
 #[cfg(feature = "date_picker")]
 pub use date_picker::DatePicker;
 
+#[cfg(feature = "date_time_picker")]
+pub mod date_time_picker;
+#[cfg(feature = "date_time_picker")]
+pub use date_time_picker::DateTimePicker;
+
 #[cfg(feature = "floating_button")]
 pub mod floating_button;
 #[cfg(feature = "floating_button")]
@@ -30,7 +35,7 @@ pub use floating_button::FloatingButton;
 #[cfg(feature = "icon_text")]
 pub mod icon_text;
 #[cfg(feature = "icon_text")]
-pub use icon_text::IconText;
+pub use icon_text::{IconText, PressableIconText};
 
 #[cfg(feature = "grid")]
 pub mod grid;
@@ -3,7 +3,10 @@
 //! *This API requires the following crate features to be activated: modal*
 use std::hash::Hash;
 
-use iced_native::{event, overlay, Clipboard, Element, Event, Layout, Point, Widget};
+use iced_native::{
+    event, overlay, Clipboard, Element, Event, HorizontalAlignment, Layout, Point,
+    VerticalAlignment, Widget,
+};
 
 pub use super::overlay::modal::Renderer;
 use super::overlay::modal::{self, ModalOverlay};
@@ -34,6 +37,16 @@ use super::overlay::modal::{self, ModalOverlay};
 /// )
 /// .backdrop(Message::CloseModal);
 /// ```
+///
+/// Modals can be stacked by wrapping a [`Modal`](Modal) in another one. The
+/// outer [`Modal`](Modal) is shown on top of the inner one, which stays
+/// visible below it without dimming the background twice. Only the topmost
+/// shown [`Modal`](Modal) receives the input, so the Escape key and clicks on
+/// the backdrop only dismiss the top layer.
+///
+/// The content can contain widgets producing an overlay by themselves, like
+/// a [`DatePicker`](crate::native::DatePicker). Their overlay is drawn above
+/// the content and receives the input first.
 #[allow(missing_debug_implementations)]
 pub struct Modal<'a, S, Content, Message, Renderer>
 where
@@ -50,8 +63,18 @@ where
     content: Content,
     /// The optional message that will be send when the user clicked on the backdrop.
     backdrop: Option<Message>,
+    /// Toggles sending the backdrop message when the user clicked on the backdrop.
+    backdrop_dismiss: bool,
     /// The optional message that will be send when the ESC key was pressed.
     esc: Option<Message>,
+    /// The horizontal alignment of the content of the [`ModalOverlay`](ModalOverlay).
+    horizontal_alignment: HorizontalAlignment,
+    /// The vertical alignment of the content of the [`ModalOverlay`](ModalOverlay).
+    vertical_alignment: VerticalAlignment,
+    /// The maximum width of the content of the [`ModalOverlay`](ModalOverlay).
+    max_width: u32,
+    /// The maximum height of the content of the [`ModalOverlay`](ModalOverlay).
+    max_height: u32,
     /// The style of the [`ModalOverlay`](ModalOverlay).
     style: Renderer::Style,
 }
@@ -83,7 +106,12 @@ where
             underlay: underlay.into(),
             content,
             backdrop: None,
+            backdrop_dismiss: true,
             esc: None,
+            horizontal_alignment: HorizontalAlignment::Center,
+            vertical_alignment: VerticalAlignment::Center,
+            max_width: u32::MAX,
+            max_height: u32::MAX,
             style: Renderer::Style::default(),
         }
     }
@@ -95,6 +123,15 @@ where
         self
     }
 
+    /// Sets whether clicking the backdrop of the [`Modal`](Modal) produces the
+    /// message set by [`backdrop`](Modal::backdrop).
+    ///
+    /// The default is true. Clicks on the backdrop never reach the underlay.
+    pub fn backdrop_dismiss(mut self, backdrop_dismiss: bool) -> Self {
+        self.backdrop_dismiss = backdrop_dismiss;
+        self
+    }
+
     /// Sets the message that will be produced when the Escape Key is
     /// pressed when the modal is open.
     ///
@@ -104,6 +141,38 @@ where
         self
     }
 
+    /// Sets the alignment of the content of the [`Modal`](Modal) inside the
+    /// window.
+    ///
+    /// The default centers the content. A
+    /// [`VerticalAlignment::Bottom`](VerticalAlignment::Bottom) alignment
+    /// together with a full width content shows it as a bottom sheet.
+    pub fn alignment(
+        mut self,
+        horizontal_alignment: HorizontalAlignment,
+        vertical_alignment: VerticalAlignment,
+    ) -> Self {
+        self.horizontal_alignment = horizontal_alignment;
+        self.vertical_alignment = vertical_alignment;
+        self
+    }
+
+    /// Sets the maximum width of the content of the [`Modal`](Modal).
+    ///
+    /// The content never exceeds the window, even without a maximum width.
+    pub fn max_width(mut self, max_width: u32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Sets the maximum height of the content of the [`Modal`](Modal).
+    ///
+    /// The content never exceeds the window, even without a maximum height.
+    pub fn max_height(mut self, max_height: u32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
     /// Sets the style of the [`Modal`](Modal).
     pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
         self.style = style.into();
@@ -184,6 +253,11 @@ where
         clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
     ) -> event::Status {
+        // The underlay must not react to any input while the modal is shown.
+        if self.state.show && !matches!(event, Event::Window(_)) {
+            return event::Status::Ignored;
+        }
+
         self.underlay.on_event(
             event,
             layout,
@@ -222,13 +296,19 @@ where
 
         let bounds = layout.bounds();
         let position = Point::new(bounds.x, bounds.y);
+        let backdrop = self.backdrop.clone().filter(|_| self.backdrop_dismiss);
+        // Shown modals of the underlay stay visible below this one
+        let below = self.underlay.overlay(layout);
 
         Some(
             ModalOverlay::new(
                 &mut self.state.state,
                 &self.content,
-                self.backdrop.clone(),
+                backdrop,
                 self.esc.clone(),
+                (self.horizontal_alignment, self.vertical_alignment),
+                (self.max_width, self.max_height),
+                below,
                 &self.style,
             )
             .overlay(position),
@@ -248,3 +328,157 @@ where
         Element::new(modal)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use iced_native::{
+        clipboard, keyboard, layout::Limits, mouse, renderer::Null, Element, Event, Layout, Point,
+        Size, Text,
+    };
+
+    use super::{Modal, State};
+
+    #[derive(Clone, Debug, PartialEq)]
+    enum Message {
+        CloseSettings,
+        CloseConfirm,
+        Pressed,
+    }
+
+    /// Sends the event to the overlay of a confirm modal stacked on top of a
+    /// settings modal.
+    fn send(show_confirm: bool, event: Event) -> Vec<Message> {
+        let mut settings = State::new(());
+        settings.show(true);
+        let mut confirm = State::new(());
+        confirm.show(show_confirm);
+
+        let settings_modal = Modal::new(&mut settings, Text::new("Underlay"), |()| {
+            Text::new("Settings").into()
+        })
+        .backdrop(Message::CloseSettings)
+        .on_esc(Message::CloseSettings);
+
+        let mut element: Element<'_, Message, Null> =
+            Modal::new(&mut confirm, settings_modal, |()| {
+                Text::new("Confirm").into()
+            })
+            .backdrop(Message::CloseConfirm)
+            .on_esc(Message::CloseConfirm)
+            .into();
+
+        let renderer = Null::new();
+        let size = Size::new(400.0, 300.0);
+        let node = element.layout(&renderer, &Limits::new(Size::ZERO, size));
+        let mut overlay = element
+            .overlay(Layout::new(&node))
+            .expect("A modal should be shown");
+        let overlay_node = overlay.layout(&renderer, size);
+
+        let mut messages = Vec::new();
+        let _ = overlay.on_event(
+            event,
+            Layout::new(&overlay_node),
+            Point::new(10.0, 10.0),
+            &renderer,
+            &mut clipboard::Null,
+            &mut messages,
+        );
+
+        messages
+    }
+
+    fn escape() -> Event {
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code: keyboard::KeyCode::Escape,
+            modifiers: keyboard::Modifiers::default(),
+        })
+    }
+
+    #[test]
+    fn escape_closes_topmost_modal() {
+        assert_eq!(send(true, escape()), vec![Message::CloseConfirm]);
+        assert_eq!(send(false, escape()), vec![Message::CloseSettings]);
+    }
+
+    #[test]
+    fn backdrop_closes_topmost_modal() {
+        let press = Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left));
+
+        assert_eq!(send(true, press.clone()), vec![Message::CloseConfirm]);
+        assert_eq!(send(false, press), vec![Message::CloseSettings]);
+    }
+
+    /// Clicks at the given position on a 400x300 window showing a modal with
+    /// a floating button sticking out of the bottom right corner of its
+    /// 100x100 content.
+    #[cfg(feature = "floating_button")]
+    fn click_floating_button(position: Point) -> Vec<Message> {
+        use iced_native::{button, Button, Length, Row};
+
+        use crate::native::FloatingButton;
+
+        let mut state = State::new(button::State::new());
+        state.show(true);
+        let mut messages = Vec::new();
+
+        // Buttons produce their message when they are released.
+        for event in [mouse::Event::ButtonPressed, mouse::Event::ButtonReleased] {
+            let mut element: Element<'_, Message, Null> =
+                Modal::new(&mut state, Text::new("Underlay"), |state| {
+                    let content = Row::new()
+                        .width(Length::Units(100))
+                        .height(Length::Units(100));
+
+                    FloatingButton::new(state, content, |state| {
+                        Button::new(state, Text::new("+"))
+                            .width(Length::Units(40))
+                            .height(Length::Units(40))
+                            .on_press(Message::Pressed)
+                    })
+                    .offset(-20.0)
+                    .into()
+                })
+                .backdrop(Message::CloseSettings)
+                .into();
+
+            let renderer = Null::new();
+            let size = Size::new(400.0, 300.0);
+            let node = element.layout(&renderer, &Limits::new(Size::ZERO, size));
+            let mut overlay = element
+                .overlay(Layout::new(&node))
+                .expect("A modal should be shown");
+            let overlay_node = overlay.layout(&renderer, size);
+
+            let _ = overlay.on_event(
+                Event::Mouse(event(mouse::Button::Left)),
+                Layout::new(&overlay_node),
+                position,
+                &renderer,
+                &mut clipboard::Null,
+                &mut messages,
+            );
+        }
+
+        messages
+    }
+
+    #[test]
+    #[cfg(feature = "floating_button")]
+    fn content_overlay_is_placed_at_the_content() {
+        // The centered content spans from (150, 100) to (250, 200), so the
+        // button is placed from (230, 180) to (270, 220).
+        assert_eq!(
+            click_floating_button(Point::new(260.0, 210.0)),
+            vec![Message::Pressed]
+        );
+        assert_eq!(
+            click_floating_button(Point::new(240.0, 190.0)),
+            vec![Message::Pressed]
+        );
+        assert_eq!(
+            click_floating_button(Point::new(280.0, 230.0)),
+            vec![Message::CloseSettings]
+        );
+    }
+}
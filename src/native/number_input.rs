@@ -12,9 +12,18 @@ use iced_native::{
     Size, Text, TextInput, Widget,
 };
 use num_traits::{Num, NumAssignOps};
+use std::cell::{Ref, RefCell, RefMut};
 use std::fmt::Display;
 use std::str::FromStr;
 
+pub use crate::core::number::Radix;
+use crate::{
+    core::number::{
+        decrease, format_radix, format_value, increase, localize, parse_radix, sanitize,
+    },
+    graphics::icons::Icon,
+};
+
 /// A field that can only be filled with numeric type.
 ///
 /// # Example
@@ -44,30 +53,92 @@ use std::str::FromStr;
 pub struct NumberInput<'a, T, Message, Renderer: self::Renderer> {
     /// The state of the [`NumberInput`](NumberInput).
     state: &'a mut ModifierState,
+    /// The text of the [`NumberInput`](NumberInput) that is not committed yet.
+    buffer: &'a mut Option<Buffer>,
+    /// The texts of the [`NumberInput`](NumberInput) formatted for its last value.
+    cache: &'a Cache,
     /// The current value of the [`NumberInput`](NumberInput).
     value: T,
+    /// The text displayed by the [`NumberInput`](NumberInput) while editing.
+    text: String,
+    /// The radix the value of the [`NumberInput`](NumberInput) is displayed
+    /// and edited in.
+    radix: &'a mut Radix,
+    /// The amount of decimal places displayed by the [`NumberInput`](NumberInput).
+    decimals: Option<usize>,
+    /// The separator between the digit groups of the [`NumberInput`](NumberInput).
+    group_separator: Option<char>,
+    /// The decimal separator of the [`NumberInput`](NumberInput).
+    decimal_separator: char,
+    /// The text displayed in front of the value of the [`NumberInput`](NumberInput).
+    prefix: String,
+    /// The text displayed behind the value of the [`NumberInput`](NumberInput).
+    suffix: String,
     /// The step for each modify of the [`NumberInput`](NumberInput).
     step: T,
+    /// The factor the step is multiplied with if the mouse wheel is scrolled
+    /// while shift is pressed.
+    wheel_modifier_factor: T,
     /// The min and max value of the [`NumberInput`](NumberInput).
     bounds: (T, T),
     /// The content padding of the [`NumberInput`](NumberInput).
     padding: u16,
     /// The text size of the [`NumberInput`](NumberInput).
     size: Option<u16>,
+    /// The width of the [`NumberInput`](NumberInput).
+    width: Length,
+    /// The maximum width of the [`NumberInput`](NumberInput).
+    max_width: Option<u32>,
+    /// The message produced if the enter key is pressed while the
+    /// [`NumberInput`](NumberInput) is focused.
+    on_submit: Option<Message>,
+    /// The parts the underlying element of the [`NumberInput`](NumberInput)
+    /// is built from.
+    pending: RefCell<Option<Pending<'a, Message, Renderer>>>,
     /// The underlying element of the [`NumberInput`](NumberInput).
-    content: TextInput<'a, Message, Renderer>,
+    ///
+    /// It is built on its first use, so that builders like
+    /// [`radix`](NumberInput::radix) can still change its text.
+    content: RefCell<Option<TextInput<'a, Message, Renderer>>>,
     /// The on_change event of the [`NumberInput`](NumberInput).
     on_change: Box<dyn Fn(T) -> Message>,
     /// The style of the [`NumberInput`](NumberInput).
     style: <Renderer as self::Renderer>::Style,
     /// The font text of the [`NumberInput`](NumberInput).
     font: Renderer::Font,
+    /// Whether the [`NumberInput`](NumberInput) is disabled.
+    is_disabled: bool,
+    /// Whether the [`NumberInput`](NumberInput) is read only.
+    is_read_only: bool,
+    /// The placement of the increase and decrease buttons of the [`NumberInput`](NumberInput).
+    buttons: ButtonLayout,
+    /// The optional icons of the increase and decrease buttons of the
+    /// [`NumberInput`](NumberInput).
+    button_icons: Option<(Icon, Icon)>,
+}
+
+/// The placement of the increase and decrease buttons of a [`NumberInput`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ButtonLayout {
+    /// The buttons are placed at the right, the increase button above the
+    /// decrease button.
+    #[default]
+    Right,
+    /// The buttons are placed at the left, the increase button above the
+    /// decrease button.
+    Left,
+    /// The decrease button is placed at the left and the increase button at
+    /// the right.
+    Split,
+    /// The buttons are hidden, so the value can only be changed by typing,
+    /// the keyboard and the mouse wheel.
+    Hidden,
 }
 
 impl<'a, T, Message, Renderer> NumberInput<'a, T, Message, Renderer>
 where
     T: Num + NumAssignOps + PartialOrd + Display + FromStr + Copy,
-    Message: Clone,
+    Message: 'static + Clone,
     Renderer: self::Renderer,
 {
     /// Creates a new [`NumberInput`].
@@ -85,31 +156,73 @@ where
         let State {
             input_state,
             mod_state,
+            buffer,
+            radix,
+            cache,
         } = state;
 
+        // The edited text is dropped if the value was changed from outside.
+        if !(input_state.is_focused()
+            && buffer
+                .as_ref()
+                .is_some_and(|buffer| buffer.value == value.to_string()))
+        {
+            *buffer = None;
+        }
+        let text = buffer.as_ref().map_or_else(
+            || {
+                cached(&cache.text, value.to_string(), *radix, || {
+                    format(value, *radix)
+                })
+                .to_owned()
+            },
+            |buffer| buffer.text.clone(),
+        );
+
         let padding = <Renderer as self::Renderer>::DEFAULT_PADDING;
-        let convert_to_num = move |s: String| {
-            on_changed(T::from_str(&s).unwrap_or(if s.is_empty() { T::zero() } else { value }))
+        let convert_to_num = move |s: String, radix| {
+            on_changed(parse(&s, radix, (T::zero(), max)).unwrap_or_else(|| {
+                if s.is_empty() {
+                    T::zero()
+                } else {
+                    value
+                }
+            }))
         };
 
         Self {
             state: mod_state,
+            buffer,
+            cache,
             value,
+            text,
+            radix,
+            decimals: None,
+            group_separator: None,
+            decimal_separator: '.',
+            prefix: String::new(),
+            suffix: String::new(),
             step: T::one(),
+            wheel_modifier_factor: ten(),
             bounds: (T::zero(), max),
             padding,
             size: None,
-            content: TextInput::new(
-                input_state,
-                "",
-                format!("{}", value).as_str(),
-                convert_to_num,
-            )
-            .padding(padding)
-            .width(Length::Units(127)),
+            width: Length::Units(127),
+            max_width: None,
+            on_submit: None,
+            pending: RefCell::new(Some(Pending {
+                state: input_state,
+                on_change: Box::new(convert_to_num),
+                style: None,
+            })),
+            content: RefCell::new(None),
             on_change: Box::new(on_changed),
             style: <Renderer as self::Renderer>::Style::default(),
             font: Default::default(),
+            is_disabled: false,
+            is_read_only: false,
+            buttons: ButtonLayout::Right,
+            button_icons: None,
         }
     }
 
@@ -119,6 +232,112 @@ where
         self
     }
 
+    /// Sets the factor the step of the [`NumberInput`] is multiplied with if
+    /// the mouse wheel is scrolled while shift is pressed.
+    pub fn wheel_modifier_factor(mut self, factor: T) -> Self {
+        self.wheel_modifier_factor = factor;
+        self
+    }
+
+    /// Sets the amount of decimal places that are displayed by the [`NumberInput`].
+    ///
+    /// The value itself is not rounded and the decimal places are only applied
+    /// while the [`NumberInput`] is not edited.
+    pub fn decimals(mut self, decimals: usize) -> Self {
+        self.decimals = Some(decimals);
+        self
+    }
+
+    /// Sets the [`Radix`] the value of the [`NumberInput`] is displayed and
+    /// edited in. The default is [`Radix::Decimal`].
+    ///
+    /// Other radixes are only supported for integer types. Typed and pasted
+    /// values are clamped into the bounds instead of overflowing the type.
+    /// A prefix like `0x` may be pasted along with the digits; use
+    /// [`prefix`](NumberInput::prefix) to display it.
+    pub fn radix(mut self, radix: Radix) -> Self {
+        if *self.radix != radix {
+            *self.radix = radix;
+            *self.buffer = None;
+            self.text = format(self.value, radix);
+        }
+        self
+    }
+
+    /// Sets the separator that is displayed between every three digits of the
+    /// [`NumberInput`] while it is not edited.
+    pub fn group_separator(mut self, separator: Option<char>) -> Self {
+        self.group_separator = separator;
+        self
+    }
+
+    /// Sets the decimal separator of the [`NumberInput`].
+    pub fn decimal_separator(mut self, separator: char) -> Self {
+        self.decimal_separator = separator;
+        self
+    }
+
+    /// Sets the text that is displayed in front of the value of the
+    /// [`NumberInput`], e.g. a currency symbol.
+    ///
+    /// The prefix can't be edited and isn't part of the value.
+    pub fn prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// Sets the text that is displayed behind the value of the
+    /// [`NumberInput`], e.g. a unit.
+    ///
+    /// The suffix can't be edited and isn't part of the value.
+    pub fn suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.suffix = suffix.into();
+        self
+    }
+
+    /// Sets whether the [`NumberInput`] is disabled.
+    ///
+    /// A disabled [`NumberInput`] ignores all events, can't be focused and
+    /// is drawn muted.
+    pub fn disabled(mut self, is_disabled: bool) -> Self {
+        self.is_disabled = is_disabled;
+        self
+    }
+
+    /// Sets whether the [`NumberInput`] is read only.
+    ///
+    /// The value of a read only [`NumberInput`] can be selected and copied,
+    /// but not edited. The increase and decrease buttons are hidden.
+    pub fn read_only(mut self, is_read_only: bool) -> Self {
+        self.is_read_only = is_read_only;
+        self
+    }
+
+    /// Sets the placement of the increase and decrease buttons of the
+    /// [`NumberInput`].
+    ///
+    /// The space of hidden buttons is left to the text.
+    pub fn buttons(mut self, buttons: ButtonLayout) -> Self {
+        self.buttons = buttons;
+        self
+    }
+
+    /// Sets the icons of the increase and decrease buttons of the
+    /// [`NumberInput`], drawn with the
+    /// [`ICON_FONT`](crate::graphics::icons::ICON_FONT).
+    ///
+    /// By default the buttons show triangles in the font of the
+    /// [`NumberInput`].
+    pub fn button_icons(mut self, increase: Icon, decrease: Icon) -> Self {
+        self.button_icons = Some((increase, decrease));
+        self
+    }
+
+    /// Returns true if the increase and decrease buttons are shown.
+    fn show_buttons(&self) -> bool {
+        !self.is_read_only && self.buttons != ButtonLayout::Hidden
+    }
+
     /// Sets the minimum value of the [`NumberInput`].
     pub fn min(mut self, min: T) -> Self {
         if min < self.bounds.1 {
@@ -148,41 +367,40 @@ where
     /// [`Font`]: crate::widget::text::Renderer::Font
     /// [`Text`]: crate::widget::Text
     pub fn font(mut self, font: Renderer::Font) -> Self {
+        // The widths of the prefix and suffix were measured with another font.
+        self.cache.clear_widths();
         self.font = font;
-        self.content = self.content.font(font);
         self
     }
 
     /// Sets the width of the [`NumberInput`].
     pub fn width(mut self, width: Length) -> Self {
-        self.content = self.content.width(width);
+        self.width = width;
         self
     }
 
     /// Sets the maximum width of the [`NumberInput`].
     pub fn max_width(mut self, max_width: u32) -> Self {
-        self.content = self.content.max_width(max_width);
+        self.max_width = Some(max_width);
         self
     }
 
     /// Sets the padding of the [`NumberInput`].
     pub fn padding(mut self, units: u16) -> Self {
         self.padding = units;
-        self.content = self.content.padding(units);
         self
     }
 
     /// Sets the text size of the [`NumberInput`].
     pub fn size(mut self, size: u16) -> Self {
         self.size = Some(size);
-        self.content = self.content.size(size);
         self
     }
 
     /// Sets the message that should be produced when the [`NumberInput`] is
     /// focused and the enter key is pressed.
     pub fn on_submit(mut self, message: Message) -> Self {
-        self.content = self.content.on_submit(message);
+        self.on_submit = Some(message);
         self
     }
 
@@ -197,60 +415,386 @@ where
         mut self,
         style: impl Into<<Renderer as text_input::Renderer>::Style>,
     ) -> Self {
-        self.content = self.content.style(style.into());
+        if let Some(pending) = self.pending.get_mut() {
+            pending.style = Some(style.into());
+        }
         self
     }
 
-    /// Decrease current value by step of the [`NumberInput`].
-    fn decrease_val(&mut self, messages: &mut Vec<Message>) {
-        if self.value > self.bounds.0 {
-            let new_val = self.value - self.step;
-            self.value = if new_val > self.bounds.0 {
-                new_val
-            } else {
-                self.bounds.0
-            };
+    /// Returns the underlying text input of the [`NumberInput`], building it
+    /// on the first call.
+    fn content(&self) -> RefMut<'_, TextInput<'a, Message, Renderer>> {
+        RefMut::map(self.content.borrow_mut(), |content| {
+            content.get_or_insert_with(|| {
+                let Pending {
+                    state,
+                    on_change,
+                    style,
+                } = self
+                    .pending
+                    .borrow_mut()
+                    .take()
+                    .expect("fail to get text input parts");
+                let radix = *self.radix;
+                let mut input =
+                    TextInput::new(state, "", &self.text, move |text| on_change(text, radix))
+                        .font(self.font)
+                        .width(self.width)
+                        .padding(self.padding);
+
+                if let Some(max_width) = self.max_width {
+                    input = input.max_width(max_width);
+                }
+                if let Some(size) = self.size {
+                    input = input.size(size);
+                }
+                if let Some(message) = self.on_submit.clone() {
+                    input = input.on_submit(message);
+                }
+                if let Some(style) = style {
+                    input = input.style(style);
+                }
+                input
+            })
+        })
+    }
+
+    /// Returns true if the [`NumberInput`] has the keyboard focus.
+    fn is_focused(&self) -> bool {
+        self.content().state().is_focused()
+    }
+
+    /// Decrease current value by the given step of the [`NumberInput`] times
+    /// the given factor, saturating at the lower bound.
+    ///
+    /// The edited text is committed first, so the step starts at its value.
+    fn decrease_val(&mut self, step: T, factor: T, messages: &mut Vec<Message>) {
+        let value = self.take_buffer().unwrap_or(self.value);
+        self.change(decrease(value, step, factor, self.bounds.0), messages);
+    }
+
+    /// Increase current value by the given step of the [`NumberInput`] times
+    /// the given factor, saturating at the upper bound.
+    ///
+    /// The edited text is committed first, so the step starts at its value.
+    fn increase_val(&mut self, step: T, factor: T, messages: &mut Vec<Message>) {
+        let value = self.take_buffer().unwrap_or(self.value);
+        self.change(increase(value, step, factor, self.bounds.1), messages);
+    }
+
+    /// Sets the value of the [`NumberInput`] and its text. A message is only
+    /// produced if the value changed.
+    fn change(&mut self, value: T, messages: &mut Vec<Message>) {
+        self.text = format(value, *self.radix);
+        if value != self.value {
+            self.value = value;
             messages.push((self.on_change)(self.value));
-            // self.content.state().move_cursor_to_end();
         }
     }
 
-    /// Increase current value by step of the [`NumberInput`].
-    fn increase_val(&mut self, messages: &mut Vec<Message>) {
-        if self.value < self.bounds.1 {
-            let new_val = self.value + self.step;
-            self.value = if new_val < self.bounds.1 {
-                new_val
-            } else {
-                self.bounds.1
-            };
-            messages.push((self.on_change)(self.value));
-            // self.content.state().move_cursor_to_end();
+    /// Clamps the given value into the bounds of the [`NumberInput`].
+    fn clamp(&self, value: T) -> T {
+        if value < self.bounds.0 {
+            self.bounds.0
+        } else if value > self.bounds.1 {
+            self.bounds.1
+        } else {
+            value
         }
     }
+
+    /// Returns the text of the [`NumberInput`] with the given text inserted at
+    /// the position of the cursor, replacing the current selection.
+    fn insert(&self, insertion: &str) -> String {
+        let mut new_val = self.text.clone();
+        match self.content().state().cursor().state(&Value::new(&new_val)) {
+            cursor::State::Index(idx) => {
+                if new_val == format(T::zero(), *self.radix) && insertion != "." {
+                    new_val = String::from(insertion);
+                } else {
+                    new_val.insert_str(idx, insertion)
+                }
+            }
+            cursor::State::Selection { start, end } => {
+                if (0..new_val.len()).contains(&start) && (0..new_val.len()).contains(&end) {
+                    new_val
+                        .replace_range(if start > end { end..start } else { start..end }, insertion)
+                }
+            }
+        }
+        new_val
+    }
+
+    /// Returns the text of the [`NumberInput`] with the current selection
+    /// removed. Without a selection, the character in front of the cursor is
+    /// removed or, if `forward` is set, the character behind it.
+    fn remove(&self, forward: bool) -> String {
+        let mut new_val = self.text.clone();
+        match self.content().state().cursor().state(&Value::new(&new_val)) {
+            cursor::State::Index(idx) => {
+                if forward && idx < new_val.len() {
+                    let _ = new_val.remove(idx);
+                } else if !forward && idx >= 1 && idx <= new_val.len() {
+                    let _ = new_val.remove(idx - 1);
+                }
+            }
+            cursor::State::Selection { start, end } => {
+                if (0..new_val.len()).contains(&start) && (0..new_val.len()).contains(&end) {
+                    new_val.replace_range(if start > end { end..start } else { start..end }, "");
+                }
+            }
+        }
+        new_val
+    }
+
+    /// Edits the text of the [`NumberInput`].
+    ///
+    /// The text is kept until it gets committed, even if it doesn't represent
+    /// a value within the bounds, so that e.g. all digits can be deleted to
+    /// retype the value or a sign can be typed in front of it.
+    fn edit(&mut self, text: String) {
+        self.text.clone_from(&text);
+        *self.buffer = Some(Buffer {
+            text,
+            value: self.value.to_string(),
+        });
+    }
+
+    /// Returns true if the edited text of the [`NumberInput`] doesn't
+    /// represent a value within the bounds.
+    fn is_invalid(&self) -> bool {
+        self.buffer.is_some()
+            && !parse(&self.text, *self.radix, self.bounds)
+                .is_some_and(|val| (self.bounds.0..=self.bounds.1).contains(&val))
+    }
+
+    /// Takes the edited text of the [`NumberInput`] and returns its value
+    /// clamped into the bounds.
+    ///
+    /// Returns `None` if nothing was edited or the text doesn't represent a
+    /// value, in which case the value is kept.
+    fn take_buffer(&mut self) -> Option<T> {
+        self.buffer
+            .take()
+            .and_then(|Buffer { text, .. }| parse(&text, *self.radix, self.bounds))
+            .map(|val| self.clamp(val))
+    }
+
+    /// Commits the edited text of the [`NumberInput`] by clamping its value
+    /// into the bounds. An edited text not representing a value is reverted.
+    fn commit(&mut self, messages: &mut Vec<Message>) {
+        let value = self.take_buffer().unwrap_or(self.value);
+        self.change(value, messages);
+    }
+
+    /// Handles the events of the [`NumberInput`] that are not related to
+    /// the modifier buttons or the mouse wheel.
+    #[allow(clippy::too_many_lines)]
+    fn on_input_event(
+        &mut self,
+        event: Event,
+        content: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        match event {
+            Event::Keyboard(keyboard::Event::CharacterReceived(_)) if self.is_read_only => {
+                event::Status::Ignored
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }) if self.is_read_only && is_editing_key(key_code, modifiers) => {
+                event::Status::Ignored
+            }
+            Event::Keyboard(keyboard::Event::CharacterReceived(c))
+                if self.is_focused()
+                    && (self.radix.is_digit(c)
+                        || c == '-'
+                        || (*self.radix == Radix::Decimal && c == self.decimal_separator)) =>
+            {
+                let c = if c == self.decimal_separator { '.' } else { c };
+                let new_val = self.insert(&c.to_string());
+                self.edit(new_val);
+                self.forward_edit(
+                    event.clone(),
+                    content,
+                    cursor_position,
+                    renderer,
+                    clipboard,
+                    messages,
+                )
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }) if self.is_focused() => match key_code {
+                keyboard::KeyCode::Up => {
+                    self.increase_val(self.step, T::one(), messages);
+                    event::Status::Captured
+                }
+                keyboard::KeyCode::Down => {
+                    self.decrease_val(self.step, T::one(), messages);
+                    event::Status::Captured
+                }
+                keyboard::KeyCode::PageUp => {
+                    self.increase_val(self.step, ten(), messages);
+                    event::Status::Captured
+                }
+                keyboard::KeyCode::PageDown => {
+                    self.decrease_val(self.step, ten(), messages);
+                    event::Status::Captured
+                }
+                keyboard::KeyCode::V if modifiers.is_command_pressed() => {
+                    // A paste without digits leaves the text unchanged.
+                    let pasted = clipboard.read().and_then(|contents| {
+                        sanitize(
+                            &contents,
+                            *self.radix,
+                            self.group_separator,
+                            self.decimal_separator,
+                            T::from_str("0.5").is_ok(),
+                        )
+                    });
+
+                    match pasted.and_then(|pasted| {
+                        let new_val = self.insert(&pasted);
+                        parse(&new_val, *self.radix, self.bounds).map(|val| (pasted, new_val, val))
+                    }) {
+                        Some((pasted, new_val, val)) => {
+                            // Values beyond the bounds are replaced by the
+                            // nearest bound. Values in other radixes are
+                            // already clamped by parsing them.
+                            let clamped = self.clamp(val);
+                            let (new_val, pasted) =
+                                if clamped > self.bounds.0 && clamped < self.bounds.1 {
+                                    (new_val, pasted)
+                                } else {
+                                    let bound = format(clamped, *self.radix);
+                                    (bound.clone(), bound)
+                                };
+                            self.edit(new_val);
+
+                            // The text input pastes the sanitized text instead
+                            // of the clipboard, so its cursor is placed behind
+                            // the inserted text.
+                            let _ = self.forward_edit(
+                                event.clone(),
+                                content,
+                                cursor_position,
+                                renderer,
+                                &mut Pasted(pasted),
+                                messages,
+                            );
+                            event::Status::Captured
+                        }
+                        _ => event::Status::Ignored,
+                    }
+                }
+                keyboard::KeyCode::Enter | keyboard::KeyCode::NumpadEnter => {
+                    self.commit(messages);
+                    self.content().on_event(
+                        event.clone(),
+                        content,
+                        cursor_position,
+                        renderer,
+                        clipboard,
+                        messages,
+                    )
+                }
+                keyboard::KeyCode::Backspace | keyboard::KeyCode::Delete => {
+                    let new_val = self.remove(key_code == keyboard::KeyCode::Delete);
+                    self.edit(new_val);
+                    self.forward_edit(
+                        event.clone(),
+                        content,
+                        cursor_position,
+                        renderer,
+                        clipboard,
+                        messages,
+                    )
+                }
+                _ => self.content().on_event(
+                    event.clone(),
+                    content,
+                    cursor_position,
+                    renderer,
+                    clipboard,
+                    messages,
+                ),
+            },
+            _ => {
+                let was_focused = self.is_focused();
+                let status = self.content().on_event(
+                    event,
+                    content,
+                    cursor_position,
+                    renderer,
+                    clipboard,
+                    messages,
+                );
+
+                if was_focused && !self.is_focused() {
+                    self.commit(messages);
+                }
+
+                status
+            }
+        }
+    }
+
+    /// Forwards an editing event to the underlying text input of the
+    /// [`NumberInput`].
+    ///
+    /// The messages of the text input are dropped, because the [`NumberInput`]
+    /// produces its own messages while editing.
+    fn forward_edit(
+        &self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        let len = messages.len();
+        let status = self.content().on_event(
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            messages,
+        );
+        messages.truncate(len);
+        status
+    }
 }
 
 impl<'a, T, Message, Renderer> Widget<Message, Renderer> for NumberInput<'a, T, Message, Renderer>
 where
     T: Num + NumAssignOps + PartialOrd + Display + FromStr + ToString + Copy,
-    Message: Clone,
+    Message: 'static + Clone,
     Renderer: self::Renderer + container::Renderer + column::Renderer + row::Renderer,
 {
     fn width(&self) -> Length {
-        Widget::<Message, Renderer>::width(&self.content)
+        self.width
     }
 
     fn height(&self) -> Length {
         Length::Shrink
     }
 
+    #[allow(clippy::too_many_lines)]
     fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
         let padding = f32::from(self.padding);
         let limits = limits
             .width(self.width())
             .height(Length::Shrink)
             .pad(padding);
-        let content = self.content.layout(renderer, &limits.loose());
+        let content = self.content().layout(renderer, &limits.loose());
         let txt_size = self.size.unwrap_or_else(|| renderer.default_size());
         let icon_size = txt_size * 3 / 4;
         let btn_mod = |c| {
@@ -258,7 +802,17 @@ where
                 .center_y()
                 .center_x()
         };
-        let mut modifier = if self.padding < Renderer::DEFAULT_PADDING {
+        let mut modifier = if !self.show_buttons() {
+            Node::with_children(
+                Size::ZERO,
+                vec![Node::new(Size::ZERO), Node::new(Size::ZERO)],
+            )
+        } else if self.buttons == ButtonLayout::Split {
+            let increase = btn_mod('+').layout(renderer, &limits.loose());
+            let decrease = btn_mod('-').layout(renderer, &limits.loose());
+            let height = increase.size().height.max(decrease.size().height);
+            Node::with_children(Size::new(0.0, height), vec![increase, decrease])
+        } else if self.padding < Renderer::DEFAULT_PADDING {
             Row::<(), Renderer>::new()
                 .spacing(1)
                 .width(Length::Shrink)
@@ -277,18 +831,83 @@ where
             content.size().width - 3.0,
             content.size().height.max(modifier.size().height),
         );
-        modifier.align(Align::End, Align::Center, intrinsic);
+
+        // The text of the content is narrowed down, so that the prefix and
+        // suffix are displayed in front of and behind it.
+        let text_bounds = content
+            .children()
+            .first()
+            .expect("fail to get content text layout")
+            .bounds();
+        let text_end = text_bounds.x + text_bounds.width;
+        let (start, end) = match self.buttons {
+            _ if !self.show_buttons() => (text_bounds.x, text_end),
+            ButtonLayout::Left => {
+                modifier.align(Align::Start, Align::Center, intrinsic);
+                let bounds = modifier.bounds();
+                (bounds.x + bounds.width, text_end)
+            }
+            ButtonLayout::Split => {
+                let children: Vec<Node> = modifier
+                    .children()
+                    .iter()
+                    .zip([Align::End, Align::Start])
+                    .map(|(button, align)| {
+                        let mut button = Node::new(button.size());
+                        button.align(align, Align::Center, intrinsic);
+                        button
+                    })
+                    .collect();
+                let start = children[1].bounds().x + children[1].bounds().width;
+                let end = children[0].bounds().x;
+                modifier = Node::with_children(intrinsic, children);
+                (start, end)
+            }
+            _ => {
+                modifier.align(Align::End, Align::Center, intrinsic);
+                if self.suffix.is_empty() {
+                    (text_bounds.x, text_end)
+                } else {
+                    (text_bounds.x, modifier.bounds().x)
+                }
+            }
+        };
+        let start = start.max(text_bounds.x);
+
+        let measure = |cache, adornment: &str| {
+            if adornment.is_empty() {
+                0.0
+            } else {
+                measured(cache, adornment, txt_size, || {
+                    renderer.measure_value(adornment, txt_size, self.font)
+                })
+            }
+        };
+        let prefix_width = measure(&self.cache.prefix_width, &self.prefix);
+        let suffix_width = measure(&self.cache.suffix_width, &self.suffix);
+        let suffix_x = end - suffix_width;
+        let mut prefix = Node::new(Size::new(prefix_width, text_bounds.height));
+        prefix.move_to(Point::new(start, text_bounds.y));
+        let mut suffix = Node::new(Size::new(suffix_width, text_bounds.height));
+        suffix.move_to(Point::new(suffix_x, text_bounds.y));
+        let mut text = Node::new(Size::new(
+            (suffix_x - start - prefix_width).max(0.0),
+            text_bounds.height,
+        ));
+        text.move_to(Point::new(start + prefix_width, text_bounds.y));
+        let content = Node::with_children(content.size(), vec![text]);
+
         let size = limits.resolve(intrinsic);
-        Node::with_children(size, vec![content, modifier])
+        Node::with_children(size, vec![content, modifier, prefix, suffix])
     }
 
     fn draw(
         &self,
         renderer: &mut Renderer,
-        _defaults: &Renderer::Defaults,
+        defaults: &Renderer::Defaults,
         layout: Layout<'_>,
         cursor_position: Point,
-        _viewport: &Rectangle,
+        viewport: &Rectangle,
     ) -> Renderer::Output {
         let bounds = layout.bounds();
         let mut children = layout.children();
@@ -305,12 +924,61 @@ where
             .next()
             .expect("fail to get decreate mod layout")
             .bounds();
+        let prefix_layout = children.next().expect("fail to get prefix layout");
+        let suffix_layout = children.next().expect("fail to get suffix layout");
         let is_mouse_over = bounds.contains(cursor_position);
-        let content = self
-            .content
-            .draw(renderer, content_layout, cursor_position, None);
-        let is_decrease_disabled = self.value <= self.bounds.0;
-        let is_increase_disabled = self.value >= self.bounds.1;
+        let txt_size = self.size.unwrap_or_else(|| renderer.default_size());
+        let mut adornment = |adornment: &str, layout| {
+            Widget::<Message, Renderer>::draw(
+                &Text::new(adornment).size(txt_size).font(self.font),
+                renderer,
+                defaults,
+                layout,
+                cursor_position,
+                viewport,
+            )
+        };
+        let prefix = adornment(&self.prefix, prefix_layout);
+        let suffix = adornment(&self.suffix, suffix_layout);
+        let edited;
+        let formatted;
+        let displayed: &str = if self.is_focused() {
+            edited = localize(&self.text, None, self.decimal_separator);
+            &edited
+        } else if *self.radix != Radix::Decimal {
+            formatted = cached(
+                &self.cache.text,
+                self.value.to_string(),
+                *self.radix,
+                || format(self.value, *self.radix),
+            );
+            &formatted
+        } else {
+            let settings: DecimalFormat =
+                (self.decimals, self.group_separator, self.decimal_separator);
+            formatted = cached(
+                &self.cache.displayed,
+                self.value.to_string(),
+                settings,
+                || {
+                    localize(
+                        &format_value(self.value, self.decimals),
+                        self.group_separator,
+                        self.decimal_separator,
+                    )
+                },
+            );
+            &formatted
+        };
+        let content = self.content().draw(
+            renderer,
+            content_layout,
+            cursor_position,
+            Some(&Value::new(displayed)),
+        );
+        let is_invalid = self.is_invalid();
+        let is_decrease_disabled = self.is_disabled || self.value <= self.bounds.0;
+        let is_increase_disabled = self.is_disabled || self.value >= self.bounds.1;
 
         self::Renderer::draw(
             renderer,
@@ -321,7 +989,15 @@ where
             is_mouse_over,
             is_decrease_disabled,
             is_increase_disabled,
+            content_layout.bounds(),
+            self.is_focused().then_some(bounds),
+            is_invalid,
+            self.is_disabled,
+            self.show_buttons(),
+            self.button_icons,
             content,
+            prefix,
+            suffix,
             &self.style,
             self.font,
         )
@@ -335,7 +1011,12 @@ where
 
         self.padding.hash(state);
         self.size.hash(state);
-        self.content.hash_layout(state);
+        self.prefix.hash(state);
+        self.suffix.hash(state);
+        self.is_disabled.hash(state);
+        self.is_read_only.hash(state);
+        self.buttons.hash(state);
+        self.content().hash_layout(state);
     }
 
     #[allow(clippy::too_many_lines)]
@@ -362,204 +1043,150 @@ where
             .next()
             .expect("fail to get decreate mod layout")
             .bounds();
-        let mouse_over_inc = inc_bounds.contains(cursor_position);
-        let mouse_over_dec = dec_bounds.contains(cursor_position);
-
-        if layout.bounds().contains(cursor_position) {
-            if mouse_over_inc || mouse_over_dec {
-                let mut event_status = event::Status::Captured;
-                match event {
-                    Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
-                        if mouse_over_dec {
-                            self.state.decrease_pressed = true;
-                            self.decrease_val(messages);
-                        } else if mouse_over_inc {
-                            self.state.increase_pressed = true;
-                            self.increase_val(messages);
-                        } else {
-                            event_status = event::Status::Ignored;
-                        }
-                    }
-                    Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
-                        if mouse_over_dec {
-                            self.state.decrease_pressed = false;
-                        } else if mouse_over_inc {
-                            self.state.increase_pressed = false;
-                        } else {
-                            event_status = event::Status::Ignored;
-                        }
-                    }
-                    _ => event_status = event::Status::Ignored,
-                }
-                event_status
-            } else {
-                match event {
-                    Event::Keyboard(keyboard::Event::CharacterReceived(c))
-                        if self.content.state().is_focused() && c.is_numeric() =>
-                    {
-                        let mut new_val = self.value.to_string();
-                        match self.content.state().cursor().state(&Value::new(&new_val)) {
-                            cursor::State::Index(idx) => {
-                                if T::zero().eq(&self.value) {
-                                    new_val = c.to_string();
-                                } else {
-                                    new_val.insert(idx, c)
-                                }
-                            }
-                            cursor::State::Selection { start, end } => {
-                                if (0..new_val.len()).contains(&start)
-                                    && (0..new_val.len()).contains(&end)
-                                {
-                                    new_val.replace_range(
-                                        if start > end { end..start } else { start..end },
-                                        &c.to_string(),
-                                    )
-                                }
-                            }
-                        }
+        let mouse_over_inc = self.show_buttons() && inc_bounds.contains(cursor_position);
+        let mouse_over_dec = self.show_buttons() && dec_bounds.contains(cursor_position);
 
-                        match T::from_str(&new_val) {
-                            Ok(val) => {
-                                if (self.bounds.0..=self.bounds.1).contains(&val) {
-                                    self.value = val;
-                                    messages.push((self.on_change)(self.value));
-                                    self.content.on_event(
-                                        event.clone(),
-                                        content,
-                                        cursor_position,
-                                        renderer,
-                                        clipboard,
-                                        messages,
-                                    )
-                                } else {
-                                    event::Status::Ignored
-                                }
-                            }
-                            Err(_) => event::Status::Ignored,
-                        }
-                    }
-                    Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. })
-                        if self.content.state().is_focused() =>
-                    {
-                        match key_code {
-                            keyboard::KeyCode::Up => {
-                                self.increase_val(messages);
-                                event::Status::Captured
-                            }
-                            keyboard::KeyCode::Down => {
-                                self.decrease_val(messages);
-                                event::Status::Captured
-                            }
-                            keyboard::KeyCode::Backspace => {
-                                if T::zero().eq(&self.value) {
-                                    event::Status::Ignored
-                                } else {
-                                    let mut new_val = self.value.to_string();
-                                    match self.content.state().cursor().state(&Value::new(&new_val))
-                                    {
-                                        cursor::State::Index(idx) => {
-                                            if idx >= 1 && idx <= new_val.len() {
-                                                if new_val.len() == 1 {
-                                                    new_val = if self.bounds.0 > T::zero() {
-                                                        self.bounds.0
-                                                    } else {
-                                                        T::zero()
-                                                    }
-                                                    .to_string();
-                                                } else {
-                                                    let _ = new_val.remove(idx - 1);
-                                                }
-                                            }
-                                        }
-                                        cursor::State::Selection { start, end } => {
-                                            if (0..new_val.len()).contains(&start)
-                                                && (0..new_val.len()).contains(&end)
-                                            {
-                                                new_val.replace_range(
-                                                    if start > end {
-                                                        end..start
-                                                    } else {
-                                                        start..end
-                                                    },
-                                                    "",
-                                                )
-                                            }
-                                        }
-                                    }
-
-                                    match T::from_str(&new_val) {
-                                        Ok(val) => {
-                                            if (self.bounds.0..=self.bounds.1).contains(&val) {
-                                                self.value = val;
-                                                messages.push((self.on_change)(self.value));
-                                                self.content.on_event(
-                                                    event.clone(),
-                                                    content,
-                                                    cursor_position,
-                                                    renderer,
-                                                    clipboard,
-                                                    messages,
-                                                )
-                                            } else {
-                                                event::Status::Ignored
-                                            }
-                                        }
-                                        Err(_) => event::Status::Ignored,
-                                    }
-                                }
-                            }
-                            _ => self.content.on_event(
-                                event.clone(),
-                                content,
-                                cursor_position,
-                                renderer,
-                                clipboard,
-                                messages,
-                            ),
-                        }
-                    }
-                    // This section from line 502 to 516 was owned by 13r0ck (https://github.com/13r0ck).
-                    Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
-                        let negative: bool;
-                        match delta {
-                            mouse::ScrollDelta::Lines { y, .. }
-                            | mouse::ScrollDelta::Pixels { y, .. } => {
-                                negative = y.is_sign_negative()
-                            }
-                        }
-                        if negative {
-                            self.increase_val(messages)
-                        } else {
-                            self.decrease_val(messages)
+        if self.is_disabled {
+            // A disabled input can't be focused, so it is unfocused like by a
+            // click outside of it and its edited text is dropped.
+            if self.is_focused() {
+                let _ = self.content().on_event(
+                    Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+                    content,
+                    Point::new(f32::INFINITY, f32::INFINITY),
+                    renderer,
+                    clipboard,
+                    &mut Vec::new(),
+                );
+                *self.buffer = None;
+            }
+            return event::Status::Ignored;
+        }
+
+        if let Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) = event {
+            self.state.keyboard_modifiers = modifiers;
+        }
+
+        match event {
+            // This section was originally owned by 13r0ck (https://github.com/13r0ck).
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if !self.is_read_only && layout.bounds().contains(cursor_position) =>
+            {
+                let factor = if self.state.keyboard_modifiers.shift {
+                    self.wheel_modifier_factor
+                } else {
+                    T::one()
+                };
+                match delta {
+                    mouse::ScrollDelta::Lines { y, .. } | mouse::ScrollDelta::Pixels { y, .. } => {
+                        if y > 0.0 {
+                            self.increase_val(self.step, factor, messages);
+                        } else if y < 0.0 {
+                            self.decrease_val(self.step, factor, messages);
                         }
-                        event::Status::Captured
                     }
-                    _ => self.content.on_event(
-                        event,
-                        content,
-                        cursor_position,
-                        renderer,
-                        clipboard,
-                        messages,
-                    ),
                 }
+                event::Status::Captured
             }
-        } else {
-            match event {
-                Event::Keyboard(_) => event::Status::Ignored,
-                _ => self.content.on_event(
-                    event,
-                    content,
-                    cursor_position,
-                    renderer,
-                    clipboard,
-                    messages,
-                ),
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                if mouse_over_inc || mouse_over_dec =>
+            {
+                if mouse_over_dec {
+                    self.state.decrease_pressed = true;
+                    self.decrease_val(self.step, T::one(), messages);
+                } else {
+                    self.state.increase_pressed = true;
+                    self.increase_val(self.step, T::one(), messages);
+                }
+                event::Status::Captured
             }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                if mouse_over_inc || mouse_over_dec =>
+            {
+                if mouse_over_dec {
+                    self.state.decrease_pressed = false;
+                } else {
+                    self.state.increase_pressed = false;
+                }
+                event::Status::Captured
+            }
+            _ => self.on_input_event(
+                event,
+                content,
+                cursor_position,
+                renderer,
+                clipboard,
+                messages,
+            ),
         }
     }
 }
 
+/// Returns true if the given key edits the text or value of a
+/// [`NumberInput`].
+fn is_editing_key(key_code: keyboard::KeyCode, modifiers: keyboard::Modifiers) -> bool {
+    match key_code {
+        keyboard::KeyCode::Up
+        | keyboard::KeyCode::Down
+        | keyboard::KeyCode::PageUp
+        | keyboard::KeyCode::PageDown
+        | keyboard::KeyCode::Backspace
+        | keyboard::KeyCode::Delete => true,
+        keyboard::KeyCode::V | keyboard::KeyCode::X => modifiers.is_command_pressed(),
+        _ => false,
+    }
+}
+
+/// A clipboard containing the sanitized text pasted into a [`NumberInput`].
+struct Pasted(String);
+
+impl Clipboard for Pasted {
+    fn read(&self) -> Option<String> {
+        Some(self.0.clone())
+    }
+
+    fn write(&mut self, _contents: String) {}
+}
+
+/// The parts the underlying text input of a [`NumberInput`] is built from.
+struct Pending<'a, Message, Renderer: text_input::Renderer> {
+    /// The state of the text input.
+    state: &'a mut text_input::State,
+    /// The function producing a message from the text and the [`Radix`] of
+    /// the text input.
+    on_change: Box<dyn Fn(String, Radix) -> Message>,
+    /// The style of the text input.
+    style: Option<<Renderer as text_input::Renderer>::Style>,
+}
+
+/// Formats the value of a [`NumberInput`] in the given [`Radix`].
+fn format<T: Num + PartialOrd + Display + Copy>(value: T, radix: Radix) -> String {
+    match radix {
+        Radix::Decimal => value.to_string(),
+        _ => format_radix(value, radix).unwrap_or_else(|| value.to_string()),
+    }
+}
+
+/// Parses the text of a [`NumberInput`] in the given [`Radix`].
+///
+/// Decimal values are parsed as they are, while values in other radixes are
+/// clamped into the given bounds.
+fn parse<T: Num + PartialOrd + FromStr + Copy>(
+    text: &str,
+    radix: Radix,
+    bounds: (T, T),
+) -> Option<T> {
+    match radix {
+        Radix::Decimal => T::from_str(text).ok(),
+        _ => parse_radix(text, radix, bounds),
+    }
+}
+
+/// Returns ten in the numeric type of a [`NumberInput`].
+fn ten<T: Num>() -> T {
+    T::from_str_radix("10", 10).unwrap_or_else(|_| T::one())
+}
+
 /// The state of a [`NumberInput`].
 #[derive(Default, Clone, Debug)]
 pub struct State {
@@ -567,6 +1194,12 @@ pub struct State {
     input_state: text_input::State,
     /// The state of the modifiers.
     mod_state: ModifierState,
+    /// The text that is edited but not committed yet.
+    buffer: Option<Buffer>,
+    /// The radix the value is displayed and edited in.
+    radix: Radix,
+    /// The texts formatted for the last value.
+    cache: Cache,
 }
 
 impl State {
@@ -577,6 +1210,121 @@ impl State {
     }
 }
 
+/// The text of a [`NumberInput`] that is edited but not committed yet.
+#[derive(Clone, Debug)]
+struct Buffer {
+    /// The edited text.
+    text: String,
+    /// The value of the [`NumberInput`] the text was edited for.
+    value: String,
+}
+
+/// The texts of a [`NumberInput`] formatted for its last value.
+///
+/// Inputs bound to rapidly changing values are recreated on every update, so
+/// the texts are only formatted again if the value or the format settings
+/// changed. The values are compared by their plain text, so that the state
+/// doesn't depend on the type of the value. The prefix and suffix are only
+/// measured again if they changed.
+#[derive(Default, Clone, Debug)]
+struct Cache {
+    /// The text of the value in its radix.
+    text: RefCell<Option<Formatted<Radix>>>,
+    /// The decimal text displayed while not edited, formatted with the
+    /// decimal places and the group and decimal separators.
+    displayed: RefCell<Option<Formatted<DecimalFormat>>>,
+    /// The measured width of the prefix.
+    prefix_width: RefCell<Option<Measured>>,
+    /// The measured width of the suffix.
+    suffix_width: RefCell<Option<Measured>>,
+}
+
+impl Cache {
+    /// Drops the measured widths of the prefix and suffix.
+    fn clear_widths(&self) {
+        let _ = self.prefix_width.replace(None);
+        let _ = self.suffix_width.replace(None);
+    }
+}
+
+/// The decimal places and the group and decimal separators of a decimal text.
+type DecimalFormat = (Option<usize>, Option<char>, char);
+
+/// A text formatted for a value with the given format settings.
+#[derive(Clone, Debug)]
+struct Formatted<S> {
+    /// The plain text of the value and the format settings the text was
+    /// formatted for.
+    key: (String, S),
+    /// The formatted text.
+    text: String,
+}
+
+/// A text measured with the given text size.
+#[derive(Clone, Debug)]
+struct Measured {
+    /// The measured text.
+    text: String,
+    /// The size of the measured text.
+    size: u16,
+    /// The measured width.
+    width: f32,
+}
+
+/// Returns the cached text if it was formatted for the value with the same
+/// plain text and the same settings, otherwise the text is formatted and
+/// cached.
+fn cached<S: PartialEq>(
+    cache: &RefCell<Option<Formatted<S>>>,
+    value: String,
+    settings: S,
+    format: impl FnOnce() -> String,
+) -> Ref<'_, str> {
+    let key = (value, settings);
+    let is_hit = cache
+        .borrow()
+        .as_ref()
+        .is_some_and(|formatted| formatted.key == key);
+
+    if !is_hit {
+        *cache.borrow_mut() = Some(Formatted {
+            key,
+            text: format(),
+        });
+    }
+
+    Ref::map(cache.borrow(), |formatted| {
+        formatted
+            .as_ref()
+            .map_or("", |formatted| formatted.text.as_str())
+    })
+}
+
+/// Returns the cached width if the same text was measured with the same size,
+/// otherwise the text is measured and its width cached.
+fn measured(
+    cache: &RefCell<Option<Measured>>,
+    text: &str,
+    size: u16,
+    measure: impl FnOnce() -> f32,
+) -> f32 {
+    if let Some(measured) = cache
+        .borrow()
+        .as_ref()
+        .filter(|measured| measured.text == text && measured.size == size)
+    {
+        return measured.width;
+    }
+
+    let width = measure();
+    *cache.borrow_mut() = Some(Measured {
+        text: text.to_owned(),
+        size,
+        width,
+    });
+    width
+}
+
 /// The modifier state of a [`NumberInput`].
 #[derive(Default, Clone, Debug)]
 pub struct ModifierState {
@@ -584,6 +1332,8 @@ pub struct ModifierState {
     pub decrease_pressed: bool,
     /// The state of increase button on a [`NumberInput`].
     pub increase_pressed: bool,
+    /// The state of the keyboard modifiers.
+    keyboard_modifiers: keyboard::Modifiers,
 }
 
 /// The renderer of a [`NumberInput`].
@@ -599,8 +1349,11 @@ pub trait Renderer: text_input::Renderer {
     /// The default padding of a [`NumberInput`].
     const DEFAULT_PADDING: u16;
 
-    #[allow(clippy::too_many_arguments)]
+    #[allow(clippy::too_many_arguments, clippy::fn_params_excessive_bools)]
     /// Draws a [`NumberInput`].
+    ///
+    /// The focused bounds are the bounds of the [`NumberInput`] if it has the
+    /// keyboard focus.
     fn draw(
         &mut self,
         cursor_position: Point,
@@ -610,7 +1363,15 @@ pub trait Renderer: text_input::Renderer {
         is_mouse_over: bool,
         is_decrease_disabled: bool,
         is_increase_disabled: bool,
+        content_bounds: Rectangle,
+        focused_bounds: Option<Rectangle>,
+        is_invalid: bool,
+        is_disabled: bool,
+        show_buttons: bool,
+        button_icons: Option<(Icon, Icon)>,
         content: Self::Output,
+        prefix: Self::Output,
+        suffix: Self::Output,
         style: &<Self as self::Renderer>::Style,
         font: Self::Font,
     ) -> Self::Output;
@@ -620,7 +1381,7 @@ impl<'a, T, Message, Renderer> From<NumberInput<'a, T, Message, Renderer>>
     for Element<'a, Message, Renderer>
 where
     T: 'a + Num + NumAssignOps + PartialOrd + Display + FromStr + Copy,
-    Message: 'a + Clone,
+    Message: 'static + Clone,
     Renderer: 'a + self::Renderer + container::Renderer + column::Renderer + row::Renderer,
 {
     fn from(num_input: NumberInput<'a, T, Message, Renderer>) -> Self {
@@ -643,9 +1404,557 @@ impl Renderer for iced_native::renderer::Null {
         _: bool,
         _: bool,
         _: bool,
+        _: Rectangle,
+        _: Option<Rectangle>,
+        _: bool,
+        _: bool,
+        _: bool,
+        _: Option<(Icon, Icon)>,
+        _: Self::Output,
+        _: Self::Output,
         _: Self::Output,
         _: &<Self as Renderer>::Style,
         _: <Self as text::Renderer>::Font,
     ) -> Self::Output {
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use iced_native::{
+        clipboard, keyboard, layout::Limits, mouse, renderer::Null, text_input, Clipboard, Event,
+        Hasher, Layout, Point, Size, Widget,
+    };
+    use num_traits::{Num, NumAssignOps};
+    use std::hash::Hasher as _;
+    use std::{fmt::Display, str::FromStr};
+
+    use std::cell::{Cell, RefCell};
+
+    use super::{cached, cursor, measured, ButtonLayout, NumberInput, Radix, State, Value};
+
+    /// A clipboard containing the given text.
+    struct Contents(&'static str);
+
+    impl Clipboard for Contents {
+        fn read(&self) -> Option<String> {
+            Some(self.0.to_owned())
+        }
+
+        fn write(&mut self, _contents: String) {}
+    }
+
+    fn focused_state() -> State {
+        State {
+            input_state: text_input::State::focused(),
+            ..State::default()
+        }
+    }
+
+    #[test]
+    fn hex_radix_displays_value() {
+        let mut state = focused_state();
+        let input = NumberInput::<u8, u8, Null>::new(&mut state, 255, 255, |value| value)
+            .radix(Radix::Hexadecimal);
+
+        assert_eq!(input.text, "ff");
+        assert_eq!(
+            super::parse(&input.text, Radix::Hexadecimal, input.bounds),
+            Some(255)
+        );
+    }
+
+    #[test]
+    fn radix_applies_to_text_input() {
+        let mut state = focused_state();
+        let mut input = NumberInput::<u8, u8, Null>::new(&mut state, 255, 255, |value| value)
+            .radix(Radix::Hexadecimal);
+
+        // The cursor moves within the text "ff" and not within "255".
+        let _ = send(
+            &mut input,
+            vec![key(keyboard::KeyCode::End), key(keyboard::KeyCode::Left)],
+            &mut clipboard::Null,
+        );
+        assert_eq!(cursor(&input), Some(1));
+    }
+
+    /// Sends the given events to the input and returns the produced messages.
+    fn send<T>(
+        input: &mut NumberInput<'_, T, T, Null>,
+        events: Vec<(Event, Point)>,
+        clipboard: &mut dyn Clipboard,
+    ) -> Vec<T>
+    where
+        T: 'static + Num + NumAssignOps + PartialOrd + Display + FromStr + Copy,
+    {
+        let renderer = Null::new();
+        let node = input.layout(&renderer, &Limits::new(Size::ZERO, Size::new(200.0, 50.0)));
+        let mut messages = Vec::new();
+        for (event, position) in events {
+            let _ = input.on_event(
+                event,
+                Layout::new(&node),
+                position,
+                &renderer,
+                clipboard,
+                &mut messages,
+            );
+        }
+        messages
+    }
+
+    fn key(key_code: keyboard::KeyCode) -> (Event, Point) {
+        (
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers: keyboard::Modifiers::default(),
+            }),
+            Point::ORIGIN,
+        )
+    }
+
+    fn character(c: char) -> (Event, Point) {
+        (
+            Event::Keyboard(keyboard::Event::CharacterReceived(c)),
+            Point::ORIGIN,
+        )
+    }
+
+    /// Presses Ctrl+V after reporting the pressed control key, like a window
+    /// does.
+    fn paste() -> Vec<(Event, Point)> {
+        let modifiers = keyboard::Modifiers {
+            control: true,
+            ..keyboard::Modifiers::default()
+        };
+
+        vec![
+            (
+                Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)),
+                Point::ORIGIN,
+            ),
+            (
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key_code: keyboard::KeyCode::V,
+                    modifiers,
+                }),
+                Point::ORIGIN,
+            ),
+        ]
+    }
+
+    /// Returns the position of the cursor of the input if nothing is selected.
+    fn cursor<T>(input: &NumberInput<'_, T, T, Null>) -> Option<usize>
+    where
+        T: 'static + Num + NumAssignOps + PartialOrd + Display + FromStr + Copy,
+    {
+        match input
+            .content()
+            .state()
+            .cursor()
+            .state(&Value::new(&input.text))
+        {
+            cursor::State::Index(index) => Some(index),
+            cursor::State::Selection { .. } => None,
+        }
+    }
+
+    #[test]
+    fn pasted_hex_value_is_clamped() {
+        let mut state = focused_state();
+        let mut input = NumberInput::<u8, u8, Null>::new(&mut state, 0, 255, |value| value)
+            .radix(Radix::Hexadecimal);
+
+        let messages = send(&mut input, paste(), &mut Contents("0x1FF"));
+        assert!(messages.is_empty());
+        assert_eq!(input.text, "ff");
+
+        let messages = send(
+            &mut input,
+            vec![key(keyboard::KeyCode::Enter)],
+            &mut clipboard::Null,
+        );
+        assert_eq!(messages, vec![255]);
+        assert_eq!(input.text, "ff");
+    }
+
+    #[test]
+    fn messy_paste_is_sanitized() {
+        let mut state = focused_state();
+        let mut input =
+            NumberInput::<f64, f64, Null>::new(&mut state, 0.0, 10_000.0, |value| value)
+                .group_separator(Some(','));
+
+        let messages = send(&mut input, paste(), &mut Contents(" $1,234.56 "));
+        assert!(messages.is_empty());
+        assert_eq!(input.text, "1234.56");
+
+        let messages = send(
+            &mut input,
+            vec![key(keyboard::KeyCode::Enter)],
+            &mut clipboard::Null,
+        );
+        assert_eq!(messages, vec![1234.56]);
+    }
+
+    #[test]
+    fn cursor_is_placed_behind_sanitized_paste() {
+        let mut state = focused_state();
+        let mut input =
+            NumberInput::<f64, f64, Null>::new(&mut state, 0.0, 10_000.0, |value| value)
+                .group_separator(Some(','));
+
+        let _ = send(&mut input, paste(), &mut Contents(" $1,234.56 "));
+        assert_eq!(input.text, "1234.56");
+        assert_eq!(cursor(&input), Some(7));
+
+        // The cursor stays behind the pasted digits in front of other digits.
+        let mut state = focused_state();
+        let mut input = NumberInput::<u32, u32, Null>::new(&mut state, 9, 10_000, |value| value);
+        let _ = send(&mut input, paste(), &mut Contents("1,2 3"));
+        assert_eq!(input.text, "1239");
+        assert_eq!(cursor(&input), Some(3));
+    }
+
+    #[test]
+    fn pasted_fraction_is_dropped_for_integers() {
+        let mut state = focused_state();
+        let mut input = NumberInput::<u32, u32, Null>::new(&mut state, 0, 10_000, |value| value);
+
+        let _ = send(&mut input, paste(), &mut Contents("USD 1,234.56"));
+        assert_eq!(input.text, "1234");
+    }
+
+    #[test]
+    fn pasted_value_is_clamped() {
+        let mut state = focused_state();
+        let mut input =
+            NumberInput::<i32, i32, Null>::new(&mut state, 0, 100, |value| value).min(-10);
+
+        let _ = send(&mut input, paste(), &mut Contents("about 5000 items"));
+        assert_eq!(input.text, "100");
+        assert_eq!(cursor(&input), Some(3));
+
+        let mut state = focused_state();
+        let mut input =
+            NumberInput::<i32, i32, Null>::new(&mut state, 0, 100, |value| value).min(-10);
+
+        let _ = send(&mut input, paste(), &mut Contents("(-20)"));
+        assert_eq!(input.text, "-10");
+        assert_eq!(cursor(&input), Some(3));
+    }
+
+    #[test]
+    fn paste_without_digits_is_ignored() {
+        let mut state = focused_state();
+        let mut input = NumberInput::<i32, i32, Null>::new(&mut state, 42, 100, |value| value);
+
+        let messages = send(&mut input, paste(), &mut Contents("n/a -.,"));
+        assert!(messages.is_empty());
+        assert_eq!(input.text, "42");
+        assert!(!input.is_invalid());
+    }
+
+    #[test]
+    fn invalid_text_is_kept_until_enter() {
+        let mut state = focused_state();
+        let mut input =
+            NumberInput::<i32, i32, Null>::new(&mut state, 12, 100, |value| value).min(-10);
+
+        // All digits are deleted and a negative value is typed.
+        let messages = send(
+            &mut input,
+            vec![
+                key(keyboard::KeyCode::Delete),
+                key(keyboard::KeyCode::Delete),
+            ],
+            &mut clipboard::Null,
+        );
+        assert!(messages.is_empty());
+        assert_eq!(input.text, "");
+        assert!(input.is_invalid());
+
+        let messages = send(&mut input, vec![character('-')], &mut clipboard::Null);
+        assert!(messages.is_empty());
+        assert!(input.is_invalid());
+
+        let messages = send(
+            &mut input,
+            vec![character('5'), key(keyboard::KeyCode::Enter)],
+            &mut clipboard::Null,
+        );
+        assert_eq!(messages, vec![-5]);
+        assert!(!input.is_invalid());
+    }
+
+    #[test]
+    fn out_of_bounds_text_is_clamped_on_blur() {
+        let mut state = focused_state();
+        let mut input = NumberInput::<i32, i32, Null>::new(&mut state, 0, 100, |value| value);
+
+        let messages = send(
+            &mut input,
+            vec![character('5'), character('0'), character('0')],
+            &mut clipboard::Null,
+        );
+        assert!(messages.is_empty());
+        assert_eq!(input.text, "500");
+        assert!(input.is_invalid());
+
+        let click_outside = (
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Point::new(500.0, 500.0),
+        );
+        let messages = send(&mut input, vec![click_outside], &mut clipboard::Null);
+        assert_eq!(messages, vec![100]);
+        assert_eq!(input.text, "100");
+    }
+
+    #[test]
+    fn stepping_saturates_at_type_limits() {
+        let mut state = focused_state();
+        let mut input = NumberInput::<u32, u32, Null>::new(&mut state, 5, u32::MAX, |value| value);
+        let messages = send(
+            &mut input,
+            vec![key(keyboard::KeyCode::PageDown)],
+            &mut clipboard::Null,
+        );
+        assert_eq!(messages, vec![0]);
+
+        let mut state = focused_state();
+        let mut input =
+            NumberInput::<u8, u8, Null>::new(&mut state, 0, u8::MAX, |value| value).step(30);
+        let messages = send(
+            &mut input,
+            vec![key(keyboard::KeyCode::PageUp)],
+            &mut clipboard::Null,
+        );
+        assert_eq!(messages, vec![u8::MAX]);
+
+        let mut state = focused_state();
+        let mut input =
+            NumberInput::<u8, u8, Null>::new(&mut state, 250, u8::MAX, |value| value).step(10);
+        let messages = send(
+            &mut input,
+            vec![key(keyboard::KeyCode::Up), key(keyboard::KeyCode::Up)],
+            &mut clipboard::Null,
+        );
+        assert_eq!(messages, vec![u8::MAX]);
+
+        let mut state = focused_state();
+        let mut input =
+            NumberInput::<i8, i8, Null>::new(&mut state, i8::MIN, i8::MAX, |value| value)
+                .min(i8::MIN)
+                .step(100);
+        let messages = send(
+            &mut input,
+            vec![
+                key(keyboard::KeyCode::Up),
+                key(keyboard::KeyCode::PageUp),
+                key(keyboard::KeyCode::PageDown),
+                key(keyboard::KeyCode::Down),
+            ],
+            &mut clipboard::Null,
+        );
+        assert_eq!(messages, vec![-28, i8::MAX, i8::MIN]);
+
+        let mut state = focused_state();
+        let mut input =
+            NumberInput::<i64, i64, Null>::new(&mut state, i64::MAX - 1, i64::MAX, |value| value)
+                .min(i64::MIN)
+                .step(i64::MAX);
+        let messages = send(
+            &mut input,
+            vec![
+                key(keyboard::KeyCode::PageUp),
+                key(keyboard::KeyCode::PageDown),
+            ],
+            &mut clipboard::Null,
+        );
+        assert_eq!(messages, vec![i64::MAX, i64::MIN]);
+    }
+
+    #[test]
+    fn shift_wheel_saturates_at_type_limits() {
+        let mut state = State::new();
+        let mut input = NumberInput::<u8, u8, Null>::new(&mut state, 200, u8::MAX, |value| value)
+            .step(30)
+            .wheel_modifier_factor(100);
+        let shift = (
+            Event::Keyboard(keyboard::Event::ModifiersChanged(keyboard::Modifiers {
+                shift: true,
+                ..keyboard::Modifiers::default()
+            })),
+            Point::ORIGIN,
+        );
+        let wheel = |y| {
+            (
+                Event::Mouse(mouse::Event::WheelScrolled {
+                    delta: mouse::ScrollDelta::Lines { x: 0.0, y },
+                }),
+                Point::new(10.0, 10.0),
+            )
+        };
+
+        let messages = send(
+            &mut input,
+            vec![shift, wheel(1.0), wheel(-1.0)],
+            &mut clipboard::Null,
+        );
+        assert_eq!(messages, vec![u8::MAX, 0]);
+    }
+
+    #[test]
+    fn disabled_input_ignores_events() {
+        let mut state = focused_state();
+        let mut input =
+            NumberInput::<i32, i32, Null>::new(&mut state, 5, 100, |value| value).disabled(true);
+
+        let messages = send(
+            &mut input,
+            vec![
+                character('7'),
+                key(keyboard::KeyCode::Up),
+                key(keyboard::KeyCode::Enter),
+            ],
+            &mut clipboard::Null,
+        );
+        assert!(messages.is_empty());
+        assert!(!input.is_focused());
+    }
+
+    #[test]
+    fn read_only_input_keeps_value() {
+        let mut state = focused_state();
+        let mut input =
+            NumberInput::<i32, i32, Null>::new(&mut state, 5, 100, |value| value).read_only(true);
+
+        let messages = send(
+            &mut input,
+            vec![
+                character('7'),
+                key(keyboard::KeyCode::Up),
+                key(keyboard::KeyCode::Delete),
+                key(keyboard::KeyCode::Enter),
+            ],
+            &mut clipboard::Null,
+        );
+        assert!(messages.is_empty());
+        assert_eq!(input.text, "5");
+        assert!(input.is_focused());
+    }
+
+    #[test]
+    fn flags_change_layout_hash() {
+        let hash = |is_disabled, is_read_only| {
+            let mut state = State::new();
+            let input = NumberInput::<i32, i32, Null>::new(&mut state, 5, 100, |value| value)
+                .disabled(is_disabled)
+                .read_only(is_read_only);
+            let mut hasher = Hasher::default();
+            input.hash_layout(&mut hasher);
+            hasher.finish()
+        };
+
+        assert_ne!(hash(false, false), hash(true, false));
+        assert_ne!(hash(false, false), hash(false, true));
+    }
+
+    /// Presses the left mouse button at the given positions of an input
+    /// with the value 1 and the given buttons.
+    fn press_buttons(buttons: ButtonLayout, positions: &[Point]) -> Vec<u8> {
+        let mut state = State::new();
+        let mut input =
+            NumberInput::<u8, u8, Null>::new(&mut state, 1, 255, |value| value).buttons(buttons);
+
+        let events = positions
+            .iter()
+            .map(|position| {
+                (
+                    Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+                    *position,
+                )
+            })
+            .collect();
+        send(&mut input, events, &mut clipboard::Null)
+    }
+
+    #[test]
+    fn button_layouts() {
+        let left = Point::new(0.0, 10.0);
+        let right = Point::new(110.0, 10.0);
+
+        assert_eq!(press_buttons(ButtonLayout::Right, &[right]), vec![2]);
+        assert_eq!(press_buttons(ButtonLayout::Left, &[left]), vec![2]);
+        assert_eq!(press_buttons(ButtonLayout::Split, &[left]), vec![0]);
+        assert_eq!(press_buttons(ButtonLayout::Split, &[right]), vec![2]);
+        assert!(press_buttons(ButtonLayout::Hidden, &[Point::ORIGIN, right]).is_empty());
+    }
+
+    #[test]
+    fn formatted_text_is_cached() {
+        let cache = RefCell::new(None);
+        let calls = Cell::new(0);
+        let format = |value: f32, settings: (Option<usize>, char)| {
+            cached(&cache, value.to_string(), settings, || {
+                calls.set(calls.get() + 1);
+                value.to_string()
+            })
+            .to_owned()
+        };
+
+        assert_eq!(format(1.5, (None, '.')), "1.5");
+        assert_eq!(format(1.5, (None, '.')), "1.5");
+        assert_eq!(calls.get(), 1);
+
+        // A new value or new settings format the text again.
+        assert_eq!(format(2.5, (None, '.')), "2.5");
+        assert_eq!(format(2.5, (Some(2), '.')), "2.5");
+        assert_eq!(calls.get(), 3);
+    }
+
+    #[test]
+    fn measured_width_is_cached() {
+        let cache = RefCell::new(None);
+        let calls = Cell::new(0);
+        let measure = |text: &str, size| {
+            measured(&cache, text, size, || {
+                calls.set(calls.get() + 1);
+                f32::from(size)
+            })
+        };
+
+        assert!((measure("kg", 20) - 20.0).abs() < f32::EPSILON);
+        assert!((measure("kg", 20) - 20.0).abs() < f32::EPSILON);
+        assert_eq!(calls.get(), 1);
+
+        // A new text or size are measured again.
+        let _ = measure("g", 20);
+        let _ = measure("g", 16);
+        assert_eq!(calls.get(), 3);
+    }
+
+    #[test]
+    fn recreated_input_reuses_text() {
+        let text = |state: &State| {
+            state
+                .cache
+                .text
+                .borrow()
+                .as_ref()
+                .map(|formatted| formatted.text.as_ptr())
+        };
+
+        let mut state = State::new();
+        let _ = NumberInput::<u8, u8, Null>::new(&mut state, 12, 255, |value| value);
+        let first = text(&state);
+
+        let input = NumberInput::<u8, u8, Null>::new(&mut state, 12, 255, |value| value);
+        assert_eq!(input.text, "12");
+        drop(input);
+
+        assert!(first.is_some());
+        assert_eq!(first, text(&state));
+    }
+}
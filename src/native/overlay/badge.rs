@@ -0,0 +1,131 @@
+//! Use a badge for color highlighting important information.
+//!
+//! *This API requires the following crate features to be activated: `badge`*
+
+use std::hash::Hash;
+
+use iced_native::{event, layout::Limits, overlay, Clipboard, Event, Layout, Point, Size, Widget};
+
+use crate::native::badge::{self, Anchor, Badge, Offset};
+
+/// The internal overlay of a [`BadgedContent`](crate::native::BadgedContent) for
+/// rendering its [`Badge`](crate::native::Badge) as an overlay.
+#[allow(missing_debug_implementations)]
+pub struct BadgeOverlay<'a, 'b, Message, Renderer: badge::Renderer> {
+    /// The badge drawn over the content.
+    badge: &'a mut Badge<'b, Message, Renderer>,
+    /// The anchor of the badge.
+    anchor: Anchor,
+    /// The offset of the badge.
+    offset: Offset,
+}
+
+impl<'a, 'b, Message, Renderer> BadgeOverlay<'a, 'b, Message, Renderer>
+where
+    'b: 'a,
+    Message: 'b,
+    Renderer: 'b + badge::Renderer,
+{
+    /// Creates a new [`BadgeOverlay`] drawing the given
+    /// [`Badge`](crate::native::Badge).
+    pub fn new(
+        badge: &'a mut Badge<'b, Message, Renderer>,
+        anchor: Anchor,
+        offset: Offset,
+    ) -> Self {
+        BadgeOverlay {
+            badge,
+            anchor,
+            offset,
+        }
+    }
+
+    /// Turns the [`BadgeOverlay`](BadgeOverlay) into an overlay
+    /// [`Element`](iced_native::overlay::Element) centered on the given
+    /// corner position.
+    pub fn overlay(self, position: Point) -> overlay::Element<'a, Message, Renderer> {
+        overlay::Element::new(position, Box::new(self))
+    }
+}
+
+impl<'a, 'b, Message, Renderer> iced_native::Overlay<Message, Renderer>
+    for BadgeOverlay<'a, 'b, Message, Renderer>
+where
+    'b: 'a,
+    Message: 'b,
+    Renderer: 'b + badge::Renderer,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> iced_native::layout::Node {
+        let limits = Limits::new(Size::ZERO, bounds);
+        let mut badge = self.badge.layout(renderer, &limits);
+        let size = badge.size();
+
+        // The badge is centered on the corner and pushed inwards by the offset.
+        let (x_direction, y_direction) = match self.anchor {
+            Anchor::TopRight => (-1.0, 1.0),
+            Anchor::TopLeft => (1.0, 1.0),
+            Anchor::BottomRight => (-1.0, -1.0),
+            Anchor::BottomLeft => (1.0, -1.0),
+        };
+
+        badge.move_to(Point::new(
+            position.x - size.width / 2.0 + x_direction * self.offset.x,
+            position.y - size.height / 2.0 + y_direction * self.offset.y,
+        ));
+
+        badge
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        self.badge.on_event(
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            messages,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self.badge.draw(
+            renderer,
+            defaults,
+            layout,
+            cursor_position,
+            &layout.bounds(),
+        )
+    }
+
+    fn hash_layout(&self, state: &mut iced_native::Hasher, position: Point) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        (position.x as u32).hash(state);
+        (position.y as u32).hash(state);
+        self.anchor.hash(state);
+        (self.offset.x as u32).hash(state);
+        (self.offset.y as u32).hash(state);
+        self.badge.hash_layout(state);
+    }
+}
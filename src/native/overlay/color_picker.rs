@@ -1,18 +1,22 @@
 //! Use a color picker as an input element for picking colors.
 //!
 //! *This API requires the following crate features to be activated: `color_picker`*
-use std::hash::Hash;
+use std::{hash::Hash, time::Instant};
 
 use iced_graphics::canvas;
 use iced_native::{
     button, column, event, keyboard,
     layout::{self, Limits},
     mouse, overlay, row, text, text_input, touch, Align, Button, Clipboard, Color, Column, Element,
-    Event, Layout, Length, Point, Rectangle, Row, Size, Text, Widget,
+    Event, Layout, Length, Point, Rectangle, Row, Size, Text, TextInput, Widget,
 };
 
 use crate::{
-    core::{color::Hsv, overlay::Position, renderer::DrawEnvironment},
+    core::{
+        color::{components, hex_to_color, with_component, ComponentMode, HexString, Hsv},
+        overlay::{is_movement, ChangeThrottle, Position},
+        renderer::DrawEnvironment,
+    },
     graphics::icons::Icon,
     native::{color_picker, icon_text, IconText},
 };
@@ -23,6 +27,8 @@ const PADDING: u16 = 10;
 const SPACING: u16 = 15;
 /// The spacing between the buttons.
 const BUTTON_SPACING: u16 = 5;
+/// The size of the preset and recent color swatches.
+const SWATCH_SIZE: f32 = 20.0;
 
 /// The step value of the keyboard change of the sat/value color values.
 const SAT_VALUE_STEP: f32 = 0.005;
@@ -44,10 +50,28 @@ where
     cancel_button: Element<'a, Message, Renderer>,
     /// The submit button of the [`ColorPickerOverlay`](ColorPickerOverlay).
     submit_button: Element<'a, Message, Renderer>,
+    /// The hex input of the [`ColorPickerOverlay`](ColorPickerOverlay) if it is shown.
+    hex_input: Option<Element<'a, String, Renderer>>,
+    /// The text of the hex input of the [`ColorPickerOverlay`](ColorPickerOverlay).
+    hex_text: &'a mut String,
+    /// The numeric component inputs of the [`ColorPickerOverlay`](ColorPickerOverlay).
+    component_mode: ComponentMode,
+    /// The component inputs of the [`ColorPickerOverlay`](ColorPickerOverlay).
+    component_inputs: Vec<Element<'a, String, Renderer>>,
+    /// The texts of the component inputs of the [`ColorPickerOverlay`](ColorPickerOverlay).
+    component_texts: &'a mut [String; 3],
     /// The function that produces a message when the submit button of the [`ColorPickerOverlay`](ColorPickerOverlay).
     on_submit: &'a dyn Fn(Color) -> Message,
+    /// The optional function that produces a message when the color of the [`ColorPickerOverlay`](ColorPickerOverlay) changes.
+    on_change: Option<&'a dyn Fn(Color) -> Message>,
     /// The position of the [`ColorPickerOverlay`](ColorPickerOverlay).
     position: Point,
+    /// The visibility of the alpha bar of the [`ColorPickerOverlay`](ColorPickerOverlay).
+    show_alpha: bool,
+    /// The preset and recent colors of the [`ColorPickerOverlay`](ColorPickerOverlay).
+    swatches: Vec<Color>,
+    /// The maximum number of recent colors kept by the [`ColorPickerOverlay`](ColorPickerOverlay).
+    recent: usize,
     /// The style of the [`ColorPickerOverlay`](ColorPickerOverlay).
     style: &'a <Renderer as self::Renderer>::Style,
 }
@@ -66,40 +90,124 @@ where
 {
     /// Creates a new [`ColorPickerOverlay`](ColorPickerOverlay) on the given
     /// position.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         state: &'a mut color_picker::State,
         on_cancel: Message,
         on_submit: &'a dyn Fn(Color) -> Message,
         position: Point,
+        show_alpha: bool,
+        show_hex_input: bool,
+        component_mode: ComponentMode,
+        presets: &[Color],
+        recent: usize,
         style: &'a <Renderer as self::Renderer>::Style,
     ) -> Self {
-        //state.color_hex = color_picker::State::color_as_string(state.color);
         let color_picker::State {
             overlay_state,
             cancel_button,
             submit_button,
+            hex_input,
+            hex_text,
+            component_inputs,
+            component_texts,
             ..
         } = state;
 
+        if !show_alpha {
+            overlay_state.color.a = 1.0;
+        }
+
+        overlay_state.recent.truncate(recent);
+        let swatches = presets
+            .iter()
+            .chain(overlay_state.recent.iter())
+            .copied()
+            .collect();
+
+        // Keep the text of the hex input while the user is typing
+        if !hex_input.is_focused() {
+            *hex_text = overlay_state.color.as_hex_string();
+        }
+
+        let hex_input = if show_hex_input {
+            Some(
+                TextInput::new(hex_input, "#RRGGBBAA", hex_text, |text| text)
+                    .padding(PADDING)
+                    .width(Length::Fill)
+                    .into(),
+            )
+        } else {
+            None
+        };
+
+        // Keep the texts of the component inputs while the user is typing
+        if let Some(values) = components(overlay_state.color, component_mode) {
+            for ((input, text), value) in component_inputs
+                .iter()
+                .zip(component_texts.iter_mut())
+                .zip(values)
+            {
+                if !input.is_focused() {
+                    *text = value.to_string();
+                }
+            }
+        }
+
+        let component_inputs = if component_mode == ComponentMode::None {
+            Vec::new()
+        } else {
+            component_inputs
+                .iter_mut()
+                .zip(component_texts.iter())
+                .map(|(input, text)| {
+                    TextInput::new(input, "", text, |text| text)
+                        .padding(PADDING)
+                        .width(Length::Fill)
+                        .into()
+                })
+                .collect()
+        };
+
         ColorPickerOverlay {
             state: overlay_state,
-            cancel_button: Button::new(cancel_button, IconText::new(Icon::X).width(Length::Fill))
-                .width(Length::Fill)
-                .on_press(on_cancel.clone())
-                .into(),
+            cancel_button: Button::new(
+                cancel_button,
+                IconText::from_icon(Icon::X).width(Length::Fill),
+            )
+            .width(Length::Fill)
+            .on_press(on_cancel.clone())
+            .into(),
             submit_button: Button::new(
                 submit_button,
-                IconText::new(Icon::Check).width(Length::Fill),
+                IconText::from_icon(Icon::Check).width(Length::Fill),
             )
             .width(Length::Fill)
             .on_press(on_cancel) // Sending a fake message
             .into(),
+            hex_input,
+            hex_text,
+            component_mode,
+            component_inputs,
+            component_texts,
             on_submit,
+            on_change: None,
             position,
+            show_alpha,
+            swatches,
+            recent,
             style,
         }
     }
 
+    /// Sets the function that produces a message when the picked color
+    /// changes before it is submitted.
+    #[must_use]
+    pub fn on_change(mut self, on_change: Option<&'a dyn Fn(Color) -> Message>) -> Self {
+        self.on_change = on_change;
+        self
+    }
+
     /// Turn this [`ColorPickerOverlay`](ColorPickerOverlay) into an overlay
     /// [`Element`](overlay::Element).
     #[must_use]
@@ -107,7 +215,21 @@ where
         overlay::Element::new(self.position, Box::new(self))
     }
 
+    /// Reports a changed color to the application if it asked for it.
+    fn report_change(&mut self, color: Color, moved: bool, messages: &mut Vec<Message>) {
+        if let Some(on_change) = self.on_change {
+            if self
+                .state
+                .change_throttle
+                .report(self.state.color != color, moved, Instant::now())
+            {
+                messages.push(on_change(self.state.color));
+            }
+        }
+    }
+
     /// The event handling for the HSV color area.
+    #[allow(clippy::too_many_lines)]
     fn on_event_hsv_color(
         &mut self,
         event: &Event,
@@ -130,6 +252,9 @@ where
             .next()
             .expect("Native: Layout should have a hue layout")
             .bounds();
+        let swatches_layout = hsv_color_children
+            .next()
+            .expect("Native: Layout should have a swatches layout");
 
         match event {
             Event::Mouse(mouse::Event::WheelScrolled { delta }) => match delta {
@@ -160,6 +285,21 @@ where
                     self.state.color_bar_dragged = ColorBarDragged::Hue;
                     self.state.focus = Focus::Hue;
                 }
+                if let Some(swatch) =
+                    swatches_layout
+                        .children()
+                        .zip(&self.swatches)
+                        .find_map(|(layout, swatch)| {
+                            layout.bounds().contains(cursor_position).then_some(*swatch)
+                        })
+                {
+                    self.state.color = Color {
+                        a: if self.show_alpha { swatch.a } else { 1.0 },
+                        ..swatch
+                    };
+                    self.state.focus = Focus::Overlay;
+                    color_changed = true;
+                }
             }
             Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerLifted { .. })
@@ -259,15 +399,15 @@ where
             .expect("Native: Layout should have a blue bar layout")
             .bounds();
 
-        let mut alpha_row_children = rgba_color_children
-            .next()
-            .expect("Native: Layout should have an alpha row layout")
-            .children();
-        let _ = alpha_row_children.next();
-        let alpha_bar_bounds = alpha_row_children
-            .next()
-            .expect("Native: Layout should have an alpha bar layout")
-            .bounds();
+        let alpha_bar_bounds = rgba_color_children.next().map(|alpha_row| {
+            let mut alpha_row_children = alpha_row.children();
+            let _ = alpha_row_children.next();
+            alpha_row_children
+                .next()
+                .expect("Native: Layout should have an alpha bar layout")
+                .bounds()
+        });
+        let alpha_bar_contains = |cursor_position: Point| matches!(alpha_bar_bounds, Some(bounds) if bounds.contains(cursor_position));
 
         match event {
             Event::Mouse(mouse::Event::WheelScrolled { delta }) => match delta {
@@ -297,7 +437,7 @@ where
                         };
                         color_changed = true;
                     }
-                    if alpha_bar_bounds.contains(cursor_position) {
+                    if alpha_bar_contains(cursor_position) {
                         self.state.color = Color {
                             a: move_value(self.state.color.a, *y),
                             ..self.state.color
@@ -320,7 +460,7 @@ where
                     self.state.color_bar_dragged = ColorBarDragged::Blue;
                     self.state.focus = Focus::Blue;
                 }
-                if alpha_bar_bounds.contains(cursor_position) {
+                if alpha_bar_contains(cursor_position) {
                     self.state.color_bar_dragged = ColorBarDragged::Alpha;
                     self.state.focus = Focus::Alpha;
                 }
@@ -360,11 +500,13 @@ where
                 color_changed = true;
             }
             ColorBarDragged::Alpha => {
-                self.state.color = Color {
-                    a: calc_percantage(&alpha_bar_bounds, &cursor_position),
-                    ..self.state.color
-                };
-                color_changed = true;
+                if let Some(alpha_bar_bounds) = alpha_bar_bounds {
+                    self.state.color = Color {
+                        a: calc_percantage(&alpha_bar_bounds, &cursor_position),
+                        ..self.state.color
+                    };
+                    color_changed = true;
+                }
             }
             _ => {}
         }
@@ -376,7 +518,108 @@ where
         }
     }
 
+    /// The event handling for the hex input.
+    fn on_event_hex_input(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+    ) -> event::Status {
+        let mut hex_messages: Vec<String> = Vec::new();
+
+        let status = if let Some(hex_input) = &mut self.hex_input {
+            let hex_input_layout = layout
+                .children()
+                .nth(1)
+                .expect("Native: Layout should have a hex input layout");
+
+            hex_input.on_event(
+                event.clone(),
+                hex_input_layout,
+                cursor_position,
+                renderer,
+                clipboard,
+                &mut hex_messages,
+            )
+        } else {
+            return event::Status::Ignored;
+        };
+
+        if let Some(text) = hex_messages.pop() {
+            // Only valid colors are applied, everything else is kept as is
+            // until the user finishes typing.
+            if let Some(color) = hex_to_color(&text) {
+                self.state.color = Color {
+                    a: if self.show_alpha { color.a } else { 1.0 },
+                    ..color
+                };
+            }
+            *self.hex_text = text;
+        }
+
+        if status == event::Status::Captured {
+            self.state.focus = Focus::Overlay;
+            self.state.color_bar_dragged = ColorBarDragged::None;
+        }
+
+        status
+    }
+
+    /// The event handling for the component inputs.
+    fn on_event_component_inputs(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+    ) -> event::Status {
+        let mut status = event::Status::Ignored;
+
+        for (index, (component_input, cell_layout)) in self
+            .component_inputs
+            .iter_mut()
+            .zip(layout.children())
+            .enumerate()
+        {
+            let component_input_layout = cell_layout
+                .children()
+                .nth(1)
+                .expect("Native: Layout should have a component input layout");
+
+            let mut component_messages: Vec<String> = Vec::new();
+            status = status.merge(component_input.on_event(
+                event.clone(),
+                component_input_layout,
+                cursor_position,
+                renderer,
+                clipboard,
+                &mut component_messages,
+            ));
+
+            if let Some(text) = component_messages.pop() {
+                // Only numbers are applied, everything else is kept as is
+                // until the user finishes typing.
+                if let Ok(value) = text.trim().parse::<i32>() {
+                    self.state.color =
+                        with_component(self.state.color, self.component_mode, index, value);
+                }
+                self.component_texts[index] = text;
+            }
+        }
+
+        if status == event::Status::Captured {
+            self.state.focus = Focus::Overlay;
+            self.state.color_bar_dragged = ColorBarDragged::None;
+        }
+
+        status
+    }
+
     /// The even handling for the keyboard input.
+    #[allow(clippy::too_many_lines)]
     fn on_event_keyboard(
         &mut self,
         event: &Event,
@@ -394,10 +637,14 @@ where
             let mut status = event::Status::Ignored;
 
             if let keyboard::KeyCode::Tab = key_code {
-                if self.state.keyboard_modifiers.shift {
-                    self.state.focus = self.state.focus.previous();
+                let step = if self.state.keyboard_modifiers.shift {
+                    Focus::previous
                 } else {
-                    self.state.focus = self.state.focus.next();
+                    Focus::next
+                };
+                self.state.focus = step(self.state.focus);
+                if !self.show_alpha && self.state.focus == Focus::Alpha {
+                    self.state.focus = step(self.state.focus);
                 }
                 // TODO: maybe place this better
                 self.state.sat_value_canvas_cache.clear();
@@ -589,6 +836,7 @@ where
         node
     }
 
+    #[allow(clippy::too_many_lines)]
     fn on_event(
         &mut self,
         event: Event,
@@ -598,6 +846,56 @@ where
         clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
     ) -> event::Status {
+        let color = self.state.color;
+        let moved = is_movement(&event);
+
+        let mut children = layout.children();
+
+        let block1_layout = children
+            .next()
+            .expect("Native: Layout should have a 1. block layout");
+        let block2_layout = children
+            .next()
+            .expect("Native: Layout should have a 2. block layout");
+        let mut block2_children = block2_layout.children();
+
+        let rgba_color_layout = block2_children
+            .next()
+            .expect("Native: Layout should have a RGBA color layout");
+
+        // ----------- Hex input ----------------------
+        let hex_text_layout = block2_children
+            .next()
+            .expect("Native: Layout should have a hex text layout");
+        let hex_input_status = self.on_event_hex_input(
+            &event,
+            hex_text_layout,
+            cursor_position,
+            renderer,
+            clipboard,
+        );
+
+        // ----------- Component inputs ----------------------
+        let component_inputs_status = block2_layout.children().nth(4).map_or(
+            event::Status::Ignored,
+            |component_inputs_layout| {
+                self.on_event_component_inputs(
+                    &event,
+                    component_inputs_layout,
+                    cursor_position,
+                    renderer,
+                    clipboard,
+                )
+            },
+        );
+
+        if hex_input_status.merge(component_inputs_status) == event::Status::Captured {
+            self.state.sat_value_canvas_cache.clear();
+            self.state.hue_canvas_cache.clear();
+            self.report_change(color, moved, messages);
+            return event::Status::Captured;
+        }
+
         if let event::Status::Captured = self.on_event_keyboard(
             &event,
             layout,
@@ -608,17 +906,13 @@ where
         ) {
             self.state.sat_value_canvas_cache.clear();
             self.state.hue_canvas_cache.clear();
+            self.report_change(color, moved, messages);
             return event::Status::Captured;
         }
 
-        let mut children = layout.children();
-
         let status = event::Status::Ignored;
 
         // ----------- Block 1 ----------------------
-        let block1_layout = children
-            .next()
-            .expect("Native: Layout should have a 1. block layout");
         let hsv_color_status = self.on_event_hsv_color(
             &event,
             block1_layout,
@@ -630,15 +924,7 @@ where
         // ----------- Block 1 end ------------------
 
         // ----------- Block 2 ----------------------
-        let mut block2_children = children
-            .next()
-            .expect("Native: Layout should have a 2. block layout")
-            .children();
-
         // ----------- RGB Color -----------------------
-        let rgba_color_layout = block2_children
-            .next()
-            .expect("Native: Layout should have a RGBA color layout");
         let rgba_color_status = self.on_event_rgba_color(
             &event,
             rgba_color_layout,
@@ -650,11 +936,6 @@ where
 
         let mut fake_messages: Vec<Message> = Vec::new();
 
-        // ----------- Text input ----------------------
-        let _text_input_layout = block2_children
-            .next()
-            .expect("Native: Layout should have a hex text layout");
-
         // ----------- Buttons -------------------------
         let cancel_button_layout = block2_children
             .next()
@@ -681,7 +962,13 @@ where
         );
 
         if !fake_messages.is_empty() {
-            messages.push((self.on_submit)(self.state.color));
+            let color = self.state.color;
+            if self.recent > 0 {
+                self.state.recent.retain(|recent| *recent != color);
+                self.state.recent.insert(0, color);
+                self.state.recent.truncate(self.recent);
+            }
+            messages.push((self.on_submit)(color));
         }
         // ----------- Block 2 end ------------------
 
@@ -692,6 +979,8 @@ where
             self.state.hue_canvas_cache.clear();
         }
 
+        self.report_change(color, moved, messages);
+
         status
             .merge(hsv_color_status)
             .merge(rgba_color_status)
@@ -717,9 +1006,14 @@ where
                 focus: self.state.focus,
             },
             &self.state.color,
+            self.show_alpha,
+            &self.swatches,
             &self.state.sat_value_canvas_cache,
             &self.state.hue_canvas_cache,
-            //&self.text_input,
+            self.hex_input.as_ref(),
+            hex_to_color(self.hex_text).is_some(),
+            self.component_mode,
+            &self.component_inputs,
             &self.cancel_button,
             &self.submit_button,
         )
@@ -732,12 +1026,16 @@ where
 
         (position.x as u32).hash(state);
         (position.y as u32).hash(state);
+        self.show_alpha.hash(state);
+        self.hex_input.is_some().hash(state);
+        self.component_mode.hash(state);
+        self.swatches.len().hash(state);
     }
 }
 
 /// Defines the layout of the 1. block of the color picker containing the HSV part.
 fn block1_layout<'a, Message, Renderer>(
-    _color_picker: &ColorPickerOverlay<'a, Message, Renderer>,
+    color_picker: &ColorPickerOverlay<'a, Message, Renderer>,
     renderer: &Renderer,
     bounds: iced_graphics::Rectangle,
     _position: Point,
@@ -757,7 +1055,15 @@ where
         .width(Length::Fill)
         .height(Length::Fill);
 
-    let mut block1_node = Column::<(), Renderer>::new()
+    let swatches = swatches_layout(color_picker.swatches.len(), bounds.width);
+    let swatches_height = if color_picker.swatches.is_empty() {
+        0.0
+    } else {
+        swatches.size().height + f32::from(PADDING)
+    };
+    let block1_limits = block1_limits.shrink(Size::new(0.0, swatches_height));
+
+    let hsv_node = Column::<(), Renderer>::new()
         .spacing(PADDING)
         .push(
             Row::new()
@@ -771,6 +1077,19 @@ where
         )
         .layout(renderer, &block1_limits);
 
+    let mut children = hsv_node.children().to_vec();
+    let mut swatches = swatches;
+    swatches.move_to(Point::new(0.0, hsv_node.size().height + f32::from(PADDING)));
+    children.push(swatches);
+
+    let mut block1_node = layout::Node::with_children(
+        Size::new(
+            hsv_node.size().width,
+            hsv_node.size().height + swatches_height,
+        ),
+        children,
+    );
+
     block1_node.move_to(Point::new(
         bounds.x + f32::from(PADDING),
         bounds.y + f32::from(PADDING),
@@ -779,7 +1098,36 @@ where
     block1_node
 }
 
+/// Defines the layout of the preset and recent color swatches, wrapping them
+/// into multiple rows if they don't fit into the given width.
+fn swatches_layout(count: usize, width: f32) -> layout::Node {
+    let count = count as u16;
+    let spacing = f32::from(BUTTON_SPACING);
+    let per_row = (((width + spacing) / (SWATCH_SIZE + spacing)).floor() as u16).max(1);
+
+    let children: Vec<layout::Node> = (0..count)
+        .map(|index| {
+            let mut node = layout::Node::new(Size::new(SWATCH_SIZE, SWATCH_SIZE));
+            node.move_to(Point::new(
+                f32::from(index % per_row) * (SWATCH_SIZE + spacing),
+                f32::from(index / per_row) * (SWATCH_SIZE + spacing),
+            ));
+            node
+        })
+        .collect();
+
+    let rows = count.div_ceil(per_row);
+    let height = if rows == 0 {
+        0.0
+    } else {
+        f32::from(rows).mul_add(SWATCH_SIZE + spacing, -spacing)
+    };
+
+    layout::Node::with_children(Size::new(width, height), children)
+}
+
 /// Defines the layout of the 2. block of the color picker containing the RGBA part, Hex and buttons.
+#[allow(clippy::too_many_lines)]
 fn block2_layout<'a, Message, Renderer>(
     color_picker: &ColorPickerOverlay<'a, Message, Renderer>,
     renderer: &Renderer,
@@ -813,15 +1161,78 @@ where
         ))
         .layout(renderer, &hex_text_limits);
 
+    if let Some(hex_input) = &color_picker.hex_input {
+        let size = hex_text.size();
+        let preview_width = ((size.width - f32::from(BUTTON_SPACING)) / 3.0).max(0.0);
+        let preview = layout::Node::new(Size::new(preview_width, size.height));
+
+        let hex_input_limits = Limits::new(
+            Size::ZERO,
+            Size::new(
+                (size.width - preview_width - f32::from(BUTTON_SPACING)).max(0.0),
+                size.height,
+            ),
+        );
+        let mut hex_input = hex_input.layout(renderer, &hex_input_limits);
+        hex_input.move_to(Point::new(preview_width + f32::from(BUTTON_SPACING), 0.0));
+
+        hex_text = layout::Node::with_children(size, vec![preview, hex_input]);
+    }
+
+    // Component inputs
+    let mut component_inputs = if color_picker.component_inputs.is_empty() {
+        None
+    } else {
+        let size = hex_text.size();
+        let spacing = f32::from(BUTTON_SPACING);
+        let cell_width = ((size.width - 2.0 * spacing) / 3.0).max(0.0);
+        let label_width = f32::from(text::Renderer::default_size(renderer)).min(cell_width);
+
+        let cells = color_picker
+            .component_inputs
+            .iter()
+            .enumerate()
+            .map(|(index, component_input)| {
+                let label = layout::Node::new(Size::new(label_width, size.height));
+
+                let component_input_limits = Limits::new(
+                    Size::ZERO,
+                    Size::new((cell_width - label_width).max(0.0), size.height),
+                );
+                let mut component_input = component_input.layout(renderer, &component_input_limits);
+                component_input.move_to(Point::new(label_width, 0.0));
+
+                #[allow(clippy::cast_precision_loss)]
+                let x = index as f32 * (cell_width + spacing);
+                let mut cell = layout::Node::with_children(
+                    Size::new(cell_width, size.height),
+                    vec![label, component_input],
+                );
+                cell.move_to(Point::new(x, 0.0));
+                cell
+            })
+            .collect();
+
+        Some(layout::Node::with_children(size, cells))
+    };
+    let component_inputs_height = component_inputs
+        .as_ref()
+        .map_or(0.0, |node| node.bounds().height + f32::from(SPACING));
+
     let block2_limits = block2_limits.shrink(Size::new(
         0.0,
-        cancel_button.bounds().height + hex_text.bounds().height + 2.0 * f32::from(SPACING),
+        cancel_button.bounds().height
+            + hex_text.bounds().height
+            + component_inputs_height
+            + 2.0 * f32::from(SPACING),
     ));
 
     // RGBA Colors
     let mut rgba_colors = Column::<(), Renderer>::new();
 
-    for _ in 0..4 {
+    let bar_count = if color_picker.show_alpha { 4 } else { 3 };
+
+    for _ in 0..bar_count {
         rgba_colors = rgba_colors.push(
             Row::new()
                 .align_items(Align::Center)
@@ -859,6 +1270,13 @@ where
         hex_text.bounds().y + rgba_colors.bounds().height + f32::from(PADDING) + f32::from(SPACING),
     ));
 
+    if let Some(component_inputs) = &mut component_inputs {
+        component_inputs.move_to(Point::new(
+            f32::from(PADDING),
+            hex_text.bounds().y + hex_text.bounds().height + f32::from(SPACING),
+        ));
+    }
+
     // Buttons
     let cancel_limits = block2_limits.clone().max_width(
         ((rgba_colors.bounds().width / 2.0) - f32::from(BUTTON_SPACING)).max(0.0) as u32,
@@ -877,6 +1295,7 @@ where
         cancel_button.bounds().y
             + rgba_colors.bounds().height
             + hex_text.bounds().height
+            + component_inputs_height
             + f32::from(PADDING)
             + 2.0 * f32::from(SPACING),
     ));
@@ -887,6 +1306,7 @@ where
         submit_button.bounds().y
             + rgba_colors.bounds().height
             + hex_text.bounds().height
+            + component_inputs_height
             + f32::from(PADDING)
             + 2.0 * f32::from(SPACING),
     ));
@@ -896,11 +1316,15 @@ where
             rgba_colors.bounds().width + (2.0 * f32::from(PADDING)),
             rgba_colors.bounds().height
                 + hex_text.bounds().height
+                + component_inputs_height
                 + cancel_button.bounds().height
                 + (2.0 * f32::from(PADDING))
                 + (2.0 * f32::from(SPACING)),
         ),
-        vec![rgba_colors, hex_text, cancel_button, submit_button],
+        vec![rgba_colors, hex_text, cancel_button, submit_button]
+            .into_iter()
+            .chain(component_inputs)
+            .collect(),
     );
     block2_node.move_to(Point::new(bounds.x, bounds.y));
 
@@ -917,13 +1341,19 @@ pub trait Renderer: iced_native::Renderer {
     type Style: Default;
 
     /// Draws a [`ColorPickerOverlay`](ColorPickerOverlay)
+    #[allow(clippy::too_many_arguments)]
     fn draw<Message>(
         &mut self,
         env: DrawEnvironment<'_, Self::Defaults, Self::Style, Focus>,
         color: &Color,
+        show_alpha: bool,
+        swatches: &[Color],
         sat_value_canvas_cache: &canvas::Cache,
         hue_canvas_cache: &canvas::Cache,
-        //text_input: &Element<'_, Message, Self>,
+        hex_input: Option<&Element<'_, String, Self>>,
+        hex_valid: bool,
+        component_mode: ComponentMode,
+        component_inputs: &[Element<'_, String, Self>],
         cancel_button: &Element<'_, Message, Self>,
         submit_button: &Element<'_, Message, Self>,
     ) -> Self::Output;
@@ -937,9 +1367,14 @@ impl Renderer for iced_native::renderer::Null {
         &mut self,
         _env: DrawEnvironment<'_, Self::Defaults, Self::Style, Focus>,
         _color: &Color,
+        _show_alpha: bool,
+        _swatches: &[Color],
         _sat_value_canvas_cache: &canvas::Cache,
         _hue_canvas_cache: &canvas::Cache,
-        //_text_input: &Element<'_, Message, Self>,
+        _hex_input: Option<&Element<'_, String, Self>>,
+        _hex_valid: bool,
+        _component_mode: ComponentMode,
+        _component_inputs: &[Element<'_, String, Self>],
         _cancel_button: &Element<'_, Message, Self>,
         _submit_button: &Element<'_, Message, Self>,
     ) -> Self::Output {
@@ -961,6 +1396,10 @@ pub struct State {
     pub(crate) focus: Focus,
     /// The previously pressed keyboard modifiers.
     pub(crate) keyboard_modifiers: keyboard::Modifiers,
+    /// The recently submitted colors of the [`ColorPickerOverlay`](ColorPickerOverlay).
+    pub(crate) recent: Vec<Color>,
+    /// The throttle of the change messages of the [`ColorPickerOverlay`](ColorPickerOverlay).
+    pub(crate) change_throttle: ChangeThrottle,
 }
 
 impl Default for State {
@@ -972,6 +1411,8 @@ impl Default for State {
             color_bar_dragged: ColorBarDragged::None,
             focus: Focus::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
+            recent: Vec::new(),
+            change_throttle: ChangeThrottle::default(),
         }
     }
 }
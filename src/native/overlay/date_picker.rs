@@ -1,24 +1,27 @@
 //! Use a date picker as an input element for picking dates.
 //!
 //! *This API requires the following crate features to be activated: `date_picker`*
-use std::hash::Hash;
+use std::{hash::Hash, time::Instant};
 
-use chrono::{Datelike, Local, NaiveDate};
+use chrono::{Datelike, Local, NaiveDate, Weekday};
 use iced_native::{
     button, column, container, event, keyboard,
     layout::{self, Limits},
     mouse, overlay, row, text, touch, Align, Button, Clipboard, Column, Container, Element, Event,
-    Layout, Length, Point, Row, Size, Text, Widget,
+    HorizontalAlignment, Layout, Length, Point, Row, Size, Text, Widget,
 };
 
 use crate::{
     core::{
-        date::{Date, IsInMonth},
-        overlay::Position,
+        date::{self, Date, MonthNames, WeekdayNames},
+        overlay::{is_movement, ChangeThrottle, Position},
         renderer::DrawEnvironment,
     },
     graphics::icons::Icon,
-    native::{date_picker, icon_text, IconText},
+    native::{
+        date_picker::{self, OnSubmit},
+        icon_text, IconText,
+    },
 };
 
 /// The padding around the elements.
@@ -29,6 +32,17 @@ const SPACING: u16 = 15;
 const DAY_CELL_PADDING: u16 = 7;
 /// The spacing between the buttons.
 const BUTTON_SPACING: u16 = 5;
+/// The maximum number of steps the keyboard navigation takes to skip over
+/// disabled days.
+const MAX_SKIPPED_STEPS: usize = 366;
+/// The number of columns of the month picker.
+const MONTH_COLUMNS: usize = 3;
+/// The number of columns of the year picker.
+const YEAR_COLUMNS: usize = 4;
+/// The number of rows of the month and year pickers.
+const PICKER_ROWS: usize = 4;
+/// The number of years shown at once by the year picker.
+const YEARS_PER_PAGE: i32 = (YEAR_COLUMNS * PICKER_ROWS) as i32;
 
 /// The overlay of the [`DatePicker`](crate::native::DatePicker).
 #[allow(missing_debug_implementations)]
@@ -43,10 +57,29 @@ where
     cancel_button: Element<'a, Message, Renderer>,
     /// The submit button of the [`DatePickerOverlay`](DatePickerOverlay).
     submit_button: Element<'a, Message, Renderer>,
+    /// The optional button of the [`DatePickerOverlay`](DatePickerOverlay)
+    /// selecting the current date.
+    today_button: Option<Element<'a, Message, Renderer>>,
     /// The function that produces a message when the submit button of the [`DatePickerOverlay`](DatePickerOverlay) is pressed.
-    on_submit: &'a dyn Fn(Date) -> Message,
+    on_submit: &'a OnSubmit<Message>,
+    /// The optional function that produces a message when the date of the [`DatePickerOverlay`](DatePickerOverlay) changes.
+    on_change: Option<&'a dyn Fn(Date) -> Message>,
     /// The position of the [`DatePickerOverlay`](DatePickerOverlay).
     position: Point,
+    /// The minimum selectable date of the [`DatePickerOverlay`](DatePickerOverlay).
+    min: Option<NaiveDate>,
+    /// The maximum selectable date of the [`DatePickerOverlay`](DatePickerOverlay).
+    max: Option<NaiveDate>,
+    /// The function that decides if a day can be selected.
+    day_is_enabled: Option<&'a dyn Fn(Date) -> bool>,
+    /// The first day of the week of the [`DatePickerOverlay`](DatePickerOverlay).
+    first_weekday: Weekday,
+    /// The names of the months and weekdays of the [`DatePickerOverlay`](DatePickerOverlay).
+    labels: Option<&'a (MonthNames, WeekdayNames)>,
+    /// The current date highlighted by the [`DatePickerOverlay`](DatePickerOverlay).
+    today: Option<NaiveDate>,
+    /// Toggles the column of week numbers of the [`DatePickerOverlay`](DatePickerOverlay).
+    show_week_numbers: bool,
     /// The style of teh [`DatePickerOverlay`](DatePickerOverlay).
     style: &'a <Renderer as self::Renderer>::Style,
 }
@@ -65,11 +98,19 @@ where
 {
     /// Creates a new [`DatePickerOverlay`](DatePickerOverlay) on the given
     /// position.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         state: &'a mut date_picker::State,
         on_cancel: Message,
-        on_submit: &'a dyn Fn(Date) -> Message,
+        on_submit: &'a OnSubmit<Message>,
         position: Point,
+        min: Option<Date>,
+        max: Option<Date>,
+        day_is_enabled: Option<&'a dyn Fn(Date) -> bool>,
+        first_weekday: Weekday,
+        labels: Option<&'a (MonthNames, WeekdayNames)>,
+        today: Option<Date>,
+        show_week_numbers: bool,
         style: &'a <Renderer as self::Renderer>::Style,
         //button_style: impl Clone +  Into<<Renderer as button::Renderer>::Style>, // clone not satisfied
     ) -> Self {
@@ -77,27 +118,74 @@ where
             overlay_state,
             cancel_button,
             submit_button,
+            today_button,
             ..
         } = state;
 
-        DatePickerOverlay {
+        let min = min.map(NaiveDate::from);
+        let max = max.map(NaiveDate::from);
+        overlay_state.date = date::clamp_date(overlay_state.date, min, max);
+
+        let mut overlay = DatePickerOverlay {
             state: overlay_state,
-            cancel_button: Button::new(cancel_button, IconText::new(Icon::X).width(Length::Fill))
-                .width(Length::Fill)
-                .on_press(on_cancel.clone())
-                //.style(button_style.clone())
-                .into(),
+            cancel_button: Button::new(
+                cancel_button,
+                IconText::from_icon(Icon::X).width(Length::Fill),
+            )
+            .width(Length::Fill)
+            .on_press(on_cancel.clone())
+            //.style(button_style.clone())
+            .into(),
             submit_button: Button::new(
                 submit_button,
-                IconText::new(Icon::Check).width(Length::Fill),
+                IconText::from_icon(Icon::Check).width(Length::Fill),
             )
             .width(Length::Fill)
-            .on_press(on_cancel) // Sending a fake message
+            .on_press(on_cancel.clone()) // Sending a fake message
             //.style(button_style)
             .into(),
+            today_button: today.map(move |_| {
+                Button::new(
+                    today_button,
+                    Text::new("Today")
+                        .width(Length::Fill)
+                        .horizontal_alignment(HorizontalAlignment::Center),
+                )
+                .width(Length::Fill)
+                .on_press(on_cancel) // Sending a fake message
+                .into()
+            }),
             on_submit,
+            on_change: None,
             position,
+            min,
+            max,
+            day_is_enabled,
+            first_weekday,
+            labels,
+            today: today.map(NaiveDate::from),
+            show_week_numbers,
             style,
+        };
+        overlay.select_nearest_enabled(|_| true);
+        overlay
+    }
+
+    /// Sets the function that produces a message when the picked date changes
+    /// before it is submitted.
+    #[must_use]
+    pub fn on_change(mut self, on_change: Option<&'a dyn Fn(Date) -> Message>) -> Self {
+        self.on_change = on_change;
+        self
+    }
+
+    /// Gives the keyboard focus to the [`DatePickerOverlay`](DatePickerOverlay)
+    /// or takes it away.
+    pub(crate) fn set_focused(&mut self, focused: bool) {
+        if !focused {
+            self.state.focus = Focus::None;
+        } else if self.state.focus == Focus::None {
+            self.state.focus = Focus::Overlay;
         }
     }
 
@@ -108,14 +196,155 @@ where
         overlay::Element::new(self.position, Box::new(self))
     }
 
+    /// Reports a changed date to the application if it asked for it.
+    fn report_change(&mut self, date: NaiveDate, moved: bool, messages: &mut Vec<Message>) {
+        if let Some(on_change) = self.on_change {
+            if self
+                .state
+                .change_throttle
+                .report(self.state.date != date, moved, Instant::now())
+            {
+                messages.push(on_change(self.state.date.into()));
+            }
+        }
+    }
+
     /// String representation of the current year.
     fn year_as_string(&self) -> String {
-        crate::core::date::year_as_string(self.state.date)
+        date::year_as_string(self.state.date)
     }
 
     /// String representation of the current month.
     fn month_as_string(&self) -> String {
-        crate::core::date::month_as_string(self.state.date)
+        self.month_name(self.state.date)
+    }
+
+    /// String representation of the month of the given date.
+    fn month_name(&self, date: NaiveDate) -> String {
+        self.labels.map_or_else(
+            || date::month_as_string(date),
+            |(months, _)| months[date.month0() as usize].clone(),
+        )
+    }
+
+    /// The cells of the month or year picker, or nothing while the days are
+    /// shown.
+    fn picker_cells(&self) -> Vec<PickerCell> {
+        let date = self.state.date;
+
+        match self.state.view {
+            View::Days => Vec::new(),
+            View::Months => (1..=12)
+                .map(|month| {
+                    let month_date = date::with_year_month(date, date.year(), month);
+                    PickerCell {
+                        label: self.month_name(month_date),
+                        is_selected: month == date.month(),
+                        is_enabled: date::is_month_in_range(month_date, self.min, self.max),
+                    }
+                })
+                .collect(),
+            View::Years(first) => (first..first + YEARS_PER_PAGE)
+                .map(|year| PickerCell {
+                    label: format!("{year:04}"),
+                    is_selected: year == date.year(),
+                    is_enabled: date::is_year_in_range(year, self.min, self.max),
+                })
+                .collect(),
+        }
+    }
+
+    /// Shows the given view or, if it is already shown, the days.
+    const fn toggle_view(&mut self, view: View) {
+        let is_shown = matches!(
+            (self.state.view, view),
+            (View::Months, View::Months) | (View::Years(_), View::Years(_))
+        );
+        self.state.view = if is_shown { View::Days } else { view };
+    }
+
+    /// The labels of the weekdays in the order of the calendar table.
+    fn weekday_labels(&self) -> Vec<String> {
+        let labels = self
+            .labels
+            .map_or(&date::WEEKDAY_LABELS[..], |(_, weekdays)| &weekdays[..]);
+
+        date::ordered_weekday_labels(labels, self.first_weekday)
+    }
+
+    /// Checks if the given day can be selected.
+    fn is_day_enabled(&self, date: NaiveDate) -> bool {
+        date::is_in_range(date, self.min, self.max)
+            && !matches!(self.day_is_enabled, Some(day_is_enabled) if !day_is_enabled(date.into()))
+    }
+
+    /// Selects the first selectable day reached by repeatedly applying the
+    /// given step to the current date.
+    fn select_step(&mut self, step: fn(NaiveDate) -> NaiveDate) {
+        let mut date = step(self.state.date);
+
+        for _ in 0..MAX_SKIPPED_STEPS {
+            if !date::is_in_range(date, self.min, self.max) {
+                break;
+            }
+            if self.is_day_enabled(date) {
+                self.state.date = date;
+                break;
+            }
+            date = step(date);
+        }
+    }
+
+    /// Selects the selectable day nearest to the current date if the current
+    /// date can't be selected. Earlier days are preferred and only days for
+    /// which `within` holds are considered.
+    fn select_nearest_enabled(&mut self, within: impl Fn(NaiveDate) -> bool) {
+        let mut earlier = self.state.date;
+        let mut later = self.state.date;
+
+        for _ in 0..MAX_SKIPPED_STEPS {
+            if let Some(date) = [earlier, later]
+                .iter()
+                .copied()
+                .find(|&date| within(date) && self.is_day_enabled(date))
+            {
+                self.state.date = date;
+                return;
+            }
+            earlier = date::pred_day(earlier);
+            later = date::succ_day(later);
+        }
+    }
+
+    /// Shows the month of the given date if it contains any selectable day.
+    ///
+    /// If the day itself can't be selected, the nearest selectable day of
+    /// the month is selected instead.
+    fn navigate(&mut self, date: NaiveDate) {
+        if date::is_month_in_range(date, self.min, self.max) {
+            let date = date::clamp_date(date, self.min, self.max);
+            self.state.date = date;
+            self.select_nearest_enabled(|day| {
+                day.year() == date.year() && day.month() == date.month()
+            });
+        }
+    }
+
+    /// Shows the month of the current date and selects it if it can be
+    /// selected.
+    fn select_today(&mut self) {
+        let Some(today) = self.today else {
+            return;
+        };
+
+        if self.is_day_enabled(today) {
+            self.state.date = today;
+            if let OnSubmit::Range(_) = self.on_submit {
+                self.state.range = self.state.range.pick(today);
+            }
+        } else {
+            self.navigate(today);
+        }
     }
 
     /// The event handling for the month / year bar.
@@ -142,7 +371,7 @@ where
             .next()
             .expect("Native: Layout should have a left month arrow layout")
             .bounds();
-        let _center_bounds = month_children
+        let center_bounds = month_children
             .next()
             .expect("Native: Layout should have a center month layout")
             .bounds();
@@ -159,10 +388,13 @@ where
                 }
 
                 if left_bounds.contains(cursor_position) {
-                    self.state.date = crate::core::date::pred_month(self.state.date);
+                    self.navigate(date::pred_month(self.state.date));
                     status = event::Status::Captured;
                 } else if right_bounds.contains(cursor_position) {
-                    self.state.date = crate::core::date::succ_month(self.state.date);
+                    self.navigate(date::succ_month(self.state.date));
+                    status = event::Status::Captured;
+                } else if center_bounds.contains(cursor_position) {
+                    self.toggle_view(View::Months);
                     status = event::Status::Captured;
                 }
             }
@@ -179,7 +411,7 @@ where
             .next()
             .expect("Native: Layout should have a left year arrow layout")
             .bounds();
-        let _center_bounds = year_children
+        let center_bounds = year_children
             .next()
             .expect("Native: Layout should have a center year layout")
             .bounds();
@@ -195,11 +427,28 @@ where
                     self.state.focus = Focus::Year;
                 }
 
-                if left_bounds.contains(cursor_position) {
-                    self.state.date = crate::core::date::pred_year(self.state.date);
+                // The arrows turn the pages of the year picker.
+                if let View::Years(first) = self.state.view {
+                    if left_bounds.contains(cursor_position) {
+                        self.state.view = View::Years(first - YEARS_PER_PAGE);
+                        status = event::Status::Captured;
+                    } else if right_bounds.contains(cursor_position) {
+                        self.state.view = View::Years(first + YEARS_PER_PAGE);
+                        status = event::Status::Captured;
+                    }
+                } else if left_bounds.contains(cursor_position) {
+                    self.navigate(date::pred_year(self.state.date));
                     status = event::Status::Captured;
                 } else if right_bounds.contains(cursor_position) {
-                    self.state.date = crate::core::date::succ_year(self.state.date);
+                    self.navigate(date::succ_year(self.state.date));
+                    status = event::Status::Captured;
+                }
+
+                if center_bounds.contains(cursor_position) {
+                    self.toggle_view(View::Years(date::year_page(
+                        self.state.date.year(),
+                        YEARS_PER_PAGE,
+                    )));
                     status = event::Status::Captured;
                 }
             }
@@ -209,6 +458,73 @@ where
         status
     }
 
+    /// The event handling for the month and year pickers.
+    fn on_event_picker(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+    ) -> event::Status {
+        if !layout.bounds().contains(cursor_position) {
+            return event::Status::Ignored;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(index) = layout
+                    .children()
+                    .flat_map(Layout::children)
+                    .position(|cell| cell.bounds().contains(cursor_position))
+                else {
+                    return event::Status::Ignored;
+                };
+
+                let date = self.state.date;
+                let picked = match self.state.view {
+                    View::Months => {
+                        Some(date::with_year_month(date, date.year(), index as u32 + 1))
+                            .filter(|picked| date::is_month_in_range(*picked, self.min, self.max))
+                    }
+                    View::Years(first) => Some(first + index as i32)
+                        .filter(|year| date::is_year_in_range(*year, self.min, self.max))
+                        .map(|year| date::with_year_month(date, year, date.month())),
+                    View::Days => None,
+                };
+
+                // The days of the picked period are shown again.
+                if let Some(picked) = picked {
+                    self.state.date = date::clamp_date(picked, self.min, self.max);
+                    self.state.view = View::Days;
+                }
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                let View::Years(first) = self.state.view else {
+                    return event::Status::Ignored;
+                };
+                let y = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } | mouse::ScrollDelta::Pixels { y, .. } => {
+                        *y
+                    }
+                };
+
+                // The year list scrolls by a row of years.
+                let row = YEAR_COLUMNS as i32;
+                if y > 0.0 {
+                    self.state.view = View::Years(first - row);
+                } else if y < 0.0 {
+                    self.state.view = View::Years(first + row);
+                }
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
     /// The event handling for the calendar days.
     fn on_event_days(
         &mut self,
@@ -238,28 +554,14 @@ where
                     for (x, label) in row.children().enumerate() {
                         let bounds = label.bounds();
                         if bounds.contains(cursor_position) {
-                            let (day, is_in_month) = crate::core::date::position_to_day(
-                                x,
-                                y,
-                                self.state.date.year(),
-                                self.state.date.month(),
-                            );
-
-                            self.state.date = match is_in_month {
-                                IsInMonth::Previous => {
-                                    crate::core::date::pred_month(self.state.date)
-                                        .with_day(day as u32)
-                                        .expect("Previous month with day should be valid")
+                            let date =
+                                date::position_to_date(x, y, self.state.date, self.first_weekday);
+                            if self.is_day_enabled(date) {
+                                self.state.date = date;
+                                if let OnSubmit::Range(_) = self.on_submit {
+                                    self.state.range = self.state.range.pick(date);
                                 }
-                                IsInMonth::Same => self
-                                    .state
-                                    .date
-                                    .with_day(day as u32)
-                                    .expect("Same month with day should be valid"),
-                                IsInMonth::Next => crate::core::date::succ_month(self.state.date)
-                                    .with_day(day as u32)
-                                    .expect("Succeeding month with day should be valid"),
-                            };
+                            }
 
                             status = event::Status::Captured;
                             break 'outer;
@@ -291,6 +593,10 @@ where
             let mut status = event::Status::Ignored;
 
             match key_code {
+                keyboard::KeyCode::Escape if self.state.view != View::Days => {
+                    self.state.view = View::Days;
+                    status = event::Status::Captured;
+                }
                 keyboard::KeyCode::Tab => {
                     if self.state.keyboard_modifiers.shift {
                         self.state.focus = self.state.focus.previous();
@@ -301,41 +607,41 @@ where
                 _ => match self.state.focus {
                     Focus::Month => match key_code {
                         keyboard::KeyCode::Left => {
-                            self.state.date = crate::core::date::pred_month(self.state.date);
+                            self.navigate(date::pred_month(self.state.date));
                             status = event::Status::Captured;
                         }
                         keyboard::KeyCode::Right => {
-                            self.state.date = crate::core::date::succ_month(self.state.date);
+                            self.navigate(date::succ_month(self.state.date));
                             status = event::Status::Captured;
                         }
                         _ => {}
                     },
                     Focus::Year => match key_code {
                         keyboard::KeyCode::Left => {
-                            self.state.date = crate::core::date::pred_year(self.state.date);
+                            self.navigate(date::pred_year(self.state.date));
                             status = event::Status::Captured;
                         }
                         keyboard::KeyCode::Right => {
-                            self.state.date = crate::core::date::succ_year(self.state.date);
+                            self.navigate(date::succ_year(self.state.date));
                             status = event::Status::Captured;
                         }
                         _ => {}
                     },
                     Focus::Day => match key_code {
                         keyboard::KeyCode::Left => {
-                            self.state.date = crate::core::date::pred_day(self.state.date);
+                            self.select_step(date::pred_day);
                             status = event::Status::Captured;
                         }
                         keyboard::KeyCode::Right => {
-                            self.state.date = crate::core::date::succ_day(self.state.date);
+                            self.select_step(date::succ_day);
                             status = event::Status::Captured;
                         }
                         keyboard::KeyCode::Up => {
-                            self.state.date = crate::core::date::pred_week(self.state.date);
+                            self.select_step(date::pred_week);
                             status = event::Status::Captured;
                         }
                         keyboard::KeyCode::Down => {
-                            self.state.date = crate::core::date::succ_week(self.state.date);
+                            self.select_step(date::succ_week);
                             status = event::Status::Captured;
                         }
                         _ => {}
@@ -443,25 +749,54 @@ where
                     ),
             );
 
-        let days = Container::<(), Renderer>::new((0..7).into_iter().fold(
-            Column::new().height(Length::Fill),
+        // The month and year pickers replace the days with a grid of cells.
+        let (columns, rows, max_width) = match self.state.view {
+            View::Days => (7, 7, font_size),
+            View::Months => (MONTH_COLUMNS, PICKER_ROWS, u32::MAX),
+            View::Years(_) => (YEAR_COLUMNS, PICKER_ROWS, u32::MAX),
+        };
+
+        let days = (0..rows).fold(
+            Column::new().width(Length::Fill).height(Length::Fill),
             |column, _y| {
-                column.push((0..7).into_iter().fold(
+                column.push((0..columns).fold(
                     Row::new().height(Length::Fill).padding(DAY_CELL_PADDING),
                     |row, _x| {
                         row.push(
                             Row::new()
                                 .width(Length::Fill)
                                 .height(Length::Fill)
-                                .max_width(font_size)
+                                .max_width(max_width)
                                 .max_height(font_size),
                         )
                     },
                 ))
             },
-        ))
-        .height(Length::Fill)
-        .center_y();
+        );
+
+        // The week numbers share the rows of the days to stay aligned with them.
+        let mut table = Row::new().height(Length::Fill);
+        if self.show_week_numbers && self.state.view == View::Days {
+            table = table.push(
+                (0..7).fold(Column::new().height(Length::Fill), |column, _y| {
+                    column.push(
+                        Row::new()
+                            .height(Length::Fill)
+                            .padding(DAY_CELL_PADDING)
+                            .push(
+                                Row::new()
+                                    .width(Length::Units(font_size as u16))
+                                    .height(Length::Fill)
+                                    .max_height(font_size),
+                            ),
+                    )
+                }),
+            );
+        }
+
+        let days = Container::<(), Renderer>::new(table.push(days))
+            .height(Length::Fill)
+            .center_y();
 
         let mut col = Column::<(), Renderer>::new()
             .spacing(SPACING)
@@ -476,17 +811,18 @@ where
         ));
 
         // Buttons
-        let cancel_limits = limits
-            .clone()
-            .max_width(((col.bounds().width / 2.0) - f32::from(BUTTON_SPACING)).max(0.0) as u32);
-
-        let mut cancel_button = self.cancel_button.layout(renderer, &cancel_limits);
+        let button_count = if self.today_button.is_some() {
+            3.0
+        } else {
+            2.0
+        };
+        let button_limits = limits.clone().max_width(
+            ((col.bounds().width / button_count) - f32::from(BUTTON_SPACING)).max(0.0) as u32,
+        );
 
-        let submit_limits = limits
-            .clone()
-            .max_width(((col.bounds().width / 2.0) - f32::from(BUTTON_SPACING)).max(0.0) as u32);
+        let mut cancel_button = self.cancel_button.layout(renderer, &button_limits);
 
-        let mut submit_button = self.submit_button.layout(renderer, &submit_limits);
+        let mut submit_button = self.submit_button.layout(renderer, &button_limits);
 
         cancel_button.move_to(Point {
             x: cancel_button.bounds().x + f32::from(PADDING),
@@ -505,22 +841,34 @@ where
                 + f32::from(SPACING),
         });
 
-        let mut node = layout::Node::with_children(
-            Size::new(
-                col.bounds().width + (2.0 * f32::from(PADDING)),
-                col.bounds().height
-                    + cancel_button.bounds().height
-                    + (2.0 * f32::from(PADDING))
-                    + f32::from(SPACING),
-            ),
-            vec![col, cancel_button, submit_button],
+        // The optional today button is centered between the other buttons.
+        let today_button = self.today_button.as_ref().map(|today_button| {
+            let mut today_button = today_button.layout(renderer, &button_limits);
+            today_button.move_to(Point {
+                x: (col.bounds().width - today_button.bounds().width) / 2.0 + f32::from(PADDING),
+                y: cancel_button.bounds().y,
+            });
+            today_button
+        });
+
+        let size = Size::new(
+            col.bounds().width + (2.0 * f32::from(PADDING)),
+            col.bounds().height
+                + cancel_button.bounds().height
+                + (2.0 * f32::from(PADDING))
+                + f32::from(SPACING),
         );
+        let mut children = vec![col, cancel_button, submit_button];
+        children.extend(today_button);
+
+        let mut node = layout::Node::with_children(size, children);
 
         node.center_and_bounce(position, bounds);
 
         node
     }
 
+    #[allow(clippy::too_many_lines)]
     fn on_event(
         &mut self,
         event: Event,
@@ -530,6 +878,9 @@ where
         clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
     ) -> event::Status {
+        let date = self.state.date;
+        let moved = is_movement(&event);
+
         if let event::Status::Captured = self.on_event_keyboard(
             &event,
             layout,
@@ -538,6 +889,7 @@ where
             renderer,
             clipboard,
         ) {
+            self.report_change(date, moved, messages);
             return event::Status::Captured;
         }
 
@@ -567,15 +919,29 @@ where
             .expect("Native: Layout should have a days table parent")
             .children()
             .next()
-            .expect("Native: Layout should have a days table layout");
-        let days_status = self.on_event_days(
-            &event,
-            days_layout,
-            cursor_position,
-            messages,
-            renderer,
-            clipboard,
-        );
+            .expect("Native: Layout should have a days table layout")
+            .children()
+            .last()
+            .expect("Native: Layout should have a days layout");
+        let days_status = if self.state.view == View::Days {
+            self.on_event_days(
+                &event,
+                days_layout,
+                cursor_position,
+                messages,
+                renderer,
+                clipboard,
+            )
+        } else {
+            self.on_event_picker(
+                &event,
+                days_layout,
+                cursor_position,
+                messages,
+                renderer,
+                clipboard,
+            )
+        };
 
         // ----------- Buttons ------------------------
         let cancel_button_layout = children
@@ -598,7 +964,7 @@ where
         let mut fake_messages: Vec<Message> = Vec::new();
 
         let submit_status = self.submit_button.on_event(
-            event,
+            event.clone(),
             submit_button_layout,
             cursor_position,
             renderer,
@@ -606,14 +972,47 @@ where
             &mut fake_messages,
         );
 
+        let mut fake_today_messages: Vec<Message> = Vec::new();
+
+        let today_status = match (self.today_button.as_mut(), children.next()) {
+            (Some(today_button), Some(today_button_layout)) => today_button.on_event(
+                event,
+                today_button_layout,
+                cursor_position,
+                renderer,
+                clipboard,
+                &mut fake_today_messages,
+            ),
+            _ => event::Status::Ignored,
+        };
+
+        if !fake_today_messages.is_empty() {
+            self.select_today();
+        }
+
+        // Days that can't be selected are never submitted, e.g. if no day
+        // around the current date can be selected.
         if !fake_messages.is_empty() {
-            messages.push((self.on_submit)(self.state.date.into()));
+            messages.extend(match self.on_submit {
+                OnSubmit::Date(on_submit) => self
+                    .is_day_enabled(self.state.date)
+                    .then(|| on_submit(self.state.date.into())),
+                OnSubmit::Range(on_submit) => {
+                    let start = self.state.range.start.unwrap_or(self.state.date);
+                    let end = self.state.range.end.unwrap_or(start);
+                    (self.is_day_enabled(start) && self.is_day_enabled(end))
+                        .then(|| on_submit(start.into(), end.into()))
+                }
+            });
         }
 
+        self.report_change(date, moved, messages);
+
         month_year_status
             .merge(days_status)
             .merge(cancel_status)
             .merge(submit_status)
+            .merge(today_status)
     }
 
     fn draw(
@@ -636,8 +1035,18 @@ where
             self.state.date,
             &self.year_as_string(),
             &self.month_as_string(),
+            self.first_weekday,
+            &self.weekday_labels(),
+            &|date| self.is_day_enabled(date),
+            match self.on_submit {
+                OnSubmit::Date(_) => None,
+                OnSubmit::Range(_) => Some(self.state.range),
+            },
+            self.today,
+            &self.picker_cells(),
             &self.cancel_button,
             &self.submit_button,
+            self.today_button.as_ref(),
         )
     }
 
@@ -648,6 +1057,7 @@ where
 
         (position.x as u32).hash(state);
         (position.y as u32).hash(state);
+        self.state.view.hash(state);
     }
 }
 
@@ -661,14 +1071,25 @@ pub trait Renderer: iced_native::Renderer {
     type Style: Default;
 
     /// Draws a [`DatePickerOverlay`](DatePickerOverlay).
+    ///
+    /// The day of the optional current date is highlighted. The cells of the
+    /// month or year picker replace the days if there are any.
+    #[allow(clippy::too_many_arguments)]
     fn draw<Message>(
         &mut self,
         env: DrawEnvironment<'_, Self::Defaults, Self::Style, Focus>,
         date: NaiveDate,
         year_str: &str,
         month_str: &str,
+        first_weekday: Weekday,
+        weekday_labels: &[String],
+        is_day_enabled: &dyn Fn(NaiveDate) -> bool,
+        range: Option<Range>,
+        today: Option<NaiveDate>,
+        picker: &[PickerCell],
         cancel_button: &Element<'_, Message, Self>,
         submit_button: &Element<'_, Message, Self>,
+        today_button: Option<&Element<'_, Message, Self>>,
     ) -> Self::Output;
 }
 
@@ -682,8 +1103,15 @@ impl Renderer for iced_native::renderer::Null {
         _date: NaiveDate,
         _year_str: &str,
         _month_str: &str,
+        _first_weekday: Weekday,
+        _weekday_labels: &[String],
+        _is_day_enabled: &dyn Fn(NaiveDate) -> bool,
+        _range: Option<Range>,
+        _today: Option<NaiveDate>,
+        _picker: &[PickerCell],
         _cancel_button: &Element<'_, Message, Self>,
         _submit_button: &Element<'_, Message, Self>,
+        _today_button: Option<&Element<'_, Message, Self>>,
     ) -> Self::Output {
     }
 }
@@ -697,6 +1125,12 @@ pub struct State {
     pub(crate) focus: Focus,
     /// The previously pressed keyboard modifiers.
     pub(crate) keyboard_modifiers: keyboard::Modifiers,
+    /// The picked range of the [`DatePickerOverlay`](DatePickerOverlay) in range mode.
+    pub(crate) range: Range,
+    /// The table shown by the [`DatePickerOverlay`](DatePickerOverlay).
+    pub(crate) view: View,
+    /// The throttle of the change messages of the [`DatePickerOverlay`](DatePickerOverlay).
+    pub(crate) change_throttle: ChangeThrottle,
 }
 
 impl Default for State {
@@ -705,6 +1139,77 @@ impl Default for State {
             date: Local::today().naive_local(),
             focus: Focus::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
+            range: Range::default(),
+            view: View::Days,
+            change_throttle: ChangeThrottle::default(),
+        }
+    }
+}
+
+/// The table shown by a [`DatePickerOverlay`](DatePickerOverlay).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum View {
+    /// The days of the month.
+    Days,
+
+    /// The months of the year, opened by pressing the month.
+    Months,
+
+    /// A page of years starting at the given year, opened by pressing the
+    /// year.
+    Years(i32),
+}
+
+/// A cell of the month or year picker of a
+/// [`DatePickerOverlay`](DatePickerOverlay).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PickerCell {
+    /// The label of the month or year.
+    pub label: String,
+    /// Whether the month or year contains the selected date.
+    pub is_selected: bool,
+    /// Whether the month or year contains any selectable day.
+    pub is_enabled: bool,
+}
+
+/// The picked range of dates of a [`DatePickerOverlay`](DatePickerOverlay) in
+/// range mode.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Range {
+    /// The first day of the range.
+    pub start: Option<NaiveDate>,
+    /// The last day of the range.
+    pub end: Option<NaiveDate>,
+}
+
+impl Range {
+    /// Picks the given date as the start of a new range or as the end of the
+    /// started range, swapping start and end if necessary.
+    #[must_use]
+    pub fn pick(self, date: NaiveDate) -> Self {
+        match self {
+            Self {
+                start: Some(start),
+                end: None,
+            } => Self {
+                start: Some(start.min(date)),
+                end: Some(start.max(date)),
+            },
+            _ => Self {
+                start: Some(date),
+                end: None,
+            },
+        }
+    }
+
+    /// Checks if the given date lays inside of the range (inclusive). The
+    /// hovered date is used as the preliminary end of a started range.
+    #[must_use]
+    pub fn contains(&self, date: NaiveDate, hovered: Option<NaiveDate>) -> bool {
+        match (self.start, self.end.or(hovered)) {
+            (Some(start), Some(end)) => start.min(end) <= date && date <= start.max(end),
+            (Some(start), None) => start == date,
+            _ => false,
         }
     }
 }
@@ -0,0 +1,288 @@
+//! Use a date time picker as an input element for picking a date and a time.
+//!
+//! *This API requires the following crate features to be activated: `date_time_picker`*
+use std::hash::Hash;
+
+use chrono::NaiveDateTime;
+use iced_native::{
+    button, column, container, event,
+    layout::{self, Node},
+    mouse, overlay, row, text, touch, Clipboard, Event, Layout, Point, Size,
+};
+
+use crate::{
+    core::{
+        date::Date,
+        date_time::{self, DateTime},
+        overlay::Position,
+        time::{Period, Time},
+    },
+    native::{
+        icon_text,
+        overlay::{
+            date_picker::{self, DatePickerOverlay},
+            time_picker::{self, TimePickerOverlay},
+        },
+    },
+};
+
+/// The overlay of the [`DateTimePicker`](crate::native::DateTimePicker).
+///
+/// It shows the calendar of a [`DatePickerOverlay`](DatePickerOverlay) next to
+/// the clock of a [`TimePickerOverlay`](TimePickerOverlay). The buttons of the
+/// calendar cancel or submit both parts at once.
+#[allow(missing_debug_implementations)]
+pub struct DateTimePickerOverlay<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + self::Renderer + button::Renderer,
+{
+    /// The calendar of the [`DateTimePickerOverlay`](DateTimePickerOverlay).
+    date: DatePickerOverlay<'a, Part, Renderer>,
+    /// The clock of the [`DateTimePickerOverlay`](DateTimePickerOverlay).
+    time: TimePickerOverlay<'a, Part, Renderer>,
+    /// The message that is send if the cancel button of the [`DateTimePickerOverlay`](DateTimePickerOverlay) is pressed.
+    on_cancel: Message,
+    /// The function that produces a message when the submit button of the [`DateTimePickerOverlay`](DateTimePickerOverlay) is pressed.
+    on_submit: &'a dyn Fn(DateTime) -> Message,
+    /// The minimum date time of the [`DateTimePickerOverlay`](DateTimePickerOverlay).
+    min: Option<NaiveDateTime>,
+    /// The maximum date time of the [`DateTimePickerOverlay`](DateTimePickerOverlay).
+    max: Option<NaiveDateTime>,
+    /// The position of the [`DateTimePickerOverlay`](DateTimePickerOverlay).
+    position: Point,
+}
+
+impl<'a, Message, Renderer> DateTimePickerOverlay<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a
+        + self::Renderer
+        + button::Renderer
+        + column::Renderer
+        + container::Renderer
+        + icon_text::Renderer
+        + row::Renderer
+        + text::Renderer,
+{
+    /// Creates a new [`DateTimePickerOverlay`](DateTimePickerOverlay) on the
+    /// given position combining the given date and time picker overlays.
+    ///
+    /// The buttons of the time picker overlay are hidden. The submitted date
+    /// time is clamped into the optional minimum and maximum.
+    pub fn new(
+        date: DatePickerOverlay<'a, Part, Renderer>,
+        time: TimePickerOverlay<'a, Part, Renderer>,
+        on_cancel: Message,
+        on_submit: &'a dyn Fn(DateTime) -> Message,
+        position: Point,
+        min: Option<DateTime>,
+        max: Option<DateTime>,
+    ) -> Self {
+        DateTimePickerOverlay {
+            date,
+            time: time.without_buttons(),
+            on_cancel,
+            on_submit,
+            min: min.map(NaiveDateTime::from),
+            max: max.map(NaiveDateTime::from),
+            position,
+        }
+    }
+
+    /// Turn this [`DateTimePickerOverlay`](DateTimePickerOverlay) into an
+    /// overlay [`Element`](overlay::Element).
+    #[must_use]
+    pub fn overlay(self) -> overlay::Element<'a, Message, Renderer> {
+        overlay::Element::new(self.position, Box::new(self))
+    }
+
+    /// Combines the submitted date with the picked time and clamps it into
+    /// the minimum and maximum.
+    fn date_time(&self, date: Date) -> DateTime {
+        let time = self.time.time();
+        let date_time = DateTime::new(date, time);
+
+        let naive = NaiveDateTime::from(date_time);
+        let clamped = date_time::clamp_date_time(naive, self.min, self.max);
+        if clamped == naive {
+            return date_time;
+        }
+
+        // The clamped time keeps the format of the picked time.
+        let (show_seconds, period) = match time {
+            Time::Hm { period, .. } => (false, period),
+            Time::Hms { period, .. } => (true, period),
+        };
+        DateTime::from_naive(clamped, show_seconds, period == Period::H24)
+    }
+}
+
+impl<'a, Message, Renderer> iced_native::Overlay<Message, Renderer>
+    for DateTimePickerOverlay<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a
+        + self::Renderer
+        + button::Renderer
+        + column::Renderer
+        + container::Renderer
+        + icon_text::Renderer
+        + row::Renderer
+        + text::Renderer,
+{
+    fn layout(&self, renderer: &Renderer, bounds: Size, position: Point) -> Node {
+        let mut date = self.date.layout(renderer, bounds, Point::ORIGIN);
+        date.move_to(Point::ORIGIN);
+
+        // The clock takes the height of the calendar to align both.
+        let mut time = self.time.layout(
+            renderer,
+            Size::new(
+                (bounds.width - date.size().width).max(0.0),
+                date.size().height,
+            ),
+            Point::ORIGIN,
+        );
+        time.move_to(Point::new(date.size().width, 0.0));
+
+        let size = Size::new(
+            date.size().width + time.size().width,
+            date.size().height.max(time.size().height),
+        );
+
+        let mut node = layout::Node::with_children(size, vec![date, time]);
+
+        node.center_and_bounce(position, bounds);
+
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        let mut children = layout.children();
+        let date_layout = children
+            .next()
+            .expect("Native: Layout should have a date picker layout");
+        let time_layout = children
+            .next()
+            .expect("Native: Layout should have a time picker layout");
+
+        // Only the pressed picker keeps the keyboard focus.
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            if date_layout.bounds().contains(cursor_position) {
+                self.date.set_focused(true);
+                self.time.set_focused(false);
+            } else if time_layout.bounds().contains(cursor_position) {
+                self.date.set_focused(false);
+                self.time.set_focused(true);
+            }
+        }
+
+        let mut parts = Vec::new();
+
+        let date_status = self.date.on_event(
+            event.clone(),
+            date_layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            &mut parts,
+        );
+
+        let time_status = self.time.on_event(
+            event,
+            time_layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            &mut parts,
+        );
+
+        for part in parts {
+            match part {
+                Part::Cancel => messages.push(self.on_cancel.clone()),
+                Part::Date(date) => messages.push((self.on_submit)(self.date_time(date))),
+                // The clock has no submit button.
+                Part::Time(_) => {}
+            }
+        }
+
+        date_status.merge(time_status)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let mut children = layout.children();
+        let date_layout = children
+            .next()
+            .expect("Native: Layout should have a date picker layout");
+        let time_layout = children
+            .next()
+            .expect("Native: Layout should have a time picker layout");
+
+        let date = self
+            .date
+            .draw(renderer, defaults, date_layout, cursor_position);
+        let time = self
+            .time
+            .draw(renderer, defaults, time_layout, cursor_position);
+
+        <Renderer as self::Renderer>::draw(renderer, date, time)
+    }
+
+    fn hash_layout(&self, state: &mut iced_native::Hasher, position: Point) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        (position.x as u32).hash(state);
+        (position.y as u32).hash(state);
+        self.date.hash_layout(state, position);
+        self.time.hash_layout(state, position);
+    }
+}
+
+/// A message of the date and time picker overlays combined by a
+/// [`DateTimePickerOverlay`](DateTimePickerOverlay).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Part {
+    /// The cancel button was pressed.
+    Cancel,
+
+    /// The submit button of the calendar was pressed.
+    Date(Date),
+
+    /// The submit button of the clock was pressed.
+    Time(Time),
+}
+
+/// The renderer of a [`DateTimePickerOverlay`](DateTimePickerOverlay).
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`DateTimePicker`](crate::native::DateTimePicker) in your
+/// user interface.
+pub trait Renderer: date_picker::Renderer + time_picker::Renderer {
+    /// Draws a [`DateTimePickerOverlay`](DateTimePickerOverlay) from the drawn
+    /// calendar and clock.
+    fn draw(&mut self, date: Self::Output, time: Self::Output) -> Self::Output;
+}
+
+#[cfg(debug_assertions)]
+impl Renderer for iced_native::renderer::Null {
+    fn draw(&mut self, _date: Self::Output, _time: Self::Output) -> Self::Output {}
+}
@@ -5,7 +5,8 @@
 use std::hash::Hash;
 
 use iced_native::{
-    button, event, layout::Limits, overlay, Button, Clipboard, Event, Layout, Point, Size, Widget,
+    button, event, layout::Limits, mouse, overlay, touch, Button, Clipboard, Event, Layout, Point,
+    Size, Widget,
 };
 
 use crate::native::floating_button::{Anchor, Offset};
@@ -107,24 +108,30 @@ where
         let limits = Limits::new(Size::ZERO, bounds);
         let mut button = self.button.layout(renderer, &limits);
 
-        match self.anchor {
-            Anchor::NorthWest => button.move_to(Point::new(
-                position.x + self.offset.x,
-                position.y + self.offset.y,
-            )),
-            Anchor::NorthEast => button.move_to(Point::new(
-                position.x - button.bounds().width - self.offset.x,
-                position.y + self.offset.y,
-            )),
-            Anchor::SouthWest => button.move_to(Point::new(
-                position.x + self.offset.x,
-                position.y - button.bounds().height - self.offset.y,
-            )),
-            Anchor::SouthEast => button.move_to(Point::new(
-                position.x - button.bounds().width - self.offset.x,
-                position.y - button.bounds().height - self.offset.y,
-            )),
-        }
+        let size = button.size();
+
+        // The offset always pushes the button away from the anchored edges.
+        // On centered axes it shifts the button towards the right or bottom.
+        let x = match self.anchor {
+            Anchor::NorthWest | Anchor::SouthWest | Anchor::West => position.x + self.offset.x,
+            Anchor::NorthEast | Anchor::SouthEast | Anchor::East => {
+                position.x - size.width - self.offset.x
+            }
+            Anchor::North | Anchor::South | Anchor::Center => {
+                position.x - size.width / 2.0 + self.offset.x
+            }
+        };
+        let y = match self.anchor {
+            Anchor::NorthWest | Anchor::NorthEast | Anchor::North => position.y + self.offset.y,
+            Anchor::SouthWest | Anchor::SouthEast | Anchor::South => {
+                position.y - size.height - self.offset.y
+            }
+            Anchor::West | Anchor::East | Anchor::Center => {
+                position.y - size.height / 2.0 + self.offset.y
+            }
+        };
+
+        button.move_to(Point::new(x, y));
 
         button
     }
@@ -138,6 +145,17 @@ where
         clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
     ) -> event::Status {
+        // Only the button itself is hit by presses. Presses next to it are
+        // left to the underlay, releases still reach a pressed button.
+        let is_press = matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+                | Event::Touch(touch::Event::FingerPressed { .. })
+        );
+        if is_press && !layout.bounds().contains(cursor_position) {
+            return event::Status::Ignored;
+        }
+
         self.button.on_event(
             event,
             layout,
@@ -1,5 +1,10 @@
 //! Display interactive elements on top of other widgets.
 
+#[cfg(feature = "badge")]
+pub mod badge;
+#[cfg(feature = "badge")]
+pub use badge::BadgeOverlay;
+
 #[cfg(feature = "color_picker")]
 pub mod color_picker;
 #[cfg(feature = "color_picker")]
@@ -10,6 +15,11 @@ pub mod date_picker;
 #[cfg(feature = "date_picker")]
 pub use date_picker::DatePickerOverlay;
 
+#[cfg(feature = "date_time_picker")]
+pub mod date_time_picker;
+#[cfg(feature = "date_time_picker")]
+pub use date_time_picker::DateTimePickerOverlay;
+
 #[cfg(feature = "floating_button")]
 pub mod floating_button;
 #[cfg(feature = "floating_button")]
@@ -24,3 +34,8 @@ pub use modal::ModalOverlay;
 pub mod time_picker;
 #[cfg(feature = "time_picker")]
 pub use time_picker::TimePickerOverlay;
+
+#[cfg(feature = "tab_bar")]
+pub mod tab_bar;
+#[cfg(feature = "tab_bar")]
+pub use tab_bar::TabBarMenuOverlay;
@@ -2,11 +2,13 @@
 //!
 //! *This API requires the following crate features to be activated: modal*
 
-use std::hash::Hash;
+use std::{cell::RefCell, hash::Hash};
 
 use iced_native::{
-    event, keyboard, layout::Limits, mouse, overlay, touch, Clipboard, Container, Element, Event,
-    Layout, Length, Point, Size,
+    event, keyboard,
+    layout::{Limits, Node},
+    mouse, overlay, touch, Align, Clipboard, Container, Element, Event, HorizontalAlignment,
+    Layout, Length, Point, Size, VerticalAlignment,
 };
 
 use crate::core::renderer::DrawEnvironment;
@@ -28,6 +30,13 @@ where
     backdrop: Option<Message>,
     /// The optional message that will be send when the ESC key was pressed.
     esc: Option<Message>,
+    /// The horizontal and vertical alignment of the content.
+    alignment: (HorizontalAlignment, VerticalAlignment),
+    /// The maximum width and height of the content.
+    max_size: (u32, u32),
+    /// The overlay shown below the [`ModalOverlay`](ModalOverlay), like the
+    /// overlay of another [`Modal`](crate::native::Modal).
+    below: Option<overlay::Element<'a, Message, Renderer>>,
     /// The style of the [`Overlay`](Overlay).
     style: &'a <Renderer as self::Renderer>::Style,
 }
@@ -40,11 +49,19 @@ where
     Renderer: self::Renderer + iced_native::container::Renderer,
 {
     /// Creates a new [`ModalOverlay`](ModalOverlay).
+    ///
+    /// The optional overlay below is drawn beneath the content without any
+    /// additional dimming and receives no events, so only the topmost
+    /// [`ModalOverlay`](ModalOverlay) reacts to the input.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         state: &'a mut State,
         content: Content,
         backdrop: Option<Message>,
         esc: Option<Message>,
+        alignment: (HorizontalAlignment, VerticalAlignment),
+        max_size: (u32, u32),
+        below: Option<overlay::Element<'a, Message, Renderer>>,
         style: &'a <Renderer as self::Renderer>::Style,
     ) -> Self {
         ModalOverlay {
@@ -52,6 +69,9 @@ where
             content,
             backdrop,
             esc,
+            alignment,
+            max_size,
+            below,
             style,
         }
     }
@@ -64,13 +84,24 @@ where
 }
 
 /// The [`Overlay`](Overlay) of the [`Modal`](crate::native::Modal).
+///
+/// The content can produce an overlay by itself, like an open
+/// [`DatePicker`](crate::native::DatePicker) in a dialog. It is laid out on
+/// demand inside the bounds of the [`Overlay`](Overlay) and drawn on top of
+/// the content.
 struct Overlay<'a, Message, Renderer: self::Renderer> {
     /// The content of the [`Overlay`](Overlay).
-    content: Element<'a, Message, Renderer>,
+    ///
+    /// It is borrowed mutably to produce its overlay while drawing.
+    content: RefCell<Element<'a, Message, Renderer>>,
     /// The optional message that will be send when the user clicks on the backdrop.
     backdrop: Option<Message>,
     /// The optional message that will be send when the ESC key was pressed.
     esc: Option<Message>,
+    /// The horizontal and vertical alignment of the content.
+    alignment: (Align, Align),
+    /// The overlay shown below the content of the [`Overlay`](Overlay).
+    below: Option<overlay::Element<'a, Message, Renderer>>,
     /// The style of the [`Overlay`](Overlay).
     style: &'a <Renderer as self::Renderer>::Style,
 }
@@ -90,23 +121,64 @@ where
             content,
             backdrop,
             esc,
+            alignment: (horizontal_alignment, vertical_alignment),
+            max_size: (max_width, max_height),
+            below,
             style,
         } = modal;
 
+        let align_x = match horizontal_alignment {
+            HorizontalAlignment::Left => Align::Start,
+            HorizontalAlignment::Center => Align::Center,
+            HorizontalAlignment::Right => Align::End,
+        };
+        let align_y = match vertical_alignment {
+            VerticalAlignment::Top => Align::Start,
+            VerticalAlignment::Center => Align::Center,
+            VerticalAlignment::Bottom => Align::End,
+        };
+
         Self {
-            content: Container::new(content(state))
+            content: RefCell::new(
+                Container::new(
+                    Container::new(content(state))
+                        .max_width(max_width)
+                        .max_height(max_height),
+                )
                 .width(Length::Fill)
                 .height(Length::Fill)
-                .center_x()
-                .center_y()
+                .align_x(align_x)
+                .align_y(align_y)
                 .into(),
+            ),
             backdrop,
             esc,
+            alignment: (align_x, align_y),
+            below,
             style,
         }
     }
 }
 
+impl<'a, Message, Renderer> Overlay<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + self::Renderer,
+{
+    /// Lays out the overlay of the content inside the bounds of the given
+    /// layout of the [`Overlay`](Overlay).
+    ///
+    /// It returns `None` if the content has no overlay.
+    fn content_overlay_layout(&self, renderer: &Renderer, layout: Layout<'_>) -> Option<Node> {
+        let content_layout = layout.children().next()?;
+        let mut content = self.content.borrow_mut();
+
+        content
+            .overlay(content_layout)
+            .map(|overlay| overlay.layout(renderer, layout.bounds().size()))
+    }
+}
+
 impl<'a, Message, Renderer> iced_native::Overlay<Message, Renderer>
     for Overlay<'a, Message, Renderer>
 where
@@ -121,11 +193,16 @@ where
     ) -> iced_native::layout::Node {
         let limits = Limits::new(Size::ZERO, bounds);
 
-        let mut content = self.content.layout(renderer, &limits);
+        let mut content = self.content.borrow().layout(renderer, &limits);
 
         content.move_to(position);
 
-        content
+        let below = self
+            .below
+            .as_ref()
+            .map(|below| below.layout(renderer, bounds));
+
+        Node::with_children(bounds, std::iter::once(content).chain(below).collect())
     }
 
     fn on_event(
@@ -137,6 +214,45 @@ where
         clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
     ) -> event::Status {
+        // The overlay of the content is on top of it, so it receives the
+        // event first and hides the cursor from the content below it.
+        let mut cursor_position = cursor_position;
+        let mut is_over_content_overlay = false;
+
+        if let Some(node) = self.content_overlay_layout(renderer, layout) {
+            let content_layout = layout
+                .children()
+                .next()
+                .expect("Native: Layout should have a content layout");
+            let content = self.content.get_mut();
+
+            if let Some(mut overlay) = content.overlay(content_layout) {
+                let status = overlay.on_event(
+                    event.clone(),
+                    Layout::new(&node),
+                    cursor_position,
+                    renderer,
+                    clipboard,
+                    messages,
+                );
+                if status == event::Status::Captured {
+                    return status;
+                }
+            }
+
+            if node.bounds().contains(cursor_position) {
+                cursor_position = Point::new(-1.0, -1.0);
+                is_over_content_overlay = true;
+            }
+        }
+
+        // The overlay below never receives any events, so only the topmost
+        // modal is dismissed.
+        let layout = layout
+            .children()
+            .next()
+            .expect("Native: Layout should have a content layout");
+
         // TODO clean this up
         let esc_status = self
             .esc
@@ -153,24 +269,26 @@ where
                 _ => event::Status::Ignored,
             });
 
-        let backdrop_status = self.backdrop.as_ref().zip(layout.children().next()).map_or(
-            event::Status::Ignored,
-            |(backdrop, layout)| match event {
+        let backdrop_status = layout
+            .children()
+            .next()
+            .map_or(event::Status::Ignored, |layout| match event {
                 Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
                 | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                    if layout.bounds().contains(cursor_position) {
+                    if is_over_content_overlay || layout.bounds().contains(cursor_position) {
                         event::Status::Ignored
                     } else {
-                        messages.push(backdrop.to_owned());
+                        if let Some(backdrop) = self.backdrop.as_ref() {
+                            messages.push(backdrop.to_owned());
+                        }
                         event::Status::Captured
                     }
                 }
                 _ => event::Status::Ignored,
-            },
-        );
+            });
 
         match esc_status.merge(backdrop_status) {
-            event::Status::Ignored => self.content.on_event(
+            event::Status::Ignored => self.content.get_mut().on_event(
                 event,
                 layout,
                 cursor_position,
@@ -189,16 +307,47 @@ where
         layout: iced_native::Layout<'_>,
         cursor_position: Point,
     ) -> Renderer::Output {
+        let mut children = layout.children();
+        let content_layout = children
+            .next()
+            .expect("Native: Layout should have a content layout");
+
+        let mut cursor_position = cursor_position;
+        let above = self
+            .content_overlay_layout(renderer, layout)
+            .and_then(|node| {
+                let mut content = self.content.borrow_mut();
+                let overlay = content.overlay(content_layout)?;
+                let above = overlay.draw(renderer, defaults, Layout::new(&node), cursor_position);
+
+                // The content below its overlay never shows a hovered state.
+                if node.bounds().contains(cursor_position) {
+                    cursor_position = Point::new(-1.0, -1.0);
+                }
+                Some(above)
+            });
+
+        // The overlay below is not interactive, so it never shows a hovered state.
+        let below = self
+            .below
+            .as_ref()
+            .zip(children.next())
+            .map(|(below, below_layout)| {
+                below.draw(renderer, defaults, below_layout, Point::new(-1.0, -1.0))
+            });
+
         renderer.draw(
             DrawEnvironment {
                 defaults,
-                layout,
+                layout: content_layout,
                 cursor_position,
                 style_sheet: self.style,
                 viewport: None,
                 focus: (),
             },
-            &self.content,
+            &self.content.borrow(),
+            below,
+            above,
         )
     }
 
@@ -209,7 +358,12 @@ where
 
         (position.x as u32).hash(state);
         (position.y as u32).hash(state);
-        self.content.hash_layout(state);
+        self.alignment.hash(state);
+        self.content.borrow().hash_layout(state);
+
+        if let Some(below) = &self.below {
+            below.hash_layout(state);
+        }
     }
 }
 
@@ -222,10 +376,18 @@ pub trait Renderer: iced_native::Renderer {
     type Style: Default;
 
     /// Draws a [`ModalOverlay`](ModalOverlay).
+    ///
+    /// If there is an already drawn overlay below, like the overlay of
+    /// another [`Modal`](crate::native::Modal), the content is drawn on top
+    /// of it without dimming the background again. The already drawn overlay
+    /// of the content itself, like an open
+    /// [`DatePicker`](crate::native::DatePicker), is drawn above the content.
     fn draw<Message>(
         &mut self,
         env: DrawEnvironment<Self::Defaults, Self::Style, ()>,
         modal: &Element<'_, Message, Self>,
+        below: Option<Self::Output>,
+        above: Option<Self::Output>,
     ) -> Self::Output;
 }
 
@@ -237,6 +399,8 @@ impl Renderer for iced_native::renderer::Null {
         &mut self,
         _env: DrawEnvironment<Self::Defaults, Self::Style, ()>,
         _modal: &Element<'_, Message, Self>,
+        _below: Option<Self::Output>,
+        _above: Option<Self::Output>,
     ) -> Self::Output {
     }
 }
@@ -0,0 +1,268 @@
+//! Use a tab bar menu to select the tabs collapsed by the overflow of a
+//! [`TabBar`](crate::native::TabBar).
+//!
+//! *This API requires the following crate features to be activated: `tab_bar`*
+
+use std::hash::Hash;
+
+use iced_native::{
+    event, keyboard,
+    layout::{Limits, Node},
+    mouse, overlay, text, touch, Clipboard, Event, Font, Layout, Point, Rectangle, Size, Text,
+    Widget,
+};
+
+use crate::{
+    core::renderer::DrawEnvironment,
+    native::tab_bar::{self, State, TabLabel},
+};
+
+/// The menu of a [`TabBar`](crate::native::TabBar) listing the tabs collapsed
+/// by [`Overflow::Menu`](crate::native::tab_bar::Overflow::Menu).
+///
+/// The menu is placed below the menu button of the
+/// [`TabBar`](crate::native::TabBar), or above it if there is not enough space
+/// below. Selecting an entry or pressing Escape closes the menu.
+#[allow(missing_debug_implementations)]
+pub struct TabBarMenuOverlay<'a, Message, Renderer: tab_bar::Renderer> {
+    /// The state of the [`TabBar`](crate::native::TabBar) holding the open
+    /// state of the menu.
+    state: &'a mut State,
+    /// The indices of the collapsed tabs.
+    indices: Vec<usize>,
+    /// The labels of the collapsed tabs.
+    tab_labels: Vec<TabLabel>,
+    /// The function that produces the message when a tab is selected.
+    on_select: &'a dyn Fn(usize) -> Message,
+    /// The bounds of the menu button.
+    button: Rectangle,
+    /// The style of the [`TabBar`](crate::native::TabBar).
+    style: &'a Renderer::Style,
+    /// The icon size.
+    icon_size: u16,
+    /// The text size.
+    text_size: u16,
+    /// The padding of the entries.
+    padding: u16,
+    /// The optional icon font.
+    icon_font: Option<Font>,
+    /// The optional text font.
+    text_font: Option<Font>,
+}
+
+impl<'a, Message, Renderer> TabBarMenuOverlay<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + tab_bar::Renderer + text::Renderer,
+{
+    /// Creates a new [`TabBarMenuOverlay`](TabBarMenuOverlay) listing the
+    /// given collapsed tabs along with their indices.
+    #[must_use]
+    pub fn new(
+        state: &'a mut State,
+        collapsed: Vec<(usize, TabLabel)>,
+        on_select: &'a dyn Fn(usize) -> Message,
+        button: Rectangle,
+        style: &'a Renderer::Style,
+    ) -> Self {
+        let (indices, tab_labels) = collapsed.into_iter().unzip();
+
+        TabBarMenuOverlay {
+            state,
+            indices,
+            tab_labels,
+            on_select,
+            button,
+            style,
+            icon_size: <Renderer as tab_bar::Renderer>::DEFAULT_ICON_SIZE,
+            text_size: <Renderer as tab_bar::Renderer>::DEFAULT_TEXT_SIZE,
+            padding: <Renderer as tab_bar::Renderer>::DEFAULT_PADDING,
+            icon_font: None,
+            text_font: None,
+        }
+    }
+
+    /// Sets the size of the icons of the entries.
+    #[must_use]
+    pub fn icon_size(mut self, icon_size: u16) -> Self {
+        self.icon_size = icon_size;
+        self
+    }
+
+    /// Sets the size of the text of the entries.
+    #[must_use]
+    pub fn text_size(mut self, text_size: u16) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the padding of the entries.
+    #[must_use]
+    pub fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the optional icon and text fonts of the entries.
+    #[must_use]
+    pub fn fonts(mut self, icon_font: Option<Font>, text_font: Option<Font>) -> Self {
+        self.icon_font = icon_font;
+        self.text_font = text_font;
+        self
+    }
+
+    /// Turns the [`TabBarMenuOverlay`](TabBarMenuOverlay) into an overlay
+    /// [`Element`](iced_native::overlay::Element) below the menu button.
+    #[must_use]
+    pub fn overlay(self) -> overlay::Element<'a, Message, Renderer> {
+        let position = Point::new(
+            self.button.x + self.button.width,
+            self.button.y + self.button.height,
+        );
+
+        overlay::Element::new(position, Box::new(self))
+    }
+}
+
+impl<'a, Message, Renderer> iced_native::Overlay<Message, Renderer>
+    for TabBarMenuOverlay<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + tab_bar::Renderer + text::Renderer,
+{
+    fn layout(&self, renderer: &Renderer, bounds: Size, position: Point) -> Node {
+        let limits = Limits::new(Size::ZERO, bounds);
+        let padding = f32::from(self.padding);
+
+        let contents: Vec<Node> = self
+            .tab_labels
+            .iter()
+            .map(|tab_label| {
+                let mut content = match tab_label.badge().0 {
+                    TabLabel::Icon(_) => Node::new(Size::new(
+                        f32::from(self.icon_size),
+                        f32::from(self.icon_size),
+                    )),
+                    TabLabel::Text(label) | TabLabel::IconText(_, label) => {
+                        Widget::<Message, Renderer>::layout(
+                            &Text::<Renderer>::new(label).size(self.text_size),
+                            renderer,
+                            &limits,
+                        )
+                    }
+                    // Nested badges are already unwrapped by `TabLabel::badge`.
+                    TabLabel::Badged(_, _) => Node::new(Size::ZERO),
+                };
+                content.move_to(Point::new(padding, padding));
+                content
+            })
+            .collect();
+
+        let width = contents
+            .iter()
+            .map(|content| content.size().width + 2.0 * padding)
+            .fold(0.0, f32::max);
+
+        let mut y = 0.0;
+        let entries: Vec<Node> = contents
+            .into_iter()
+            .map(|content| {
+                let height = content.size().height + 2.0 * padding;
+                let mut entry = Node::with_children(Size::new(width, height), vec![content]);
+                entry.move_to(Point::new(0.0, y));
+                y += height;
+                entry
+            })
+            .collect();
+
+        let height = y;
+
+        // The menu is aligned to the right edge of the menu button.
+        let x = (position.x - width).max(0.0);
+        let y = if position.y + height <= bounds.height {
+            position.y
+        } else {
+            (self.button.y - height).max(0.0)
+        };
+
+        let mut node = Node::with_children(Size::new(width, height), entries);
+        node.move_to(Point::new(x, y));
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Escape,
+                ..
+            }) => {
+                self.state.is_menu_open = false;
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(row) = layout
+                    .children()
+                    .position(|entry| entry.bounds().contains(cursor_position))
+                {
+                    messages.push((self.on_select)(self.indices[row]));
+                    self.state.is_menu_open = false;
+                    event::Status::Captured
+                } else if layout.bounds().contains(cursor_position) {
+                    event::Status::Captured
+                } else {
+                    // The menu button toggles the menu by itself.
+                    if !self.button.contains(cursor_position) {
+                        self.state.is_menu_open = false;
+                    }
+                    event::Status::Ignored
+                }
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        tab_bar::Renderer::draw_menu(
+            renderer,
+            DrawEnvironment {
+                defaults,
+                layout,
+                cursor_position,
+                style_sheet: self.style,
+                viewport: None,
+                focus: (),
+            },
+            &self.tab_labels,
+            self.icon_font,
+            self.text_font,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut iced_native::Hasher, position: Point) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        (position.x as u32).hash(state);
+        (position.y as u32).hash(state);
+        self.tab_labels.iter().for_each(|tab| tab.hash(state));
+        self.icon_size.hash(state);
+        self.text_size.hash(state);
+        self.padding.hash(state);
+    }
+}
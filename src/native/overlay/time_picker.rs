@@ -1,15 +1,19 @@
 //! Use a time picker as an input element for picking times.
 //!
 //! *This API requires the following crate features to be activated: `time_picker`*
-use std::hash::Hash;
+use std::{hash::Hash, time::Instant};
 
 use crate::{
     core::clock::{
-        NearestRadius, HOUR_RADIUS_PERCENTAGE, HOUR_RADIUS_PERCENTAGE_NO_SECONDS,
-        MINUTE_RADIUS_PERCENTAGE, MINUTE_RADIUS_PERCENTAGE_NO_SECONDS, PERIOD_PERCENTAGE,
-        SECOND_RADIUS_PERCENTAGE,
+        NearestRadius, DIAL_INNER_RADIUS_PERCENTAGE, DIAL_RADIUS_PERCENTAGE,
+        HOUR_RADIUS_PERCENTAGE, HOUR_RADIUS_PERCENTAGE_NO_SECONDS, MINUTE_RADIUS_PERCENTAGE,
+        MINUTE_RADIUS_PERCENTAGE_NO_SECONDS, PERIOD_PERCENTAGE, SECOND_RADIUS_PERCENTAGE,
+    },
+    core::{
+        overlay::{is_movement, ChangeThrottle, Position},
+        renderer::DrawEnvironment,
+        time,
     },
-    core::{overlay::Position, renderer::DrawEnvironment, time::Period},
     graphics::icons::Icon,
     native::{
         icon_text,
@@ -48,8 +52,12 @@ where
     submit_button: Element<'a, Message, Renderer>,
     /// The function that produces a message when the submit button of the [`TimePickerOverlay`] is pressed.
     on_submit: &'a dyn Fn(Time) -> Message,
+    /// The optional function that produces a message when the time of the [`TimePickerOverlay`](TimePickerOverlay) changes.
+    on_change: Option<&'a dyn Fn(Time) -> Message>,
     /// The position of the [`TimePickerOverlay`](TimePickerOverlay).
     position: Point,
+    /// Toggles the cancel and submit buttons of the [`TimePickerOverlay`](TimePickerOverlay).
+    show_buttons: bool,
     /// The style of the [`TimePickerOverlay`](TimePickerOverlay).
     style: &'a <Renderer as self::Renderer>::Style,
 }
@@ -82,25 +90,64 @@ where
             ..
         } = state;
 
+        overlay_state.time = time::snap_minute(overlay_state.time, overlay_state.minute_step);
+
         TimePickerOverlay {
             state: overlay_state,
-            cancel_button: Button::new(cancel_button, IconText::new(Icon::X).width(Length::Fill))
-                .width(Length::Fill)
-                .on_press(on_cancel.clone())
-                .into(),
+            cancel_button: Button::new(
+                cancel_button,
+                IconText::from_icon(Icon::X).width(Length::Fill),
+            )
+            .width(Length::Fill)
+            .on_press(on_cancel.clone())
+            .into(),
             submit_button: Button::new(
                 submit_button,
-                IconText::new(Icon::Check).width(Length::Fill),
+                IconText::from_icon(Icon::Check).width(Length::Fill),
             )
             .width(Length::Fill)
             .on_press(on_cancel) // Sending a fake message
             .into(),
             on_submit,
+            on_change: None,
             position,
+            show_buttons: true,
             style,
         }
     }
 
+    /// Hides the cancel and submit buttons of the
+    /// [`TimePickerOverlay`](TimePickerOverlay), e.g. if it is embedded in
+    /// another overlay providing them.
+    #[must_use]
+    pub(crate) const fn without_buttons(mut self) -> Self {
+        self.show_buttons = false;
+        self
+    }
+
+    /// Gets the picked time of the [`TimePickerOverlay`](TimePickerOverlay).
+    pub(crate) fn time(&self) -> Time {
+        Time::from_naive(self.state.time, self.state.show_seconds, self.state.use_24h)
+    }
+
+    /// Gives the keyboard focus to the [`TimePickerOverlay`](TimePickerOverlay)
+    /// or takes it away.
+    pub(crate) fn set_focused(&mut self, focused: bool) {
+        if !focused {
+            self.state.focus = Focus::None;
+        } else if self.state.focus == Focus::None {
+            self.state.focus = Focus::Overlay;
+        }
+    }
+
+    /// Sets the function that produces a message when the picked time changes
+    /// before it is submitted.
+    #[must_use]
+    pub fn on_change(mut self, on_change: Option<&'a dyn Fn(Time) -> Message>) -> Self {
+        self.on_change = on_change;
+        self
+    }
+
     /// Turn this [`TimePickerOverlay`](TimePickerOverlay) into an overlay
     /// [`Element`](overlay::Element).
     #[must_use]
@@ -108,6 +155,23 @@ where
         overlay::Element::new(self.position, Box::new(self))
     }
 
+    /// Reports a changed time to the application if it asked for it.
+    fn report_change(&mut self, time: NaiveTime, moved: bool, messages: &mut Vec<Message>) {
+        if let Some(on_change) = self.on_change {
+            if self
+                .state
+                .change_throttle
+                .report(self.state.time != time, moved, Instant::now())
+            {
+                messages.push(on_change(Time::from_naive(
+                    self.state.time,
+                    self.state.show_seconds,
+                    self.state.use_24h,
+                )));
+            }
+        }
+    }
+
     /// The event handling for the clock.
     #[allow(clippy::too_many_lines)]
     fn on_event_clock(
@@ -236,10 +300,13 @@ where
                     let nearest_point =
                         crate::core::clock::nearest_point(&minute_points, cursor_position);
 
+                    let minute =
+                        time::nearest_minute(nearest_point as u32, self.state.minute_step) % 60;
+
                     self.state.time = self
                         .state
                         .time
-                        .with_minute(nearest_point as u32)
+                        .with_minute(minute)
                         .expect("New time with minute should be valid");
                     event::Status::Captured
                 }
@@ -273,6 +340,135 @@ where
         }
     }
 
+    /// The event handling for the clock in dial mode.
+    ///
+    /// Dragging anywhere on the dial sets the shown hand. Releasing the hour
+    /// switches to the minutes and releasing the minutes to the seconds.
+    #[allow(clippy::too_many_lines)]
+    fn on_event_dial(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        dial: Dial,
+    ) -> event::Status {
+        let clock_bounds = layout.bounds();
+
+        if clock_bounds.contains(cursor_position) {
+            self.state.clock_cache_needs_clearance = true;
+            self.state.clock_cache.clear();
+        } else if self.state.clock_cache_needs_clearance {
+            self.state.clock_cache.clear();
+            self.state.clock_cache_needs_clearance = false;
+        }
+
+        let center = clock_bounds.center();
+        let radius = clock_bounds.width.min(clock_bounds.height) * 0.5;
+
+        let pressed_status = match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if clock_bounds.contains(cursor_position) =>
+            {
+                if !self.state.use_24h
+                    && cursor_position.distance(center) < radius * PERIOD_PERCENTAGE
+                {
+                    self.state.time = time::toggle_period(self.state.time);
+                } else {
+                    self.state.focus = dial.focus();
+                    self.state.clock_dragged = match dial {
+                        Dial::Hour => ClockDragged::Hour,
+                        Dial::Minute => ClockDragged::Minute,
+                        Dial::Second => ClockDragged::Second,
+                    };
+                }
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                let next = match self.state.clock_dragged {
+                    ClockDragged::Hour => Some(Dial::Minute),
+                    ClockDragged::Minute if self.state.show_seconds => Some(Dial::Second),
+                    _ => None,
+                };
+                self.state.clock_dragged = ClockDragged::None;
+
+                if let Some(next) = next {
+                    self.state.dial = Some(next);
+                    self.state.focus = next.focus();
+                    self.state.clock_cache.clear();
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            _ => event::Status::Ignored,
+        };
+
+        let dial_radius = radius * DIAL_RADIUS_PERCENTAGE;
+
+        let dragged_status = match self.state.clock_dragged {
+            ClockDragged::Hour => {
+                let hour_points = crate::core::clock::circle_points(dial_radius, center, 12);
+                let nearest_point =
+                    crate::core::clock::nearest_point(&hour_points, cursor_position) as u32;
+
+                let hour = if self.state.use_24h {
+                    let inner = crate::core::clock::nearest_radius(
+                        &[
+                            (dial_radius, NearestRadius::Hour),
+                            (radius * DIAL_INNER_RADIUS_PERCENTAGE, NearestRadius::Period),
+                        ],
+                        cursor_position,
+                        center,
+                    ) == NearestRadius::Period;
+
+                    crate::core::clock::dial_hour(nearest_point, inner)
+                } else {
+                    let (pm, _) = self.state.time.hour12();
+                    (nearest_point + if pm { 12 } else { 0 }) % 24
+                };
+
+                self.state.time = self
+                    .state
+                    .time
+                    .with_hour(hour)
+                    .expect("New time with hour should be valid");
+                event::Status::Captured
+            }
+            ClockDragged::Minute => {
+                let minute_points = crate::core::clock::circle_points(dial_radius, center, 60);
+                let nearest_point =
+                    crate::core::clock::nearest_point(&minute_points, cursor_position);
+
+                let minute =
+                    time::nearest_minute(nearest_point as u32, self.state.minute_step) % 60;
+
+                self.state.time = self
+                    .state
+                    .time
+                    .with_minute(minute)
+                    .expect("New time with minute should be valid");
+                event::Status::Captured
+            }
+            ClockDragged::Second => {
+                let second_points = crate::core::clock::circle_points(dial_radius, center, 60);
+                let nearest_point =
+                    crate::core::clock::nearest_point(&second_points, cursor_position);
+
+                self.state.time = self
+                    .state
+                    .time
+                    .with_second(nearest_point as u32)
+                    .expect("New time with second should be valid");
+                event::Status::Captured
+            }
+            ClockDragged::None => event::Status::Ignored,
+        };
+
+        pressed_status.merge(dragged_status)
+    }
+
     /// The event handling for the digital clock.
     #[allow(clippy::too_many_lines)]
     fn on_event_digital_clock(
@@ -339,6 +535,7 @@ where
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
                 if hour_layout.bounds().contains(cursor_position) {
                     self.state.focus = Focus::DigitalHour;
+                    self.state.select_dial();
 
                     calculate_time(
                         &mut self.state.time,
@@ -348,13 +545,19 @@ where
                     )
                 } else if minute_layout.bounds().contains(cursor_position) {
                     self.state.focus = Focus::DigitalMinute;
+                    self.state.select_dial();
 
-                    calculate_time(
-                        &mut self.state.time,
-                        minute_up_arrow,
-                        minute_down_arrow,
-                        Duration::minutes(1),
-                    )
+                    if minute_up_arrow.bounds().contains(cursor_position) {
+                        self.state.time =
+                            time::step_minute(self.state.time, self.state.minute_step, true);
+                        event::Status::Captured
+                    } else if minute_down_arrow.bounds().contains(cursor_position) {
+                        self.state.time =
+                            time::step_minute(self.state.time, self.state.minute_step, false);
+                        event::Status::Captured
+                    } else {
+                        event::Status::Ignored
+                    }
                 } else {
                     event::Status::Ignored
                 }
@@ -383,6 +586,7 @@ where
                 | Event::Touch(touch::Event::FingerPressed { .. }) => {
                     if second_layout.bounds().contains(cursor_position) {
                         self.state.focus = Focus::DigitalSecond;
+                        self.state.select_dial();
 
                         calculate_time(
                             &mut self.state.time,
@@ -400,7 +604,28 @@ where
             event::Status::Ignored
         };
 
-        let digital_clock_status = digital_clock_status.merge(second_status);
+        let period_status = if self.state.use_24h {
+            event::Status::Ignored
+        } else {
+            let period_layout = digital_clock_children
+                .next()
+                .expect("Native: Layout should have a period layout");
+
+            match event {
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerPressed { .. })
+                    if period_layout.bounds().contains(cursor_position) =>
+                {
+                    self.state.time = time::toggle_period(self.state.time);
+                    event::Status::Captured
+                }
+                _ => event::Status::Ignored,
+            }
+        };
+
+        let digital_clock_status = digital_clock_status
+            .merge(second_status)
+            .merge(period_status);
 
         if digital_clock_status == event::Status::Captured {
             self.state.clock_cache.clear()
@@ -427,11 +652,20 @@ where
             let mut status = event::Status::Ignored;
 
             if let keyboard::KeyCode::Tab = key_code {
-                if self.state.keyboard_modifiers.shift {
-                    self.state.focus = self.state.focus.previous(self.state.show_seconds);
+                let show_seconds = self.state.show_seconds;
+                let step: fn(Focus, bool) -> Focus = if self.state.keyboard_modifiers.shift {
+                    Focus::previous
                 } else {
-                    self.state.focus = self.state.focus.next(self.state.show_seconds);
+                    Focus::next
+                };
+                self.state.focus = step(self.state.focus, show_seconds);
+                // Hidden buttons are skipped.
+                while !self.show_buttons
+                    && matches!(self.state.focus, Focus::Cancel | Focus::Submit)
+                {
+                    self.state.focus = step(self.state.focus, show_seconds);
                 }
+                self.state.select_dial();
             } else {
                 let mut keyboard_handle =
                     |key_code: &keyboard::KeyCode, time: &mut NaiveTime, duration: Duration| {
@@ -452,9 +686,19 @@ where
                     Focus::DigitalHour => {
                         keyboard_handle(key_code, &mut self.state.time, Duration::hours(1))
                     }
-                    Focus::DigitalMinute => {
-                        keyboard_handle(key_code, &mut self.state.time, Duration::minutes(1))
-                    }
+                    Focus::DigitalMinute => match key_code {
+                        keyboard::KeyCode::Left | keyboard::KeyCode::Down => {
+                            self.state.time =
+                                time::step_minute(self.state.time, self.state.minute_step, false);
+                            status = event::Status::Captured;
+                        }
+                        keyboard::KeyCode::Right | keyboard::KeyCode::Up => {
+                            self.state.time =
+                                time::step_minute(self.state.time, self.state.minute_step, true);
+                            status = event::Status::Captured;
+                        }
+                        _ => {}
+                    },
                     Focus::DigitalSecond => {
                         keyboard_handle(key_code, &mut self.state.time, Duration::seconds(1))
                     }
@@ -507,14 +751,17 @@ where
         let mut digital_clock = digital_clock(self, renderer, digital_clock_limits);
 
         // Pre-Buttons TODO: get rid of it
-        let cancel_limits = limits;
-        let cancel_button = self.cancel_button.layout(renderer, &cancel_limits);
+        let buttons_height = if self.show_buttons {
+            let cancel_limits = limits;
+            let cancel_button = self.cancel_button.layout(renderer, &cancel_limits);
+            cancel_button.bounds().height + f32::from(SPACING)
+        } else {
+            0.0
+        };
 
         let limits = limits.shrink(Size::new(
             0.0,
-            digital_clock.bounds().height
-                + cancel_button.bounds().height
-                + 2.0 * f32::from(SPACING),
+            digital_clock.bounds().height + buttons_height + f32::from(SPACING),
         ));
 
         // Clock-Canvas
@@ -536,49 +783,48 @@ where
                 + clock.bounds().height,
         ));
 
+        let size = Size::new(
+            clock.bounds().width + (2.0 * f32::from(PADDING)),
+            clock.bounds().height
+                + digital_clock.bounds().height
+                + buttons_height
+                + (2.0 * f32::from(PADDING))
+                + f32::from(SPACING),
+        );
+        let buttons_y = clock.bounds().height + f32::from(PADDING) + digital_clock.bounds().height;
+        let clock_width = clock.bounds().width;
+        let mut children = vec![clock, digital_clock];
+
         // Buttons
-        let cancel_limits = limits
-            .clone()
-            .max_width(((clock.bounds().width / 2.0) - f32::from(BUTTON_SPACING)).max(0.0) as u32);
+        if self.show_buttons {
+            let cancel_limits = limits
+                .clone()
+                .max_width(((clock_width / 2.0) - f32::from(BUTTON_SPACING)).max(0.0) as u32);
 
-        let mut cancel_button = self.cancel_button.layout(renderer, &cancel_limits);
+            let mut cancel_button = self.cancel_button.layout(renderer, &cancel_limits);
 
-        let submit_limits = limits
-            .clone()
-            .max_width(((clock.bounds().width / 2.0) - f32::from(BUTTON_SPACING)).max(0.0) as u32);
+            let submit_limits = limits
+                .clone()
+                .max_width(((clock_width / 2.0) - f32::from(BUTTON_SPACING)).max(0.0) as u32);
 
-        let mut submit_button = self.submit_button.layout(renderer, &submit_limits);
+            let mut submit_button = self.submit_button.layout(renderer, &submit_limits);
 
-        cancel_button.move_to(Point {
-            x: cancel_button.bounds().x + f32::from(PADDING),
-            y: cancel_button.bounds().y
-                + clock.bounds().height
-                + f32::from(PADDING)
-                + digital_clock.bounds().height
-                + 2.0 * f32::from(SPACING),
-        });
-
-        submit_button.move_to(Point {
-            x: submit_button.bounds().x + clock.bounds().width - submit_button.bounds().width
-                + f32::from(PADDING),
-            y: submit_button.bounds().y
-                + clock.bounds().height
-                + f32::from(PADDING)
-                + digital_clock.bounds().height
-                + 2.0 * f32::from(SPACING),
-        });
-
-        let mut node = layout::Node::with_children(
-            Size::new(
-                clock.bounds().width + (2.0 * f32::from(PADDING)),
-                clock.bounds().height
-                    + digital_clock.bounds().height
-                    + cancel_button.bounds().height
-                    + (2.0 * f32::from(PADDING))
-                    + 2.0 * f32::from(SPACING),
-            ),
-            vec![clock, digital_clock, cancel_button, submit_button],
-        );
+            cancel_button.move_to(Point {
+                x: cancel_button.bounds().x + f32::from(PADDING),
+                y: cancel_button.bounds().y + buttons_y + 2.0 * f32::from(SPACING),
+            });
+
+            submit_button.move_to(Point {
+                x: submit_button.bounds().x + clock_width - submit_button.bounds().width
+                    + f32::from(PADDING),
+                y: submit_button.bounds().y + buttons_y + 2.0 * f32::from(SPACING),
+            });
+
+            children.push(cancel_button);
+            children.push(submit_button);
+        }
+
+        let mut node = layout::Node::with_children(size, children);
 
         node.center_and_bounce(position, bounds);
 
@@ -594,6 +840,9 @@ where
         clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
     ) -> event::Status {
+        let time = self.state.time;
+        let moved = is_movement(&event);
+
         if let event::Status::Captured = self.on_event_keyboard(
             &event,
             layout,
@@ -602,6 +851,7 @@ where
             renderer,
             clipboard,
         ) {
+            self.report_change(time, moved, messages);
             return event::Status::Captured;
         }
 
@@ -611,14 +861,18 @@ where
         let clock_layout = children
             .next()
             .expect("Native: Layout should have a clock canvas layout");
-        let clock_status = self.on_event_clock(
-            &event,
-            clock_layout,
-            cursor_position,
-            messages,
-            renderer,
-            clipboard,
-        );
+        let clock_status = if let Some(dial) = self.state.dial {
+            self.on_event_dial(&event, clock_layout, cursor_position, dial)
+        } else {
+            self.on_event_clock(
+                &event,
+                clock_layout,
+                cursor_position,
+                messages,
+                renderer,
+                clipboard,
+            )
+        };
 
         // ----------- Digital clock ------------------
         let digital_clock_layout = children
@@ -637,60 +891,46 @@ where
         );
 
         // ----------- Buttons ------------------------
-        let cancel_button_layout = children
-            .next()
-            .expect("Native: Layout should have a cancel button layout for a TimePicker");
-
-        let cancel_status = self.cancel_button.on_event(
-            event.clone(),
-            cancel_button_layout,
-            cursor_position,
-            renderer,
-            clipboard,
-            messages,
-        );
-
-        let submit_button_layout = children
-            .next()
-            .expect("Native: Layout should have a submit button layout for a TimePicker");
+        // The buttons have no layout if they are hidden.
+        let cancel_status =
+            children
+                .next()
+                .map_or(event::Status::Ignored, |cancel_button_layout| {
+                    self.cancel_button.on_event(
+                        event.clone(),
+                        cancel_button_layout,
+                        cursor_position,
+                        renderer,
+                        clipboard,
+                        messages,
+                    )
+                });
 
         let mut fake_messages: Vec<Message> = Vec::new();
 
-        let submit_status = self.submit_button.on_event(
-            event,
-            submit_button_layout,
-            cursor_position,
-            renderer,
-            clipboard,
-            &mut fake_messages,
-        );
+        let submit_status =
+            children
+                .next()
+                .map_or(event::Status::Ignored, |submit_button_layout| {
+                    self.submit_button.on_event(
+                        event,
+                        submit_button_layout,
+                        cursor_position,
+                        renderer,
+                        clipboard,
+                        &mut fake_messages,
+                    )
+                });
 
         if !fake_messages.is_empty() {
-            let (hour, period) = if self.state.use_24h {
-                (self.state.time.hour(), Period::H24)
-            } else {
-                let (period, hour) = self.state.time.hour12();
-                (hour, if period { Period::Pm } else { Period::Am })
-            };
-
-            let time = if self.state.show_seconds {
-                Time::Hms {
-                    hour,
-                    minute: self.state.time.minute(),
-                    second: self.state.time.second(),
-                    period,
-                }
-            } else {
-                Time::Hm {
-                    hour,
-                    minute: self.state.time.minute(),
-                    period,
-                }
-            };
+            let time =
+                Time::from_naive(self.state.time, self.state.show_seconds, self.state.use_24h);
 
             messages.push((self.on_submit)(time))
         }
 
+        self.report_change(time, moved, messages);
+
         clock_status
             .merge(digital_clock_status)
             .merge(cancel_status)
@@ -729,6 +969,7 @@ where
         (position.y as u32).hash(state);
         self.state.show_seconds.hash(state);
         self.state.use_24h.hash(state);
+        self.show_buttons.hash(state);
     }
 }
 
@@ -865,6 +1106,8 @@ pub trait Renderer: iced_native::Renderer {
     type Style: Default;
 
     /// Draws a [`TimePickerOverlay`](TimePickerOverlay).
+    ///
+    /// The buttons are only drawn if the layout contains them.
     fn draw<Message>(
         &mut self,
         env: DrawEnvironment<Self::Defaults, Self::Style, Focus>,
@@ -901,12 +1144,19 @@ pub struct State {
     pub(crate) use_24h: bool,
     /// Toggle the use of the seconds of the [`TimePickerOverlay`](TimePickerOverlay).
     pub(crate) show_seconds: bool,
+    /// The step of the minutes of the [`TimePickerOverlay`](TimePickerOverlay).
+    pub(crate) minute_step: u8,
     /// The dragged clock element of the [`TimePickerOverlay`](TimePickerOverlay).
     pub(crate) clock_dragged: ClockDragged,
+    /// The hand shown on the dial of the [`TimePickerOverlay`](TimePickerOverlay)
+    /// or `None` if all hands are shown at once.
+    pub(crate) dial: Option<Dial>,
     /// The focus of the [`TimePickerOverlay`](TimePickerOverlay).
     pub(crate) focus: Focus,
     /// The previously pressed keyboard modifiers.
     pub(crate) keyboard_modifiers: keyboard::Modifiers,
+    /// The throttle of the change messages of the [`TimePickerOverlay`](TimePickerOverlay).
+    pub(crate) change_throttle: ChangeThrottle,
 }
 
 impl Default for State {
@@ -917,9 +1167,30 @@ impl Default for State {
             clock_cache: canvas::Cache::new(),
             use_24h: false,
             show_seconds: false,
+            minute_step: 1,
             clock_dragged: ClockDragged::None,
+            dial: None,
             focus: Focus::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
+            change_throttle: ChangeThrottle::default(),
+        }
+    }
+}
+
+impl State {
+    /// Shows the hand of the focused digital element on the dial, if the dial
+    /// is used.
+    pub(crate) fn select_dial(&mut self) {
+        let dial = match self.focus {
+            Focus::DigitalHour => Dial::Hour,
+            Focus::DigitalMinute => Dial::Minute,
+            Focus::DigitalSecond => Dial::Second,
+            _ => return,
+        };
+
+        if self.dial.is_some() && self.dial != Some(dial) {
+            self.dial = Some(dial);
+            self.clock_cache.clear();
         }
     }
 }
@@ -940,6 +1211,31 @@ pub enum ClockDragged {
     Second,
 }
 
+/// The hand shown on the dial of the [`TimePickerOverlay`](TimePickerOverlay).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Dial {
+    /// The hours are picked.
+    Hour,
+
+    /// The minutes are picked.
+    Minute,
+
+    /// The seconds are picked.
+    Second,
+}
+
+impl Dial {
+    /// Gets the digital element showing the hand of the dial.
+    #[must_use]
+    pub const fn focus(self) -> Focus {
+        match self {
+            Self::Hour => Focus::DigitalHour,
+            Self::Minute => Focus::DigitalMinute,
+            Self::Second => Focus::DigitalSecond,
+        }
+    }
+}
+
 /// An enumeration of all focusable elements of the [`TimePickerOverlay`](TimePickerOverlay).
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum Focus {
@@ -1,29 +1,85 @@
 //! Display a dropdown list of selectable values.
 pub mod list;
+use crate::core::selection_list::{matches_filter, scroll_into_view, slot_to_row, visible_slots};
 use crate::selection_list;
+use crate::style::focus_ring::FocusRing;
 use iced_native::{
     container,
     event::{self, Event},
-    layout, scrollable, text, Clipboard, Container, Element, Hasher, Layout, Length, Point,
-    Rectangle, Scrollable, Size, Widget,
+    keyboard, layout, scrollable, text, text_input, Clipboard, Container, Element, Hasher, Layout,
+    Length, Point, Rectangle, Scrollable, Size, Text, TextInput, VerticalAlignment, Widget,
 };
-pub use list::List;
-use std::marker::PhantomData;
+pub use list::{Drag, DraggedRow, List, Virtualized};
+use std::{borrow::Cow, cell::Cell, marker::PhantomData, ops::Range, time::Instant};
+
+/// The placeholder of the filter of a [`SelectionList`].
+const FILTER_PLACEHOLDER: &str = "Filter...";
+
+/// The number of rows built above and below the viewport of a virtualized
+/// [`SelectionList`].
+const OVERSCAN: usize = 5;
 
 /// A widget for selecting a single value from a dynamic scrollable list of options.
+///
+/// The options are displayed as text by [`new`](SelectionList::new) or as
+/// custom elements by [`with_items`](SelectionList::with_items).
+///
+/// With [`multi_select`](SelectionList::multi_select) several values can be
+/// selected with Ctrl+click (toggle) and Shift+click (range).
+///
+/// After clicking the list, the arrow keys, Page Up, Page Down, Home and End
+/// move the selection and scroll it into view.
+///
+/// With [`filterable`](SelectionList::filterable) a text field above the list
+/// narrows the displayed options to those containing its text, and with
+/// [`type_to_search`](SelectionList::type_to_search) typing while the cursor
+/// is over the list selects the first displayed option starting with the
+/// typed text. Filtering only changes which options are displayed: all
+/// indices, like the ones of
+/// [`on_multi_select`](SelectionList::on_multi_select) and
+/// [`State::selected_indices`](State::selected_indices), always refer to the
+/// full list of options given to [`new`](SelectionList::new).
+///
+/// With [`grouped`](SelectionList::grouped) the options are organized below
+/// non-selectable section headers.
+///
+/// Large lists can be virtualized by [`row_height`](SelectionList::row_height),
+/// so only the rows in the viewport are built and laid out.
 #[allow(missing_debug_implementations)]
 pub struct SelectionList<'a, T, Message, Renderer: self::Renderer>
 where
     T: Clone + ToString,
 {
-    /// Container for Rendering List.
-    container: Container<'a, Message, Renderer>,
+    /// The state of the [`SelectionList`].
+    state: &'a mut State<T>,
     /// List of Elements to Render.
-    options: &'a [T],
+    options: Cow<'a, [T]>,
+    /// The labels of the section headers along with the index of the first
+    /// option of their group.
+    groups: Vec<(String, usize)>,
+    /// Function Pointer On Select to call on Mouse button press.
+    on_selected: Box<dyn Fn(T) -> Message>,
+    /// Function Pointer to create the element displaying an option.
+    #[allow(clippy::type_complexity)]
+    item: Box<dyn Fn(&T, bool) -> Element<'a, Message, Renderer> + 'a>,
+    /// Whether several options can be selected.
+    multi_select: bool,
+    /// Function Pointer to call with all selected indices in multi select mode.
+    on_multi_select: Option<Box<dyn Fn(Vec<usize>) -> Message>>,
+    /// Whether a filter is displayed above the list.
+    filterable: bool,
+    /// Whether typing selects the first option starting with the typed text.
+    type_to_search: bool,
+    /// Function Pointer to call with the old and new index of a dragged option.
+    on_reorder: Option<Box<dyn Fn(usize, usize) -> Message>>,
+    /// The fixed height of the rows if the list is virtualized.
+    row_height: Option<f32>,
     /// Label Font
     font: Renderer::Font,
     /// Style for Looks
     style: selection_list::Style,
+    /// The focus ring drawn around the list while it has the keyboard focus.
+    focus_ring: Option<FocusRing>,
 }
 
 /// The local state of a [`SelectionList`].
@@ -35,6 +91,26 @@ pub struct State<T> {
     hovered_option: Option<usize>,
     /// Statehood of last_selection
     last_selection: Option<T>,
+    /// Statehood of the selected options in multi select mode
+    selected_options: Vec<usize>,
+    /// Statehood of the anchor of Shift click ranges
+    anchor: Option<usize>,
+    /// Statehood of the pressed keyboard modifiers
+    modifiers: keyboard::Modifiers,
+    /// Statehood of the filter text
+    filter: String,
+    /// Statehood of the filter text input
+    filter_input: text_input::State,
+    /// Statehood of the text typed to search an option
+    search: String,
+    /// Statehood of the time the last character was typed to search an option
+    last_search: Option<Instant>,
+    /// Statehood of the keyboard focus of the list
+    is_focused: bool,
+    /// Statehood of the option dragged to a new position
+    dragged: Option<Drag>,
+    /// Statehood of the rows built by the last layout of a virtualized list
+    window: Cell<(usize, usize)>,
 }
 
 impl<T> Default for State<T> {
@@ -43,10 +119,56 @@ impl<T> Default for State<T> {
             scrollable: scrollable::State::default(),
             hovered_option: Option::default(),
             last_selection: Option::default(),
+            selected_options: Vec::new(),
+            anchor: None,
+            modifiers: keyboard::Modifiers::default(),
+            filter: String::new(),
+            filter_input: text_input::State::default(),
+            search: String::new(),
+            last_search: None,
+            is_focused: false,
+            dragged: None,
+            window: Cell::default(),
         }
     }
 }
 
+impl<T> State<T> {
+    /// Gets the sorted indices of the selected options in multi select mode.
+    #[must_use]
+    pub fn selected_indices(&self) -> &[usize] {
+        &self.selected_options
+    }
+
+    /// Sets the indices of the selected options in multi select mode.
+    pub fn set_selected_indices(&mut self, mut indices: Vec<usize>) {
+        indices.sort_unstable();
+        indices.dedup();
+        self.selected_options = indices;
+        self.anchor = self.selected_options.first().copied();
+    }
+
+    /// Gets the text of the filter.
+    #[must_use]
+    pub fn filter(&self) -> &str {
+        &self.filter
+    }
+
+    /// Returns whether the list handles the navigation keys.
+    ///
+    /// The list is focused by clicking it and unfocused by clicking anywhere
+    /// else.
+    #[must_use]
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    /// Sets the text of the filter.
+    pub fn set_filter(&mut self, filter: &str) {
+        filter.clone_into(&mut self.filter);
+    }
+}
+
 impl<'a, T, Message, Renderer: self::Renderer> SelectionList<'a, T, Message, Renderer>
 where
     Message: 'a,
@@ -56,6 +178,8 @@ where
     /// Creates a new [`SelectionList`] with the given [`State`], a list of options,
     /// the current selected value, and the message to produce when an option is
     /// selected.
+    ///
+    /// The options are displayed as text.
     pub fn new(
         state: &'a mut State<T>,
         options: &'a [T],
@@ -63,33 +187,452 @@ where
         on_selected: impl Fn(T) -> Message + 'static,
         style: selection_list::Style,
     ) -> Self {
+        Self::with_items(
+            state,
+            options,
+            selected,
+            on_selected,
+            move |option, is_selected| {
+                label(
+                    option.to_string(),
+                    if is_selected {
+                        style.selected_text_color
+                    } else {
+                        style.text_color
+                    },
+                    style,
+                )
+            },
+            style,
+        )
+    }
+
+    /// Creates a new [`SelectionList`] with the given [`State`], groups of
+    /// options below section headers, the current selected value, and the
+    /// message to produce when an option is selected.
+    ///
+    /// The options are displayed as text. The headers can't be selected and
+    /// are skipped by the keyboard navigation. All indices, like the ones of
+    /// [`on_multi_select`](SelectionList::on_multi_select), refer to the
+    /// options of all groups in order, without the headers. They can be
+    /// mapped back to a group and an index inside the group by
+    /// [`group_position`](crate::core::selection_list::group_position).
+    pub fn grouped<H: ToString>(
+        state: &'a mut State<T>,
+        groups: &[(H, Vec<T>)],
+        selected: &Option<T>,
+        on_selected: impl Fn(T) -> Message + 'static,
+        style: selection_list::Style,
+    ) -> Self {
+        let mut starts = Vec::with_capacity(groups.len());
+        let mut options = Vec::new();
+        for (header, group) in groups {
+            starts.push((header.to_string(), options.len()));
+            options.extend(group.iter().cloned());
+        }
+
+        let mut selection_list = Self::with_options(
+            state,
+            Cow::Owned(options),
+            selected.as_ref(),
+            on_selected,
+            Box::new(move |option: &T, is_selected| {
+                label(
+                    option.to_string(),
+                    if is_selected {
+                        style.selected_text_color
+                    } else {
+                        style.text_color
+                    },
+                    style,
+                )
+            }),
+            style,
+        );
+        selection_list.groups = starts;
+        selection_list
+    }
+
+    /// Creates a new [`SelectionList`] with the given [`State`], a list of options,
+    /// the current selected value, the message to produce when an option is
+    /// selected, and the function creating the element displaying an option.
+    ///
+    /// The function receives the option and whether it is hovered or
+    /// selected. Each element sets the height of its row. The elements are
+    /// only displayed and do not receive any events. The labels of the
+    /// options are still used to filter and search them.
+    pub fn with_items<F>(
+        state: &'a mut State<T>,
+        options: &'a [T],
+        selected: &Option<T>,
+        on_selected: impl Fn(T) -> Message + 'static,
+        item: F,
+        style: selection_list::Style,
+    ) -> Self
+    where
+        F: 'a + Fn(&T, bool) -> Element<'a, Message, Renderer>,
+    {
+        Self::with_options(
+            state,
+            Cow::Borrowed(options),
+            selected.as_ref(),
+            on_selected,
+            Box::new(item),
+            style,
+        )
+    }
+
+    /// Creates a new [`SelectionList`] with the given borrowed or owned
+    /// options.
+    #[allow(clippy::type_complexity)]
+    fn with_options(
+        state: &'a mut State<T>,
+        options: Cow<'a, [T]>,
+        selected: Option<&T>,
+        on_selected: impl Fn(T) -> Message + 'static,
+        item: Box<dyn Fn(&T, bool) -> Element<'a, Message, Renderer> + 'a>,
+        style: selection_list::Style,
+    ) -> Self {
+        state.hovered_option = options.iter().position(|option| Some(option) == selected);
+
+        Self {
+            state,
+            options,
+            groups: Vec::new(),
+            on_selected: Box::new(on_selected),
+            item,
+            multi_select: false,
+            on_multi_select: None,
+            filterable: false,
+            type_to_search: false,
+            on_reorder: None,
+            row_height: None,
+            font: Default::default(),
+            style,
+            focus_ring: Some(FocusRing::default()),
+        }
+    }
+
+    /// Sets whether several options of the [`SelectionList`] can be selected
+    /// with Ctrl+click (toggle) and Shift+click (range).
+    ///
+    /// The selected indices are kept in the [`State`]. A plain click still
+    /// selects only the clicked option and sends the `on_selected` message.
+    pub fn multi_select(mut self, multi_select: bool) -> Self {
+        self.multi_select = multi_select;
+        self
+    }
+
+    /// Sets the message that is send with the sorted indices of all selected
+    /// options whenever they change in multi select mode.
+    pub fn on_multi_select<F>(mut self, on_multi_select: F) -> Self
+    where
+        F: 'static + Fn(Vec<usize>) -> Message,
+    {
+        self.on_multi_select = Some(Box::new(on_multi_select));
+        self
+    }
+
+    /// Sets whether a text field above the list of the [`SelectionList`]
+    /// filters the displayed options.
+    ///
+    /// Only the options containing the filter text, ignoring the case, are
+    /// displayed. The filter text is kept in the [`State`]. The indices of the
+    /// options still refer to the full list of options.
+    pub fn filterable(mut self, filterable: bool) -> Self {
+        self.filterable = filterable;
+        self
+    }
+
+    /// Sets whether typing while the cursor is over the list of the
+    /// [`SelectionList`] selects the first displayed option starting with the
+    /// typed text, ignoring the case.
+    ///
+    /// The typed text is reset after a second without typing.
+    pub fn type_to_search(mut self, type_to_search: bool) -> Self {
+        self.type_to_search = type_to_search;
+        self
+    }
+
+    /// Enables reordering the options of the [`SelectionList`] by dragging
+    /// them.
+    ///
+    /// While an option is dragged, a line shows where it would be dropped.
+    /// The message is send with the old and the new index of the dropped
+    /// option in the full list of options. Moving the option is up to the
+    /// application, like with `options.insert(to, options.remove(from))`.
+    pub fn on_reorder<F>(mut self, on_reorder: F) -> Self
+    where
+        F: 'static + Fn(usize, usize) -> Message,
+    {
+        self.on_reorder = Some(Box::new(on_reorder));
+        self
+    }
+
+    /// Virtualizes the [`SelectionList`] by giving every row and section
+    /// header the same height.
+    ///
+    /// Only the rows in the viewport, plus a few rows above and below it, are
+    /// built and laid out, which keeps lists with thousands of options fast.
+    /// The height should fit the displayed elements. A fixed
+    /// [`width`](crate::style::selection_list::Style::width) avoids measuring
+    /// all options.
+    pub fn row_height(mut self, row_height: f32) -> Self {
+        self.row_height = Some(row_height);
+        self
+    }
+
+    /// Sets the focus ring drawn around the list of the [`SelectionList`]
+    /// while it has the keyboard focus. `None` draws no ring.
+    ///
+    /// The default is [`FocusRing::default`](FocusRing::default).
+    pub fn focus_ring(mut self, focus_ring: Option<FocusRing>) -> Self {
+        self.focus_ring = focus_ring;
+        self
+    }
+
+    /// Gets the indices of the options displayed with the given filter.
+    fn visible(&self, filter: &str) -> Vec<usize> {
+        self.options
+            .iter()
+            .enumerate()
+            .filter(|(_, option)| !self.filterable || matches_filter(&option.to_string(), filter))
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Builds the elements of the displayed options.
+    fn items(&self, state: &State<T>, visible: &[usize]) -> Vec<Element<'a, Message, Renderer>> {
+        visible
+            .iter()
+            .filter_map(|index| {
+                let option = self.options.get(*index)?;
+                let is_selected = state.hovered_option == Some(*index)
+                    || (self.multi_select && state.selected_options.binary_search(index).is_ok());
+
+                Some((self.item)(option, is_selected))
+            })
+            .collect()
+    }
+
+    /// Gets the section headers above the first displayed option of each
+    /// group, along with the row of that option.
+    fn header_rows(&self, visible: &[usize]) -> Vec<(usize, &str)> {
+        self.groups
+            .iter()
+            .enumerate()
+            .filter_map(|(group, (header, start))| {
+                let end = self
+                    .groups
+                    .get(group + 1)
+                    .map_or(self.options.len(), |(_, next)| *next);
+                let row = visible
+                    .iter()
+                    .position(|index| (*start..end).contains(index))?;
+
+                Some((row, header.as_str()))
+            })
+            .collect()
+    }
+
+    /// Gets the height of a row including its padding in virtualized mode.
+    fn slot_height(&self) -> Option<f32> {
+        self.row_height
+            .map(|row_height| row_height + 2.0 * f32::from(self.style.padding))
+    }
+
+    /// Gets the rows that are built for a viewport of the given height.
+    ///
+    /// All rows are built if the list is not virtualized.
+    fn window(&self, state: &State<T>, visible: &[usize], viewport_height: f32) -> Range<usize> {
+        let Some(slot_height) = self.slot_height() else {
+            return 0..visible.len();
+        };
+        let header_rows: Vec<usize> = self
+            .header_rows(visible)
+            .into_iter()
+            .map(|(row, _)| row)
+            .collect();
+
+        let slots = visible_slots(
+            scroll_offset(&state.scrollable),
+            viewport_height,
+            slot_height,
+            visible.len() + header_rows.len(),
+            OVERSCAN,
+        );
+
+        slot_to_row(slots.start, &header_rows).min(visible.len())
+            ..slot_to_row(slots.end, &header_rows).min(visible.len())
+    }
+
+    /// Builds the scrollable [`List`] of the displayed options.
+    ///
+    /// The page size is only used to handle the Page Up and Page Down keys.
+    fn list<'b>(
+        &'b self,
+        state: &'b mut State<T>,
+        visible: &'b [usize],
+        page_size: usize,
+    ) -> Container<'b, Message, Renderer> {
+        let window = if self.row_height.is_some() {
+            let (start, end) = state.window.get();
+            start.min(visible.len())..end.min(visible.len())
+        } else {
+            0..visible.len()
+        };
+        let items = self.items(state, &visible[window.clone()]);
+
+        let header_rows = self.header_rows(visible);
+        let slots = visible.len() + header_rows.len();
+        let skipped = window.start
+            + header_rows
+                .iter()
+                .filter(|(row, _)| *row < window.start)
+                .count();
+        let headers = header_rows
+            .into_iter()
+            .filter(|(row, _)| window.contains(row))
+            .map(|(row, header)| {
+                (
+                    row - window.start,
+                    label(header.to_owned(), self.style.text_color, self.style),
+                )
+            })
+            .collect();
+        let virtualized = self.row_height.map(|row_height| Virtualized {
+            row_height,
+            first: window.start,
+            skipped,
+            slots,
+        });
+
         let State {
+            scrollable,
             hovered_option,
             last_selection,
+            selected_options,
+            anchor,
+            modifiers,
+            filter_input,
+            search,
+            last_search,
+            is_focused,
+            dragged,
             ..
         } = state;
 
-        *hovered_option = options
-            .iter()
-            .position(|option| Some(option) == selected.as_ref());
-
-        let container = Container::new(Scrollable::new(&mut state.scrollable).push(List {
-            options,
+        Container::new(Scrollable::new(scrollable).push(List {
+            options: &self.options,
+            visible,
             hovered_option,
             last_selection,
-            font: Default::default(),
-            style,
-            on_selected: Box::new(on_selected),
+            selected_options,
+            anchor,
+            modifiers,
+            multi_select: self.multi_select,
+            search,
+            last_search,
+            type_to_search: self.type_to_search && !filter_input.is_focused(),
+            is_focused,
+            page_size,
+            items,
+            headers,
+            virtualized,
+            style: self.style,
+            on_selected: &*self.on_selected,
+            on_multi_select: self.on_multi_select.as_deref(),
+            dragged,
+            on_reorder: self.on_reorder.as_deref(),
             phantomdata: PhantomData::default(),
         }))
-        .padding(1);
+        .padding(1)
+    }
 
-        Self {
-            options,
-            font: Default::default(),
-            style,
-            container,
+    /// Builds the text input of the filter.
+    fn filter_input<'b>(&self, state: &'b mut State<T>) -> TextInput<'b, String, Renderer> {
+        TextInput::new(
+            &mut state.filter_input,
+            FILTER_PLACEHOLDER,
+            &state.filter,
+            String::from,
+        )
+        .font(self.font)
+        .padding(self.style.padding)
+        .size(self.style.text_size)
+    }
+
+    /// Gets the number of options visible at once in the scrollable list of
+    /// the given layout, based on the average height of the rows.
+    fn page_size(&self, layout: Layout<'_>) -> usize {
+        let Some(scrollable) = layout.children().next() else {
+            return 1;
+        };
+        if let Some(slot_height) = self.slot_height() {
+            return ((scrollable.bounds().height / slot_height).floor() as usize).max(1);
         }
+        let Some(list) = scrollable.children().flat_map(Layout::children).next() else {
+            return 1;
+        };
+
+        let rows = list.children().count();
+        if rows == 0 {
+            return 1;
+        }
+
+        #[allow(clippy::cast_precision_loss)]
+        let row_height = list.bounds().height / rows as f32;
+
+        ((scrollable.bounds().height / row_height).floor() as usize).max(1)
+    }
+
+    /// Scrolls the list so that the hovered option is fully visible.
+    fn scroll_to_hovered(&self, state: &mut State<T>, visible: &[usize], layout: Layout<'_>) {
+        let Some(scrollable) = layout.children().next() else {
+            return;
+        };
+        let Some(content) = scrollable.children().next() else {
+            return;
+        };
+        let Some(row) = state
+            .hovered_option
+            .and_then(|hovered| visible.iter().position(|index| *index == hovered))
+        else {
+            return;
+        };
+
+        let bounds = scrollable.bounds();
+        let content_bounds = content.bounds();
+        let padding = f32::from(self.style.padding);
+
+        // The row of a virtualized list may not be laid out.
+        let (item_y, item_height) = if let Some(slot_height) = self.slot_height() {
+            let slot = row
+                + self
+                    .header_rows(visible)
+                    .iter()
+                    .filter(|(header, _)| *header <= row)
+                    .count();
+
+            #[allow(clippy::cast_precision_loss)]
+            (slot as f32 * slot_height, slot_height)
+        } else {
+            let Some(item) = content.children().flat_map(Layout::children).nth(row) else {
+                return;
+            };
+            (
+                item.bounds().y - padding - content_bounds.y,
+                item.bounds().height + 2.0 * padding,
+            )
+        };
+
+        #[allow(clippy::cast_precision_loss)]
+        let offset = state.scrollable.offset(bounds, content_bounds) as f32;
+        let target = scroll_into_view(offset, bounds.height, item_y, item_height);
+
+        state
+            .scrollable
+            .scroll(offset - target, bounds, content_bounds);
     }
 }
 
@@ -115,16 +658,16 @@ where
 
         let max_width = match self.style.width {
             Length::Shrink => {
-                let labels = self.options.iter().map(ToString::to_string);
+                let item_limits =
+                    layout::Limits::new(Size::ZERO, Size::new(f32::INFINITY, f32::INFINITY));
 
-                labels
-                    .map(|label| {
-                        let (width, _) = renderer.measure(
-                            &label,
-                            self.style.text_size,
-                            self.font,
-                            Size::new(f32::INFINITY, f32::INFINITY),
-                        );
+                self.options
+                    .iter()
+                    .map(|option| {
+                        let width = (self.item)(option, false)
+                            .layout(renderer, &item_limits)
+                            .size()
+                            .width;
 
                         width.round() as u32 + u32::from(self.style.padding * 2)
                     })
@@ -136,9 +679,33 @@ where
 
         let limits = limits.max_width(max_width + u32::from(self.style.padding * 2));
 
-        let content = self.container.layout(renderer, &limits);
-        let size = limits.resolve(content.size());
-        layout::Node::with_children(size, vec![content])
+        let mut state = self.state.clone();
+        let visible = self.visible(&state.filter);
+
+        let filter = if self.filterable {
+            Some(self.filter_input(&mut state).layout(renderer, &limits))
+        } else {
+            None
+        };
+        let filter_height = filter.as_ref().map_or(0.0, |filter| filter.size().height);
+
+        // The container of the list is padded by one on each side.
+        let window = self.window(&state, &visible, limits.max().height - filter_height - 2.0);
+        self.state.window.set((window.start, window.end));
+        state.window.set((window.start, window.end));
+
+        let mut content = self.list(&mut state, &visible, 0).layout(
+            renderer,
+            &limits.clone().shrink(Size::new(0.0, filter_height)),
+        );
+        content.move_to(Point::new(0.0, filter_height));
+
+        let size = limits.resolve(Size::new(
+            content.size().width,
+            content.size().height + filter_height,
+        ));
+
+        layout::Node::with_children(size, std::iter::once(content).chain(filter).collect())
     }
 
     fn hash_layout(&self, state: &mut Hasher) {
@@ -159,7 +726,18 @@ where
             }
         }
 
-        self.container.hash_layout(state);
+        self.filterable.hash(state);
+        self.groups.hash(state);
+
+        // A virtualized list is laid out again once it is scrolled by a row.
+        if let Some(slot_height) = self.slot_height() {
+            slot_height.to_bits().hash(state);
+            ((scroll_offset(&self.state.scrollable) / slot_height) as usize).hash(state);
+        }
+
+        let mut list_state = self.state.clone();
+        let visible = self.visible(&list_state.filter);
+        self.list(&mut list_state, &visible, 0).hash_layout(state);
     }
 
     fn on_event(
@@ -171,17 +749,60 @@ where
         clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
     ) -> event::Status {
-        self.container.on_event(
-            event,
-            layout
-                .children()
+        let mut children = layout.children();
+        let content_layout = children
+            .next()
+            .expect("Scrollable Child Missing in Selection List");
+
+        let mut state = std::mem::take(&mut *self.state);
+        let mut status = event::Status::Ignored;
+
+        if self.filterable {
+            let filter_layout = children
                 .next()
-                .expect("Scrollable Child Missing in Selection List"),
-            cursor_position,
-            renderer,
-            clipboard,
-            messages,
-        )
+                .expect("Filter Child Missing in Selection List");
+            let mut changes = Vec::new();
+
+            status = self.filter_input(&mut state).on_event(
+                event.clone(),
+                filter_layout,
+                cursor_position,
+                renderer,
+                clipboard,
+                &mut changes,
+            );
+
+            if let Some(filter) = changes.pop() {
+                state.filter = filter;
+                state.scrollable = scrollable::State::default();
+            }
+
+            if state.filter_input.is_focused() {
+                state.is_focused = false;
+            }
+        }
+
+        if status == event::Status::Ignored {
+            let visible = self.visible(&state.filter);
+            let is_keyboard = matches!(event, Event::Keyboard(_));
+            let page_size = self.page_size(content_layout);
+
+            status = self.list(&mut state, &visible, page_size).on_event(
+                event,
+                content_layout,
+                cursor_position,
+                renderer,
+                clipboard,
+                messages,
+            );
+
+            if is_keyboard && status == event::Status::Captured {
+                self.scroll_to_hovered(&mut state, &visible, content_layout);
+            }
+        }
+
+        *self.state = state;
+        status
     }
 
     fn draw(
@@ -192,21 +813,74 @@ where
         cursor_position: Point,
         _viewport: &Rectangle,
     ) -> Renderer::Output {
-        let primitives = self.container.draw(
+        let mut children = layout.children();
+        let content_layout = children
+            .next()
+            .expect("Scrollable Child Missing in Selection List");
+
+        let mut state = self.state.clone();
+
+        let filter = children.next().map(|filter_layout| {
+            Widget::draw(
+                &self.filter_input(&mut state),
+                renderer,
+                defaults,
+                filter_layout,
+                cursor_position,
+                &filter_layout.bounds(),
+            )
+        });
+
+        let visible = self.visible(&state.filter);
+        let primitives = self.list(&mut state, &visible, 0).draw(
             renderer,
             defaults,
-            layout
-                .children()
-                .next()
-                .expect("Scrollable Child Missing in Selection List"),
+            content_layout,
             cursor_position,
-            &layout.bounds(),
+            &content_layout.bounds(),
         );
 
-        renderer.decorate(layout.bounds(), cursor_position, &self.style, primitives)
+        renderer.decorate(
+            content_layout.bounds(),
+            cursor_position,
+            self.focus_ring.filter(|_| state.is_focused),
+            &self.style,
+            primitives,
+            filter,
+        )
     }
 }
 
+/// Gets the scroll offset of the list of a [`SelectionList`], which is not
+/// yet limited to the height of the content.
+fn scroll_offset(state: &scrollable::State) -> f32 {
+    let unbounded = Rectangle {
+        height: f32::INFINITY,
+        ..Rectangle::default()
+    };
+
+    #[allow(clippy::cast_precision_loss)]
+    let offset = state.offset(Rectangle::default(), unbounded) as f32;
+    offset
+}
+
+/// Builds the text of an option or a section header of a [`SelectionList`].
+fn label<'a, Message, Renderer>(
+    content: String,
+    color: iced_native::Color,
+    style: selection_list::Style,
+) -> Element<'a, Message, Renderer>
+where
+    Renderer: 'a + text::Renderer,
+{
+    Text::new(content)
+        .size(style.text_size)
+        .height(Length::Units(style.text_size))
+        .vertical_alignment(VerticalAlignment::Center)
+        .color(color)
+        .into()
+}
+
 /// The renderer of a [`SelectionList`].
 ///
 /// Your [renderer] will need to implement this trait before being
@@ -214,23 +888,44 @@ where
 ///
 /// [renderer]: crate::renderer
 pub trait Renderer:
-    text::Renderer + list::Renderer + container::Renderer + scrollable::Renderer
+    text::Renderer + list::Renderer + container::Renderer + scrollable::Renderer + text_input::Renderer
 {
     /// Decorates a the list of options of a [`SelectionList`].
     ///
     /// This method can be used to draw a background for the [`SelectionList`].
+    /// The filter is given if the [`SelectionList`] is
+    /// [`filterable`](SelectionList::filterable). The focus ring is given if
+    /// the list has the keyboard focus.
     fn decorate(
         &mut self,
         bounds: Rectangle,
         cursor_position: Point,
+        focus_ring: Option<FocusRing>,
         style: &selection_list::Style,
         primitive: Self::Output,
+        filter: Option<Self::Output>,
     ) -> Self::Output;
 
     /// Draws the list of options of a [`SelectionList`].
     fn draw(&mut self) -> Self::Output;
 }
 
+#[cfg(debug_assertions)]
+impl Renderer for iced_native::renderer::Null {
+    fn decorate(
+        &mut self,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _focus_ring: Option<FocusRing>,
+        _style: &selection_list::Style,
+        _primitive: Self::Output,
+        _filter: Option<Self::Output>,
+    ) -> Self::Output {
+    }
+
+    fn draw(&mut self) -> Self::Output {}
+}
+
 impl<'a, T, Message, Renderer> From<SelectionList<'a, T, Message, Renderer>>
     for Element<'a, Message, Renderer>
 where
@@ -242,3 +937,72 @@ where
         Element::new(selection_list)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use iced_native::{layout::Limits, renderer::Null, Layout, Length, Rectangle, Size, Widget};
+
+    use super::{SelectionList, State};
+    use crate::selection_list::Style;
+
+    /// Lays out a virtualized list of the given options and returns the
+    /// number of laid out rows and the position of the first one.
+    #[allow(clippy::cast_precision_loss)]
+    fn layout_rows(state: &mut State<String>, options: &[String]) -> (usize, f32) {
+        let style = Style {
+            width: Length::Units(100),
+            height: Length::Units(200),
+            padding: 0,
+            ..Style::default()
+        };
+        let selection_list =
+            SelectionList::<_, _, Null>::new(state, options, &None, |option| option, style)
+                .row_height(20.0);
+
+        let node = selection_list.layout(
+            &Null::new(),
+            &Limits::new(Size::ZERO, Size::new(500.0, 1000.0)),
+        );
+        let layout = Layout::new(&node);
+        let list = layout
+            .children()
+            .next()
+            .and_then(|container| container.children().next())
+            .and_then(|scrollable| scrollable.children().next())
+            .and_then(|content| content.children().next())
+            .expect("The selection list should have a list");
+
+        assert!((list.bounds().height - 20.0 * options.len() as f32).abs() < 1.0);
+
+        let first = list
+            .children()
+            .next()
+            .map_or(0.0, |row| row.bounds().y - list.bounds().y);
+        (list.children().count(), first)
+    }
+
+    #[test]
+    fn virtualized_list_builds_visible_rows() {
+        let options: Vec<String> = (0..100_000).map(|i| i.to_string()).collect();
+        let mut state = State::default();
+
+        let (rows, first) = layout_rows(&mut state, &options);
+        assert!(rows > 0 && rows <= 20, "{} rows were built", rows);
+        assert!(first.abs() < f32::EPSILON);
+
+        // Scrolling moves the built rows along.
+        let bounds = Rectangle {
+            height: 200.0,
+            ..Rectangle::default()
+        };
+        let content = Rectangle {
+            height: 2_000_000.0,
+            ..Rectangle::default()
+        };
+        state.scrollable.scroll(-100_000.0, bounds, content);
+
+        let (rows, first) = layout_rows(&mut state, &options);
+        assert!(rows > 0 && rows <= 20, "{} rows were built", rows);
+        assert!((first - 99_900.0).abs() < f32::EPSILON);
+    }
+}
@@ -1,32 +1,236 @@
 //! Build and show dropdown `ListMenus`.
+use crate::core::selection_list::{drop_row, matches_prefix, navigate, select, Click, Navigation};
 use crate::selection_list;
 use iced_native::{
     container,
     event::{self, Event},
-    layout, mouse, scrollable, text, touch, Clipboard, Element, Hasher, Layout, Length, Point,
-    Rectangle, Size, Widget,
+    keyboard, layout, mouse, scrollable, text, touch, Clipboard, Element, Hasher, Layout, Length,
+    Point, Rectangle, Size, Widget,
 };
-use std::marker::PhantomData;
+use std::{
+    cmp::Ordering,
+    marker::PhantomData,
+    time::{Duration, Instant},
+};
+
+/// The idle time after which typing starts a new search.
+const SEARCH_TIMEOUT: Duration = Duration::from_secs(1);
 
 /// The Private [`List`] Handles the Actual list rendering.
+///
+/// All item indices held by the [`List`] refer to the full `options` slice,
+/// even if only the `visible` options are displayed.
 #[allow(missing_debug_implementations)]
 pub struct List<'a, T, Message, Renderer: self::Renderer> {
     /// Options pointer to hold all rendered strings
     pub options: &'a [T],
+    /// Indices of the options that are displayed, in display order
+    pub visible: &'a [usize],
     /// Hovered Item Pointer
     pub hovered_option: &'a mut Option<usize>,
     /// Last choosen Item Clicked for Processing
     pub last_selection: &'a mut Option<T>,
-    /// Label Font
-    pub font: Renderer::Font,
+    /// Sorted indices of the selected items in multi select mode
+    pub selected_options: &'a mut Vec<usize>,
+    /// Item a Shift click range starts from in multi select mode
+    pub anchor: &'a mut Option<usize>,
+    /// Currently pressed keyboard modifiers
+    pub modifiers: &'a mut keyboard::Modifiers,
+    /// Whether several items can be selected with Ctrl and Shift clicks
+    pub multi_select: bool,
+    /// Text typed so far to jump to an item
+    pub search: &'a mut String,
+    /// Time of the last typed character of the search
+    pub last_search: &'a mut Option<Instant>,
+    /// Whether typing while the cursor is over the list jumps to an item
+    pub type_to_search: bool,
+    /// Whether the list was clicked last and handles the navigation keys
+    pub is_focused: &'a mut bool,
+    /// Number of items a Page Up or Page Down moves the selection
+    pub page_size: usize,
+    /// The displayed elements of the `visible` options, in display order,
+    /// starting at the first displayed row if virtualized
+    pub items: Vec<Element<'a, Message, Renderer>>,
+    /// The non-selectable section headers, each placed above the row of the
+    /// given position among the displayed rows
+    pub headers: Vec<(usize, Element<'a, Message, Renderer>)>,
+    /// The fixed row height and the skipped rows if only a part of the rows
+    /// is displayed
+    pub virtualized: Option<Virtualized>,
     /// Style for Font colors and Box hover colors.
     pub style: selection_list::Style,
     /// Function Pointer On Select to call on Mouse button press.
-    pub on_selected: Box<dyn Fn(T) -> Message>,
+    pub on_selected: &'a dyn Fn(T) -> Message,
+    /// Function Pointer to call with all selected indices in multi select mode.
+    pub on_multi_select: Option<&'a dyn Fn(Vec<usize>) -> Message>,
+    /// The option that is currently dragged to a new position
+    pub dragged: &'a mut Option<Drag>,
+    /// Function Pointer to call with the old and new index of a dragged option.
+    pub on_reorder: Option<&'a dyn Fn(usize, usize) -> Message>,
     /// Shadow Type holder for Renderer.
     pub phantomdata: PhantomData<Renderer>,
 }
 
+impl<T, Message, Renderer> List<'_, T, Message, Renderer>
+where
+    T: Clone,
+    Renderer: self::Renderer,
+{
+    /// Gets the position of the first displayed row in the `visible` options.
+    fn first_row(&self) -> usize {
+        self.virtualized.map_or(0, |virtualized| virtualized.first)
+    }
+
+    /// Gets the layouts of the rows of the displayed options.
+    ///
+    /// The section headers are laid out behind the rows.
+    fn rows<'b>(&self, layout: Layout<'b>) -> impl Iterator<Item = Layout<'b>> {
+        layout.children().take(self.items.len())
+    }
+
+    /// Gets the index of the option displayed at the given cursor position.
+    fn option_at(&self, layout: Layout<'_>, cursor_position: Point) -> Option<usize> {
+        let padding = f32::from(self.style.padding);
+
+        self.rows(layout)
+            .position(|item| {
+                let bounds = item.bounds();
+                cursor_position.y >= bounds.y - padding
+                    && cursor_position.y < bounds.y + bounds.height + padding
+            })
+            .and_then(|row| self.visible.get(self.first_row() + row).copied())
+    }
+
+    /// Gets the padded bounds of the row of each displayed option.
+    fn row_bounds(&self, layout: Layout<'_>) -> Vec<Rectangle> {
+        let padding = f32::from(self.style.padding);
+
+        self.rows(layout)
+            .map(|item| {
+                let bounds = item.bounds();
+                Rectangle {
+                    x: layout.bounds().x,
+                    y: bounds.y - padding,
+                    width: layout.bounds().width,
+                    height: bounds.height + 2.0 * padding,
+                }
+            })
+            .collect()
+    }
+
+    /// Gets the rows the dragged option is moved from and would be dropped at
+    /// along with the distance it was moved by.
+    fn dragged_row(&self, layout: Layout<'_>) -> Option<(usize, usize, f32)> {
+        let drag = (*self.dragged)?;
+        let from = self
+            .visible
+            .iter()
+            .position(|index| *index == drag.index)?
+            .checked_sub(self.first_row())?;
+        let rows = self.row_bounds(layout);
+        let offset = drag.position.y - drag.origin.y;
+
+        let centers: Vec<f32> = rows.iter().map(Rectangle::center_y).collect();
+        let center = rows.get(from)?.center_y() + offset;
+
+        Some((from, drop_row(&centers, from, center), offset))
+    }
+
+    /// Gets the multi select [`Click`](Click) given by the keyboard modifiers.
+    fn click(&self) -> Click {
+        if !self.multi_select {
+            Click::Replace
+        } else if self.modifiers.shift {
+            Click::Range
+        } else if self.modifiers.is_command_pressed() {
+            Click::Toggle
+        } else {
+            Click::Replace
+        }
+    }
+
+    /// Selects the option at the given index with the given multi select
+    /// [`Click`](Click).
+    fn select(&mut self, index: usize, click: Click, messages: &mut Vec<Message>) -> event::Status {
+        let Some(option) = self.options.get(index) else {
+            return event::Status::Ignored;
+        };
+
+        if self.multi_select {
+            let (selected, anchor) = select(self.selected_options, *self.anchor, index, click);
+            *self.selected_options = selected;
+            *self.anchor = anchor;
+
+            if let Some(on_multi_select) = self.on_multi_select {
+                messages.push(on_multi_select(self.selected_options.clone()));
+            }
+        }
+
+        if click == Click::Replace {
+            *self.last_selection = Some(option.clone());
+        }
+
+        if let Some(last) = self.last_selection.take() {
+            messages.push((self.on_selected)(last));
+        }
+
+        event::Status::Captured
+    }
+
+    /// Adds the typed character to the search and selects the first displayed
+    /// option starting with it. The search starts over after the
+    /// [`SEARCH_TIMEOUT`](SEARCH_TIMEOUT).
+    fn search(&mut self, c: char, messages: &mut Vec<Message>) -> event::Status
+    where
+        T: ToString,
+    {
+        let now = Instant::now();
+        if self
+            .last_search
+            .is_none_or(|last| now.duration_since(last) > SEARCH_TIMEOUT)
+        {
+            self.search.clear();
+        }
+        *self.last_search = Some(now);
+        self.search.push(c);
+
+        let found = self.visible.iter().copied().find(|index| {
+            self.options
+                .get(*index)
+                .is_some_and(|option| matches_prefix(&option.to_string(), self.search))
+        });
+
+        found.map_or(event::Status::Ignored, |index| {
+            *self.hovered_option = Some(index);
+            self.select(index, Click::Replace, messages)
+        })
+    }
+
+    /// Moves the selection by the given [`Navigation`](Navigation) through
+    /// the displayed options. Holding Shift extends the selection in multi
+    /// select mode.
+    fn navigate(&mut self, navigation: Navigation, messages: &mut Vec<Message>) -> event::Status {
+        let current = self
+            .hovered_option
+            .and_then(|hovered| self.visible.iter().position(|index| *index == hovered));
+
+        let Some(index) = navigate(current, self.visible.len(), self.page_size, navigation)
+            .and_then(|row| self.visible.get(row).copied())
+        else {
+            return event::Status::Ignored;
+        };
+
+        let click = if self.multi_select && self.modifiers.shift {
+            Click::Range
+        } else {
+            Click::Replace
+        };
+
+        *self.hovered_option = Some(index);
+        self.select(index, click, messages)
+    }
+}
+
 impl<'a, T, Message, Renderer> Widget<Message, Renderer> for List<'a, T, Message, Renderer>
 where
     T: Clone + ToString,
@@ -40,17 +244,69 @@ where
         Length::Shrink
     }
 
-    fn layout(&self, _renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
-        use std::f32;
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
         let limits = limits.height(Length::Fill).width(Length::Fill);
+        let padding = f32::from(self.style.padding);
+        let width = limits.fill().width;
 
-        #[allow(clippy::cast_precision_loss)]
-        let intrinsic = Size::new(
-            limits.fill().width,
-            f32::from(self.style.text_size + (self.style.padding * 2)) * self.options.len() as f32,
+        let item_limits = layout::Limits::new(
+            Size::ZERO,
+            Size::new((width - 2.0 * padding).max(0.0), f32::INFINITY),
         );
 
-        layout::Node::new(intrinsic)
+        // Virtualized rows are placed in their slots of the full list.
+        let slot_height = self
+            .virtualized
+            .map(|virtualized| virtualized.row_height + 2.0 * padding);
+        let item_limits = self.virtualized.map_or(item_limits, |virtualized| {
+            item_limits.max_height(virtualized.row_height as u32)
+        });
+
+        #[allow(clippy::cast_precision_loss)]
+        let mut height = self
+            .virtualized
+            .zip(slot_height)
+            .map_or(0.0, |(virtualized, slot_height)| {
+                virtualized.skipped as f32 * slot_height
+            });
+        let mut headers = self.headers.iter().peekable();
+        let mut header_nodes = Vec::new();
+        let mut layout_row = |element: &Element<'a, Message, Renderer>| {
+            let mut node = element.layout(renderer, &item_limits);
+            node.move_to(Point::new(padding, height + padding));
+            height += slot_height.unwrap_or_else(|| node.size().height + 2.0 * padding);
+            node
+        };
+
+        let mut children: Vec<layout::Node> = self
+            .items
+            .iter()
+            .enumerate()
+            .map(|(row, item)| {
+                while let Some((_, header)) = headers.next_if(|(header_row, _)| *header_row == row)
+                {
+                    header_nodes.push(layout_row(header));
+                }
+                layout_row(item)
+            })
+            .collect();
+
+        #[allow(clippy::cast_precision_loss)]
+        let height = self
+            .virtualized
+            .zip(slot_height)
+            .map_or(height, |(virtualized, slot_height)| {
+                virtualized.slots as f32 * slot_height
+            });
+
+        if !header_nodes.is_empty() {
+            children.push(layout::Node::with_children(
+                Size::new(width, height),
+                header_nodes,
+            ));
+        }
+
+        layout::Node::with_children(Size::new(width, height), children)
     }
 
     fn hash_layout(&self, state: &mut Hasher) {
@@ -58,9 +314,18 @@ where
         #[allow(clippy::missing_docs_in_private_items)]
         struct Marker;
         std::any::TypeId::of::<Marker>().hash(state);
-        self.options.len().hash(state);
-        self.style.text_size.hash(state);
         self.style.padding.hash(state);
+        if let Some(virtualized) = self.virtualized {
+            virtualized.row_height.to_bits().hash(state);
+            virtualized.first.hash(state);
+            virtualized.skipped.hash(state);
+            virtualized.slots.hash(state);
+        }
+        self.items.iter().for_each(|item| item.hash_layout(state));
+        self.headers.iter().for_each(|(row, header)| {
+            row.hash(state);
+            header.hash_layout(state);
+        });
     }
 
     fn on_event(
@@ -75,36 +340,92 @@ where
         let bounds = layout.bounds();
         let mut status = event::Status::Ignored;
 
+        if self.dragged.is_some() {
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { .. })
+                | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                    // Outside of the list the cursor position is not known.
+                    if bounds.contains(cursor_position) {
+                        if let Some(drag) = self.dragged.as_mut() {
+                            drag.position = cursor_position;
+                        }
+                    }
+                    return event::Status::Captured;
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                | Event::Touch(
+                    touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. },
+                ) => {
+                    let dragged = self.dragged_row(layout);
+                    *self.dragged = None;
+
+                    if let (Some(on_reorder), Some((from, to, _))) = (self.on_reorder, dragged) {
+                        if from != to {
+                            let first = self.first_row();
+                            messages.push(on_reorder(
+                                self.visible[first + from],
+                                self.visible[first + to],
+                            ));
+                        }
+                    }
+                    return event::Status::Captured;
+                }
+                _ => {}
+            }
+        }
+
+        match event {
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                *self.modifiers = modifiers;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) if *self.is_focused => {
+                let navigation = match key_code {
+                    keyboard::KeyCode::Up => Some(Navigation::Previous),
+                    keyboard::KeyCode::Down => Some(Navigation::Next),
+                    keyboard::KeyCode::PageUp => Some(Navigation::PageUp),
+                    keyboard::KeyCode::PageDown => Some(Navigation::PageDown),
+                    keyboard::KeyCode::Home => Some(Navigation::First),
+                    keyboard::KeyCode::End => Some(Navigation::Last),
+                    _ => None,
+                };
+
+                if let Some(navigation) = navigation {
+                    status = self.navigate(navigation, messages);
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                *self.is_focused = bounds.contains(cursor_position);
+            }
+            _ => {}
+        }
+
         if bounds.contains(cursor_position) {
             match event {
+                Event::Keyboard(keyboard::Event::CharacterReceived(c))
+                    if self.type_to_search && !c.is_control() =>
+                {
+                    status = self.search(c, messages);
+                }
                 Event::Mouse(mouse::Event::CursorMoved { .. }) => {
-                    *self.hovered_option = Some(
-                        ((cursor_position.y - bounds.y)
-                            / f32::from(self.style.text_size + (self.style.padding * 2)))
-                            as usize,
-                    );
+                    *self.hovered_option = self.option_at(layout, cursor_position);
                 }
                 Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
                 | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                    *self.hovered_option = Some(
-                        ((cursor_position.y - bounds.y)
-                            / f32::from(self.style.text_size + (self.style.padding * 2)))
-                            as usize,
-                    );
-
-                    if let Some(index) = self.hovered_option {
-                        if let Some(option) = self.options.get(*index) {
-                            *self.last_selection = Some(option.clone());
+                    *self.hovered_option = self.option_at(layout, cursor_position);
+
+                    if let Some(index) = *self.hovered_option {
+                        let click = self.click();
+                        status = self.select(index, click, messages);
+
+                        if click == Click::Replace && self.on_reorder.is_some() {
+                            *self.dragged = Some(Drag {
+                                index,
+                                origin: cursor_position,
+                                position: cursor_position,
+                            });
                         }
                     }
-
-                    status = self
-                        .last_selection
-                        .take()
-                        .map_or(event::Status::Ignored, |last| {
-                            messages.push((self.on_selected)(last));
-                            event::Status::Captured
-                        });
                 }
                 _ => {}
             }
@@ -116,24 +437,121 @@ where
     fn draw(
         &self,
         renderer: &mut Renderer,
-        _defaults: &Renderer::Defaults,
+        defaults: &Renderer::Defaults,
         layout: Layout<'_>,
         cursor_position: Point,
         viewport: &Rectangle,
     ) -> Renderer::Output {
+        let dragged = self.dragged_row(layout);
+        let row_bounds = self.row_bounds(layout);
+        let mut ghost = None;
+
+        let rows = self
+            .rows(layout)
+            .zip(&self.items)
+            .zip(&self.visible[self.first_row().min(self.visible.len())..])
+            .zip(&row_bounds)
+            .enumerate()
+            .filter_map(|(row, (((item_layout, item), index), bounds))| {
+                let is_dragged = dragged.is_some_and(|(from, _, _)| from == row);
+                if !is_dragged {
+                    let _ = bounds.intersection(viewport)?;
+                }
+
+                let is_selected = *self.hovered_option == Some(*index)
+                    || (self.multi_select && self.selected_options.binary_search(index).is_ok());
+                let primitive =
+                    item.draw(renderer, defaults, item_layout, cursor_position, viewport);
+
+                if is_dragged {
+                    ghost = Some((*bounds, is_selected, primitive));
+                    None
+                } else {
+                    Some((*bounds, is_selected, primitive))
+                }
+            })
+            .collect();
+
+        let dragged = ghost.zip(dragged).map(|(row, (from, to, offset))| {
+            let drop_y = match to.cmp(&from) {
+                Ordering::Less => row_bounds.get(to).map(|bounds| bounds.y),
+                Ordering::Greater => row_bounds.get(to).map(|bounds| bounds.y + bounds.height),
+                Ordering::Equal => None,
+            };
+
+            DraggedRow {
+                row,
+                offset,
+                drop_y,
+            }
+        });
+
+        let headers = layout
+            .children()
+            .nth(self.items.len())
+            .map(|headers_layout| {
+                headers_layout
+                    .children()
+                    .zip(&self.headers)
+                    .filter(|(header_layout, _)| {
+                        header_layout.bounds().intersection(viewport).is_some()
+                    })
+                    .map(|(header_layout, (_, header))| {
+                        header.draw(renderer, defaults, header_layout, cursor_position, viewport)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
         self::Renderer::draw(
             renderer,
             layout.bounds(),
             cursor_position,
-            viewport,
-            self.options,
-            *self.hovered_option,
-            self.font,
+            rows,
+            headers,
+            dragged,
             &self.style,
         )
     }
 }
 
+/// The rows of a virtualized [`List`] of which only a part is displayed.
+#[derive(Clone, Copy, Debug)]
+pub struct Virtualized {
+    /// The fixed height of every row and section header.
+    pub row_height: f32,
+    /// The position of the first displayed row in the displayed options.
+    pub first: usize,
+    /// The number of rows and section headers above the displayed ones.
+    pub skipped: usize,
+    /// The number of rows and section headers of the full list.
+    pub slots: usize,
+}
+
+/// An option pressed by the user that may be dragged to a new position.
+#[derive(Clone, Copy, Debug)]
+pub struct Drag {
+    /// The index of the dragged option.
+    pub index: usize,
+    /// The position of the cursor when the option was pressed.
+    pub origin: Point,
+    /// The current position of the cursor.
+    pub position: Point,
+}
+
+/// The row of a [`List`] being dragged to a new position.
+#[allow(missing_debug_implementations)]
+pub struct DraggedRow<Output> {
+    /// The bounds, whether it is selected and the drawn element of the row at
+    /// its original position.
+    pub row: (Rectangle, bool, Output),
+    /// The vertical distance the row was moved by.
+    pub offset: f32,
+    /// The vertical position the row would be inserted at, if it is moved
+    /// to a new position.
+    pub drop_y: Option<f32>,
+}
+
 /// The renderer of a [`List`].
 ///
 /// Your [renderer] will need to implement this trait before being
@@ -142,19 +560,36 @@ where
 /// [renderer]: crate::renderer
 pub trait Renderer: scrollable::Renderer + container::Renderer + text::Renderer {
     /// Draws the list of options of a [`List`].
-    #[allow(clippy::too_many_arguments)]
-    fn draw<T: ToString>(
+    ///
+    /// The rows visible in the viewport are given by their bounds, whether
+    /// they are hovered or selected and their drawn element, followed by the
+    /// drawn section headers visible in the viewport. A dragged row is given
+    /// separately to be drawn on top of the others.
+    fn draw(
         &mut self,
         bounds: Rectangle,
         cursor_position: Point,
-        viewport: &Rectangle,
-        options: &[T],
-        hovered_option: Option<usize>,
-        font: Self::Font,
+        rows: Vec<(Rectangle, bool, Self::Output)>,
+        headers: Vec<Self::Output>,
+        dragged: Option<DraggedRow<Self::Output>>,
         style: &selection_list::Style,
     ) -> Self::Output;
 }
 
+#[cfg(debug_assertions)]
+impl Renderer for iced_native::renderer::Null {
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _rows: Vec<(Rectangle, bool, Self::Output)>,
+        _headers: Vec<Self::Output>,
+        _dragged: Option<DraggedRow<Self::Output>>,
+        _style: &selection_list::Style,
+    ) -> Self::Output {
+    }
+}
+
 impl<'a, T, Message, Renderer> From<List<'a, T, Message, Renderer>>
     for Element<'a, Message, Renderer>
 where
@@ -1,13 +1,27 @@
 //! Use a split to split the available space in two parts to display two different elements.
 //!
 //! *This API requires the following crate features to be activated: split*
-use std::hash::Hash;
+use std::{
+    hash::Hash,
+    time::{Duration, Instant},
+};
 
 use iced_native::{
-    container, mouse, row, touch, Container, Element, Event, Length, Point, Row, Size, Widget,
+    container, event, layout, mouse, row, touch, Container, Element, Event, Layout, Length, Point,
+    Rectangle, Row, Size, Widget,
 };
 
-use crate::core::renderer::DrawEnvironment;
+use crate::core::{renderer::DrawEnvironment, split::clamp_first_size};
+
+pub mod tree;
+pub use tree::{SplitTree, TreeState};
+
+/// The longest time between two presses on the divider of a [`Split`](Split)
+/// to count as a double click.
+const DOUBLE_CLICK_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The size of the collapse buttons on the divider of a [`Split`](Split).
+const COLLAPSE_BUTTON_SIZE: f32 = 16.0;
 
 /// A split can divide the available space by half to display two different elements.
 /// It can split horizontally or vertically.
@@ -42,6 +56,9 @@ pub struct Split<'a, Message, Renderer: self::Renderer> {
     /// The spacing between the elements of the [`Split`](Split).
     /// This is also the width of the divider.
     spacing: f32,
+    /// The margin around the divider of the [`Split`](Split) that can also be
+    /// used to drag it.
+    drag_margin: f32,
     /// The width of the [`Split`](Split).
     width: Length,
     /// The height of the [`Split`](Split).
@@ -50,6 +67,17 @@ pub struct Split<'a, Message, Renderer: self::Renderer> {
     min_size_first: u16,
     /// The minimum size of the second element of the [`Split`](Split).
     min_size_second: u16,
+    /// The maximum size of the first element of the [`Split`](Split).
+    max_size_first: u16,
+    /// The maximum size of the second element of the [`Split`](Split).
+    max_size_second: u16,
+    /// The ratio the divider of the [`Split`](Split) is reset to on a double click.
+    default_ratio: Option<f32>,
+    /// Whether the divider of the [`Split`](Split) shows buttons to collapse the elements.
+    collapsible: bool,
+    /// The message that is send when an element of the [`Split`](Split) is
+    /// collapsed or restored.
+    on_collapse: Option<Box<dyn Fn(Option<Pane>) -> Message>>,
     /// The message that is send when the divider of the [`Split`](Split) is moved.
     on_resize: Box<dyn Fn(u16) -> Message>,
     /// The style of the [`Split`](Split).
@@ -86,10 +114,16 @@ where
                 .into(),
             padding: 0.0,
             spacing: 5.0,
+            drag_margin: 3.0,
             width: Length::Fill,
             height: Length::Fill,
             min_size_first: 5,
             min_size_second: 5,
+            max_size_first: u16::MAX,
+            max_size_second: u16::MAX,
+            default_ratio: None,
+            collapsible: false,
+            on_collapse: None,
             on_resize: Box::new(on_resize),
             style: <Renderer as self::Renderer>::Style::default(),
         }
@@ -108,6 +142,16 @@ where
         self
     }
 
+    /// Sets the margin on both sides of the divider of the [`Split`](Split)
+    /// that can also be used to drag it.
+    ///
+    /// Presses in the margin are only used for dragging if they are not
+    /// captured by the elements of the [`Split`](Split).
+    pub fn drag_margin(mut self, margin: f32) -> Self {
+        self.drag_margin = margin;
+        self
+    }
+
     /// Sets the width of the [`Split`](Split).
     pub fn width(mut self, width: Length) -> Self {
         self.width = width;
@@ -132,6 +176,52 @@ where
         self
     }
 
+    /// Sets the maximum size of the first element of the [`Split`](Split).
+    pub fn max_size_first(mut self, size: u16) -> Self {
+        self.max_size_first = size;
+        self
+    }
+
+    /// Sets the maximum size of the second element of the [`Split`](Split).
+    pub fn max_size_second(mut self, size: u16) -> Self {
+        self.max_size_second = size;
+        self
+    }
+
+    /// Sets the ratio of the space given to the first element of the
+    /// [`Split`](Split) when the divider is double clicked. It is also used
+    /// as long as the [`State`](State) has no divider position.
+    ///
+    /// Without a default ratio, double clicking the divider does nothing.
+    pub fn default_ratio(mut self, ratio: f32) -> Self {
+        self.default_ratio = Some(ratio.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Shows buttons on the divider of the [`Split`](Split) to fully collapse
+    /// either element and to restore it.
+    ///
+    /// The position of the divider before collapsing is kept in the
+    /// [`State`](State). Dragging the divider of a collapsed element also
+    /// restores it.
+    pub fn collapsible(mut self, collapsible: bool) -> Self {
+        self.collapsible = collapsible;
+        self
+    }
+
+    /// Sets the message that is send when an element of the [`Split`](Split)
+    /// is collapsed or restored.
+    ///
+    /// The message carries the collapsed [`Pane`](Pane), or `None` if the
+    /// elements were restored.
+    pub fn on_collapse<F>(mut self, on_collapse: F) -> Self
+    where
+        F: 'static + Fn(Option<Pane>) -> Message,
+    {
+        self.on_collapse = Some(Box::new(on_collapse));
+        self
+    }
+
     /// Sets the style of the [`Split`](Split).
     pub fn style(mut self, style: impl Into<<Renderer as self::Renderer>::Style>) -> Self {
         self.style = style.into();
@@ -168,6 +258,7 @@ where
         }
     }
 
+    #[allow(clippy::too_many_lines)]
     fn on_event(
         &mut self,
         event: iced_native::Event,
@@ -177,6 +268,16 @@ where
         clipboard: &mut dyn iced_native::Clipboard,
         messages: &mut Vec<Message>,
     ) -> iced_native::event::Status {
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            if press_collapse_button(self, layout, cursor_position, messages)
+                == event::Status::Captured
+            {
+                return event::Status::Captured;
+            }
+        }
+
         let mut children = layout.children();
 
         let first_layout = children
@@ -194,11 +295,61 @@ where
         let divider_layout = children
             .next()
             .expect("Native: Layout should have a divider layout");
+
+        let second_layout = children
+            .next()
+            .expect("Native: Layout should have a second layout");
+        let second_status = self.second.on_event(
+            event.clone(),
+            second_layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            messages,
+        );
+
+        let status = first_status.merge(second_status);
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if divider_layout.bounds().contains(cursor_position) {
-                    self.state.dragging = true;
+                let is_over_divider = divider_layout.bounds().contains(cursor_position)
+                    || (status == event::Status::Ignored
+                        && divider_hit_bounds(
+                            divider_layout.bounds(),
+                            self.state.axis,
+                            self.drag_margin,
+                        )
+                        .contains(cursor_position));
+
+                if is_over_divider {
+                    let now = Instant::now();
+                    let double_click = self
+                        .state
+                        .last_divider_press
+                        .is_some_and(|last| now.duration_since(last) <= DOUBLE_CLICK_INTERVAL);
+
+                    if double_click {
+                        self.state.dragging = false;
+                        self.state.last_divider_press = None;
+                        self.state.collapsed = None;
+
+                        if let Some(ratio) = self.default_ratio {
+                            let space = match self.state.axis {
+                                Axis::Horizontal => layout.bounds().height,
+                                Axis::Vertical => layout.bounds().width,
+                            };
+                            let first_size =
+                                first_size(self, (space - self.spacing) * ratio, space);
+
+                            messages.push((self.on_resize)(
+                                (first_size + self.spacing / 2.0).round() as u16,
+                            ));
+                        }
+                    } else {
+                        self.state.dragging = true;
+                        self.state.last_divider_press = Some(now);
+                    }
                 }
             }
 
@@ -212,31 +363,28 @@ where
             Event::Mouse(mouse::Event::CursorMoved { position })
             | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
                 if self.state.dragging {
-                    let position = match self.state.axis {
-                        Axis::Horizontal => position.y,
-                        Axis::Vertical => position.x,
+                    let bounds = layout.bounds();
+                    let (position, space) = match self.state.axis {
+                        Axis::Horizontal => (position.y - bounds.y, bounds.height),
+                        Axis::Vertical => (position.x - bounds.x, bounds.width),
                     };
 
-                    messages.push((self.on_resize)(position as u16));
+                    let half_spacing = self.spacing / 2.0;
+                    let first_size = first_size(self, position - half_spacing, space);
+
+                    if self.state.collapsed.take().is_some() {
+                        if let Some(on_collapse) = &self.on_collapse {
+                            messages.push(on_collapse(None));
+                        }
+                    }
+                    messages.push((self.on_resize)((first_size + half_spacing).round() as u16));
                 }
             }
 
             _ => {}
         }
 
-        let second_layout = children
-            .next()
-            .expect("Native: Layout should have a second layout");
-        let second_status = self.second.on_event(
-            event,
-            second_layout,
-            cursor_position,
-            renderer,
-            clipboard,
-            messages,
-        );
-
-        first_status.merge(second_status)
+        status
     }
 
     fn draw(
@@ -261,6 +409,9 @@ where
             &self.second,
             self.state.dragging,
             self.state.axis,
+            self.drag_margin,
+            self.collapsible,
+            self.state.collapsed,
         )
     }
 
@@ -287,11 +438,156 @@ where
         std::any::TypeId::of::<Marker>().hash(state);
 
         self.state.divider_position.hash(state);
+        self.state.collapsed.hash(state);
+        self.collapsible.hash(state);
+        self.min_size_first.hash(state);
+        self.min_size_second.hash(state);
+        self.max_size_first.hash(state);
+        self.max_size_second.hash(state);
         self.first.hash_layout(state);
         self.second.hash_layout(state);
     }
 }
 
+/// Gets the clamped size of the first element of the [`Split`](Split) for
+/// the wanted size and the space available along the axis of the split.
+fn first_size<Message, Renderer: self::Renderer>(
+    split: &Split<'_, Message, Renderer>,
+    size: f32,
+    space: f32,
+) -> f32 {
+    clamp_first_size(
+        size,
+        space - split.spacing,
+        (
+            f32::from(split.min_size_first),
+            f32::from(split.max_size_first),
+        ),
+        (
+            f32::from(split.min_size_second),
+            f32::from(split.max_size_second),
+        ),
+    )
+    .floor()
+}
+
+/// Gets the size of the first element of the [`Split`](Split) from the
+/// position of the divider stored in its [`State`](State).
+fn divider_size<Message, Renderer: self::Renderer>(
+    split: &Split<'_, Message, Renderer>,
+    space: f32,
+) -> f32 {
+    match split.state.collapsed {
+        Some(Pane::First) => return 0.0,
+        Some(Pane::Second) => return (space - split.spacing).max(0.0),
+        None => {}
+    }
+
+    let size = split.state.divider_position.map_or_else(
+        || (space - split.spacing) * split.default_ratio.unwrap_or(0.5),
+        |position| f32::from(position) - split.spacing / 2.0,
+    );
+
+    first_size(split, size, space)
+}
+
+/// Gets the area of the divider of a [`Split`](Split) that can be used to
+/// drag it, which are its bounds widened by the margin across the axis.
+pub(crate) fn divider_hit_bounds(bounds: Rectangle, axis: Axis, margin: f32) -> Rectangle {
+    match axis {
+        Axis::Horizontal => Rectangle {
+            y: bounds.y - margin,
+            height: bounds.height + 2.0 * margin,
+            ..bounds
+        },
+        Axis::Vertical => Rectangle {
+            x: bounds.x - margin,
+            width: bounds.width + 2.0 * margin,
+            ..bounds
+        },
+    }
+}
+
+/// Handles a press on the collapse buttons of the divider of the
+/// [`Split`](Split).
+///
+/// Pressing the button pointing towards an element collapses it, or
+/// restores the other element if that one is collapsed.
+fn press_collapse_button<Message, Renderer: self::Renderer>(
+    split: &mut Split<'_, Message, Renderer>,
+    layout: Layout<'_>,
+    cursor_position: Point,
+    messages: &mut Vec<Message>,
+) -> event::Status {
+    if !split.collapsible {
+        return event::Status::Ignored;
+    }
+
+    let pressed = layout
+        .children()
+        .nth(1)
+        .into_iter()
+        .flat_map(Layout::children)
+        .zip([Pane::First, Pane::Second])
+        .find(|(button_layout, _)| button_layout.bounds().contains(cursor_position))
+        .map(|(_, pane)| pane);
+
+    let collapsed = match (pressed, split.state.collapsed) {
+        (None, _) => return event::Status::Ignored,
+        (Some(pane), Some(collapsed)) if pane == collapsed => {
+            return event::Status::Ignored;
+        }
+        (Some(_), Some(_)) => {
+            split.state.restore();
+            None
+        }
+        (Some(pane), None) => {
+            split.state.collapse(pane);
+            Some(pane)
+        }
+    };
+
+    if let Some(on_collapse) = &split.on_collapse {
+        messages.push(on_collapse(collapsed));
+    }
+
+    event::Status::Captured
+}
+
+/// Lays out the divider of the [`Split`](Split) with its collapse buttons
+/// centered on it.
+fn divider_node<Message, Renderer: self::Renderer>(
+    split: &Split<'_, Message, Renderer>,
+    size: Size,
+    axis: Axis,
+) -> layout::Node {
+    if !split.collapsible {
+        return layout::Node::new(size);
+    }
+
+    let button_size = Size::new(COLLAPSE_BUTTON_SIZE, COLLAPSE_BUTTON_SIZE);
+    let center = Point::new(size.width / 2.0, size.height / 2.0);
+    let half = COLLAPSE_BUTTON_SIZE / 2.0;
+
+    let (first_position, second_position) = match axis {
+        Axis::Horizontal => (
+            Point::new(center.x - COLLAPSE_BUTTON_SIZE, center.y - half),
+            Point::new(center.x, center.y - half),
+        ),
+        Axis::Vertical => (
+            Point::new(center.x - half, center.y - COLLAPSE_BUTTON_SIZE),
+            Point::new(center.x - half, center.y),
+        ),
+    };
+
+    let mut first = layout::Node::new(button_size);
+    first.move_to(first_position);
+    let mut second = layout::Node::new(button_size);
+    second.move_to(second_position);
+
+    layout::Node::with_children(size, vec![first, second])
+}
+
 /// Do a horizontal split.
 fn horizontal_split<'a, Message, Renderer: self::Renderer>(
     split: &Split<'a, Message, Renderer>,
@@ -299,41 +595,11 @@ fn horizontal_split<'a, Message, Renderer: self::Renderer>(
     limits: &iced_native::layout::Limits,
     space: &iced_native::layout::Node,
 ) -> iced_native::layout::Node {
-    if space.bounds().height
-        < split.spacing + f32::from(split.min_size_first + split.min_size_second)
-    {
-        return iced_native::layout::Node::with_children(
-            space.bounds().size(),
-            vec![
-                split.first.layout(
-                    renderer,
-                    &limits.clone().shrink(Size::new(0.0, space.bounds().height)),
-                ),
-                iced_native::layout::Node::new(Size::new(space.bounds().height, split.spacing)),
-                split.second.layout(
-                    renderer,
-                    &limits.clone().shrink(Size::new(0.0, space.bounds().width)),
-                ),
-            ],
-        );
-    }
-
-    let divider_position = split
-        .state
-        .divider_position
-        .unwrap_or_else(|| (space.bounds().height / 2.0) as u16)
-        .max((split.spacing / 2.0) as u16);
-    let divider_position = (divider_position - (split.spacing / 2.0) as u16).clamp(
-        split.min_size_first,
-        space.bounds().height as u16 - split.min_size_second - split.spacing as u16,
-    );
+    let divider_position = divider_size(split, space.bounds().height);
 
     let first_limits = limits
         .clone()
-        .shrink(Size::new(
-            0.0,
-            space.bounds().height - f32::from(divider_position),
-        ))
+        .shrink(Size::new(0.0, space.bounds().height - divider_position))
         .pad(split.padding);
     let mut first = split.first.layout(renderer, &first_limits);
     first.move_to(Point::new(
@@ -341,18 +607,21 @@ fn horizontal_split<'a, Message, Renderer: self::Renderer>(
         space.bounds().y + split.padding,
     ));
 
-    let mut divider =
-        iced_native::layout::Node::new(Size::new(space.bounds().width, split.spacing));
-    divider.move_to(Point::new(space.bounds().x, f32::from(divider_position)));
+    let mut divider = divider_node(
+        split,
+        Size::new(space.bounds().width, split.spacing),
+        Axis::Horizontal,
+    );
+    divider.move_to(Point::new(space.bounds().x, divider_position));
 
     let second_limits = limits
         .clone()
-        .shrink(Size::new(0.0, f32::from(divider_position) + split.spacing))
+        .shrink(Size::new(0.0, divider_position + split.spacing))
         .pad(split.padding);
     let mut second = split.second.layout(renderer, &second_limits);
     second.move_to(Point::new(
         space.bounds().x + split.padding,
-        space.bounds().y + f32::from(divider_position) + split.spacing + split.padding,
+        space.bounds().y + divider_position + split.spacing + split.padding,
     ));
 
     iced_native::layout::Node::with_children(space.bounds().size(), vec![first, divider, second])
@@ -365,41 +634,11 @@ fn vertical_split<'a, Message, Renderer: self::Renderer>(
     limits: &iced_native::layout::Limits,
     space: &iced_native::layout::Node,
 ) -> iced_native::layout::Node {
-    if space.bounds().width
-        < split.spacing + f32::from(split.min_size_first + split.min_size_second)
-    {
-        return iced_native::layout::Node::with_children(
-            space.bounds().size(),
-            vec![
-                split.first.layout(
-                    renderer,
-                    &limits.clone().shrink(Size::new(space.bounds().width, 0.0)),
-                ),
-                iced_native::layout::Node::new(Size::new(split.spacing, space.bounds().height)),
-                split.second.layout(
-                    renderer,
-                    &limits.clone().shrink(Size::new(space.bounds().width, 0.0)),
-                ),
-            ],
-        );
-    }
-
-    let divider_position = split
-        .state
-        .divider_position
-        .unwrap_or_else(|| (space.bounds().width / 2.0) as u16)
-        .max((split.spacing / 2.0) as u16);
-    let divider_position = (divider_position - (split.spacing / 2.0) as u16).clamp(
-        split.min_size_first,
-        space.bounds().width as u16 - split.min_size_second - split.spacing as u16,
-    );
+    let divider_position = divider_size(split, space.bounds().width);
 
     let first_limits = limits
         .clone()
-        .shrink(Size::new(
-            space.bounds().width - f32::from(divider_position),
-            0.0,
-        ))
+        .shrink(Size::new(space.bounds().width - divider_position, 0.0))
         .pad(split.padding);
     let mut first = split.first.layout(renderer, &first_limits);
     first.move_to(Point::new(
@@ -407,17 +646,20 @@ fn vertical_split<'a, Message, Renderer: self::Renderer>(
         space.bounds().y + split.padding,
     ));
 
-    let mut divider =
-        iced_native::layout::Node::new(Size::new(split.spacing, space.bounds().height));
-    divider.move_to(Point::new(f32::from(divider_position), space.bounds().y));
+    let mut divider = divider_node(
+        split,
+        Size::new(split.spacing, space.bounds().height),
+        Axis::Vertical,
+    );
+    divider.move_to(Point::new(divider_position, space.bounds().y));
 
     let second_limits = limits
         .clone()
-        .shrink(Size::new(f32::from(divider_position) + split.spacing, 0.0))
+        .shrink(Size::new(divider_position + split.spacing, 0.0))
         .pad(split.padding);
     let mut second = split.second.layout(renderer, &second_limits);
     second.move_to(Point::new(
-        space.bounds().x + f32::from(divider_position) + split.spacing + split.padding,
+        space.bounds().x + divider_position + split.spacing + split.padding,
         space.bounds().y + split.padding,
     ));
 
@@ -433,6 +675,7 @@ pub trait Renderer: iced_native::Renderer {
     type Style: Default;
 
     /// Draws a [`Split`](Split).
+    #[allow(clippy::too_many_arguments)]
     fn draw<Message>(
         &mut self,
         env: DrawEnvironment<Self::Defaults, Self::Style, ()>,
@@ -440,6 +683,9 @@ pub trait Renderer: iced_native::Renderer {
         second: &Element<'_, Message, Self>,
         dragging: bool,
         axis: Axis,
+        drag_margin: f32,
+        collapsible: bool,
+        collapsed: Option<Pane>,
     ) -> Self::Output;
 }
 
@@ -454,6 +700,9 @@ impl Renderer for iced_native::renderer::Null {
         _second: &Element<'_, Message, Self>,
         _dragging: bool,
         _axis: Axis,
+        _drag_margin: f32,
+        _collapsible: bool,
+        _collapsed: Option<Pane>,
     ) -> Self::Output {
     }
 }
@@ -477,6 +726,12 @@ pub struct State {
     axis: Axis,
     /// If the divider is dragged by the user.
     dragging: bool,
+    /// The time of the last press on the divider.
+    last_divider_press: Option<Instant>,
+    /// The collapsed element.
+    collapsed: Option<Pane>,
+    /// The position of the divider before an element was collapsed.
+    restore_position: Option<u16>,
 }
 
 impl State {
@@ -491,6 +746,9 @@ impl State {
             divider_position,
             axis,
             dragging: false,
+            last_divider_press: None,
+            collapsed: None,
+            restore_position: None,
         }
     }
 
@@ -504,6 +762,29 @@ impl State {
     pub fn set_divider_position(&mut self, position: u16) {
         self.divider_position = Some(position);
     }
+
+    /// Gets the collapsed element, if any.
+    #[must_use]
+    pub const fn collapsed(&self) -> Option<Pane> {
+        self.collapsed
+    }
+
+    /// Collapses the given element and remembers the position of the
+    /// divider to restore it later.
+    pub fn collapse(&mut self, pane: Pane) {
+        if self.collapsed.is_none() {
+            self.restore_position = self.divider_position;
+        }
+        self.collapsed = Some(pane);
+    }
+
+    /// Restores the collapsed element and the previous position of the
+    /// divider.
+    pub fn restore(&mut self) {
+        if self.collapsed.take().is_some() {
+            self.divider_position = self.restore_position.take();
+        }
+    }
 }
 
 /// The axis to split at.
@@ -515,8 +796,150 @@ pub enum Axis {
     Vertical,
 }
 
+/// An element of a [`Split`](Split).
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Pane {
+    /// The first element (left or top).
+    First,
+    /// The second element (right or bottom).
+    Second,
+}
+
 impl Default for Axis {
     fn default() -> Self {
         Self::Vertical
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use iced_native::{
+        button, clipboard, layout::Limits, mouse, renderer::Null, Button, Event, Layout, Length,
+        Point, Size, Text, Widget,
+    };
+
+    use super::{Axis, Pane, Split, State};
+
+    fn move_to(split: &mut Split<'_, u16, Null>, position: Point) -> Vec<u16> {
+        send(
+            split,
+            Event::Mouse(mouse::Event::CursorMoved { position }),
+            position,
+        )
+    }
+
+    fn press(split: &mut Split<'_, u16, Null>, position: Point) -> Vec<u16> {
+        send(
+            split,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            position,
+        )
+    }
+
+    fn send(split: &mut Split<'_, u16, Null>, event: Event, position: Point) -> Vec<u16> {
+        let renderer = Null::new();
+        let node = Widget::layout(
+            split,
+            &renderer,
+            &Limits::new(Size::ZERO, Size::new(205.0, 100.0)),
+        );
+        let mut messages = Vec::new();
+
+        let _ = split.on_event(
+            event,
+            Layout::new(&node),
+            position,
+            &renderer,
+            &mut clipboard::Null,
+            &mut messages,
+        );
+
+        messages
+    }
+
+    #[test]
+    fn double_click_resets_ratio() {
+        let mut state = State::new(Some(150), Axis::Vertical);
+        let mut split = Split::new(&mut state, Text::new("First"), Text::new("Second"), |p| p)
+            .default_ratio(0.25);
+
+        assert!(press(&mut split, Point::new(149.0, 50.0)).is_empty());
+        assert_eq!(press(&mut split, Point::new(149.0, 50.0)), vec![53]);
+    }
+
+    #[test]
+    fn double_click_without_default_ratio() {
+        let mut state = State::new(None, Axis::Vertical);
+        let mut split = Split::new(&mut state, Text::new("First"), Text::new("Second"), |p| p);
+
+        assert!(press(&mut split, Point::new(102.0, 50.0)).is_empty());
+        assert!(press(&mut split, Point::new(102.0, 50.0)).is_empty());
+    }
+
+    #[test]
+    fn collapse_and_restore() {
+        let mut state = State::new(Some(80), Axis::Vertical);
+        let mut split = Split::new(&mut state, Text::new("First"), Text::new("Second"), |p| p)
+            .collapsible(true)
+            .on_collapse(|pane| match pane {
+                Some(Pane::First) => 1,
+                Some(Pane::Second) => 2,
+                None => 0,
+            });
+
+        // The button above the center of the divider collapses the first element.
+        assert_eq!(press(&mut split, Point::new(80.0, 45.0)), vec![1]);
+        // The divider is now at the left edge, the button below its center restores.
+        assert_eq!(press(&mut split, Point::new(2.0, 55.0)), vec![0]);
+        drop(split);
+
+        assert_eq!(state.collapsed(), None);
+        assert_eq!(state.divider_position(), Some(80));
+
+        state.collapse(Pane::Second);
+        assert_eq!(state.collapsed(), Some(Pane::Second));
+        state.restore();
+        assert_eq!(state.divider_position(), Some(80));
+    }
+
+    #[test]
+    fn drag_margin_starts_dragging() {
+        let mut state = State::new(None, Axis::Vertical);
+        let mut split =
+            Split::new(&mut state, Text::new("First"), Text::new("Second"), |p| p).drag_margin(4.0);
+
+        // The divider spans from 100 to 105, the margin starts at 96.
+        assert!(press(&mut split, Point::new(97.0, 50.0)).is_empty());
+        assert_eq!(move_to(&mut split, Point::new(60.0, 50.0)), vec![60]);
+    }
+
+    #[test]
+    fn drag_margin_outside() {
+        let mut state = State::new(None, Axis::Vertical);
+        let mut split =
+            Split::new(&mut state, Text::new("First"), Text::new("Second"), |p| p).drag_margin(4.0);
+
+        assert!(press(&mut split, Point::new(95.0, 50.0)).is_empty());
+        assert!(move_to(&mut split, Point::new(60.0, 50.0)).is_empty());
+    }
+
+    #[test]
+    fn drag_margin_keeps_captured_presses() {
+        let mut button = button::State::new();
+        let mut state = State::new(None, Axis::Vertical);
+        let mut split = Split::new(
+            &mut state,
+            Button::new(&mut button, Text::new("First"))
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .on_press(0),
+            Text::new("Second"),
+            |p| p,
+        )
+        .drag_margin(4.0);
+
+        // The button captures the press, so the divider is not dragged.
+        assert!(press(&mut split, Point::new(97.0, 50.0)).is_empty());
+        assert!(move_to(&mut split, Point::new(60.0, 50.0)).is_empty());
+    }
+}
@@ -0,0 +1,298 @@
+//! Use a split tree to compose nested splits into a multi pane layout.
+//!
+//! Every divider of a [`SplitTree`](SplitTree) is identified by its path:
+//! the [`Pane`](super::Pane)s leading from the root split to the split
+//! owning the divider. The root split has the empty path, the split in its
+//! second element has the path `[Pane::Second]` and so on. Resize messages
+//! carry this path, so a single message can serve all dividers:
+//!
+//! ```
+//! # use iced_aw::split::{Axis, Pane, TreeState};
+//! # use iced_native::{Text, renderer::Null};
+//! #
+//! # pub type Tree<'a, Message> = iced_aw::native::split::SplitTree<'a, Message, Null>;
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Resized(Vec<Pane>, u16),
+//! }
+//!
+//! let mut state = TreeState::new();
+//!
+//! let tree = Tree::split(
+//!     Axis::Vertical,
+//!     0.25,
+//!     Tree::leaf(Text::new("Files")),
+//!     Tree::split(
+//!         Axis::Horizontal,
+//!         0.75,
+//!         Tree::leaf(Text::new("Editor")),
+//!         Tree::leaf(Text::new("Terminal")),
+//!     ),
+//! );
+//!
+//! let content = tree.build(&mut state, Message::Resized);
+//!
+//! // On `Message::Resized(path, position)`:
+//! // state.resize(&path, position);
+//! ```
+//!
+//! *This API requires the following crate features to be activated: `split`*
+use std::{collections::HashMap, rc::Rc};
+
+use iced_native::{container, row, Element};
+
+use super::{Axis, Pane, Split, State};
+
+/// A tree of nested [`Split`](Split)s with elements as leaves.
+///
+/// See the [module documentation](self) for how the dividers are identified.
+#[allow(missing_debug_implementations)]
+pub enum SplitTree<'a, Message, Renderer: super::Renderer> {
+    /// An element displayed in a pane of the tree.
+    Leaf(Element<'a, Message, Renderer>),
+    /// A [`Split`](Split) dividing its space between two subtrees.
+    Split {
+        /// The axis to split at.
+        axis: Axis,
+        /// The ratio of the space given to the first subtree as long as the
+        /// divider was not moved.
+        ratio: f32,
+        /// The first subtree.
+        first: Box<Self>,
+        /// The second subtree.
+        second: Box<Self>,
+    },
+}
+
+impl<'a, Message, Renderer> SplitTree<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + super::Renderer + container::Renderer + row::Renderer,
+{
+    /// Creates a new leaf of a [`SplitTree`](SplitTree) displaying the given
+    /// element.
+    pub fn leaf<T>(content: T) -> Self
+    where
+        T: Into<Element<'a, Message, Renderer>>,
+    {
+        Self::Leaf(content.into())
+    }
+
+    /// Creates a new [`SplitTree`](SplitTree) splitting the available space
+    /// between the two given subtrees.
+    ///
+    /// It expects:
+    ///     - The [`Axis`](Axis) to split at
+    ///     - The ratio of the space given to the first subtree
+    ///     - The first subtree
+    ///     - The second subtree
+    pub fn split(axis: Axis, ratio: f32, first: Self, second: Self) -> Self {
+        Self::Split {
+            axis,
+            ratio,
+            first: Box::new(first),
+            second: Box::new(second),
+        }
+    }
+
+    /// Builds the nested [`Split`](Split)s of this [`SplitTree`](SplitTree).
+    ///
+    /// The states of all splits are kept in the given
+    /// [`TreeState`](TreeState). The message is send with the path of the
+    /// moved divider and its new position.
+    pub fn build<F>(self, state: &'a mut TreeState, on_resize: F) -> Element<'a, Message, Renderer>
+    where
+        F: 'static + Fn(Vec<Pane>, u16) -> Message,
+    {
+        let mut path = Vec::new();
+        self.sync(&mut path, state);
+
+        let mut states: HashMap<&Vec<Pane>, &'a mut State> = state.states.iter_mut().collect();
+        self.into_element(&mut path, &mut states, &Rc::new(on_resize))
+    }
+
+    /// Adds the missing states of the splits of this
+    /// [`SplitTree`](SplitTree) to the [`TreeState`](TreeState) and updates
+    /// their axes.
+    fn sync(&self, path: &mut Vec<Pane>, state: &mut TreeState) {
+        if let Self::Split {
+            axis,
+            first,
+            second,
+            ..
+        } = self
+        {
+            state
+                .states
+                .entry(path.clone())
+                .or_insert_with(|| State::new(None, *axis))
+                .axis = *axis;
+
+            path.push(Pane::First);
+            first.sync(path, state);
+            let _ = path.pop();
+
+            path.push(Pane::Second);
+            second.sync(path, state);
+            let _ = path.pop();
+        }
+    }
+
+    /// Turns this [`SplitTree`](SplitTree) into nested [`Split`](Split)s
+    /// using the given states.
+    fn into_element<F>(
+        self,
+        path: &mut Vec<Pane>,
+        states: &mut HashMap<&Vec<Pane>, &'a mut State>,
+        on_resize: &Rc<F>,
+    ) -> Element<'a, Message, Renderer>
+    where
+        F: 'static + Fn(Vec<Pane>, u16) -> Message,
+    {
+        match self {
+            Self::Leaf(content) => content,
+            Self::Split {
+                ratio,
+                first,
+                second,
+                ..
+            } => {
+                let state = states
+                    .remove(path)
+                    .expect("Native: A state should exist for every split of the tree");
+
+                path.push(Pane::First);
+                let first = first.into_element(path, states, on_resize);
+                let _ = path.pop();
+
+                path.push(Pane::Second);
+                let second = second.into_element(path, states, on_resize);
+                let _ = path.pop();
+
+                let split_path = path.clone();
+                let on_resize = Rc::clone(on_resize);
+
+                Split::new(state, first, second, move |position| {
+                    on_resize(split_path.clone(), position)
+                })
+                .default_ratio(ratio)
+                .into()
+            }
+        }
+    }
+}
+
+/// The states of all [`Split`](Split)s of a [`SplitTree`](SplitTree).
+#[derive(Clone, Debug, Default)]
+pub struct TreeState {
+    /// The state of each split by its path.
+    states: HashMap<Vec<Pane>, State>,
+}
+
+impl TreeState {
+    /// Creates a new empty [`TreeState`](TreeState).
+    ///
+    /// The states of the splits are added when the
+    /// [`SplitTree`](SplitTree) is built.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Gets the [`State`](State) of the split at the given path.
+    #[must_use]
+    pub fn get(&self, path: &[Pane]) -> Option<&State> {
+        self.states.get(path)
+    }
+
+    /// Gets the mutable [`State`](State) of the split at the given path.
+    #[must_use]
+    pub fn get_mut(&mut self, path: &[Pane]) -> Option<&mut State> {
+        self.states.get_mut(path)
+    }
+
+    /// Sets the position of the divider of the split at the given path.
+    pub fn resize(&mut self, path: &[Pane], position: u16) {
+        if let Some(state) = self.states.get_mut(path) {
+            state.set_divider_position(position);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iced_native::{
+        clipboard, layout::Limits, mouse, renderer::Null, Event, Layout, Point, Size, Text,
+    };
+
+    use super::{SplitTree, TreeState};
+    use crate::native::split::{Axis, Pane};
+
+    type Tree<'a> = SplitTree<'a, (Vec<Pane>, u16), Null>;
+
+    fn tree<'a>() -> Tree<'a> {
+        Tree::split(
+            Axis::Vertical,
+            0.5,
+            Tree::leaf(Text::new("First")),
+            Tree::split(
+                Axis::Horizontal,
+                0.5,
+                Tree::leaf(Text::new("Top")),
+                Tree::leaf(Text::new("Bottom")),
+            ),
+        )
+    }
+
+    #[test]
+    fn build_creates_states() {
+        let mut state = TreeState::new();
+        let _ = tree().build(&mut state, |path, position| (path, position));
+
+        assert!(state.get(&[]).is_some());
+        assert!(state.get(&[Pane::Second]).is_some());
+        assert!(state.get(&[Pane::First]).is_none());
+
+        state.resize(&[Pane::Second], 42);
+        assert_eq!(
+            state
+                .get(&[Pane::Second])
+                .and_then(super::State::divider_position),
+            Some(42)
+        );
+    }
+
+    #[test]
+    fn resize_messages_carry_path() {
+        let mut state = TreeState::new();
+        let mut element = tree().build(&mut state, |path, position| (path, position));
+
+        let renderer = Null::new();
+        let node = element.layout(&renderer, &Limits::new(Size::ZERO, Size::new(205.0, 205.0)));
+        let mut messages = Vec::new();
+
+        for (event, position) in [
+            (
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+                Point::new(150.0, 102.0),
+            ),
+            (
+                Event::Mouse(mouse::Event::CursorMoved {
+                    position: Point::new(150.0, 60.0),
+                }),
+                Point::new(150.0, 60.0),
+            ),
+        ] {
+            let _ = element.on_event(
+                event,
+                Layout::new(&node),
+                position,
+                &renderer,
+                &mut clipboard::Null,
+                &mut messages,
+            );
+        }
+
+        assert_eq!(messages, vec![(vec![Pane::Second], 60)]);
+    }
+}
@@ -4,18 +4,30 @@
 //! to use the [`Tabs`](super::tabs::Tabs) widget instead.
 //!
 //! *This API requires the following crate features to be activated: `tab_bar`*
-use iced_native::{touch, Element};
+use iced_native::{overlay, touch, Element};
 use std::hash::Hash;
 
 use iced_native::{
-    column, event, layout, mouse, row, text, Align, Clipboard, Column, Event, Font, Hasher, Layout,
-    Length, Point, Rectangle, Row, Text, Widget,
+    column, event, keyboard, layout, mouse, row, text, Align, Clipboard, Column, Event, Font,
+    Hasher, Layout, Length, Point, Rectangle, Row, Size, Text, Vector, Widget,
 };
 
 pub mod tab_label;
 pub use tab_label::TabLabel;
 
-use crate::core::renderer::DrawEnvironment;
+pub mod orientation;
+pub use orientation::Orientation;
+
+pub mod overflow;
+pub use overflow::Overflow;
+
+use crate::{core::renderer::DrawEnvironment, native::overlay::TabBarMenuOverlay};
+
+/// The spacing between the label of a tab and its badge.
+const BADGE_SPACING: u16 = 5;
+
+/// The padding of the badge of a tab.
+const BADGE_PADDING: u16 = 3;
 
 /// A tab bar to show tabs.
 ///
@@ -24,7 +36,7 @@ use crate::core::renderer::DrawEnvironment;
 /// # use iced_aw::{TabLabel};
 /// # use iced_native::{renderer::Null};
 /// #
-/// # pub type TabBar<Message> = iced_aw::native::TabBar<Message, Null>;
+/// # pub type TabBar<'a, Message> = iced_aw::native::TabBar<'a, Message, Null>;
 /// #[derive(Debug, Clone)]
 /// enum Message {
 ///     TabSelected(usize),
@@ -41,13 +53,20 @@ use crate::core::renderer::DrawEnvironment;
 /// .push(TabLabel::Text(String::from("Three")));
 /// ```
 #[allow(missing_debug_implementations)]
-pub struct TabBar<Message, Renderer: self::Renderer> {
+pub struct TabBar<'a, Message, Renderer: self::Renderer> {
     /// The currently active tab.
     active_tab: usize,
     /// The vector containing the labels of the tabs.
     tab_labels: Vec<TabLabel>,
     /// The function that produces the message when a tab is selected.
     on_select: Box<dyn Fn(usize) -> Message>,
+    /// The state of the hovered and the dragged tab.
+    state: Option<&'a mut State>,
+    /// The function that produces the message when a tab was dragged to a new
+    /// position.
+    on_reorder: Option<Box<dyn Fn(usize, usize) -> Message>>,
+    /// The flags marking which tabs can be closed.
+    closable: Vec<bool>,
     /// The function that produces the message when the close icon was pressed.
     on_close: Option<Box<dyn Fn(usize) -> Message>>,
     /// The width of the [`TabBar`](TabBar).
@@ -68,15 +87,24 @@ pub struct TabBar<Message, Renderer: self::Renderer> {
     padding: u16,
     /// The spacing of the tabs of the [`TabBar`](TabBar).
     spacing: u16,
+    /// The orientation of the [`TabBar`](TabBar).
+    orientation: Orientation,
+    /// The handling of the tabs overflowing the width of the
+    /// [`TabBar`](TabBar).
+    overflow: Option<Overflow>,
+    /// Toggles the selection of the tabs of the [`TabBar`](TabBar) by keyboard.
+    keyboard_navigation: bool,
     /// The optional icon font of the [`TabBar`](TabBar).
     icon_font: Option<Font>,
     /// The optional text font of the [`TabBar`](TabBar).
     text_font: Option<Font>,
     /// The style of the [`TabBar`](TabBar).
     style: Renderer::Style,
+    /// The style of the badges of the [`TabLabel`](tab_label::TabLabel)s.
+    badge_style: Renderer::BadgeStyle,
 }
 
-impl<Message, Renderer> TabBar<Message, Renderer>
+impl<'a, Message, Renderer> TabBar<'a, Message, Renderer>
 where
     Renderer: self::Renderer,
 {
@@ -108,9 +136,12 @@ where
     {
         Self {
             active_tab,
+            closable: vec![true; tab_labels.len()],
             tab_labels,
             on_select: Box::new(on_select),
             on_close: None,
+            state: None,
+            on_reorder: None,
             width: Length::Fill,
             tab_width: Length::Fill,
             height: Length::Shrink,
@@ -120,9 +151,13 @@ where
             close_size: <Renderer as self::Renderer>::DEFAULT_CLOSE_SIZE,
             padding: <Renderer as self::Renderer>::DEFAULT_PADDING,
             spacing: <Renderer as self::Renderer>::DEFAULT_SPACING,
+            orientation: Orientation::default(),
+            overflow: None,
+            keyboard_navigation: false,
             icon_font: None,
             text_font: None,
             style: Renderer::Style::default(),
+            badge_style: Renderer::BadgeStyle::default(),
         }
     }
 
@@ -143,6 +178,31 @@ where
         self
     }
 
+    /// Enables reordering the tabs of the [`TabBar`](TabBar) by dragging them.
+    ///
+    /// It expects:
+    ///     * a mutable reference to the [`State`](State) of the dragged tab.
+    ///     * the function that will be called if a tab is dropped at a new
+    ///         position. It takes the old and the new index of the tab.
+    pub fn on_reorder<F>(mut self, state: &'a mut State, on_reorder: F) -> Self
+    where
+        F: 'static + Fn(usize, usize) -> Message,
+    {
+        self.state = Some(state);
+        self.on_reorder = Some(Box::new(on_reorder));
+        self
+    }
+
+    /// Sets the [`State`](State) keeping track of the hovered tab of the
+    /// [`TabBar`](TabBar).
+    ///
+    /// Without a state, the hovered tab is taken from the cursor position
+    /// when drawing.
+    pub fn state(mut self, state: &'a mut State) -> Self {
+        self.state = Some(state);
+        self
+    }
+
     /// Sets the width of the [`TabBar`](TabBar).
     pub fn width(mut self, width: Length) -> Self {
         self.width = width;
@@ -230,14 +290,404 @@ where
         self
     }
 
+    /// Sets the style of the badges shown by
+    /// [`TabLabel::with_badge`](tab_label::TabLabel::with_badge).
+    pub fn badge_style(mut self, style: impl Into<Renderer::BadgeStyle>) -> Self {
+        self.badge_style = style.into();
+        self
+    }
+
+    /// Sets the [`Orientation`](Orientation) of the [`TabBar`](TabBar).
+    ///
+    /// The default is [`Orientation::Horizontal`](Orientation::Horizontal).
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Enables the horizontal scrolling of the tabs of the [`TabBar`](TabBar).
+    ///
+    /// Scrolling is only supported by a horizontal [`TabBar`](TabBar).
+    ///
+    /// If the tabs overflow the available width, arrows are shown on both sides
+    /// of the [`TabBar`](TabBar) to select the previous or next tab. The strip
+    /// of tabs always scrolls to keep the active tab visible. Tabs with a
+    /// [`Length::Fill`](Length::Fill) width shrink to their content instead.
+    ///
+    /// This is the same as [`overflow`](TabBar::overflow) with
+    /// [`Overflow::Scroll`](Overflow::Scroll).
+    pub fn scrollable(mut self, scrollable: bool) -> Self {
+        self.overflow = scrollable.then_some(Overflow::Scroll);
+        self
+    }
+
+    /// Sets the [`Overflow`](Overflow) handling the tabs that do not fit into
+    /// the width of the [`TabBar`](TabBar).
+    ///
+    /// Overflowing is only supported by a horizontal [`TabBar`](TabBar). Tabs
+    /// with a [`Length::Fill`](Length::Fill) width shrink to their content.
+    ///
+    /// With [`Overflow::Menu`](Overflow::Menu) the leading tabs are shown as
+    /// long as they fit and the others are collapsed into a dropdown menu
+    /// opened by a button at the end of the [`TabBar`](TabBar). The active tab
+    /// is never collapsed. The menu keeps its open state in the
+    /// [`State`](State), so it can only be opened if a state is set.
+    pub fn overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = Some(overflow);
+        self
+    }
+
+    /// Enables selecting the tabs of the [`TabBar`](TabBar) by keyboard.
+    ///
+    /// Ctrl+Tab and Ctrl+Shift+Tab cycle through the tabs, wrapping around at
+    /// both ends. The arrow keys select the neighboring tabs while the cursor
+    /// is over the [`TabBar`](TabBar).
+    ///
+    /// With a [`State`](State) the tab selected by keyboard shows the focus
+    /// ring of the style until the next press.
+    pub fn keyboard_navigation(mut self, keyboard_navigation: bool) -> Self {
+        self.keyboard_navigation = keyboard_navigation;
+        self
+    }
+
     /// Pushes a [`TabLabel`](tab_label::TabLabel) to the [`TabBar`](TabBar).
     pub fn push(mut self, tab_label: TabLabel) -> Self {
         self.tab_labels.push(tab_label);
+        self.closable.push(true);
         self
     }
+
+    /// Sets whether the most recently pushed tab of the [`TabBar`](TabBar)
+    /// can be closed.
+    ///
+    /// All tabs are closable by default. The close icon is only drawn if an
+    /// [`on_close`](TabBar::on_close) function is set.
+    pub fn closable(mut self, closable: bool) -> Self {
+        if let Some(last) = self.closable.last_mut() {
+            *last = closable;
+        }
+        self
+    }
+}
+
+impl<Message, Renderer> TabBar<'_, Message, Renderer>
+where
+    Renderer: self::Renderer + column::Renderer + text::Renderer + row::Renderer,
+{
+    /// Creates the row holding the label and the close icon of a tab.
+    fn tab_row<'b>(
+        &'b self,
+        tab_label: &'b TabLabel,
+        closable: bool,
+        tab_width: Length,
+    ) -> Row<'b, Message, Renderer> {
+        let (tab_label, count) = tab_label.badge();
+        let text_width = if count == 0 {
+            tab_width
+        } else {
+            Length::Shrink
+        };
+        let icon = || {
+            Row::new()
+                .width(Length::Units(self.icon_size))
+                .height(Length::Units(self.icon_size))
+        };
+        let text = |text| Text::new(text).size(self.text_size).width(text_width);
+
+        // The badge is put next to the text or, without a text, the icon.
+        let badged = |label: Element<'b, Message, Renderer>| -> Element<'b, Message, Renderer> {
+            if count == 0 {
+                label
+            } else {
+                Row::new()
+                    .align_items(Align::Center)
+                    .spacing(BADGE_SPACING)
+                    .push(label)
+                    .push(
+                        Row::new()
+                            .padding(BADGE_PADDING)
+                            .push(Text::new(count.to_string()).size(self.text_size * 3 / 4)),
+                    )
+                    .into()
+            }
+        };
+
+        let label = match tab_label {
+            TabLabel::Icon(_icon) => Column::new()
+                .align_items(Align::Center)
+                .push(badged(icon().into())),
+            TabLabel::Text(label) => Column::new()
+                .align_items(Align::Center)
+                .push(badged(text(label).into())),
+            TabLabel::IconText(_icon, label) => Column::new()
+                .align_items(Align::Center)
+                .push(icon())
+                .push(badged(text(label).into())),
+            // Nested badges are already unwrapped by `TabLabel::badge`.
+            TabLabel::Badged(_, _) => Column::new(),
+        }
+        .width(tab_width)
+        .height(self.height);
+
+        let mut label_row = Row::new()
+            .align_items(Align::Center)
+            .padding(self.padding)
+            .width(tab_width)
+            .push(label);
+
+        if self.on_close.is_some() && closable {
+            label_row = label_row.push(
+                Row::new()
+                    .width(Length::Units(self.close_size))
+                    .height(Length::Units(self.close_size))
+                    .align_items(Align::Center),
+            );
+        }
+
+        label_row
+    }
+
+    /// Lays out each tab by itself without limiting its width.
+    fn layout_tabs(&self, renderer: &Renderer, limits: &layout::Limits) -> Vec<layout::Node> {
+        let tab_limits =
+            layout::Limits::new(Size::ZERO, Size::new(f32::INFINITY, limits.max().height));
+        let tab_width = match self.tab_width {
+            Length::Fill | Length::FillPortion(_) => Length::Shrink,
+            width => width,
+        };
+
+        self.tab_labels
+            .iter()
+            .zip(&self.closable)
+            .map(|(tab_label, closable)| {
+                self.tab_row(tab_label, *closable, tab_width)
+                    .layout(renderer, &tab_limits)
+            })
+            .collect()
+    }
+
+    /// Lays out the tabs next to each other without limiting their width.
+    ///
+    /// If the tabs overflow the available width, the layout gets two additional
+    /// children for the scroll arrows and the tabs are shifted to keep the
+    /// active tab visible.
+    fn layout_scrollable(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let spacing = f32::from(self.spacing);
+        let mut tabs = self.layout_tabs(renderer, &limits);
+
+        let mut x = 0.0;
+        let positions: Vec<f32> = tabs
+            .iter()
+            .map(|tab| {
+                let position = x;
+                x += tab.size().width + spacing;
+                position
+            })
+            .collect();
+        let total_width = (x - spacing).max(0.0);
+        let height = tabs.iter().map(|tab| tab.size().height).fold(0.0, f32::max);
+
+        let size = limits.resolve(Size::new(total_width, height));
+
+        if total_width <= size.width {
+            tabs.iter_mut()
+                .zip(positions)
+                .for_each(|(tab, x)| tab.move_to(Point::new(x, 0.0)));
+
+            return layout::Node::with_children(size, tabs);
+        }
+
+        let arrow_width = f32::from(self.text_size + 2 * self.padding);
+        let viewport_width = (size.width - 2.0 * arrow_width).max(0.0);
+        let offset = tabs.get(self.active_tab).map_or(0.0, |tab| {
+            (positions[self.active_tab] + tab.size().width - viewport_width).max(0.0)
+        });
+
+        tabs.iter_mut().zip(positions).for_each(|(tab, x)| {
+            tab.move_to(Point::new(arrow_width + x - offset, 0.0));
+        });
+
+        let left_arrow = layout::Node::new(Size::new(arrow_width, size.height));
+        let mut right_arrow = layout::Node::new(Size::new(arrow_width, size.height));
+        right_arrow.move_to(Point::new(size.width - arrow_width, 0.0));
+
+        tabs.push(left_arrow);
+        tabs.push(right_arrow);
+
+        layout::Node::with_children(size, tabs)
+    }
+
+    /// Lays out the tabs next to each other without limiting their width.
+    ///
+    /// If the tabs overflow the available width, the layout gets an
+    /// additional child for the menu button at the end of the
+    /// [`TabBar`](TabBar). The tabs that do not fit are collapsed to an empty
+    /// layout at the center of the menu button.
+    fn layout_menu(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let spacing = f32::from(self.spacing);
+        let mut tabs = self.layout_tabs(renderer, &limits);
+
+        let widths: Vec<f32> = tabs.iter().map(|tab| tab.size().width).collect();
+        #[allow(clippy::cast_precision_loss)]
+        let total_width =
+            widths.iter().sum::<f32>() + spacing * widths.len().saturating_sub(1) as f32;
+        let height = tabs.iter().map(|tab| tab.size().height).fold(0.0, f32::max);
+
+        let size = limits.resolve(Size::new(total_width, height));
+
+        let button_width = f32::from(self.text_size + 2 * self.padding);
+        let visible = if total_width <= size.width {
+            (0..tabs.len()).collect()
+        } else {
+            crate::core::tab_bar::visible_tabs(
+                &widths,
+                spacing,
+                size.width - button_width - spacing,
+                self.active_tab,
+            )
+        };
+
+        let mut button = layout::Node::new(Size::new(button_width, size.height));
+        button.move_to(Point::new(size.width - button_width, 0.0));
+        let collapsed = button.bounds().center();
+
+        let mut x = 0.0;
+        for (index, tab) in tabs.iter_mut().enumerate() {
+            if visible.contains(&index) {
+                tab.move_to(Point::new(x, 0.0));
+                x += tab.size().width + spacing;
+            } else {
+                *tab = layout::Node::new(Size::ZERO);
+                tab.move_to(collapsed);
+            }
+        }
+
+        if visible.len() < tabs.len() {
+            tabs.push(button);
+        }
+
+        layout::Node::with_children(size, tabs)
+    }
+
+    /// Gets the layout of the button opening the menu of the collapsed tabs,
+    /// if there is one.
+    fn menu_button<'b>(&self, layout: Layout<'b>) -> Option<Layout<'b>> {
+        if self.overflow == Some(Overflow::Menu) {
+            layout.children().nth(self.tab_labels.len())
+        } else {
+            None
+        }
+    }
+
+    /// Gets the indices of the tabs collapsed into the menu.
+    fn collapsed_tabs(&self, layout: Layout<'_>) -> Vec<usize> {
+        self.menu_button(layout).map_or_else(Vec::new, |button| {
+            let center = button.bounds().center();
+            layout
+                .children()
+                .take(self.tab_labels.len())
+                .enumerate()
+                .filter(|(_, tab)| {
+                    let bounds = tab.bounds();
+                    bounds.width <= 0.0 && bounds.position() == center
+                })
+                .map(|(index, _)| index)
+                .collect()
+        })
+    }
+
+    /// Gets the bounds of the active tab the indicator is drawn below.
+    fn indicator_bounds(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        layout
+            .children()
+            .take(self.tab_labels.len())
+            .nth(self.active_tab)
+            .map(|tab| tab.bounds())
+    }
+
+    /// Gets the currently dragged tab along with its drop position.
+    fn dragged_tab(&self, layout: Layout<'_>) -> Option<DraggedTab> {
+        let drag = self.state.as_ref()?.dragged?;
+        let tabs: Vec<Rectangle> = layout
+            .children()
+            .take(self.tab_labels.len())
+            .map(|layout| layout.bounds())
+            .collect();
+        let bounds = tabs.get(drag.index)?;
+        let spacing = f32::from(self.spacing);
+
+        let (centers, center, offset, shift): (Vec<f32>, _, _, _) = match self.orientation {
+            Orientation::Horizontal => {
+                let offset = drag.position.x - drag.origin.x;
+                (
+                    tabs.iter().map(Rectangle::center_x).collect(),
+                    bounds.center_x() + offset,
+                    Vector::new(offset, 0.0),
+                    Vector::new(bounds.width + spacing, 0.0),
+                )
+            }
+            Orientation::Vertical => {
+                let offset = drag.position.y - drag.origin.y;
+                (
+                    tabs.iter().map(Rectangle::center_y).collect(),
+                    bounds.center_y() + offset,
+                    Vector::new(0.0, offset),
+                    Vector::new(0.0, bounds.height + spacing),
+                )
+            }
+        };
+
+        Some(DraggedTab {
+            from: drag.index,
+            to: crate::core::tab_bar::drop_index(&centers, drag.index, center),
+            offset,
+            shift,
+        })
+    }
+
+    /// Gets the index of the tab under the cursor.
+    ///
+    /// Tabs scrolled below the scroll arrows are not hovered.
+    fn hovered_tab(&self, layout: Layout<'_>, cursor_position: Point) -> Option<usize> {
+        if !layout.bounds().contains(cursor_position)
+            || layout
+                .children()
+                .skip(self.tab_labels.len())
+                .any(|arrow| arrow.bounds().contains(cursor_position))
+        {
+            return None;
+        }
+
+        layout
+            .children()
+            .take(self.tab_labels.len())
+            .position(|tab| tab.bounds().contains(cursor_position))
+    }
+
+    /// Shows or hides the focus ring of the active tab.
+    fn set_keyboard_focused(&mut self, is_keyboard_focused: bool) {
+        if let Some(state) = self.state.as_mut() {
+            state.is_keyboard_focused = is_keyboard_focused;
+        }
+    }
+
+    /// Produces the message selecting the tab next to the active tab.
+    fn select_next(&self, forward: bool, messages: &mut Vec<Message>) {
+        let next = if forward {
+            Some(self.active_tab + 1).filter(|next| *next < self.tab_labels.len())
+        } else {
+            self.active_tab.checked_sub(1)
+        };
+
+        if let Some(next) = next {
+            messages.push((self.on_select)(next));
+        }
+    }
 }
 
-impl<Message, Renderer> Widget<Message, Renderer> for TabBar<Message, Renderer>
+impl<Message, Renderer> Widget<Message, Renderer> for TabBar<'_, Message, Renderer>
 where
     Renderer: self::Renderer + column::Renderer + text::Renderer + row::Renderer,
 {
@@ -250,53 +700,45 @@ where
     }
 
     fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
-        self.tab_labels
-            .iter()
-            .fold(Row::<Message, Renderer>::new(), |row, tab_label| {
-                let label = match tab_label {
-                    TabLabel::Icon(_icon) => Column::new().align_items(Align::Center).push(
-                        Row::new()
-                            .width(Length::Units(self.icon_size))
-                            .height(Length::Units(self.icon_size)),
-                    ),
-                    TabLabel::Text(text) => Column::new()
-                        .align_items(Align::Center)
-                        .push(Text::new(text).size(self.text_size).width(self.tab_width)),
-                    TabLabel::IconText(_icon, text) => Column::new()
-                        .align_items(Align::Center)
-                        .push(
-                            Row::new()
-                                .width(Length::Units(self.icon_size))
-                                .height(Length::Units(self.icon_size)),
-                        )
-                        .push(Text::new(text).size(self.text_size).width(self.tab_width)),
-                }
-                .width(self.tab_width)
-                .height(self.height);
-
-                let mut label_row = Row::new()
-                    .align_items(Align::Center)
-                    .padding(self.padding)
-                    .width(self.tab_width)
-                    .push(label);
+        if self.orientation == Orientation::Vertical {
+            return self
+                .tab_labels
+                .iter()
+                .zip(&self.closable)
+                .fold(
+                    Column::<Message, Renderer>::new(),
+                    |column, (tab_label, closable)| {
+                        column.push(self.tab_row(tab_label, *closable, self.tab_width))
+                    },
+                )
+                .width(self.width)
+                .height(self.height)
+                .spacing(self.spacing)
+                .layout(renderer, limits);
+        }
 
-                if self.on_close.is_some() {
-                    label_row = label_row.push(
-                        Row::new()
-                            .width(Length::Units(self.close_size))
-                            .height(Length::Units(self.close_size))
-                            .align_items(Align::Center),
-                    );
-                }
+        match self.overflow {
+            Some(Overflow::Scroll) => return self.layout_scrollable(renderer, limits),
+            Some(Overflow::Menu) => return self.layout_menu(renderer, limits),
+            None => {}
+        }
 
-                row.push(label_row)
-            })
+        self.tab_labels
+            .iter()
+            .zip(&self.closable)
+            .fold(
+                Row::<Message, Renderer>::new(),
+                |row, (tab_label, closable)| {
+                    row.push(self.tab_row(tab_label, *closable, self.tab_width))
+                },
+            )
             .width(self.width)
             .height(self.height)
             .spacing(self.spacing)
             .layout(renderer, limits)
     }
 
+    #[allow(clippy::too_many_lines)]
     fn on_event(
         &mut self,
         event: Event,
@@ -307,29 +749,152 @@ where
         messages: &mut Vec<Message>,
     ) -> event::Status {
         match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                let hovered = self.hovered_tab(layout, cursor_position);
+                if let Some(state) = self.state.as_mut() {
+                    state.hovered = hovered;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorLeft) => {
+                if let Some(state) = self.state.as_mut() {
+                    state.hovered = None;
+                }
+            }
+            _ => {}
+        }
+
+        if let Some(dragged) = self.dragged_tab(layout) {
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { position })
+                | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                    if let Some(drag) = self.state.as_mut().and_then(|state| state.dragged.as_mut())
+                    {
+                        drag.position = position;
+                    }
+                    return event::Status::Captured;
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                | Event::Touch(
+                    touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. },
+                ) => {
+                    if let Some(state) = self.state.as_mut() {
+                        state.dragged = None;
+                    }
+                    if let Some(on_reorder) = self.on_reorder.as_ref() {
+                        if dragged.from != dragged.to {
+                            messages.push((on_reorder)(dragged.from, dragged.to));
+                        }
+                    }
+                    return event::Status::Captured;
+                }
+                _ => {}
+            }
+        }
+
+        let mut arrows = layout.children().skip(self.tab_labels.len());
+        let arrows = arrows.next().zip(arrows.next());
+
+        match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }) if self.keyboard_navigation && !self.tab_labels.is_empty() => {
+                let forward = match (key_code, self.orientation) {
+                    (keyboard::KeyCode::Tab, _) if modifiers.control => {
+                        messages.push((self.on_select)(crate::core::tab_bar::cycle(
+                            self.active_tab,
+                            self.tab_labels.len(),
+                            !modifiers.shift,
+                        )));
+                        self.set_keyboard_focused(true);
+                        return event::Status::Captured;
+                    }
+                    (keyboard::KeyCode::Left, Orientation::Horizontal)
+                    | (keyboard::KeyCode::Up, Orientation::Vertical) => false,
+                    (keyboard::KeyCode::Right, Orientation::Horizontal)
+                    | (keyboard::KeyCode::Down, Orientation::Vertical) => true,
+                    _ => return event::Status::Ignored,
+                };
+
+                if layout.bounds().contains(cursor_position) {
+                    self.select_next(forward, messages);
+                    self.set_keyboard_focused(true);
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if arrows.is_some() && layout.bounds().contains(cursor_position) =>
+            {
+                let (x, y) = match delta {
+                    mouse::ScrollDelta::Lines { x, y } | mouse::ScrollDelta::Pixels { x, y } => {
+                        (x, y)
+                    }
+                };
+                let amount = if x.abs() > y.abs() { x } else { y };
+
+                if amount != 0.0 {
+                    self.select_next(amount < 0.0, messages);
+                }
+                event::Status::Captured
+            }
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                self.set_keyboard_focused(false);
+
+                if let Some(button) = self.menu_button(layout) {
+                    if button.bounds().contains(cursor_position) {
+                        if let Some(state) = self.state.as_mut() {
+                            state.is_menu_open = !state.is_menu_open;
+                        }
+                        return event::Status::Captured;
+                    }
+                }
+
+                if let Some((left_arrow, right_arrow)) = arrows {
+                    if left_arrow.bounds().contains(cursor_position) {
+                        self.select_next(false, messages);
+                        return event::Status::Captured;
+                    }
+                    if right_arrow.bounds().contains(cursor_position) {
+                        self.select_next(true, messages);
+                        return event::Status::Captured;
+                    }
+                }
+
                 if layout.bounds().contains(cursor_position) {
                     let tabs_map: Vec<bool> = layout
                         .children()
+                        .take(self.tab_labels.len())
                         .map(|layout| layout.bounds().contains(cursor_position))
                         .collect();
 
                     if let Some(new_selected) = tabs_map.iter().position(|b| *b) {
-                        messages.push(
-                            self.on_close
-                                .as_ref()
-                                .filter(|_on_close| {
-                                    let tab_layout = layout.children().nth(new_selected).expect("Native: Layout should have a tab layout at the selected index");
-                                    let cross_layout = tab_layout.children().nth(1).expect("Native: Layout should have a close layout");
-
-                                    cross_layout.bounds().contains(cursor_position)
-                                })
-                                .map_or_else(
-                                    || (self.on_select)(new_selected),
-                                    |on_close| (on_close)(new_selected),
-                                ),
-                        );
+                        let on_close = self.on_close.as_ref().filter(|_on_close| {
+                            let tab_layout = layout.children().nth(new_selected).expect(
+                                "Native: Layout should have a tab layout at the selected index",
+                            );
+                            tab_layout.children().nth(1).is_some_and(|cross_layout| {
+                                cross_layout.bounds().contains(cursor_position)
+                            })
+                        });
+
+                        if let Some(on_close) = on_close {
+                            messages.push((on_close)(new_selected));
+                        } else {
+                            messages.push((self.on_select)(new_selected));
+
+                            let is_reorderable = self.on_reorder.is_some();
+                            if let Some(state) = self.state.as_mut().filter(|_| is_reorderable) {
+                                state.dragged = Some(Drag {
+                                    index: new_selected,
+                                    origin: cursor_position,
+                                    position: cursor_position,
+                                });
+                            }
+                        }
                         return event::Status::Captured;
                     }
                 }
@@ -355,12 +920,22 @@ where
                 cursor_position,
                 style_sheet: &self.style,
                 viewport: Some(viewport),
-                focus: (),
+                focus: self
+                    .state
+                    .as_ref()
+                    .is_some_and(|state| state.is_keyboard_focused),
             },
             self.active_tab,
+            self.state.as_ref().map_or_else(
+                || self.hovered_tab(layout, cursor_position),
+                |state| state.hovered,
+            ),
             &self.tab_labels,
+            self.dragged_tab(layout),
+            self.indicator_bounds(layout),
             self.icon_font,
             self.text_font,
+            &self.badge_style,
         )
     }
 
@@ -371,6 +946,10 @@ where
 
         self.active_tab.hash(state);
         self.tab_labels.iter().for_each(|tab| tab.hash(state));
+        self.closable.hash(state);
+        self.orientation.hash(state);
+        self.overflow.hash(state);
+        self.on_close.is_some().hash(state);
         self.width.hash(state);
         self.height.hash(state);
         self.max_height.hash(state);
@@ -378,6 +957,95 @@ where
         self.text_size.hash(state);
         self.close_size.hash(state);
     }
+
+    fn overlay(&mut self, layout: Layout<'_>) -> Option<overlay::Element<'_, Message, Renderer>> {
+        let button = self.menu_button(layout).map(|button| button.bounds());
+        let collapsed: Vec<(usize, TabLabel)> = self
+            .collapsed_tabs(layout)
+            .into_iter()
+            .map(|index| (index, self.tab_labels[index].clone()))
+            .collect();
+        let state = self.state.as_mut()?;
+
+        // The menu is closed when all tabs fit again.
+        let button = match button {
+            Some(button) if state.is_menu_open => button,
+            _ => {
+                state.is_menu_open = false;
+                return None;
+            }
+        };
+
+        Some(
+            TabBarMenuOverlay::new(state, collapsed, &self.on_select, button, &self.style)
+                .icon_size(self.icon_size)
+                .text_size(self.text_size)
+                .padding(self.padding)
+                .fonts(self.icon_font, self.text_font)
+                .overlay(),
+        )
+    }
+}
+
+/// The state of a [`TabBar`](TabBar) keeping track of its hovered tab, the
+/// tab dragged to a new position and the menu of the collapsed tabs.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct State {
+    /// The tab under the cursor.
+    hovered: Option<usize>,
+    /// The tab that is currently dragged.
+    dragged: Option<Drag>,
+    /// The open state of the menu of the collapsed tabs.
+    pub(crate) is_menu_open: bool,
+    /// Whether the active tab was selected by keyboard, which shows its
+    /// focus ring until the next press.
+    is_keyboard_focused: bool,
+}
+
+impl State {
+    /// Creates a new [`State`](State) without a hovered or dragged tab.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Gets the index of the tab under the cursor.
+    #[must_use]
+    pub const fn hovered(&self) -> Option<usize> {
+        self.hovered
+    }
+
+    /// Returns true if the menu of the tabs collapsed by
+    /// [`Overflow::Menu`](Overflow::Menu) is open.
+    #[must_use]
+    pub const fn is_menu_open(&self) -> bool {
+        self.is_menu_open
+    }
+}
+
+/// A tab pressed by the user that may be dragged to a new position.
+#[derive(Clone, Copy, Debug)]
+struct Drag {
+    /// The index of the dragged tab.
+    index: usize,
+    /// The position of the cursor when the tab was pressed.
+    origin: Point,
+    /// The current position of the cursor.
+    position: Point,
+}
+
+/// A tab being dragged to a new position on the [`TabBar`](TabBar).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DraggedTab {
+    /// The index of the dragged tab.
+    pub from: usize,
+    /// The index the dragged tab would be dropped at.
+    pub to: usize,
+    /// The distance the dragged tab was moved by.
+    pub offset: Vector,
+    /// The distance the tabs between the old and the new index of the dragged
+    /// tab make way by.
+    pub shift: Vector,
 }
 
 /// The renderer of a [`TabBar`](TabBar).
@@ -388,6 +1056,9 @@ pub trait Renderer: iced_native::Renderer {
     /// The style supported by this renderer.
     type Style: Default;
 
+    /// The style of the badges of the tabs supported by this renderer.
+    type BadgeStyle: Default;
+
     /// The default icon size of a [`TabBar`](TabBar).
     const DEFAULT_ICON_SIZE: u16;
 
@@ -404,10 +1075,31 @@ pub trait Renderer: iced_native::Renderer {
     const DEFAULT_SPACING: u16;
 
     /// Draws a [`TabBar`](TabBar).
+    ///
+    /// The hovered tab is drawn with the hovered style. The indicator is
+    /// given by the bounds of the tab it is currently over. The focus is true
+    /// if the active tab was selected by keyboard and shows its focus ring.
+    #[allow(clippy::too_many_arguments)]
     fn draw(
         &mut self,
-        env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, bool>,
         active_tab: usize,
+        hovered_tab: Option<usize>,
+        tab_labels: &[TabLabel],
+        dragged: Option<DraggedTab>,
+        indicator: Option<Rectangle>,
+        icon_font: Option<Font>,
+        text_font: Option<Font>,
+        badge_style: &Self::BadgeStyle,
+    ) -> Self::Output;
+
+    /// Draws the menu of the tabs collapsed by
+    /// [`Overflow::Menu`](Overflow::Menu).
+    ///
+    /// Each child of the layout is an entry of a collapsed tab.
+    fn draw_menu(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
         tab_labels: &[TabLabel],
         icon_font: Option<Font>,
         text_font: Option<Font>,
@@ -418,6 +1110,8 @@ pub trait Renderer: iced_native::Renderer {
 impl Renderer for iced_native::renderer::Null {
     type Style = ();
 
+    type BadgeStyle = ();
+
     const DEFAULT_ICON_SIZE: u16 = 0;
 
     const DEFAULT_TEXT_SIZE: u16 = 0;
@@ -430,8 +1124,21 @@ impl Renderer for iced_native::renderer::Null {
 
     fn draw(
         &mut self,
-        _env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        _env: DrawEnvironment<'_, Self::Defaults, Self::Style, bool>,
         _active_tab: usize,
+        _hovered_tab: Option<usize>,
+        _tab_labels: &[TabLabel],
+        _dragged: Option<DraggedTab>,
+        _indicator: Option<Rectangle>,
+        _icon_font: Option<Font>,
+        _text_font: Option<Font>,
+        _badge_style: &Self::BadgeStyle,
+    ) -> Self::Output {
+    }
+
+    fn draw_menu(
+        &mut self,
+        _env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
         _tab_labels: &[TabLabel],
         _icon_font: Option<Font>,
         _text_font: Option<Font>,
@@ -439,12 +1146,260 @@ impl Renderer for iced_native::renderer::Null {
     }
 }
 
-impl<'a, Message, Renderer> From<TabBar<Message, Renderer>> for Element<'a, Message, Renderer>
+impl<'a, Message, Renderer> From<TabBar<'a, Message, Renderer>> for Element<'a, Message, Renderer>
 where
     Renderer: 'a + self::Renderer + column::Renderer + text::Renderer + row::Renderer,
     Message: 'a,
 {
-    fn from(tab_bar: TabBar<Message, Renderer>) -> Self {
+    fn from(tab_bar: TabBar<'a, Message, Renderer>) -> Self {
         Element::new(tab_bar)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use iced_native::{
+        clipboard, keyboard, layout::Limits, mouse, renderer::Null, Event, Layout, Length, Point,
+        Size, Widget,
+    };
+
+    use super::{Overflow, State, TabBar, TabLabel};
+
+    fn move_cursor(state: &mut State, event: Event, position: Point) {
+        let mut tab_bar = TabBar::<(), Null>::new(0, |_| ())
+            .push(TabLabel::Text(String::from("One")))
+            .push(TabLabel::Text(String::from("Two")))
+            .state(state);
+
+        let renderer = Null::new();
+        let node = Widget::layout(
+            &tab_bar,
+            &renderer,
+            &Limits::new(Size::ZERO, Size::new(200.0, 20.0)),
+        );
+
+        let _ = tab_bar.on_event(
+            event,
+            Layout::new(&node),
+            position,
+            &renderer,
+            &mut clipboard::Null,
+            &mut Vec::new(),
+        );
+    }
+
+    #[test]
+    fn cursor_moves_track_hovered_tab() {
+        let mut state = State::new();
+
+        for (position, hovered) in [
+            (Point::new(150.0, 0.0), Some(1)),
+            (Point::new(50.0, 0.0), Some(0)),
+            (Point::new(50.0, 100.0), None),
+        ] {
+            move_cursor(
+                &mut state,
+                Event::Mouse(mouse::Event::CursorMoved { position }),
+                position,
+            );
+            assert_eq!(state.hovered(), hovered);
+        }
+    }
+
+    #[test]
+    fn cursor_leaving_clears_hovered_tab() {
+        let mut state = State::new();
+        let position = Point::new(50.0, 0.0);
+
+        move_cursor(
+            &mut state,
+            Event::Mouse(mouse::Event::CursorMoved { position }),
+            position,
+        );
+        assert_eq!(state.hovered(), Some(0));
+
+        move_cursor(&mut state, Event::Mouse(mouse::Event::CursorLeft), position);
+        assert_eq!(state.hovered(), None);
+    }
+
+    #[test]
+    fn keyboard_selection_shows_focus_ring() {
+        let mut state = State::new();
+        let position = Point::new(50.0, 0.0);
+        let handle = |state: &mut State, event| {
+            let mut tab_bar = TabBar::<usize, Null>::new(0, |index| index)
+                .push(TabLabel::Text(String::from("One")))
+                .push(TabLabel::Text(String::from("Two")))
+                .keyboard_navigation(true)
+                .state(state);
+
+            let renderer = Null::new();
+            let node = Widget::layout(
+                &tab_bar,
+                &renderer,
+                &Limits::new(Size::ZERO, Size::new(200.0, 20.0)),
+            );
+
+            let _ = tab_bar.on_event(
+                event,
+                Layout::new(&node),
+                position,
+                &renderer,
+                &mut clipboard::Null,
+                &mut Vec::new(),
+            );
+        };
+
+        handle(
+            &mut state,
+            Event::Mouse(mouse::Event::CursorMoved { position }),
+        );
+        assert!(!state.is_keyboard_focused);
+
+        handle(
+            &mut state,
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Right,
+                modifiers: keyboard::Modifiers::default(),
+            }),
+        );
+        assert!(state.is_keyboard_focused);
+
+        handle(
+            &mut state,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+        );
+        assert!(!state.is_keyboard_focused);
+    }
+
+    /// Gets the width of the first tab with the given label.
+    fn tab_width(tab_label: TabLabel) -> f32 {
+        let tab_bar = TabBar::<(), Null>::new(0, |_| ())
+            .push(tab_label)
+            .scrollable(true);
+        let node = Widget::layout(
+            &tab_bar,
+            &Null::new(),
+            &Limits::new(Size::ZERO, Size::new(500.0, 20.0)),
+        );
+
+        let width = Layout::new(&node)
+            .children()
+            .next()
+            .map_or(0.0, |tab| tab.bounds().width);
+        width
+    }
+
+    #[test]
+    fn badge_reserves_space() {
+        let label = || TabLabel::Text(String::from("Inbox"));
+
+        assert!(tab_width(label().with_badge(3)) > tab_width(label()));
+        assert!((tab_width(label().with_badge(0)) - tab_width(label())).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn with_badge_replaces_count() {
+        let label = TabLabel::Icon('a').with_badge(3).with_badge(5);
+
+        assert!(
+            matches!(label, TabLabel::Badged(ref inner, 5) if matches!(**inner, TabLabel::Icon('a')))
+        );
+        assert!(matches!(label.badge(), (TabLabel::Icon('a'), 5)));
+        assert!(matches!(
+            TabLabel::Icon('a').badge(),
+            (TabLabel::Icon('a'), 0)
+        ));
+    }
+
+    #[test]
+    fn indicator_is_below_active_tab() {
+        let tab_bar = TabBar::<(), Null>::new(1, |_| ())
+            .push(TabLabel::Text(String::from("One")))
+            .push(TabLabel::Text(String::from("Two")));
+        let node = tab_bar.layout(
+            &Null::new(),
+            &Limits::new(Size::ZERO, Size::new(200.0, 50.0)),
+        );
+        let layout = Layout::new(&node);
+
+        assert_eq!(
+            tab_bar.indicator_bounds(layout),
+            layout.children().nth(1).map(|tab| tab.bounds())
+        );
+    }
+
+    /// Presses the left mouse button at the given position of a tab bar
+    /// with five tabs, four of which do not fit.
+    fn press_menu(state: &mut State, position: Point) -> Vec<usize> {
+        let mut tab_bar = TabBar::<usize, Null>::new(4, |index| index)
+            .tab_width(Length::Units(50))
+            .text_size(10)
+            .padding(5)
+            .overflow(Overflow::Menu)
+            .state(state);
+        for label in ["One", "Two", "Three", "Four", "Five"] {
+            tab_bar = tab_bar.push(TabLabel::Text(String::from(label)));
+        }
+
+        let renderer = Null::new();
+        let node = Widget::layout(
+            &tab_bar,
+            &renderer,
+            &Limits::new(Size::ZERO, Size::new(200.0, 100.0)),
+        );
+        let layout = Layout::new(&node);
+        assert_eq!(tab_bar.collapsed_tabs(layout), vec![2, 3]);
+
+        let event = Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left));
+        let mut messages = Vec::new();
+
+        if let Some(mut menu) = tab_bar.overlay(layout) {
+            let menu_node = menu.layout(&renderer, Size::new(200.0, 500.0));
+            let _ = menu.on_event(
+                event.clone(),
+                Layout::new(&menu_node),
+                position,
+                &renderer,
+                &mut clipboard::Null,
+                &mut messages,
+            );
+        }
+
+        let _ = tab_bar.on_event(
+            event,
+            layout,
+            position,
+            &renderer,
+            &mut clipboard::Null,
+            &mut messages,
+        );
+
+        messages
+    }
+
+    #[test]
+    fn menu_selects_collapsed_tab() {
+        let mut state = State::new();
+
+        // The menu button is at the end of the tab bar.
+        assert!(press_menu(&mut state, Point::new(190.0, 5.0)).is_empty());
+        assert!(state.is_menu_open());
+
+        // The second entry below the menu button.
+        assert_eq!(press_menu(&mut state, Point::new(195.0, 75.0)), vec![3]);
+        assert!(!state.is_menu_open());
+    }
+
+    #[test]
+    fn menu_closes_on_outside_press() {
+        let mut state = State::new();
+
+        let _ = press_menu(&mut state, Point::new(190.0, 5.0));
+        assert!(state.is_menu_open());
+
+        // The pressed tab is selected and the menu is closed.
+        assert_eq!(press_menu(&mut state, Point::new(10.0, 5.0)), vec![0]);
+        assert!(!state.is_menu_open());
+    }
+}
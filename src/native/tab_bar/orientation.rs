@@ -0,0 +1,18 @@
+//! An [`Orientation`](Orientation) for defining the direction in which the
+//! tabs of a [`TabBar`](crate::native::tab_bar::TabBar) are stacked.
+//!
+//! *This API requires the following crate features to be activated: `tab_bar`*
+
+/// An [`Orientation`](Orientation) for defining the direction in which the
+/// tabs of a [`TabBar`](crate::native::tab_bar::TabBar) are stacked.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    /// An [`Orientation`] for placing the tabs next to each other from left to
+    /// right.
+    #[default]
+    Horizontal,
+
+    /// An [`Orientation`] for placing the tabs below each other from top to
+    /// bottom.
+    Vertical,
+}
@@ -0,0 +1,18 @@
+//! An [`Overflow`](Overflow) for defining how a
+//! [`TabBar`](crate::native::tab_bar::TabBar) handles tabs that do not fit
+//! into its width.
+//!
+//! *This API requires the following crate features to be activated: `tab_bar`*
+
+/// An [`Overflow`](Overflow) for defining how a
+/// [`TabBar`](crate::native::tab_bar::TabBar) handles tabs that do not fit
+/// into its width.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Overflow {
+    /// An [`Overflow`] scrolling the strip of tabs with arrows on both sides.
+    Scroll,
+
+    /// An [`Overflow`] collapsing the tabs that do not fit into a dropdown
+    /// menu opened by a button at the end of the tabs.
+    Menu,
+}
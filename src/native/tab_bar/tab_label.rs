@@ -15,5 +15,35 @@ pub enum TabLabel {
 
     /// A [`TabLabel`](TabLabel) showing an icon and a text on the tab.
     IconText(char, String),
+
+    /// A [`TabLabel`](TabLabel) showing a count badge next to another label.
+    ///
+    /// The badge is hidden if the count is zero.
+    Badged(Box<Self>, usize),
     // TODO: Support any element as a label.
 }
+
+impl TabLabel {
+    /// Shows a badge with the given count next to the
+    /// [`TabLabel`](TabLabel), e.g. for the unread messages of the tab.
+    ///
+    /// The badge is hidden if the count is zero. An existing badge of the
+    /// label is replaced.
+    #[must_use]
+    pub fn with_badge(self, count: usize) -> Self {
+        match self {
+            Self::Badged(label, _) => Self::Badged(label, count),
+            label => Self::Badged(Box::new(label), count),
+        }
+    }
+
+    /// Returns the [`TabLabel`](TabLabel) without its badge along with the
+    /// count of the badge, which is zero if there is no badge.
+    #[must_use]
+    pub fn badge(&self) -> (&Self, usize) {
+        match self {
+            Self::Badged(label, count) => (label.badge().0, *count),
+            label => (label, 0),
+        }
+    }
+}
@@ -14,7 +14,7 @@ use iced_native::{
 
 use crate::{
     core::renderer::DrawEnvironment,
-    native::{TabBar, TabLabel},
+    native::{tab_bar, TabBar, TabLabel},
 };
 
 pub mod tab_bar_position;
@@ -48,7 +48,7 @@ pub use tab_bar_position::TabBarPosition;
 #[allow(missing_debug_implementations)]
 pub struct Tabs<'a, Message, Renderer: self::Renderer> {
     /// The [`TabBar`](crate::native::TabBar) of the [`Tabs`](Tabs).
-    tab_bar: TabBar<Message, Renderer>,
+    tab_bar: TabBar<'a, Message, Renderer>,
     /// The vector containing the content of the tabs.
     tabs: Vec<Element<'a, Message, Renderer>>,
     /// The position of the [`TabBar`](crate::native::TabBar).
@@ -112,6 +112,52 @@ where
         }
     }
 
+    /// Similar to [`with_tabs`](Tabs::with_tabs) but only the content of the
+    /// active tab is created by the given function.
+    ///
+    /// This saves building the content of every tab on each `view`, which
+    /// matters if there are many tabs or their content is expensive. The
+    /// tradeoff is that the content of a tab is created anew whenever it is
+    /// selected and inactive tabs have no content that could process events
+    /// in the background, so any state of a tab has to live in the
+    /// application. More tabs can still be added with [`push`](Tabs::push).
+    ///
+    /// It expects:
+    ///     * the index of the currently active tab.
+    ///     * a vector containing the [`TabLabel`](TabLabel)s of the tabs.
+    ///     * the function that creates the content of the tab with the given
+    ///         index. It is only called for the active tab.
+    ///     * the function that will be called if a tab is selected by the user.
+    ///         It takes the index of the selected tab.
+    pub fn lazy<C, F>(
+        active_tab: usize,
+        tab_labels: Vec<TabLabel>,
+        content: C,
+        on_select: F,
+    ) -> Self
+    where
+        C: FnOnce(usize) -> Element<'a, Message, Renderer>,
+        F: 'static + Fn(usize) -> Message,
+        Message: 'a,
+        Renderer: 'a + row::Renderer,
+    {
+        let mut tabs: Vec<Element<'a, Message, Renderer>> =
+            std::iter::repeat_with(|| Row::new().into())
+                .take(tab_labels.len())
+                .collect();
+        if let Some(tab) = tabs.get_mut(active_tab) {
+            *tab = content(active_tab);
+        }
+
+        Tabs {
+            tab_bar: TabBar::width_tab_labels(active_tab, tab_labels, on_select),
+            tabs,
+            tab_bar_position: TabBarPosition::Top,
+            width: Length::Fill,
+            height: Length::Fill,
+        }
+    }
+
     /// Sets the message that will be produced when the close icon of a tab
     /// on the [`TabBar`](TabBar) is pressed.
     ///
@@ -124,6 +170,21 @@ where
         self
     }
 
+    /// Enables reordering the tabs of the [`Tabs`](Tabs) by dragging them.
+    ///
+    /// It expects:
+    ///     * a mutable reference to the [`State`](super::tab_bar::State) of the
+    ///         dragged tab.
+    ///     * the function that will be called if a tab is dropped at a new
+    ///         position. It takes the old and the new index of the tab.
+    pub fn on_reorder<F>(mut self, state: &'a mut tab_bar::State, on_reorder: F) -> Self
+    where
+        F: 'static + Fn(usize, usize) -> Message,
+    {
+        self.tab_bar = self.tab_bar.on_reorder(state, on_reorder);
+        self
+    }
+
     /// Sets the width of the [`Tabs`](Tabs).
     pub fn width(mut self, width: Length) -> Self {
         self.width = width;
@@ -157,6 +218,42 @@ where
         self
     }
 
+    /// Enables the horizontal scrolling of the tabs of the
+    /// [`TabBar`](super::tab_bar::TabBar) if they overflow the available width.
+    pub fn tab_bar_scrollable(mut self, scrollable: bool) -> Self {
+        self.tab_bar = self.tab_bar.scrollable(scrollable);
+        self
+    }
+
+    /// Sets the [`Overflow`](super::tab_bar::Overflow) handling the tabs that
+    /// do not fit into the width of the [`TabBar`](super::tab_bar::TabBar).
+    ///
+    /// The menu of [`Overflow::Menu`](super::tab_bar::Overflow::Menu) needs a
+    /// [`State`](super::tab_bar::State) set by
+    /// [`tab_bar_state`](Tabs::tab_bar_state) or
+    /// [`on_reorder`](Tabs::on_reorder).
+    pub fn tab_bar_overflow(mut self, overflow: tab_bar::Overflow) -> Self {
+        self.tab_bar = self.tab_bar.overflow(overflow);
+        self
+    }
+
+    /// Sets the [`State`](super::tab_bar::State) of the
+    /// [`TabBar`](super::tab_bar::TabBar) of the [`Tabs`](Tabs).
+    pub fn tab_bar_state(mut self, state: &'a mut tab_bar::State) -> Self {
+        self.tab_bar = self.tab_bar.state(state);
+        self
+    }
+
+    /// Enables selecting the tabs of the [`Tabs`](Tabs) by keyboard.
+    ///
+    /// Ctrl+Tab and Ctrl+Shift+Tab cycle through the tabs, wrapping around at
+    /// both ends. The arrow keys select the neighboring tabs while the cursor
+    /// is over the [`TabBar`](super::tab_bar::TabBar).
+    pub fn keyboard_navigation(mut self, keyboard_navigation: bool) -> Self {
+        self.tab_bar = self.tab_bar.keyboard_navigation(keyboard_navigation);
+        self
+    }
+
     /// Sets the icon size of the [`TabLabel`](super::tab_bar::TabLabel) of the
     /// [`TabBar`](super::tab_bar::TabBar).
     pub fn icon_size(mut self, icon_size: u16) -> Self {
@@ -217,6 +314,17 @@ where
         self
     }
 
+    /// Sets the style of the badges of the
+    /// [`TabLabel`](super::tab_bar::TabLabel)s of the
+    /// [`TabBar`](super::tab_bar::TabBar).
+    pub fn tab_badge_style<T>(mut self, style: T) -> Self
+    where
+        T: Into<<Renderer as crate::native::tab_bar::Renderer>::BadgeStyle>,
+    {
+        self.tab_bar = self.tab_bar.badge_style(style);
+        self
+    }
+
     /// Sets the [`TabBarPosition`](TabBarPosition) of the
     /// [`TabBar`](super::tab_bar::TabBar).
     pub fn tab_bar_position(mut self, position: TabBarPosition) -> Self {
@@ -234,6 +342,16 @@ where
         self.tabs.push(element.into());
         self
     }
+
+    /// Sets whether the most recently pushed tab of the [`Tabs`](Tabs) can be
+    /// closed.
+    ///
+    /// All tabs are closable by default. The close icon is only drawn if an
+    /// [`on_close`](Tabs::on_close) function is set.
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.tab_bar = self.tab_bar.closable(closable);
+        self
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer> for Tabs<'a, Message, Renderer>
@@ -423,20 +541,26 @@ where
         &mut self,
         layout: Layout<'_>,
     ) -> Option<iced_native::overlay::Element<'_, Message, Renderer>> {
-        let layout = match self.tab_bar_position {
-            TabBarPosition::Top => layout
-                .children()
-                .nth(1)
-                .expect("Native: Layout should have a tab content layout at top position"),
-            TabBarPosition::Bottom => layout
-                .children()
-                .next()
-                .expect("Native: Layout should have a tab content layout at bottom position"),
+        let mut children = layout.children();
+        let (tab_bar_layout, layout) = match self.tab_bar_position {
+            TabBarPosition::Top => (children.next(), children.next()),
+            TabBarPosition::Bottom => {
+                let tab_content_layout = children.next();
+                (children.next(), tab_content_layout)
+            }
         };
-        self.tabs
-            .get_mut(self.tab_bar.get_active_tab())
-            .expect("Native: self.tab_bar.get_active_tab() should never return a value greater than self.tabs.len()")
-            .overlay(layout)
+        let tab_bar_layout = tab_bar_layout.expect("Native: Layout should have a tab bar layout");
+        let layout = layout.expect("Native: Layout should have a tab content layout");
+
+        let active_tab = self.tab_bar.get_active_tab();
+        let Self { tab_bar, tabs, .. } = self;
+
+        // The menu of the collapsed tabs is shown over the content.
+        tab_bar.overlay(tab_bar_layout).or_else(move || {
+            tabs.get_mut(active_tab)
+                .expect("Native: self.tab_bar.get_active_tab() should never return a value greater than self.tabs.len()")
+                .overlay(layout)
+        })
     }
 }
 
@@ -478,3 +602,139 @@ where
         Element::new(tabs)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use iced_native::{
+        clipboard, keyboard, layout::Limits, renderer::Null, Event, Layout, Point, Size, Text,
+        Widget,
+    };
+
+    use std::cell::RefCell;
+
+    use super::Tabs;
+    use crate::native::TabLabel;
+
+    fn tabs(active_tab: usize) -> Tabs<'static, usize, Null> {
+        Tabs::new(active_tab, |index| index)
+            .push(TabLabel::Text(String::from("One")), Text::new("One"))
+            .push(TabLabel::Text(String::from("Two")), Text::new("Two"))
+            .push(TabLabel::Text(String::from("Three")), Text::new("Three"))
+            .keyboard_navigation(true)
+    }
+
+    fn press(
+        tabs: &mut Tabs<'_, usize, Null>,
+        key_code: keyboard::KeyCode,
+        control: bool,
+        shift: bool,
+    ) -> Vec<usize> {
+        let renderer = Null::new();
+        let node = Widget::layout(
+            tabs,
+            &renderer,
+            &Limits::new(Size::ZERO, Size::new(300.0, 200.0)),
+        );
+        let mut messages = Vec::new();
+
+        let _ = tabs.on_event(
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers: keyboard::Modifiers {
+                    shift,
+                    control,
+                    ..keyboard::Modifiers::default()
+                },
+            }),
+            Layout::new(&node),
+            Point::new(-1.0, -1.0),
+            &renderer,
+            &mut clipboard::Null,
+            &mut messages,
+        );
+
+        messages
+    }
+
+    #[test]
+    fn ctrl_tab_cycles_forward() {
+        assert_eq!(
+            press(&mut tabs(0), keyboard::KeyCode::Tab, true, false),
+            vec![1]
+        );
+        assert_eq!(
+            press(&mut tabs(1), keyboard::KeyCode::Tab, true, false),
+            vec![2]
+        );
+        assert_eq!(
+            press(&mut tabs(2), keyboard::KeyCode::Tab, true, false),
+            vec![0]
+        );
+    }
+
+    #[test]
+    fn ctrl_shift_tab_cycles_backward() {
+        assert_eq!(
+            press(&mut tabs(2), keyboard::KeyCode::Tab, true, true),
+            vec![1]
+        );
+        assert_eq!(
+            press(&mut tabs(0), keyboard::KeyCode::Tab, true, true),
+            vec![2]
+        );
+    }
+
+    #[test]
+    fn keyboard_navigation_is_opt_in() {
+        let mut tabs = tabs(0).keyboard_navigation(false);
+        assert!(press(&mut tabs, keyboard::KeyCode::Tab, true, false).is_empty());
+    }
+
+    #[test]
+    fn lazy_content_is_only_created_for_active_tab() {
+        let created = RefCell::new(Vec::new());
+        let labels = ["One", "Two", "Three"]
+            .iter()
+            .map(|label| TabLabel::Text(String::from(*label)))
+            .collect();
+
+        let mut tabs = Tabs::<usize, Null>::lazy(
+            1,
+            labels,
+            |index| {
+                created.borrow_mut().push(index);
+                Text::new("Content").into()
+            },
+            |index| index,
+        )
+        .keyboard_navigation(true);
+
+        assert_eq!(*created.borrow(), vec![1]);
+        assert_eq!(
+            press(&mut tabs, keyboard::KeyCode::Tab, true, false),
+            vec![2]
+        );
+    }
+
+    #[test]
+    fn lazy_content_skips_missing_active_tab() {
+        let created = RefCell::new(Vec::new());
+        let tabs = Tabs::<usize, Null>::lazy(
+            3,
+            vec![TabLabel::Text(String::from("One"))],
+            |index| {
+                created.borrow_mut().push(index);
+                Text::new("Content").into()
+            },
+            |index| index,
+        );
+
+        assert!(created.borrow().is_empty());
+        assert_eq!(tabs.tabs.len(), 1);
+    }
+
+    #[test]
+    fn tab_without_ctrl_is_ignored() {
+        assert!(press(&mut tabs(0), keyboard::KeyCode::Tab, false, false).is_empty());
+    }
+}
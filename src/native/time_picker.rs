@@ -12,9 +12,10 @@ use iced_native::{
 pub use super::overlay::time_picker::Renderer;
 use super::{
     icon_text,
-    overlay::time_picker::{self, Focus, TimePickerOverlay},
+    overlay::time_picker::{self, Dial, Focus, TimePickerOverlay},
 };
 
+use crate::core::overlay::ChangeThrottle;
 pub use crate::core::time::{Period, Time};
 /// An input element for picking times.
 ///
@@ -57,6 +58,8 @@ where
     on_cancel: Message,
     /// The function that produces a message when the submit button of the [`TimePickerOverlay`](TimePickerOverlay) is pressed.
     on_submit: Box<dyn Fn(Time) -> Message>,
+    /// The optional function that produces a message when the time of the [`TimePickerOverlay`](TimePickerOverlay) changes.
+    on_change: Option<Box<dyn Fn(Time) -> Message>>,
     /// The style of the [`TimePickerOverlay`](TimePickerOverlay).
     style: <Renderer as time_picker::Renderer>::Style,
 }
@@ -86,12 +89,27 @@ where
             underlay: underlay.into(),
             on_cancel,
             on_submit: Box::new(on_submit),
+            on_change: None,
             //use_24h: false,
             //show_seconds: false,
             style: <Renderer as time_picker::Renderer>::Style::default(),
         }
     }
 
+    /// Sets the function that produces a message when the user changes the
+    /// time of the [`TimePicker`](TimePicker) before submitting it, for
+    /// example for a live preview.
+    ///
+    /// Changes while dragging the clock are sent at most once per frame. The
+    /// submit and cancel messages are sent as before.
+    pub fn on_change<F>(mut self, on_change: F) -> Self
+    where
+        F: 'static + Fn(Time) -> Message,
+    {
+        self.on_change = Some(Box::new(on_change));
+        self
+    }
+
     /// Use 24 hour format instead of AM/PM.
     pub fn use_24h(mut self) -> Self {
         self.state.overlay_state.use_24h = true;
@@ -104,6 +122,25 @@ where
         self
     }
 
+    /// Constrains the picked minutes to multiples of the given step, e.g. 5 or 15.
+    ///
+    /// The default is 1.
+    pub fn minute_step(self, step: u8) -> Self {
+        self.state.overlay_state.minute_step = step;
+        self
+    }
+
+    /// Picks the time on a dial showing one hand at a time, like the time
+    /// pickers of mobile platforms, instead of showing all hands at once.
+    ///
+    /// Dragging anywhere on the dial sets the shown hand. Releasing the hour
+    /// switches to the minutes, and clicking a digital element shows its hand.
+    /// With the 24 hour format the hours 0 and 13 to 23 are on an inner ring.
+    pub fn dial(self) -> Self {
+        let _ = self.state.overlay_state.dial.get_or_insert(Dial::Hour);
+        self
+    }
+
     /// Sets the style of the [`TimePicker`](TimePicker).
     pub fn style<S>(mut self, style: S) -> Self
     where
@@ -115,6 +152,13 @@ where
 }
 
 /// The state of the [`TimePicker`](TimePicker) / [`TimePickerOverlay`](TimePickerOverlay).
+///
+/// The visibility of the [`TimePickerOverlay`](TimePickerOverlay) is fully controlled by
+/// the application through [`show`](State::show). The picker never opens or
+/// closes itself: pressing the underlay, cancel or submit only sends the
+/// messages of the application, which decides whether to change the
+/// visibility. This way the overlay can also be opened from anywhere else,
+/// like a menu entry, or stay open until a save has finished.
 #[derive(Debug)]
 pub struct State {
     /// The visibility of the overlay.
@@ -140,17 +184,33 @@ impl State {
     }
 
     /// Sets the visibility of the [`TimePickerOverlay`](TimePickerOverlay).
+    ///
+    /// Showing the overlay also gives it the keyboard focus and starts the
+    /// dial with the hours.
     pub fn show(&mut self, b: bool) {
         self.overlay_state.focus = if b { Focus::Overlay } else { Focus::None };
+        self.overlay_state.change_throttle = ChangeThrottle::default();
+        if b && self.overlay_state.dial.is_some() {
+            self.overlay_state.dial = Some(Dial::Hour);
+            self.overlay_state.clock_cache.clear();
+        }
         self.show = b;
     }
 
+    /// Returns true if the [`TimePickerOverlay`](TimePickerOverlay) is shown.
+    #[must_use]
+    pub const fn is_shown(&self) -> bool {
+        self.show
+    }
+
     /// Resets the time of the state to the current time.
     pub fn reset(&mut self) {
         self.overlay_state.clock_cache.clear();
         self.overlay_state.time = Local::now().naive_local().time();
         self.overlay_state.use_24h = false;
         self.overlay_state.show_seconds = false;
+        self.overlay_state.minute_step = 1;
+        self.overlay_state.dial = None;
     }
 }
 
@@ -238,6 +298,7 @@ where
                 position,
                 &self.style,
             )
+            .on_change(self.on_change.as_deref())
             .overlay(),
         )
     }
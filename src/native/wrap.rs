@@ -18,6 +18,8 @@ pub struct Wrap<'a, Message, Renderer, Direction> {
     pub elements: Vec<Element<'a, Message, Renderer>>,
     /// The alignment of the [`Wrap`](Wrap).
     pub alignment: Align,
+    /// The distribution of the space left on each line of the [`Wrap`](Wrap).
+    pub justify: Justify,
     /// The width of the [`Wrap`](Wrap).
     pub width: Length,
     /// The height of the [`Wrap`](Wrap).
@@ -56,6 +58,29 @@ impl<'a, Message, Renderer> Wrap<'a, Message, Renderer, direction::Horizontal> {
             ..Wrap::default()
         }
     }
+
+    /// Turns this [`Wrap`](Wrap) into a vertical [`Wrap`](Wrap) keeping its
+    /// elements and settings.
+    ///
+    /// The elements flow from top to bottom and wrap into a new column when
+    /// they exceed the available height.
+    #[must_use]
+    pub fn vertical(self) -> Wrap<'a, Message, Renderer, direction::Vertical> {
+        Wrap {
+            elements: self.elements,
+            alignment: self.alignment,
+            justify: self.justify,
+            width: self.width,
+            height: self.height,
+            max_width: self.max_width,
+            max_height: self.max_height,
+            padding: self.padding,
+            spacing: self.spacing,
+            line_spacing: self.line_spacing,
+            line_minimal_length: self.line_minimal_length,
+            _direction: PhantomData,
+        }
+    }
 }
 
 impl<'a, Message, Renderer> Wrap<'a, Message, Renderer, direction::Vertical> {
@@ -79,14 +104,14 @@ impl<'a, Message, Renderer> Wrap<'a, Message, Renderer, direction::Vertical> {
 }
 
 impl<'a, Message, Renderer, Direction> Wrap<'a, Message, Renderer, Direction> {
-    /// Sets the spacing of the [`Wrap`](Wrap).
+    /// Sets the spacing between the elements on a line of the [`Wrap`](Wrap).
     #[must_use]
     pub const fn spacing(mut self, units: u16) -> Self {
         self.spacing = units;
         self
     }
 
-    /// Sets the spacing of the lines of the [`Wrap`](Wrap).
+    /// Sets the spacing between the lines of the [`Wrap`](Wrap).
     #[must_use]
     pub const fn line_spacing(mut self, units: u16) -> Self {
         self.line_spacing = units;
@@ -135,13 +160,25 @@ impl<'a, Message, Renderer, Direction> Wrap<'a, Message, Renderer, Direction> {
         self
     }
 
-    /// Sets the alignment of the [`Wrap`](Wrap).
+    /// Sets the alignment of the elements of the [`Wrap`](Wrap) in the
+    /// cross axis of their line.
     #[must_use]
     pub const fn align_items(mut self, align: Align) -> Self {
         self.alignment = align;
         self
     }
 
+    /// Sets how the space left on each line of the [`Wrap`](Wrap) is
+    /// distributed between its elements.
+    ///
+    /// The lines take all the available space of the main axis unless the
+    /// [`Justify`](Justify) is [`Start`](Justify::Start).
+    #[must_use]
+    pub const fn justify_content(mut self, justify: Justify) -> Self {
+        self.justify = justify;
+        self
+    }
+
     /// Pushes an [`Element`](iced_native::Element) to the [`Wrap`](Wrap).
     pub fn push<E>(mut self, element: E) -> Self
     where
@@ -150,6 +187,180 @@ impl<'a, Message, Renderer, Direction> Wrap<'a, Message, Renderer, Direction> {
         self.elements.push(element.into());
         self
     }
+
+    /// Lays out the elements of the [`Wrap`](Wrap) in lines along the given
+    /// main [`Axis`](Axis), wrapping into a new line when the next element
+    /// exceeds the available space.
+    fn layout_along(&self, renderer: &Renderer, limits: &Limits, axis: Axis) -> Node
+    where
+        Renderer: iced_native::Renderer,
+    {
+        let padding = f32::from(self.padding);
+        let spacing = f32::from(self.spacing);
+        let line_spacing = f32::from(self.line_spacing);
+        #[allow(clippy::cast_precision_loss)] // TODO: possible precision loss
+        let line_minimal_length = self.line_minimal_length as f32;
+        let limits = limits
+            .pad(padding)
+            .width(self.width)
+            .height(self.height)
+            .max_width(self.max_width)
+            .max_height(self.max_height);
+        let max_main = axis.main(limits.max());
+        let node_limit = Limits::new(
+            axis.size(axis.main(limits.min()), line_minimal_length),
+            limits.max(),
+        );
+
+        let mut curse = padding;
+        let mut deep_curse = padding;
+        let mut current_line_length = line_minimal_length;
+        let mut main_length = curse;
+        let mut align = vec![];
+        let mut start = 0;
+        let mut end = 0;
+        let mut nodes: Vec<Node> = self
+            .elements
+            .iter()
+            .map(|elem| {
+                let mut node = elem.layout(renderer, &node_limit);
+
+                let size = node.size();
+
+                // the spacing only separates elements on the same line, so it
+                // is not needed behind the last element of a line
+                if end != start && curse + axis.main(size) > max_main + padding {
+                    deep_curse += current_line_length + line_spacing;
+                    align.push((start..end, current_line_length, curse - spacing - padding));
+                    start = end;
+                    current_line_length = line_minimal_length;
+                    curse = padding;
+                }
+                node.move_to(axis.point(curse, deep_curse));
+                end += 1;
+                main_length = main_length.max(curse + axis.main(size));
+                curse += axis.main(size) + spacing;
+                current_line_length = current_line_length.max(axis.cross(size));
+
+                node
+            })
+            .collect();
+        if end != start {
+            align.push((start..end, current_line_length, curse - spacing - padding));
+        }
+        let justify = if max_main.is_finite() {
+            self.justify
+        } else {
+            Justify::Start
+        };
+        if justify != Justify::Start {
+            main_length = main_length.max(max_main + padding);
+        }
+        let (horizontal, vertical) = match axis {
+            Axis::Horizontal => (Align::Start, self.alignment),
+            Axis::Vertical => (self.alignment, Align::Start),
+        };
+        align
+            .into_iter()
+            .for_each(|(range, max_length, used_length)| {
+                let count = range.len();
+                nodes[range]
+                    .iter_mut()
+                    .enumerate()
+                    .for_each(|(index, node)| {
+                        let space = axis.size(axis.main(node.size()), max_length);
+                        node.align(horizontal, vertical, space);
+
+                        let offset = justify.offset(max_main - used_length, index, count);
+                        let position = node.bounds().position();
+                        let shift = axis.point(offset, 0.0);
+                        node.move_to(Point::new(position.x + shift.x, position.y + shift.y));
+                    });
+            });
+
+        let size = limits.resolve(axis.size(
+            main_length - padding,
+            deep_curse - padding + current_line_length,
+        ));
+
+        Node::with_children(size.pad(padding), nodes)
+    }
+}
+
+/// The distribution of the space left on each line of a [`Wrap`](Wrap).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Justify {
+    /// Packs the elements at the start of the line.
+    Start,
+    /// Puts the space between the elements, but not before the first or
+    /// after the last element.
+    SpaceBetween,
+    /// Puts the same space around each element, so the space before the
+    /// first and after the last element is half the space between two
+    /// elements.
+    SpaceAround,
+    /// Packs the elements at the center of the line.
+    Center,
+}
+
+impl Justify {
+    /// Calculates the offset of the element at the given index of a line of
+    /// `count` elements with the given space left.
+    fn offset(self, left: f32, index: usize, count: usize) -> f32 {
+        #[allow(clippy::cast_precision_loss)]
+        let (index, count) = (index as f32, count as f32);
+
+        match self {
+            Self::SpaceBetween if count > 1.0 => left / (count - 1.0) * index,
+            Self::Start | Self::SpaceBetween => 0.0,
+            Self::SpaceAround => left / count * (index + 0.5),
+            Self::Center => left / 2.0,
+        }
+    }
+}
+
+/// The axis along which a [`Wrap`](Wrap) lays out the elements of a line.
+#[derive(Clone, Copy, Debug)]
+enum Axis {
+    /// Lines run from left to right and wrap downwards.
+    Horizontal,
+    /// Lines run from top to bottom and wrap to the right.
+    Vertical,
+}
+
+impl Axis {
+    /// Gets the length of the size along the main axis.
+    const fn main(self, size: Size) -> f32 {
+        match self {
+            Self::Horizontal => size.width,
+            Self::Vertical => size.height,
+        }
+    }
+
+    /// Gets the length of the size along the cross axis.
+    const fn cross(self, size: Size) -> f32 {
+        match self {
+            Self::Horizontal => size.height,
+            Self::Vertical => size.width,
+        }
+    }
+
+    /// Creates a size from its lengths along the main and the cross axis.
+    const fn size(self, main: f32, cross: f32) -> Size {
+        match self {
+            Self::Horizontal => Size::new(main, cross),
+            Self::Vertical => Size::new(cross, main),
+        }
+    }
+
+    /// Creates a point from its coordinates along the main and the cross
+    /// axis.
+    const fn point(self, main: f32, cross: f32) -> Point {
+        match self {
+            Self::Horizontal => Point::new(main, cross),
+            Self::Vertical => Point::new(cross, main),
+        }
+    }
 }
 
 impl<'a, Message, Renderer, Direction> Widget<Message, Renderer>
@@ -177,6 +388,7 @@ where
         std::any::TypeId::of::<Marker>().hash(state);
 
         self.alignment.hash(state);
+        self.justify.hash(state);
         self.line_minimal_length.hash(state);
         self.width.hash(state);
         self.height.hash(state);
@@ -265,6 +477,7 @@ impl<'a, Message, Renderer, Direction> Default for Wrap<'a, Message, Renderer, D
         Self {
             elements: vec![],
             alignment: Align::Start,
+            justify: Justify::Start,
             width: Length::Shrink,
             height: Length::Shrink,
             max_width: u32::MAX,
@@ -273,7 +486,7 @@ impl<'a, Message, Renderer, Direction> Default for Wrap<'a, Message, Renderer, D
             spacing: 0,
             line_spacing: 0,
             line_minimal_length: 10,
-            _direction: PhantomData::default(),
+            _direction: PhantomData,
         }
     }
 }
@@ -303,77 +516,7 @@ where
     #[allow(clippy::inline_always)]
     #[inline(always)]
     fn inner_layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
-        let padding = f32::from(self.padding);
-        let spacing = f32::from(self.spacing);
-        let line_spacing = f32::from(self.line_spacing);
-        #[allow(clippy::cast_precision_loss)] // TODO: possible precision loss
-        let line_minimal_length = self.line_minimal_length as f32;
-        let limits = limits
-            .pad(padding)
-            .width(self.width)
-            .height(self.height)
-            .max_width(self.max_width)
-            .max_height(self.max_height);
-        let max_width = limits.max().width;
-
-        let mut curse = padding;
-        let mut deep_curse = padding;
-        let mut current_line_height = line_minimal_length;
-        let mut max_main = curse;
-        let mut align = vec![];
-        let mut start = 0;
-        let mut end = 0;
-        let mut nodes: Vec<Node> = self
-            .elements
-            .iter()
-            .map(|elem| {
-                let node_limit = Limits::new(
-                    Size::new(limits.min().width, line_minimal_length),
-                    limits.max(),
-                );
-                let mut node = elem.layout(renderer, &node_limit);
-
-                let size = node.size();
-
-                let offset_init = size.width + spacing;
-                let offset = curse + offset_init;
-
-                if offset > max_width {
-                    deep_curse += current_line_height + line_spacing;
-                    align.push((start..end, current_line_height));
-                    start = end;
-                    end += 1;
-                    current_line_height = line_minimal_length;
-                    node.move_to(Point::new(padding, deep_curse));
-                    curse = offset_init + padding;
-                } else {
-                    node.move_to(Point::new(curse, deep_curse));
-                    curse = offset;
-                    end += 1;
-                }
-                current_line_height = current_line_height.max(size.height);
-                max_main = max_main.max(curse);
-
-                node
-            })
-            .collect();
-        if end != start {
-            align.push((start..end, current_line_height));
-        }
-        align.into_iter().for_each(|(range, max_length)| {
-            nodes[range].iter_mut().for_each(|node| {
-                let size = node.size();
-                let space = Size::new(size.width, max_length);
-                node.align(Align::Start, self.alignment, space);
-            });
-        });
-        let (width, height) = (
-            max_main - padding,
-            deep_curse - padding + current_line_height,
-        );
-        let size = limits.resolve(Size::new(width, height));
-
-        Node::with_children(size.pad(padding), nodes)
+        self.layout_along(renderer, limits, Axis::Horizontal)
     }
 
     #[allow(clippy::inline_always)]
@@ -397,78 +540,7 @@ where
     #[allow(clippy::inline_always)]
     #[inline(always)]
     fn inner_layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
-        let padding = f32::from(self.padding);
-        let spacing = f32::from(self.spacing);
-        let line_spacing = f32::from(self.line_spacing);
-        #[allow(clippy::cast_precision_loss)] // TODO: possible precision loss
-        let line_minimal_length = self.line_minimal_length as f32;
-        let limits = limits
-            .pad(padding)
-            .width(self.width)
-            .height(self.height)
-            .max_width(self.max_width)
-            .max_height(self.max_height);
-        let max_height = limits.max().height;
-
-        let mut curse = padding;
-        let mut wide_curse = padding;
-        let mut current_line_width = line_minimal_length;
-        let mut max_main = curse;
-        let mut align = vec![];
-        let mut start = 0;
-        let mut end = 0;
-        let mut nodes: Vec<Node> = self
-            .elements
-            .iter()
-            .map(|elem| {
-                let node_limit = Limits::new(
-                    Size::new(line_minimal_length, limits.min().height),
-                    limits.max(),
-                );
-                let mut node = elem.layout(renderer, &node_limit);
-
-                let size = node.size();
-
-                let offset_init = size.height + spacing;
-                let offset = curse + offset_init;
-
-                if offset > max_height {
-                    wide_curse += current_line_width + line_spacing;
-                    align.push((start..end, current_line_width));
-                    start = end;
-                    end += 1;
-                    current_line_width = line_minimal_length;
-                    node.move_to(Point::new(wide_curse, padding));
-                    curse = offset_init + padding;
-                } else {
-                    node.move_to(Point::new(wide_curse, curse));
-                    end += 1;
-                    curse = offset;
-                }
-                current_line_width = current_line_width.max(size.width);
-                max_main = max_main.max(curse);
-
-                node
-            })
-            .collect();
-        if end != start {
-            align.push((start..end, current_line_width));
-        }
-        align.into_iter().for_each(|(range, max_length)| {
-            nodes[range].iter_mut().for_each(|node| {
-                let size = node.size();
-                let space = Size::new(max_length, size.height);
-                node.align(self.alignment, Align::Start, space);
-            });
-        });
-
-        let (width, height) = (
-            wide_curse - padding + current_line_width,
-            max_main - padding,
-        );
-        let size = limits.resolve(Size::new(width, height));
-
-        Node::with_children(size.pad(padding), nodes)
+        self.layout_along(renderer, limits, Axis::Vertical)
     }
 
     #[allow(clippy::inline_always)]
@@ -494,3 +566,153 @@ pub mod direction {
     #[derive(Debug)]
     pub struct Horizontal;
 }
+
+#[cfg(test)]
+mod tests {
+    use iced_native::{layout::Limits, renderer::Null, Layout, Length, Point, Size, Text, Widget};
+
+    use super::Wrap;
+
+    fn positions(wrap: &Wrap<'_, (), Null, super::direction::Horizontal>) -> Vec<Point> {
+        let node = wrap.layout(
+            &Null::new(),
+            &Limits::new(Size::ZERO, Size::new(100.0, 100.0)),
+        );
+
+        Layout::new(&node)
+            .children()
+            .map(|child| child.position())
+            .collect()
+    }
+
+    fn wrap<'a>(spacing: u16) -> Wrap<'a, (), Null, super::direction::Horizontal> {
+        (0..4).fold(Wrap::new().spacing(spacing), |wrap, _| {
+            wrap.push(
+                Text::new("")
+                    .width(Length::Units(30))
+                    .height(Length::Units(10)),
+            )
+        })
+    }
+
+    #[test]
+    fn without_spacing() {
+        assert_eq!(
+            positions(&wrap(0)),
+            vec![
+                Point::new(0.0, 0.0),
+                Point::new(30.0, 0.0),
+                Point::new(60.0, 0.0),
+                Point::new(0.0, 10.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn with_spacing() {
+        assert_eq!(
+            positions(&wrap(10)),
+            vec![
+                Point::new(0.0, 0.0),
+                Point::new(40.0, 0.0),
+                Point::new(0.0, 10.0),
+                Point::new(40.0, 10.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn spacing_behind_last_element() {
+        // 3 * 30 + 2 * 5 fits exactly without a spacing behind the last element
+        assert_eq!(positions(&wrap(5))[2], Point::new(70.0, 0.0));
+    }
+
+    #[test]
+    fn with_line_spacing() {
+        assert_eq!(
+            positions(&wrap(10).line_spacing(5))[2],
+            Point::new(0.0, 15.0)
+        );
+    }
+
+    #[test]
+    fn vertical() {
+        let wrap = wrap(10).vertical();
+        let node = wrap.layout(
+            &Null::new(),
+            &Limits::new(Size::ZERO, Size::new(100.0, 100.0)),
+        );
+
+        // 30 wide and 10 high elements, so all fit into the first column
+        assert_eq!(
+            Layout::new(&node)
+                .children()
+                .map(|child| child.position())
+                .collect::<Vec<_>>(),
+            vec![
+                Point::new(0.0, 0.0),
+                Point::new(0.0, 20.0),
+                Point::new(0.0, 40.0),
+                Point::new(0.0, 60.0),
+            ]
+        );
+
+        let node = wrap.layout(
+            &Null::new(),
+            &Limits::new(Size::ZERO, Size::new(100.0, 45.0)),
+        );
+
+        assert_eq!(
+            Layout::new(&node)
+                .children()
+                .map(|child| child.position())
+                .collect::<Vec<_>>(),
+            vec![
+                Point::new(0.0, 0.0),
+                Point::new(0.0, 20.0),
+                Point::new(30.0, 0.0),
+                Point::new(30.0, 20.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn justify_space_between() {
+        let wrap = (0..3).fold(
+            Wrap::<(), Null, _>::new().justify_content(super::Justify::SpaceBetween),
+            |wrap, _| {
+                wrap.push(
+                    Text::new("")
+                        .width(Length::Units(30))
+                        .height(Length::Units(10)),
+                )
+            },
+        );
+        let node = wrap.layout(
+            &Null::new(),
+            &Limits::new(Size::ZERO, Size::new(180.0, 100.0)),
+        );
+
+        // 90 of 180 are left, so the gaps are 45 wide
+        assert_eq!(
+            Layout::new(&node)
+                .children()
+                .map(|child| child.position().x)
+                .collect::<Vec<_>>(),
+            vec![0.0, 75.0, 150.0]
+        );
+        assert!((node.size().width - 180.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn justify_offsets() {
+        use super::Justify;
+
+        assert!((Justify::Start.offset(90.0, 1, 3)).abs() < f32::EPSILON);
+        assert!((Justify::Center.offset(90.0, 1, 3) - 45.0).abs() < f32::EPSILON);
+        assert!((Justify::SpaceBetween.offset(90.0, 2, 3) - 90.0).abs() < f32::EPSILON);
+        assert!((Justify::SpaceBetween.offset(90.0, 0, 1)).abs() < f32::EPSILON);
+        assert!((Justify::SpaceAround.offset(90.0, 0, 3) - 15.0).abs() < f32::EPSILON);
+        assert!((Justify::SpaceAround.offset(90.0, 2, 3) - 75.0).abs() < f32::EPSILON);
+    }
+}
@@ -47,6 +47,13 @@ pub struct Style {
 pub trait StyleSheet {
     /// The normal appearance of a [`Card`](crate::native::card::Card).
     fn active(&self) -> Style;
+
+    /// The appearance when the [`Card`](crate::native::card::Card) is hovered.
+    ///
+    /// It defaults to the [`active`](StyleSheet::active) appearance.
+    fn hovered(&self) -> Style {
+        self.active()
+    }
 }
 
 /// The default appearance of a [`Card`](crate::native::card::Card).
@@ -6,6 +6,8 @@ use iced_native::{Background, Color};
 #[cfg(target_arch = "wasm32")]
 use iced_web::{Background, Color};
 
+use super::focus_ring::FocusRing;
+
 /// The appearance of a [`ColorPicker`](crate::native::ColorPicker).
 #[derive(Clone, Copy, Debug)]
 pub struct Style {
@@ -47,6 +49,12 @@ pub trait StyleSheet {
     /// The appearance when something is focused of the
     /// [`ColorPicker`](crate::native::ColorPicker).
     fn focused(&self) -> Style;
+
+    /// The focus ring drawn around the buttons of the
+    /// [`ColorPicker`](crate::native::ColorPicker) while they have the keyboard focus.
+    fn focus_ring(&self) -> Option<FocusRing> {
+        Some(FocusRing::default())
+    }
 }
 
 /// The default appearance of the [`ColorPicker`](crate::native::ColorPicker).
@@ -6,6 +6,8 @@ use iced_native::{Background, Color};
 #[cfg(target_arch = "wasm32")]
 use iced_web::{Background, Color};
 
+use super::focus_ring::FocusRing;
+
 /// The appearance of a [`DatePicker`](crate::native::DatePicker).
 #[derive(Clone, Copy, Debug)]
 pub struct Style {
@@ -49,6 +51,12 @@ pub trait StyleSheet {
     /// The appearance when something is focused of the
     /// [`DatePicker`](crate::native::DatePicker).
     fn focused(&self) -> Style;
+
+    /// The focus ring drawn around the buttons of the
+    /// [`DatePicker`](crate::native::DatePicker) while they have the keyboard focus.
+    fn focus_ring(&self) -> Option<FocusRing> {
+        Some(FocusRing::default())
+    }
 }
 
 /// The default appearance of the [`DatePicker`](crate::native::DatePicker).
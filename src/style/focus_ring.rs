@@ -0,0 +1,81 @@
+//! The focus ring drawn around widgets with the keyboard focus.
+#[cfg(not(target_arch = "wasm32"))]
+use iced_native::{Color, Rectangle};
+#[cfg(target_arch = "wasm32")]
+use iced_web::Color;
+
+/// The outline drawn around a widget or a part of it while it has the
+/// keyboard focus.
+///
+/// Each focusable widget takes its [`FocusRing`](FocusRing) from its style
+/// sheet, so all widgets share the same look unless a style overrides it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FocusRing {
+    /// The color of the ring.
+    pub color: Color,
+    /// The width of the ring.
+    pub width: f32,
+    /// The gap between the ring and the focused bounds.
+    pub offset: f32,
+    /// The border radius of the ring.
+    pub border_radius: f32,
+}
+
+impl std::default::Default for FocusRing {
+    fn default() -> Self {
+        Self {
+            color: [0.2, 0.5, 1.0].into(),
+            width: 2.0,
+            offset: 1.0,
+            border_radius: 3.0,
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl FocusRing {
+    /// Gets the outer bounds of the ring drawn around the given focused
+    /// bounds.
+    #[must_use]
+    pub fn bounds(&self, focused: Rectangle) -> Rectangle {
+        let spread = self.offset + self.width;
+
+        Rectangle {
+            x: focused.x - spread,
+            y: focused.y - spread,
+            width: focused.width + 2.0 * spread,
+            height: focused.height + 2.0 * spread,
+        }
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use iced_native::Rectangle;
+
+    use super::FocusRing;
+
+    #[test]
+    fn bounds_surround_focused_bounds() {
+        let ring = FocusRing {
+            width: 2.0,
+            offset: 1.0,
+            ..FocusRing::default()
+        };
+
+        assert_eq!(
+            ring.bounds(Rectangle {
+                x: 10.0,
+                y: 20.0,
+                width: 30.0,
+                height: 40.0,
+            }),
+            Rectangle {
+                x: 7.0,
+                y: 17.0,
+                width: 36.0,
+                height: 46.0,
+            }
+        );
+    }
+}
@@ -2,6 +2,8 @@
 
 pub mod style_state;
 
+pub mod focus_ring;
+
 #[cfg(feature = "colors")]
 pub mod colors;
 
@@ -6,6 +6,8 @@ use iced_native::{Background, Color};
 #[cfg(target_arch = "wasm32")]
 use iced_web::{Background, Color};
 
+use super::focus_ring::FocusRing;
+
 /// The appearance of a [`NumberInput`](crate::native::number_input::NumberInput).
 #[derive(Clone, Copy, Debug)]
 pub struct Style {
@@ -50,6 +52,29 @@ pub trait StyleSheet {
             },
         }
     }
+
+    /// The background drawn over the text of a disabled
+    /// [`NumberInput`](crate::native::number_input::NumberInput) to mute it.
+    fn disabled_overlay(&self) -> Option<Background> {
+        Some(Background::Color(Color {
+            a: 0.5,
+            ..Color::WHITE
+        }))
+    }
+
+    /// The color of the border around the text of the
+    /// [`NumberInput`](crate::native::number_input::NumberInput) while the
+    /// edited text isn't a valid value.
+    fn invalid_border_color(&self) -> Color {
+        Color::from_rgb(0.8, 0.2, 0.2)
+    }
+
+    /// The focus ring drawn around the
+    /// [`NumberInput`](crate::native::number_input::NumberInput) while it has
+    /// the keyboard focus.
+    fn focus_ring(&self) -> Option<FocusRing> {
+        Some(FocusRing::default())
+    }
 }
 
 /// The default appearance of the [`NumberInput`](crate::native::number_input::NumberInput).
@@ -10,6 +10,8 @@ use iced_native::{Background, Color};
 #[cfg(target_arch = "wasm32")]
 use iced_web::{Background, Color};
 
+use super::focus_ring::FocusRing;
+
 /// The appearance of a [`TabBar`](crate::native::tab_bar::TabBar).
 #[derive(Clone, Copy, Debug)]
 pub struct Style {
@@ -38,6 +40,18 @@ pub struct Style {
     pub text_color: Color,
 }
 
+/// The appearance of the indicator below the active tab of a
+/// [`TabBar`](crate::native::tab_bar::TabBar).
+#[derive(Clone, Copy, Debug)]
+pub struct Indicator {
+    /// The color of the indicator.
+    pub color: Color,
+
+    /// The height of the indicator. An indicator with a height of zero is not
+    /// drawn.
+    pub height: f32,
+}
+
 /// The appearance of a [`TabBar`](crate::native::tab_bar::TabBar).
 pub trait StyleSheet {
     /// The normal appearance0of a tab bar and its tab labels.
@@ -49,6 +63,22 @@ pub trait StyleSheet {
     ///
     /// `is_active` is true if the tab is selected.
     fn hovered(&self, is_active: bool) -> Style;
+
+    /// The indicator drawn below the active tab label.
+    ///
+    /// The default indicator has the text color of the active tab label.
+    fn indicator(&self) -> Indicator {
+        Indicator {
+            color: self.active(true).text_color,
+            height: 2.0,
+        }
+    }
+
+    /// The focus ring drawn around the active tab label after it was selected
+    /// by keyboard.
+    fn focus_ring(&self) -> Option<FocusRing> {
+        Some(FocusRing::default())
+    }
 }
 
 /// The default appearance of a [`TabBar`](crate::native::TabBar).
@@ -6,6 +6,8 @@ use iced_native::{Background, Color};
 #[cfg(target_arch = "wasm32")]
 use iced_web::{Background, Color};
 
+use super::focus_ring::FocusRing;
+
 /// The appearance of a [`TimePicker`](crate::native::TimePicker).
 #[derive(Clone, Copy, Debug)]
 pub struct Style {
@@ -61,6 +63,12 @@ pub trait StyleSheet {
     /// The appearance when something is focused of the
     /// [`TimePicker`](crate::native::TimePicker).
     fn focused(&self) -> Style;
+
+    /// The focus ring drawn around the buttons of the
+    /// [`TimePicker`](crate::native::TimePicker) while they have the keyboard focus.
+    fn focus_ring(&self) -> Option<FocusRing> {
+        Some(FocusRing::default())
+    }
 }
 
 /// The default appearance of the [`TimePicker`](crate::native::TimePicker)
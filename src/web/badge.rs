@@ -2,8 +2,10 @@
 //!
 //! *This API requires the following crate features to be activated: badge*
 use dodrio::bumpalo;
-use iced_web::{css, Align, Background, Bus, Css, Element, Length, Widget};
+use iced_web::{css, Align, Background, Bus, Css, Element, Length, Text, Widget};
 
+use crate::core::badge::Count;
+pub use crate::core::badge::Padding;
 pub use crate::style::badge::{Style, StyleSheet};
 
 /// A badge for color highlighting small information.
@@ -17,16 +19,19 @@ pub use crate::style::badge::{Style, StyleSheet};
 /// }
 ///
 /// let badge = Badge::<Message>::new(Text::new("Text"));
+/// let unread = Badge::<Message>::count(120).max(99).hide_zero(true);
 /// ```
 #[allow(missing_debug_implementations)]
 pub struct Badge<'a, Message> {
-    padding: u16,
+    padding: Padding,
+    min_width: u16,
     width: Length,
     height: Length,
     horizontal_alignment: Align,
     vertical_alignment: Align,
     style: Box<dyn StyleSheet>,
     content: Element<'a, Message>,
+    count: Option<Count>,
 }
 
 impl<'a, Message> Badge<'a, Message> {
@@ -38,19 +43,67 @@ impl<'a, Message> Badge<'a, Message> {
         T: Into<Element<'a, Message>>,
     {
         Badge {
-            padding: 7,
+            padding: Padding::new(7),
+            min_width: 0,
             width: Length::Shrink,
             height: Length::Shrink,
             horizontal_alignment: Align::Center,
             vertical_alignment: Align::Center,
             style: Default::default(),
             content: content.into(),
+            count: None,
         }
     }
 
+    /// Creates a new [`Badge`](Badge) displaying the given number.
+    pub fn count(value: u64) -> Self {
+        let count = Count::new(value);
+        let mut badge = Self::new(Text::new(count.label()));
+        badge.count = Some(count);
+        badge
+    }
+
+    /// Sets the highest number displayed as is by a [`Badge`](Badge) created
+    /// with [`count`](Badge::count). Larger numbers are displayed as the
+    /// maximum followed by a `+`, e.g. `99+`.
+    pub fn max(self, max: u64) -> Self {
+        self.update_count(|count| count.max = Some(max))
+    }
+
+    /// Hides a [`Badge`](Badge) created with [`count`](Badge::count) if its
+    /// number is zero.
+    pub fn hide_zero(self, hide_zero: bool) -> Self {
+        self.update_count(|count| count.hide_zero = hide_zero)
+    }
+
+    /// Updates the [`Count`](Count) of the [`Badge`](Badge) and its displayed
+    /// text. Does nothing if the [`Badge`](Badge) has no [`Count`](Count).
+    fn update_count<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(&mut Count),
+    {
+        if let Some(count) = &mut self.count {
+            f(count);
+            self.content = Text::new(count.label()).into();
+        }
+        self
+    }
+
     /// Sets the padding of the [`Badge`](Badge).
-    pub fn padding(mut self, units: u16) -> Self {
-        self.padding = units;
+    ///
+    /// The padding can be given for all sides at once, as `[vertical,
+    /// horizontal]` or as `[top, right, bottom, left]`.
+    pub fn padding<P>(mut self, padding: P) -> Self
+    where
+        P: Into<Padding>,
+    {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the minimum width of the [`Badge`](Badge).
+    pub fn min_width(mut self, min_width: u16) -> Self {
+        self.min_width = min_width;
         self
     }
 
@@ -101,27 +154,20 @@ where
         // (https://github.com/hecrj/iced/blob/master/web/src/widget/button.rs#L144)
         let style = self.style.active();
 
-        let padding_class = style_sheet.insert(bump, css::Rule::Padding(self.padding));
-
         let border_color = match style.border_color {
             None => String::from("none"),
             Some(border_color) => css::color(border_color),
         };
 
-        let class = {
-            use dodrio::bumpalo::collections::String;
-
-            String::from_str_in(&padding_class, bump).into_bump_str()
-        };
-
         let node = div(bump)
-            .attr("class", class)
             .attr(
                 "style",
                 bumpalo::format!(
                     in bump,
                     "background: {}; border-radius: {}rem; width:{}; height: {} \
-                    border: {}px solid {}; display: inline-block; color: {}",
+                    border: {}px solid {}; display: {}; color: {}; \
+                    padding: {}px {}px {}px {}px; min-width: {}px; \
+                    box-sizing: border-box; text-align: center",
                     //css::color(style.background)
                     match style.background {
                         Background::Color(color) => css::color(color),
@@ -131,7 +177,17 @@ where
                     css::length(self.height),
                     style.border_width,
                     border_color,
-                    css::color(style.text_color)
+                    if self.count.as_ref().is_some_and(Count::is_hidden) {
+                        "none"
+                    } else {
+                        "inline-block"
+                    },
+                    css::color(style.text_color),
+                    self.padding.top,
+                    self.padding.right,
+                    self.padding.bottom,
+                    self.padding.left,
+                    self.min_width,
                 )
                 .into_bump_str(),
             )
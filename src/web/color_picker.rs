@@ -41,6 +41,7 @@ pub struct ColorPicker<'a, Message> {
     _underlay: Element<'a, Message>,
     _on_cancel: Message,
     on_submit: Rc<dyn Fn(Color) -> Message>,
+    on_change: Option<Rc<dyn Fn(Color) -> Message>>,
     // You cannot currently style the appearance of the color picker.
     // https://developers.google.com/web/updates/2012/08/Quick-FAQs-on-input-type-date-in-Google-Chrome
     _style: Box<dyn StyleSheet>,
@@ -70,10 +71,22 @@ impl<'a, Message> ColorPicker<'a, Message> {
             _underlay: _underlay.into(),
             _on_cancel,
             on_submit: Rc::new(on_submit),
+            on_change: None,
             _style: Default::default(),
         }
     }
 
+    /// Sets the function that produces a message when the user changes the
+    /// color of the [`ColorPicker`](ColorPicker) before submitting it, for
+    /// example to recolor a preview while dragging.
+    pub fn on_change<F>(mut self, on_change: F) -> Self
+    where
+        F: 'static + Fn(Color) -> Message,
+    {
+        self.on_change = Some(Rc::new(on_change));
+        self
+    }
+
     /// Sets the style of the [`ColorPicker`](ColorPicker).
     ///
     /// The style will be ignored on the web, since the color input can't be styled.
@@ -126,29 +139,46 @@ where
         let on_submit = self.on_submit.clone();
         let input_event_bus = bus.clone();
 
-        let color_picker = input(bump)
-            .attr("type", "color")
-            .on("change", move |_root, _vdom, event| {
-                let color_input = match event
+        let mut color_picker =
+            input(bump)
+                .attr("type", "color")
+                .on("change", move |_root, _vdom, event| {
+                    let color_input = match event
+                        .target()
+                        .and_then(|c| c.dyn_into::<web_sys::HtmlInputElement>().ok())
+                    {
+                        None => return,
+                        Some(color_input) => color_input,
+                    };
+
+                    // The value of an <input> element of type color is always a
+                    // DOMString which contains a 7-character string specifying an
+                    // RGB color in hexadecimal format.
+                    //
+                    // ... In addition, colors with an alpha channel are not supported. T.T
+                    // https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input/color#value
+                    let value = color_input.value();
+                    let color = hex_to_color(&value);
+
+                    input_event_bus.publish(on_submit(color));
+                });
+
+        if let Some(on_change) = self.on_change.clone() {
+            let input_event_bus = bus.clone();
+
+            // The input event is fired on every change while the color dialog
+            // is open, the change event only when it is closed.
+            color_picker = color_picker.on("input", move |_root, _vdom, event| {
+                if let Some(color_input) = event
                     .target()
                     .and_then(|c| c.dyn_into::<web_sys::HtmlInputElement>().ok())
                 {
-                    None => return,
-                    Some(color_input) => color_input,
-                };
-
-                // The value of an <input> element of type color is always a
-                // DOMString which contains a 7-character string specifying an
-                // RGB color in hexadecimal format.
-                //
-                // ... In addition, colors with an alpha channel are not supported. T.T
-                // https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input/color#value
-                let value = color_input.value();
-                let color = hex_to_color(&value);
-
-                input_event_bus.publish(on_submit(color));
-            })
-            .finish();
+                    input_event_bus.publish(on_change(hex_to_color(&color_input.value())));
+                }
+            });
+        }
+
+        let color_picker = color_picker.finish();
 
         let node = label(bump).children(vec![color_picker]);
 
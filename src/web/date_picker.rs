@@ -42,6 +42,8 @@ pub struct DatePicker<'a, Message> {
     _underlay: Element<'a, Message>,
     _on_cancel: Message,
     on_submit: Rc<dyn Fn(Date) -> Message>,
+    min: Option<Date>,
+    max: Option<Date>,
     // You cannot currently style the appearance of the date picker.
     // https://developers.google.com/web/updates/2012/08/Quick-FAQs-on-input-type-date-in-Google-Chrome
     _style: Box<dyn StyleSheet>,
@@ -71,10 +73,36 @@ impl<'a, Message> DatePicker<'a, Message> {
             _underlay: _underlay.into(),
             _on_cancel,
             on_submit: Rc::new(on_submit),
+            min: None,
+            max: None,
             _style: Default::default(),
         }
     }
 
+    /// Sets the minimum selectable date of the [`DatePicker`](DatePicker).
+    pub fn min(mut self, min: Date) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the maximum selectable date of the [`DatePicker`](DatePicker).
+    pub fn max(mut self, max: Date) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets the function that produces a message when the user changes the
+    /// date of the [`DatePicker`](DatePicker) before submitting it.
+    ///
+    /// The function will be ignored on the web, since the date input submits
+    /// every change.
+    pub fn on_change<F>(self, _on_change: F) -> Self
+    where
+        F: 'static + Fn(Date) -> Message,
+    {
+        self
+    }
+
     /// Sets the style of the [`DateBicker`](DatePicker).
     ///
     /// The style will be ignored on the web, since the date input can't be styled.
@@ -129,8 +157,18 @@ where
         let on_submit = self.on_submit.clone();
         let input_event_bus = bus.clone();
 
-        let date_picker = input(bump)
-            .attr("type", "date")
+        let mut date_picker = input(bump).attr("type", "date");
+
+        if let Some(min) = self.min {
+            date_picker =
+                date_picker.attr("min", bumpalo::format!(in bump, "{}", min).into_bump_str());
+        }
+        if let Some(max) = self.max {
+            date_picker =
+                date_picker.attr("max", bumpalo::format!(in bump, "{}", max).into_bump_str());
+        }
+
+        let date_picker = date_picker
             // https://www.w3schools.com/jsref/event_onchange.asp
             .on("change", move |_root, _vdom, event| {
                 let date_input = match event
@@ -49,6 +49,8 @@ pub struct TabBar<Message> {
     tab_labels: Vec<TabLabel>,
     /// The function that produces the message when a tab is selected.
     on_select: Rc<dyn Fn(usize) -> Message>,
+    /// The flags marking which tabs can be closed.
+    closable: Vec<bool>,
     /// The function that produces the message when the close icon was pressed.
     on_close: Option<Rc<dyn Fn(usize) -> Message>>,
     /// The width of the [`TabBar`](TabBar).
@@ -67,6 +69,8 @@ pub struct TabBar<Message> {
     padding: u16,
     /// The spacing of the tabs of the [`TabBar`](TabBar).
     spacing: u16,
+    /// Toggles the horizontal scrolling of the tabs of the [`TabBar`](TabBar).
+    scrollable: bool,
     /// The style of the [`TabBar`](TabBar).
     style: Box<dyn StyleSheet>,
 }
@@ -100,6 +104,7 @@ impl<Message> TabBar<Message> {
     {
         Self {
             active_tab,
+            closable: vec![true; tab_labels.len()],
             tab_labels,
             on_select: Rc::new(on_select),
             on_close: None,
@@ -111,6 +116,7 @@ impl<Message> TabBar<Message> {
             close_size: DEFAULT_CLOSE_SIZE,
             padding: DEFAULT_PADDING,
             spacing: DEFAULT_SPACING,
+            scrollable: false,
             style: Default::default(),
         }
     }
@@ -198,9 +204,29 @@ impl<Message> TabBar<Message> {
         self
     }
 
+    /// Enables the horizontal scrolling of the tabs of the [`TabBar`](TabBar)
+    /// if they overflow the available width.
+    pub fn scrollable(mut self, scrollable: bool) -> Self {
+        self.scrollable = scrollable;
+        self
+    }
+
     /// Pushes a [`TabLabel`](tab_label::TabLabel) to the [`TabBar`](TabBar).
     pub fn push(mut self, tab_label: TabLabel) -> Self {
         self.tab_labels.push(tab_label);
+        self.closable.push(true);
+        self
+    }
+
+    /// Sets whether the most recently pushed tab of the [`TabBar`](TabBar)
+    /// can be closed.
+    ///
+    /// All tabs are closable by default. The close icon is only drawn if an
+    /// [`on_close`](TabBar::on_close) function is set.
+    pub fn closable(mut self, closable: bool) -> Self {
+        if let Some(last) = self.closable.last_mut() {
+            *last = closable;
+        }
         self
     }
 }
@@ -236,7 +262,7 @@ where
                 style
             };
 
-            let close = self.on_close.clone().map(|on_close| {
+            let close = self.on_close.clone().filter(|_| self.closable[i]).map(|on_close| {
                 use dodrio::bumpalo::collections::String;
                 let event_bus = bus.clone();
                 div(bump)
@@ -251,7 +277,9 @@ where
                             css::color(style.icon_color)
                         ).into_bump_str(),
                     )
-                    .on("click", move |_root, _vdom, _event| {
+                    .on("click", move |_root, _vdom, event| {
+                        // Closing a tab must not select it as well.
+                        event.stop_propagation();
                         event_bus.publish((on_close)(i))
                     })
                     .child(text(String::from_str_in(r#"×"#, bump).into_bump_str()))
@@ -322,7 +350,7 @@ where
                 bumpalo::format!(
                     in bump,
                     "background: {}; border-style: solid; border-color: {}; border-width: {}px; \
-                    width: {}; height: {}; maximum-height: {}px",
+                    width: {}; height: {}; maximum-height: {}px; overflow-x: {}",
                     background,
                     style.border_color.map_or_else(
                         || String::from("none"),
@@ -331,7 +359,8 @@ where
                     style.border_width,
                     css::length(self.width),
                     css::length(self.height),
-                    self.max_height
+                    self.max_height,
+                    if self.scrollable { "auto" } else { "visible" }
                 )
                 .into_bump_str(),
             )
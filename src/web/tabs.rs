@@ -141,6 +141,13 @@ impl<'a, Message> Tabs<'a, Message> {
         self
     }
 
+    /// Enables the horizontal scrolling of the tabs of the
+    /// [`TabBar`](super::tab_bar::TabBar) if they overflow the available width.
+    pub fn tab_bar_scrollable(mut self, scrollable: bool) -> Self {
+        self.tab_bar = self.tab_bar.scrollable(scrollable);
+        self
+    }
+
     /// Sets the text size of the [`TabLabel`](super::tab_bar::TabLabel) of the
     /// [`TabBar`](super::tab_bar::TabBar).
     pub fn text_size(mut self, text_size: u16) -> Self {
@@ -195,6 +202,16 @@ impl<'a, Message> Tabs<'a, Message> {
         self.tabs.push(element.into());
         self
     }
+
+    /// Sets whether the most recently pushed tab of the [`Tabs`](Tabs) can be
+    /// closed.
+    ///
+    /// All tabs are closable by default. The close icon is only drawn if an
+    /// [`on_close`](Tabs::on_close) function is set.
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.tab_bar = self.tab_bar.closable(closable);
+        self
+    }
 }
 
 impl<'a, Message> Widget<Message> for Tabs<'a, Message>
@@ -91,6 +91,18 @@ impl<'a, Message> TimePicker<'a, Message> {
         self
     }
 
+    /// Sets the function that produces a message when the user changes the
+    /// time of the [`TimePicker`](TimePicker) before submitting it.
+    ///
+    /// The function will be ignored on the web, since the time input submits
+    /// every change.
+    pub fn on_change<F>(self, _on_change: F) -> Self
+    where
+        F: 'static + Fn(Time) -> Message,
+    {
+        self
+    }
+
     /// Sets the style of the [`TimePicker`](TimePicker).
     ///
     /// The style will be ignored on the web, since the time input can't be styled.